@@ -1,11 +1,15 @@
 //!
 //! # Methods for astronomy
 //!
+//! Sky coordinates, radiative balance of planets, and orbit determination: the two-body propagation of
+//! states, their Keplerian elements, Gauss's angles-only initial orbit and its least squares refinement.
+//!
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use std::f64::consts::{     // Using std lib constants
-    PI                      // Pi
+    PI,                     // Pi
+    TAU                     // Full turn
 };
 
 use std::fmt::{             // Formatter display
@@ -13,6 +17,12 @@ use std::fmt::{             // Formatter display
     Result as DRes          // The associated result
 };
 
+use crate::coordinate::cartesian::Cartesian;    // Positions and velocities
+
+use crate::precision::Precision;                // Precision policy
+
+use crate::diagnostics;                         // Iteration reports
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Radec coordinate system
@@ -36,9 +46,8 @@ impl Display for Radec {
         write!(f, "ra={}° :: dec={}°", self.ra.to_degrees(), self.dec.to_degrees())?;
 
         // If there is a distance, we print it as well
-        match self.dist_earth {
-            Some(d) => write!(f, " :: distance={}ly", d)?,
-            None => {}
+        if let Some(d) = self.dist_earth {
+            write!(f, " :: distance={}ly", d)?;
         }
         Ok(())
     }
@@ -169,3 +178,431 @@ pub fn received_energy(luminosity: f64, distance: f64, surface: f64) -> f64 {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Reference tolerance of Kepler's equation
+///
+/// Relative tolerance on the universal anomaly of the two-body propagation.
+const KEPLER_PRECISION: f64 = 1.0e-14;
+
+/// # Iteration cap of Kepler's equation
+const KEPLER_ITERATIONS: usize = 100;
+
+/// # Reference tolerance of the differential correction
+///
+/// Relative size of the last correction to the state, below which the orbit is converged.
+const CORRECTION_PRECISION: f64 = 1.0e-12;
+
+/// # Iteration cap of the differential correction
+const CORRECTION_ITERATIONS: usize = 50;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Two-body state
+///
+/// Position and velocity of a body around a central mass, in any inertial frame centered on it and in
+/// consistent units, the gravitational parameter `mu = G M` being given in the same units.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct State {
+    /// Position relative to the central body
+    pub position: Cartesian,
+    /// Velocity relative to the central body
+    pub velocity: Cartesian
+}
+
+/// # Keplerian orbital elements
+///
+/// Angles in radians, measured in the reference frame of the state. The semi-major axis is negative for
+/// hyperbolic orbits. For circular orbits the periapsis is taken at the ascending node, and for equatorial
+/// orbits the node is taken along the `x` axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Elements {
+    /// Semi-major axis
+    pub semi_major_axis: f64,
+    /// Eccentricity
+    pub eccentricity: f64,
+    /// Inclination on the `xy` plane
+    pub inclination: f64,
+    /// Longitude of the ascending node, from the `x` axis
+    pub ascending_node: f64,
+    /// Argument of periapsis, from the ascending node
+    pub periapsis: f64,
+    /// True anomaly, from the periapsis
+    pub true_anomaly: f64
+}
+
+/// Implementing required methods
+impl State {
+    /// # Two-body propagation
+    ///
+    /// State after the time `dt` (negative to go back), around a central body of gravitational parameter
+    /// `mu`. Kepler's equation is solved in universal variables, valid for all the conic sections.
+    ///
+    /// ```
+    /// # use std::f64::consts::TAU;
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::astronomy::State;
+    /// // Elliptic orbit, of semi-major axis 4/3 for mu = 1: back to the start after a period
+    /// let s = State { position: Cartesian::from(1.0, 0.0, 0.0), velocity: Cartesian::from(0.0, 1.1, 0.2) };
+    /// let period: f64 = TAU * (4.0f64 / 3.0).powf(1.5);
+    /// let res = s.propagate(1.0, period);
+    ///
+    /// assert!((res.position - s.position).norm() < 1.0e-12);
+    /// assert!((s.propagate(1.0, 3.7).propagate(1.0, -3.7).velocity - s.velocity).norm() < 1.0e-13);
+    /// ```
+    pub fn propagate(&self, mu: f64, dt: f64) -> Self {
+
+        let prec: Precision = Precision::current();                 // Precision policy
+        let tol: f64 = prec.tolerance(KEPLER_PRECISION);            // Convergence tolerance
+        let max_iter: usize = prec.iterations(KEPLER_ITERATIONS);   // Iteration cap
+
+        let (r0, v0): (Cartesian, Cartesian) = (self.position, self.velocity);
+        let (r0n, sqmu): (f64, f64) = (r0.norm(), mu.sqrt());
+        let vr0: f64 = r0.dot(&v0) / r0n;
+        let alpha: f64 = 2.0 / r0n - v0.dot(&v0) / mu;
+
+        // Newton iterations on the universal anomaly
+        let mut chi: f64 = sqmu * alpha.abs() * dt;
+        for it in 0..max_iter {
+            let z: f64 = alpha * chi * chi;
+            let (c, s): (f64, f64) = stumpff(z);
+            let f: f64 = r0n * vr0 / sqmu * chi * chi * c + (1.0 - alpha * r0n) * chi.powi(3) * s + r0n * chi
+                - sqmu * dt;
+            let df: f64 = r0n * vr0 / sqmu * chi * (1.0 - z * s) + (1.0 - alpha * r0n) * chi * chi * c + r0n;
+            let step: f64 = f / df;
+            chi -= step;
+            let residual: f64 = step.abs() / (1.0 + chi.abs());
+            diagnostics::step("astronomy::propagate", it, residual, step.abs());
+            if residual < tol || it + 1 == max_iter {
+                diagnostics::finish("astronomy::propagate", it + 1, residual, tol);
+                break;
+            }
+        }
+
+        // Lagrange coefficients
+        let z: f64 = alpha * chi * chi;
+        let (c, s): (f64, f64) = stumpff(z);
+        let f: f64 = 1.0 - chi * chi / r0n * c;
+        let g: f64 = dt - chi.powi(3) * s / sqmu;
+        let position: Cartesian = r0 * f + v0 * g;
+        let rn: f64 = position.norm();
+        let df: f64 = sqmu / (rn * r0n) * (alpha * chi.powi(3) * s - chi);
+        let dg: f64 = 1.0 - chi * chi / rn * c;
+
+        Self { position, velocity: r0 * df + v0 * dg }
+    }
+
+    /// # Orbital elements
+    ///
+    /// Keplerian elements of the osculating orbit, around a central body of gravitational parameter `mu`.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::astronomy::State;
+    /// // Periapsis of an orbit inclined by 45° along the x axis
+    /// let v: f64 = 1.2 / 2f64.sqrt();
+    /// let s = State { position: Cartesian::from(1.0, 0.0, 0.0), velocity: Cartesian::from(0.0, v, v) };
+    /// let el = s.elements(1.0);
+    ///
+    /// assert!((el.semi_major_axis - 1.0 / (2.0 - 1.44)).abs() < 1.0e-14);
+    /// assert!((el.eccentricity - 0.44).abs() < 1.0e-14);
+    /// assert!((el.inclination - std::f64::consts::FRAC_PI_4).abs() < 1.0e-14);
+    /// assert!(el.ascending_node.abs() < 1.0e-14 && el.periapsis.abs() < 1.0e-14 && el.true_anomaly.abs() < 1.0e-14);
+    /// ```
+    pub fn elements(&self, mu: f64) -> Elements {
+
+        let (r, v): (Cartesian, Cartesian) = (self.position, self.velocity);
+        let rn: f64 = r.norm();
+        let h: Cartesian = r.cross(&v);
+        let node: Cartesian = Cartesian::from(-h.y, h.x, 0.0);
+        let ecc: Cartesian = (r * (v.dot(&v) - mu / rn) - v * r.dot(&v)) / mu;
+        let e: f64 = ecc.norm();
+
+        // Angle from a to b, in [0, 2 pi), the orientation given by the angular momentum
+        let angle = |a: Cartesian, b: Cartesian| -> f64 {
+            (a.cross(&b).dot(&h) / h.norm()).atan2(a.dot(&b)).rem_euclid(TAU)
+        };
+        let small: f64 = 1.0e-12;
+        let reference: Cartesian = if node.norm() > small * h.norm() { node } else { Cartesian::from(1.0, 0.0, 0.0) };
+        let periapsis: Cartesian = if e > small { ecc } else { reference };
+
+        Elements {
+            semi_major_axis: 1.0 / (2.0 / rn - v.dot(&v) / mu),
+            eccentricity: e,
+            inclination: (h.z / h.norm()).acos(),
+            ascending_node: if node.norm() > small * h.norm() { node.y.atan2(node.x).rem_euclid(TAU) } else { 0.0 },
+            periapsis: if e > small { angle(reference, ecc) } else { 0.0 },
+            true_anomaly: angle(periapsis, r)
+        }
+    }
+}
+
+/// # Astrometric observation
+///
+/// Direction of a body seen from an observing site at a given time, the site being given by its position
+/// relative to the central body, in the frame and units of the orbit. Light-time and aberration are not
+/// modelled: the directions must be geometric, or the times corrected beforehand.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Observation {
+    /// Time of the observation
+    pub time: f64,
+    /// Observed right ascension and declination
+    pub direction: Radec,
+    /// Position of the observer relative to the central body
+    pub site: Cartesian
+}
+
+/// # Gauss's method of initial orbit determination
+///
+/// Finds the state of a body at the time of the second of three angles-only `observations`, around a central
+/// body of gravitational parameter `mu`. The distance at the middle observation is a root of Gauss's
+/// polynomial of degree 8, and the velocity follows from the series of the Lagrange coefficients, which
+/// require the observations to span a small arc of the orbit. When several roots give positive distances to
+/// the three observations, the largest is kept. Returns `None` if no root is found, or for coplanar lines of
+/// sight.
+///
+/// The result is a first approximation, to be improved with `refine_orbit`.
+///
+/// ```
+/// # use scilib::coordinate::cartesian::Cartesian;
+/// # use scilib::astronomy::{ Observation, Radec, State, gauss_orbit };
+/// // Heliocentric units of AU and days, an asteroid seen from the Earth on a circular orbit
+/// let mu: f64 = 2.9591220828559115e-4;
+/// let earth = State { position: Cartesian::from(1.0, 0.0, 0.0), velocity: Cartesian::from(0.0, mu.sqrt(), 0.0) };
+/// let body = State { position: Cartesian::from(0.5, 2.2, 0.3), velocity: Cartesian::from(-0.0105, 0.002, 0.001) };
+///
+/// let observe = |t: f64| {
+///     let site: Cartesian = earth.propagate(mu, t).position;
+///     let d: Cartesian = body.propagate(mu, t).position - site;
+///     Observation { time: t, direction: Radec::from_rad(d.y.atan2(d.x), (d.z / d.norm()).asin()), site }
+/// };
+///
+/// let res = gauss_orbit(&[observe(-5.0), observe(0.0), observe(5.0)], mu).unwrap();
+/// assert!((res.position - body.position).norm() < 1.0e-3);
+/// assert!((res.velocity - body.velocity).norm() < 1.0e-5);
+/// ```
+pub fn gauss_orbit(observations: &[Observation; 3], mu: f64) -> Option<State> {
+
+    let rho: Vec<Cartesian> = observations.iter().map(|o| direction(&o.direction)).collect();
+    let site: Vec<Cartesian> = observations.iter().map(|o| o.site).collect();
+    let tau1: f64 = observations[0].time - observations[1].time;
+    let tau3: f64 = observations[2].time - observations[1].time;
+    let tau: f64 = tau3 - tau1;
+
+    let p: [Cartesian; 3] = [rho[1].cross(&rho[2]), rho[0].cross(&rho[2]), rho[0].cross(&rho[1])];
+    let d0: f64 = rho[0].dot(&p[0]);
+    if d0.abs() < 1.0e-14 {
+        return None;
+    }
+    let d = |i: usize, j: usize| site[i].dot(&p[j]);
+
+    let a: f64 = (-d(0, 1) * tau3 / tau + d(1, 1) + d(2, 1) * tau1 / tau) / d0;
+    let b: f64 = (d(0, 1) * (tau3 * tau3 - tau * tau) * tau3 / tau + d(2, 1) * (tau * tau - tau1 * tau1) * tau1 / tau)
+        / (6.0 * d0);
+    let e: f64 = site[1].dot(&rho[1]);
+    let r2: f64 = site[1].dot(&site[1]);
+
+    // Gauss's polynomial x^8 + c6 x^6 + c3 x^3 + c0, its positive roots bracketed on a geometric grid
+    let (c6, c3, c0): (f64, f64, f64) = (-(a * a + 2.0 * a * e + r2), -2.0 * mu * b * (a + e), -(mu * b).powi(2));
+    let poly = |x: f64| ((x * x + c6) * x * x * x + c3) * x * x * x + c0;
+    let scale: f64 = r2.sqrt().max(f64::MIN_POSITIVE);
+
+    let ranges = |x: f64| -> [f64; 3] {
+        let x3: f64 = x.powi(3);
+        [
+            ((6.0 * (d(2, 0) * tau1 / tau3 + d(1, 0) * tau / tau3) * x3 + mu * d(2, 0) * (tau * tau - tau1 * tau1)
+                * tau1 / tau3) / (6.0 * x3 + mu * (tau * tau - tau3 * tau3)) - d(0, 0)) / d0,
+            a + mu * b / x3,
+            ((6.0 * (d(0, 2) * tau3 / tau1 - d(1, 2) * tau / tau1) * x3 + mu * d(0, 2) * (tau * tau - tau3 * tau3)
+                * tau3 / tau1) / (6.0 * x3 + mu * (tau * tau - tau1 * tau1)) - d(2, 2)) / d0
+        ]
+    };
+
+    let mut best: Option<f64> = None;
+    let steps: usize = 4000;
+    let point = |k: usize| scale * 1.0e-3 * 1.0e7f64.powf(k as f64 / steps as f64);
+    for k in 0..steps {
+        let (mut lo, mut hi): (f64, f64) = (point(k), point(k + 1));
+        if poly(lo).signum() == poly(hi).signum() {
+            continue;
+        }
+        for _ in 0..200 {
+            let mid: f64 = 0.5 * (lo + hi);
+            if poly(mid).signum() == poly(lo).signum() { lo = mid } else { hi = mid }
+        }
+        let root: f64 = 0.5 * (lo + hi);
+        if ranges(root).iter().all(|&r| r > 0.0) {
+            best = Some(root);
+        }
+    }
+    let x: f64 = best?;
+
+    let dist: [f64; 3] = ranges(x);
+    let r: Vec<Cartesian> = (0..3).map(|i| site[i] + rho[i] * dist[i]).collect();
+
+    // Lagrange coefficients, to the first terms of their series
+    let x3: f64 = x.powi(3);
+    let (f1, g1): (f64, f64) = (1.0 - 0.5 * mu * tau1 * tau1 / x3, tau1 - mu * tau1.powi(3) / (6.0 * x3));
+    let (f3, g3): (f64, f64) = (1.0 - 0.5 * mu * tau3 * tau3 / x3, tau3 - mu * tau3.powi(3) / (6.0 * x3));
+    let velocity: Cartesian = (r[2] * f1 - r[0] * f3) / (f1 * g3 - f3 * g1);
+
+    Some(State { position: r[1], velocity })
+}
+
+/// # Differential correction of an orbit
+///
+/// Improves the `state` at the time `epoch`, such as found by `gauss_orbit`, by fitting the two-body motion
+/// to all the `observations` in the least squares sense: the residuals are the differences of right
+/// ascension (scaled by the cosine of the declination) and of declination, and the six components of the
+/// state are corrected by Gauss-Newton steps, with a numerical Jacobian. A step increasing the residuals is
+/// halved. At least three observations are needed.
+///
+/// ```
+/// # use scilib::coordinate::cartesian::Cartesian;
+/// # use scilib::astronomy::{ Observation, Radec, State, gauss_orbit, refine_orbit };
+/// let mu: f64 = 2.9591220828559115e-4;
+/// let earth = State { position: Cartesian::from(1.0, 0.0, 0.0), velocity: Cartesian::from(0.0, mu.sqrt(), 0.0) };
+/// let body = State { position: Cartesian::from(0.5, 2.2, 0.3), velocity: Cartesian::from(-0.0105, 0.002, 0.001) };
+///
+/// let observe = |t: f64| {
+///     let site: Cartesian = earth.propagate(mu, t).position;
+///     let d: Cartesian = body.propagate(mu, t).position - site;
+///     Observation { time: t, direction: Radec::from_rad(d.y.atan2(d.x), (d.z / d.norm()).asin()), site }
+/// };
+/// let obs: Vec<Observation> = [-20.0, -5.0, 0.0, 5.0, 12.0, 30.0].iter().map(|&t| observe(t)).collect();
+///
+/// let first = gauss_orbit(&[obs[1], obs[2], obs[3]], mu).unwrap();
+/// let res = refine_orbit(first, 0.0, &obs, mu);
+/// assert!((res.position - body.position).norm() < 1.0e-9);
+/// assert!((res.velocity - body.velocity).norm() < 1.0e-11);
+///
+/// let el = res.elements(mu);
+/// assert!((el.semi_major_axis - body.elements(mu).semi_major_axis).abs() < 1.0e-8);
+/// ```
+pub fn refine_orbit(state: State, epoch: f64, observations: &[Observation], mu: f64) -> State {
+
+    assert!(observations.len() >= 3, "At least three observations are needed to fit an orbit");
+
+    let prec: Precision = Precision::current();                     // Precision policy
+    let tol: f64 = prec.tolerance(CORRECTION_PRECISION);            // Convergence tolerance
+    let max_iter: usize = prec.iterations(CORRECTION_ITERATIONS);   // Iteration cap
+
+    let pack = |s: &State| [s.position.x, s.position.y, s.position.z, s.velocity.x, s.velocity.y, s.velocity.z];
+    let unpack = |p: &[f64; 6]| State {
+        position: Cartesian::from(p[0], p[1], p[2]),
+        velocity: Cartesian::from(p[3], p[4], p[5])
+    };
+    let residuals = |p: &[f64; 6]| -> Vec<f64> {
+        let s: State = unpack(p);
+        observations.iter().flat_map(|o| {
+            let d: Cartesian = s.propagate(mu, o.time - epoch).position - o.site;
+            let (ra, dec): (f64, f64) = (d.y.atan2(d.x), (d.z / d.norm()).asin());
+            let dra: f64 = (o.direction.ra - ra + PI).rem_euclid(TAU) - PI;
+            [dra * o.direction.dec.cos(), o.direction.dec - dec]
+        }).collect()
+    };
+    let cost = |res: &[f64]| res.iter().map(|r| r * r).sum::<f64>();
+
+    let mut x: [f64; 6] = pack(&state);
+    let mut res: Vec<f64> = residuals(&x);
+    for it in 0..max_iter {
+
+        // Numerical Jacobian, with steps relative to the position and velocity scales
+        let scales: [f64; 2] = [state.position.norm(), state.velocity.norm()];
+        let jac: Vec<Vec<f64>> = (0..6).map(|k| {
+            let h: f64 = 1.0e-7 * scales[k / 3].max(f64::MIN_POSITIVE);
+            let (mut up, mut down): ([f64; 6], [f64; 6]) = (x, x);
+            up[k] += h;
+            down[k] -= h;
+            residuals(&up).iter().zip(residuals(&down)).map(|(a, b)| (a - b) / (2.0 * h)).collect()
+        }).collect();
+
+        // Normal equations
+        let mut mat: Vec<Vec<f64>> = (0..6).map(|i| {
+            let mut row: Vec<f64> = (0..6).map(|j| jac[i].iter().zip(&jac[j]).map(|(a, b)| a * b).sum()).collect();
+            row.push(-jac[i].iter().zip(&res).map(|(a, r)| a * r).sum::<f64>());
+            row
+        }).collect();
+        let dx: [f64; 6] = match solve6(&mut mat) {
+            Some(dx) => dx,
+            None => break
+        };
+
+        // Halving the step until the residuals decrease
+        let mut factor: f64 = 1.0;
+        let mut next: [f64; 6] = x;
+        let mut next_res: Vec<f64> = res.clone();
+        for _ in 0..30 {
+            for k in 0..6 {
+                next[k] = x[k] + factor * dx[k];
+            }
+            next_res = residuals(&next);
+            if cost(&next_res) <= cost(&res) {
+                break;
+            }
+            factor *= 0.5;
+        }
+
+        let size: f64 = (0..6).map(|k| (factor * dx[k] / scales[k / 3]).powi(2)).sum::<f64>().sqrt();
+        x = next;
+        res = next_res;
+        diagnostics::step("astronomy::refine_orbit", it, size, cost(&res).sqrt());
+        if size < tol || it + 1 == max_iter {
+            diagnostics::finish("astronomy::refine_orbit", it + 1, size, tol);
+            break;
+        }
+    }
+
+    unpack(&x)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Stumpff functions `C(z)` and `S(z)`, from their series near zero
+fn stumpff(z: f64) -> (f64, f64) {
+    if z.abs() < 1.0e-3 {
+        (
+            0.5 - z / 24.0 + z * z / 720.0 - z.powi(3) / 40320.0,
+            1.0 / 6.0 - z / 120.0 + z * z / 5040.0 - z.powi(3) / 362880.0
+        )
+    } else if z > 0.0 {
+        let s: f64 = z.sqrt();
+        ((1.0 - s.cos()) / z, (s - s.sin()) / s.powi(3))
+    } else {
+        let s: f64 = (-z).sqrt();
+        ((s.cosh() - 1.0) / -z, (s.sinh() - s) / s.powi(3))
+    }
+}
+
+/// Unit vector pointing along a direction of the sky
+fn direction(radec: &Radec) -> Cartesian {
+    Cartesian::from(radec.dec.cos() * radec.ra.cos(), radec.dec.cos() * radec.ra.sin(), radec.dec.sin())
+}
+
+/// Solves the 6 x 6 linear system given as an augmented matrix, with partial pivoting
+fn solve6(mat: &mut [Vec<f64>]) -> Option<[f64; 6]> {
+
+    for col in 0..6 {
+        let pivot: usize = (col..6).max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs()))?;
+        if mat[pivot][col] == 0.0 {
+            return None;
+        }
+        mat.swap(col, pivot);
+        for row in col + 1..6 {
+            let ratio: f64 = mat[row][col] / mat[col][col];
+            let pivot_row: Vec<f64> = mat[col].clone();
+            for (v, p) in mat[row].iter_mut().zip(&pivot_row).skip(col) {
+                *v -= ratio * p;
+            }
+        }
+    }
+
+    let mut x: [f64; 6] = [0.0; 6];
+    for row in (0..6).rev() {
+        let sum: f64 = (row + 1..6).map(|k| mat[row][k] * x[k]).sum();
+        x[row] = (mat[row][6] - sum) / mat[row][row];
+    }
+
+    Some(x)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
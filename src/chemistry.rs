@@ -0,0 +1,318 @@
+//!
+//! # Chemistry
+//!
+//! Periodic table data and chemical formula handling.
+//!
+//! The element database provides, for each of the 118 elements, its symbol, name, atomic number, standard
+//! atomic weight (IUPAC abridged values, in g/mol) and first ionization energy (NIST, in eV). For elements
+//! without stable isotopes, the mass number of the longest-lived known isotope is given instead, and the
+//! ionization energy is left empty when it has not been measured.
+//!
+//! ```
+//! # use scilib::chemistry::*;
+//! let fe = element("Fe").unwrap();
+//! assert_eq!(fe.number, 26);
+//!
+//! // Hematite
+//! let m = molar_mass("Fe2O3").unwrap();
+//! assert!((m - 159.687).abs() < 1.0e-10);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Chemical element
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Element {
+    /// Atomic number Z
+    pub number: u8,
+    /// Chemical symbol
+    pub symbol: &'static str,
+    /// English name (IUPAC spelling)
+    pub name: &'static str,
+    /// Standard atomic weight, in g/mol
+    pub mass: f64,
+    /// First ionization energy, in eV
+    pub ionization: Option<f64>
+}
+
+/// Shorthand constructor for the table
+const fn el(number: u8, symbol: &'static str, name: &'static str, mass: f64, ionization: Option<f64>) -> Element {
+    Element { number, symbol, name, mass, ionization }
+}
+
+/// # Periodic table
+///
+/// Elements sorted by atomic number, so that `ELEMENTS[z - 1]` is the element of atomic number `z`.
+pub const ELEMENTS: [Element; 118] = [
+    el(1,   "H",  "Hydrogen",      1.008,         Some(13.598434)),
+    el(2,   "He", "Helium",        4.002602,      Some(24.587389)),
+    el(3,   "Li", "Lithium",       6.94,          Some(5.391715)),
+    el(4,   "Be", "Beryllium",     9.0121831,     Some(9.322699)),
+    el(5,   "B",  "Boron",         10.81,         Some(8.298019)),
+    el(6,   "C",  "Carbon",        12.011,        Some(11.260288)),
+    el(7,   "N",  "Nitrogen",      14.007,        Some(14.534130)),
+    el(8,   "O",  "Oxygen",        15.999,        Some(13.618055)),
+    el(9,   "F",  "Fluorine",      18.998403163,  Some(17.42282)),
+    el(10,  "Ne", "Neon",          20.1797,       Some(21.564541)),
+    el(11,  "Na", "Sodium",        22.98976928,   Some(5.139077)),
+    el(12,  "Mg", "Magnesium",     24.305,        Some(7.646236)),
+    el(13,  "Al", "Aluminium",     26.9815385,    Some(5.985769)),
+    el(14,  "Si", "Silicon",       28.085,        Some(8.151683)),
+    el(15,  "P",  "Phosphorus",    30.973761998,  Some(10.486686)),
+    el(16,  "S",  "Sulfur",        32.06,         Some(10.36001)),
+    el(17,  "Cl", "Chlorine",      35.45,         Some(12.967633)),
+    el(18,  "Ar", "Argon",         39.948,        Some(15.759611)),
+    el(19,  "K",  "Potassium",     39.0983,       Some(4.340663)),
+    el(20,  "Ca", "Calcium",       40.078,        Some(6.113155)),
+    el(21,  "Sc", "Scandium",      44.955908,     Some(6.56149)),
+    el(22,  "Ti", "Titanium",      47.867,        Some(6.82812)),
+    el(23,  "V",  "Vanadium",      50.9415,       Some(6.746187)),
+    el(24,  "Cr", "Chromium",      51.9961,       Some(6.76651)),
+    el(25,  "Mn", "Manganese",     54.938044,     Some(7.434038)),
+    el(26,  "Fe", "Iron",          55.845,        Some(7.9024681)),
+    el(27,  "Co", "Cobalt",        58.933194,     Some(7.88101)),
+    el(28,  "Ni", "Nickel",        58.6934,       Some(7.639878)),
+    el(29,  "Cu", "Copper",        63.546,        Some(7.72638)),
+    el(30,  "Zn", "Zinc",          65.38,         Some(9.394197)),
+    el(31,  "Ga", "Gallium",       69.723,        Some(5.999302)),
+    el(32,  "Ge", "Germanium",     72.630,        Some(7.899435)),
+    el(33,  "As", "Arsenic",       74.921595,     Some(9.78855)),
+    el(34,  "Se", "Selenium",      78.971,        Some(9.752392)),
+    el(35,  "Br", "Bromine",       79.904,        Some(11.81381)),
+    el(36,  "Kr", "Krypton",       83.798,        Some(13.9996055)),
+    el(37,  "Rb", "Rubidium",      85.4678,       Some(4.177128)),
+    el(38,  "Sr", "Strontium",     87.62,         Some(5.69486745)),
+    el(39,  "Y",  "Yttrium",       88.90584,      Some(6.21726)),
+    el(40,  "Zr", "Zirconium",     91.224,        Some(6.634126)),
+    el(41,  "Nb", "Niobium",       92.90637,      Some(6.75885)),
+    el(42,  "Mo", "Molybdenum",    95.95,         Some(7.09243)),
+    el(43,  "Tc", "Technetium",    98.0,          Some(7.11938)),
+    el(44,  "Ru", "Ruthenium",     101.07,        Some(7.3605)),
+    el(45,  "Rh", "Rhodium",       102.9055,      Some(7.4589)),
+    el(46,  "Pd", "Palladium",     106.42,        Some(8.336839)),
+    el(47,  "Ag", "Silver",        107.8682,      Some(7.576234)),
+    el(48,  "Cd", "Cadmium",       112.414,       Some(8.99382)),
+    el(49,  "In", "Indium",        114.818,       Some(5.7863554)),
+    el(50,  "Sn", "Tin",           118.710,       Some(7.343918)),
+    el(51,  "Sb", "Antimony",      121.760,       Some(8.608389)),
+    el(52,  "Te", "Tellurium",     127.60,        Some(9.009808)),
+    el(53,  "I",  "Iodine",        126.90447,     Some(10.45126)),
+    el(54,  "Xe", "Xenon",         131.293,       Some(12.1298437)),
+    el(55,  "Cs", "Caesium",       132.90545196,  Some(3.893905695)),
+    el(56,  "Ba", "Barium",        137.327,       Some(5.2116646)),
+    el(57,  "La", "Lanthanum",     138.90547,     Some(5.5769)),
+    el(58,  "Ce", "Cerium",        140.116,       Some(5.5386)),
+    el(59,  "Pr", "Praseodymium",  140.90766,     Some(5.4702)),
+    el(60,  "Nd", "Neodymium",     144.242,       Some(5.525)),
+    el(61,  "Pm", "Promethium",    145.0,         Some(5.577)),
+    el(62,  "Sm", "Samarium",      150.36,        Some(5.64371)),
+    el(63,  "Eu", "Europium",      151.964,       Some(5.670385)),
+    el(64,  "Gd", "Gadolinium",    157.25,        Some(6.1498)),
+    el(65,  "Tb", "Terbium",       158.92535,     Some(5.8638)),
+    el(66,  "Dy", "Dysprosium",    162.500,       Some(5.93905)),
+    el(67,  "Ho", "Holmium",       164.93033,     Some(6.0215)),
+    el(68,  "Er", "Erbium",        167.259,       Some(6.1077)),
+    el(69,  "Tm", "Thulium",       168.93422,     Some(6.18431)),
+    el(70,  "Yb", "Ytterbium",     173.045,       Some(6.25416)),
+    el(71,  "Lu", "Lutetium",      174.9668,      Some(5.425871)),
+    el(72,  "Hf", "Hafnium",       178.49,        Some(6.82507)),
+    el(73,  "Ta", "Tantalum",      180.94788,     Some(7.549571)),
+    el(74,  "W",  "Tungsten",      183.84,        Some(7.86403)),
+    el(75,  "Re", "Rhenium",       186.207,       Some(7.83352)),
+    el(76,  "Os", "Osmium",        190.23,        Some(8.43823)),
+    el(77,  "Ir", "Iridium",       192.217,       Some(8.96702)),
+    el(78,  "Pt", "Platinum",      195.084,       Some(8.95883)),
+    el(79,  "Au", "Gold",          196.966569,    Some(9.225554)),
+    el(80,  "Hg", "Mercury",       200.592,       Some(10.437504)),
+    el(81,  "Tl", "Thallium",      204.38,        Some(6.1082873)),
+    el(82,  "Pb", "Lead",          207.2,         Some(7.4166799)),
+    el(83,  "Bi", "Bismuth",       208.9804,      Some(7.285516)),
+    el(84,  "Po", "Polonium",      209.0,         Some(8.414)),
+    el(85,  "At", "Astatine",      210.0,         Some(9.31751)),
+    el(86,  "Rn", "Radon",         222.0,         Some(10.7485)),
+    el(87,  "Fr", "Francium",      223.0,         Some(4.0727411)),
+    el(88,  "Ra", "Radium",        226.0,         Some(5.2784239)),
+    el(89,  "Ac", "Actinium",      227.0,         Some(5.380226)),
+    el(90,  "Th", "Thorium",       232.0377,      Some(6.3067)),
+    el(91,  "Pa", "Protactinium",  231.03588,     Some(5.89)),
+    el(92,  "U",  "Uranium",       238.02891,     Some(6.19405)),
+    el(93,  "Np", "Neptunium",     237.0,         Some(6.2655)),
+    el(94,  "Pu", "Plutonium",     244.0,         Some(6.0258)),
+    el(95,  "Am", "Americium",     243.0,         Some(5.9738)),
+    el(96,  "Cm", "Curium",        247.0,         Some(5.9914)),
+    el(97,  "Bk", "Berkelium",     247.0,         Some(6.1978)),
+    el(98,  "Cf", "Californium",   251.0,         Some(6.2817)),
+    el(99,  "Es", "Einsteinium",   252.0,         Some(6.3676)),
+    el(100, "Fm", "Fermium",       257.0,         Some(6.5)),
+    el(101, "Md", "Mendelevium",   258.0,         Some(6.58)),
+    el(102, "No", "Nobelium",      259.0,         Some(6.62621)),
+    el(103, "Lr", "Lawrencium",    266.0,         Some(4.96)),
+    el(104, "Rf", "Rutherfordium", 267.0,         None),
+    el(105, "Db", "Dubnium",       268.0,         None),
+    el(106, "Sg", "Seaborgium",    269.0,         None),
+    el(107, "Bh", "Bohrium",       270.0,         None),
+    el(108, "Hs", "Hassium",       269.0,         None),
+    el(109, "Mt", "Meitnerium",    278.0,         None),
+    el(110, "Ds", "Darmstadtium",  281.0,         None),
+    el(111, "Rg", "Roentgenium",   282.0,         None),
+    el(112, "Cn", "Copernicium",   285.0,         None),
+    el(113, "Nh", "Nihonium",      286.0,         None),
+    el(114, "Fl", "Flerovium",     289.0,         None),
+    el(115, "Mc", "Moscovium",     290.0,         None),
+    el(116, "Lv", "Livermorium",   293.0,         None),
+    el(117, "Ts", "Tennessine",    294.0,         None),
+    el(118, "Og", "Oganesson",     294.0,         None),
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Element lookup by symbol
+///
+/// The symbol is case-sensitive, as in chemical formulas.
+///
+/// ```
+/// # use scilib::chemistry::element;
+/// let he = element("He").unwrap();
+///
+/// assert_eq!(he.name, "Helium");
+/// assert_eq!(he.ionization, Some(24.587389));
+/// assert!(element("Xx").is_none());
+/// ```
+pub fn element(symbol: &str) -> Option<&'static Element> {
+    ELEMENTS.iter().find(|e| e.symbol == symbol)
+}
+
+/// # Element lookup by atomic number
+///
+/// ```
+/// # use scilib::chemistry::element_z;
+/// assert_eq!(element_z(79).unwrap().symbol, "Au");
+/// assert!(element_z(0).is_none());
+/// ```
+pub fn element_z(z: u8) -> Option<&'static Element> {
+    match z {
+        1..=118 => Some(&ELEMENTS[z as usize - 1]),
+        _ => None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Elemental composition of a formula
+///
+/// Parses a chemical formula and returns the number of atoms of each element, sorted by atomic number.
+/// Returns `None` if the formula is malformed, contains an unknown symbol, or if a count overflows.
+///
+/// The supported syntax covers:
+/// - Element symbols followed by an optional count: `H2O`
+/// - Nested groups with parentheses or brackets: `Ca3(PO4)2`, `K4[Fe(CN)6]`
+/// - Hydrates and adducts, separated by `·`, `.` or `*`, with an optional leading multiplier: `CuSO4·5H2O`
+///
+/// ```
+/// # use scilib::chemistry::composition;
+/// let comp = composition("K4[Fe(CN)6]").unwrap();
+/// let res: Vec<(&str, u32)> = comp.iter().map(|(e, n)| (e.symbol, *n)).collect();
+///
+/// assert_eq!(res, vec![("C", 6), ("N", 6), ("K", 4), ("Fe", 1)]);
+/// assert!(composition("H2O)").is_none());
+/// assert!(composition("(H99999)99999").is_none());
+/// ```
+pub fn composition(formula: &str) -> Option<Vec<(&'static Element, u32)>> {
+
+    let mut counts: [u32; 118] = [0; 118];
+
+    for part in formula.split(['·', '.', '*']) {
+        let chars: Vec<char> = part.trim().chars().collect();
+        let mut pos: usize = 0;
+
+        // Leading multiplier of hydrates
+        let mult: u32 = read_count(&chars, &mut pos)?;
+
+        // Stack of the counts of the currently opened groups
+        let mut stack: Vec<([u32; 118], char)> = vec![([0; 118], ' ')];
+
+        while pos < chars.len() {
+            let c: char = chars[pos];
+            pos += 1;
+
+            if c == '(' || c == '[' {
+                stack.push(([0; 118], if c == '(' { ')' } else { ']' }));
+            } else if c == ')' || c == ']' {
+                let (group, close) = stack.pop()?;
+                if close != c || stack.is_empty() {
+                    return None;
+                }
+                let n: u32 = read_count(&chars, &mut pos)?;
+                let top: &mut [u32; 118] = &mut stack.last_mut()?.0;
+                for (c, g) in top.iter_mut().zip(group) {
+                    *c = c.checked_add(n.checked_mul(g)?)?;
+                }
+            } else if c.is_ascii_uppercase() {
+                let mut sym: String = c.to_string();
+                while pos < chars.len() && chars[pos].is_ascii_lowercase() {
+                    sym.push(chars[pos]);
+                    pos += 1;
+                }
+                let z: usize = element(&sym)?.number as usize;
+                let n: u32 = read_count(&chars, &mut pos)?;
+                let count: &mut u32 = &mut stack.last_mut()?.0[z - 1];
+                *count = count.checked_add(n)?;
+            } else {
+                return None;
+            }
+        }
+
+        // All the groups must be closed
+        if stack.len() != 1 {
+            return None;
+        }
+
+        for (c, n) in counts.iter_mut().zip(stack[0].0) {
+            *c = c.checked_add(mult.checked_mul(n)?)?;
+        }
+    }
+
+    let res: Vec<(&'static Element, u32)> = counts.iter().zip(ELEMENTS.iter())
+        .filter(|(n, _)| **n > 0)
+        .map(|(n, e)| (e, *n))
+        .collect();
+
+    if res.is_empty() { None } else { Some(res) }
+}
+
+/// Reads the count at the current position, 1 if there is none, `None` if it does not fit
+fn read_count(chars: &[char], pos: &mut usize) -> Option<u32> {
+    let start: usize = *pos;
+
+    while *pos < chars.len() && chars[*pos].is_ascii_digit() {
+        *pos += 1;
+    }
+
+    if *pos == start {
+        return Some(1);
+    }
+
+    chars[start..*pos].iter().collect::<String>().parse().ok()
+}
+
+/// # Molar mass of a formula
+///
+/// Computes the molar mass, in g/mol, from the standard atomic weights. Returns `None` if the formula
+/// cannot be parsed, see `composition` for the supported syntax.
+///
+/// ```
+/// # use scilib::chemistry::molar_mass;
+/// let water = molar_mass("H2O").unwrap();
+/// let vitriol = molar_mass("CuSO4·5H2O").unwrap();
+///
+/// assert!((water - 18.015).abs() < 1.0e-10);
+/// assert!((vitriol - 249.677).abs() < 1.0e-10);
+/// assert!(molar_mass("H4294967296").is_none());
+/// ```
+pub fn molar_mass(formula: &str) -> Option<f64> {
+    let comp: Vec<(&'static Element, u32)> = composition(formula)?;
+    Some(comp.iter().fold(0.0, |res, (e, n)| res + e.mass * *n as f64))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
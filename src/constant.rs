@@ -4,6 +4,9 @@
 //! This file contains many physical constants useful for scientific applications.
 //! They are stored as `f64` to guarantee maximum precision, most are castable to `f32`.
 //!
+//! Measured constants come with a `_UNC` counterpart holding their standard uncertainty. Both can be combined
+//! in a [`Measure`](crate::math::measure::Measure) to propagate the uncertainty to derived quantities.
+//!
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Astrophysics and astronomy
@@ -21,6 +24,11 @@ pub const C: f64 = 299_792_458.0;
 /// - Unit: `m3.kg-1.s-2`
 pub const G: f64 = 6.674_30e-11;
 
+/// # Newtonian gravitational constant: standard uncertainty
+///
+/// Standard uncertainty associated to [`G`], in the same unit.
+pub const G_UNC: f64 = 0.000_15e-11;
+
 /// # Standard earth gravity acceleration
 ///
 /// - Value: 9.806 65
@@ -145,6 +153,11 @@ pub const H_BAR: f64 = 1.054_571_817e-34;
 /// - Unit: `m`
 pub const PLANCK_LENGTH: f64 = 1.616_255e-35;
 
+/// # Planck length: standard uncertainty
+///
+/// Standard uncertainty associated to [`PLANCK_LENGTH`], in the same unit.
+pub const PLANCK_LENGTH_UNC: f64 = 0.000_018e-35;
+
 /// # Planck mass
 ///
 /// - Value: 2.176 434×10⁻⁸
@@ -152,6 +165,11 @@ pub const PLANCK_LENGTH: f64 = 1.616_255e-35;
 /// - Unit: `kg`
 pub const PLANCK_MASS: f64 = 2.176_434e-8;
 
+/// # Planck mass: standard uncertainty
+///
+/// Standard uncertainty associated to [`PLANCK_MASS`], in the same unit.
+pub const PLANCK_MASS_UNC: f64 = 0.000_024e-8;
+
 /// # Planck temperature
 ///
 /// - Value: 1.416_784×10³²
@@ -159,6 +177,11 @@ pub const PLANCK_MASS: f64 = 2.176_434e-8;
 /// - Unit: `K`
 pub const PLANCK_TEMP: f64 = 1.416_784e32;
 
+/// # Planck temperature: standard uncertainty
+///
+/// Standard uncertainty associated to [`PLANCK_TEMP`], in the same unit.
+pub const PLANCK_TEMP_UNC: f64 = 0.000_016e32;
+
 /// # Planck time
 ///
 /// - Value: 5.391 247×10⁻⁴⁴
@@ -166,6 +189,11 @@ pub const PLANCK_TEMP: f64 = 1.416_784e32;
 /// - Unit: `s`
 pub const PLANCK_TIME: f64 = 5.391_247e-44;
 
+/// # Planck time: standard uncertainty
+///
+/// Standard uncertainty associated to [`PLANCK_TIME`], in the same unit.
+pub const PLANCK_TIME_UNC: f64 = 0.000_060e-44;
+
 /// # Vacuum electric permittivity
 ///
 /// - Value: 8.854 187 812 8×10⁻¹²
@@ -173,6 +201,11 @@ pub const PLANCK_TIME: f64 = 5.391_247e-44;
 /// - Unit: `F.m-1` = `s4.A2.m-3.kg-1`
 pub const EPSILON_0: f64 = 8.854_187_812_8e-12;
 
+/// # Vacuum electric permittivity: standard uncertainty
+///
+/// Standard uncertainty associated to [`EPSILON_0`], in the same unit.
+pub const EPSILON_0_UNC: f64 = 0.000_000_001_3e-12;
+
 /// # Vacuum magnetic permeability
 ///
 /// - Value: 1.256 637 062 12×10⁻⁶
@@ -180,6 +213,11 @@ pub const EPSILON_0: f64 = 8.854_187_812_8e-12;
 /// - Unit: `N.A-2` = `kg.m.s-2.A-2`
 pub const MU_0: f64 = 1.256_637_062_12e-6;
 
+/// # Vacuum magnetic permeability: standard uncertainty
+///
+/// Standard uncertainty associated to [`MU_0`], in the same unit.
+pub const MU_0_UNC: f64 = 0.000_000_000_19e-6;
+
 /// # Characteristic impedance of vacuum
 ///
 /// - Value: 376.730 313 668
@@ -187,6 +225,11 @@ pub const MU_0: f64 = 1.256_637_062_12e-6;
 /// - Unit: `Ohm` = `kg.m2.s-3.A-2`
 pub const Z_0: f64 = 376.730_313_668;
 
+/// # Characteristic impedance of vacuum: standard uncertainty
+///
+/// Standard uncertainty associated to [`Z_0`], in the same unit.
+pub const Z_0_UNC: f64 = 0.000_000_057;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Defined
 
@@ -232,6 +275,11 @@ pub const ATM: f64 = 101_325.0;
 /// - Unit: `m`
 pub const A_0: f64 = 5.291_772_109_03e-11;
 
+/// # Bohr radius: standard uncertainty
+///
+/// Standard uncertainty associated to [`A_0`], in the same unit.
+pub const A_0_UNC: f64 = 0.000_000_000_80e-11;
+
 /// # Bohr magneton
 ///
 /// - Value: 9.274 010 08×10⁻²⁴
@@ -249,7 +297,12 @@ pub const WIEN_B: f64 = 0.002_897_771_96;
 /// - Value: 2.817 940 326 2×10⁻¹⁵
 /// - Uncertainty: 0.000 000 0013×10⁻¹⁵
 /// - Unit: `m`
-pub const E_RADIUS_C: f64 = 2.817_940_3262e-15;
+pub const E_RADIUS_C: f64 = 2.817_940_326_2e-15;
+
+/// # Classical electron radius: standard uncertainty
+///
+/// Standard uncertainty associated to [`E_RADIUS_C`], in the same unit.
+pub const E_RADIUS_C_UNC: f64 = 0.000_000_001_3e-15;
 
 /// # Compton wavelength
 ///
@@ -258,12 +311,22 @@ pub const E_RADIUS_C: f64 = 2.817_940_3262e-15;
 /// - Unit: `m`
 pub const LAMBDA_COMPTON: f64 = 2.426_310_238_67e-12;
 
+/// # Compton wavelength: standard uncertainty
+///
+/// Standard uncertainty associated to [`LAMBDA_COMPTON`], in the same unit.
+pub const LAMBDA_COMPTON_UNC: f64 = 0.000_000_000_73e-12;
+
 /// # Electron mass
 ///
 /// - Value: 9.109 383 701 5×10⁻³¹
 /// - Uncertainty: 0.000 000 0028×10⁻³¹
 /// - Unit: `kg`
-pub const ELECTRON_MASS: f64 = 9.109_383_7015e-31;
+pub const ELECTRON_MASS: f64 = 9.109_383_701_5e-31;
+
+/// # Electron mass: standard uncertainty
+///
+/// Standard uncertainty associated to [`ELECTRON_MASS`], in the same unit.
+pub const ELECTRON_MASS_UNC: f64 = 0.000_000_002_8e-31;
 
 /// # Neutron mass
 ///
@@ -272,6 +335,11 @@ pub const ELECTRON_MASS: f64 = 9.109_383_7015e-31;
 /// - Unit: `kg`
 pub const NEUTRON_MASS: f64 = 1.674_927_498_04e-27;
 
+/// # Neutron mass: standard uncertainty
+///
+/// Standard uncertainty associated to [`NEUTRON_MASS`], in the same unit.
+pub const NEUTRON_MASS_UNC: f64 = 0.000_000_000_95e-27;
+
 /// # Proton mass
 ///
 /// - Value: 1.672 621 923 69×10⁻²⁷
@@ -279,6 +347,11 @@ pub const NEUTRON_MASS: f64 = 1.674_927_498_04e-27;
 /// - Unit: `kg`
 pub const PROTON_MASS: f64 = 1.672_621_923_69e-27;
 
+/// # Proton mass: standard uncertainty
+///
+/// Standard uncertainty associated to [`PROTON_MASS`], in the same unit.
+pub const PROTON_MASS_UNC: f64 = 0.000_000_000_51e-27;
+
 /// # Rydberg constant
 ///
 /// - Value: 10 973 731.568 160
@@ -286,6 +359,11 @@ pub const PROTON_MASS: f64 = 1.672_621_923_69e-27;
 /// - Unit: `m-1`
 pub const RYD: f64 = 10_973_731.568_160;
 
+/// # Rydberg constant: standard uncertainty
+///
+/// Standard uncertainty associated to [`RYD`], in the same unit.
+pub const RYD_UNC: f64 = 0.000_021;
+
 /// # Gas constant
 ///
 /// - Value: 8.314 462 618
@@ -155,6 +155,40 @@ impl Cartesian {
         (dist.x.powi(2) + dist.y.powi(2) + dist.z.powi(2)).sqrt()
     }
 
+    /// # Dot product
+    ///
+    /// Computes the scalar product of two vectors.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let u = Cartesian::from(1, 2, 3);
+    /// let v = Cartesian::from(-2, 0.5, 4);
+    ///
+    /// assert_eq!(u.dot(&v), 11.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// # Cross product
+    ///
+    /// Computes the vector product `self x other`.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let u = Cartesian::from(1, 0, 0);
+    /// let v = Cartesian::from(0, 1, 0);
+    ///
+    /// assert_eq!(u.cross(&v), Cartesian::from(0, 0, 1));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x
+        }
+    }
+
     /// # Coordinate rotation
     /// 
     /// Computes the resulting coordinates after an arbitrary rotation in 3D. The rotation
@@ -230,20 +264,20 @@ impl Cartesian {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.phi - expected.phi).abs() < 1.0e-15);
 /// ```
-impl Into<Spherical> for Cartesian {
-    fn into(self) -> Spherical {
-        let rho: f64 = self.norm();
-        let mut nt: f64 = (self.y / self.x).atan();
+impl From<Cartesian> for Spherical {
+    fn from(c: Cartesian) -> Self {
+        let rho: f64 = c.norm();
+        let mut nt: f64 = (c.y / c.x).atan();
 
         // If we were in the wrong quadrants, the atan range doesn't work
-        if self.x.is_sign_negative() {
+        if c.x.is_sign_negative() {
             nt += PI;
         }
 
         Spherical {
             r: rho,
             theta: nt,
-            phi: (self.z / rho).acos()
+            phi: (c.z / rho).acos()
         }
     }
 }
@@ -267,20 +301,20 @@ impl Into<Spherical> for Cartesian {
 /// 
 /// assert_eq!(conv, expected);
 /// ```
-impl Into<Cylindrical> for Cartesian {
-    fn into(self) -> Cylindrical {
-        let rho: f64 = (self.x.powi(2) + self.y.powi(2)).sqrt();
-        let mut nt: f64 = (self.y / self.x).atan();
+impl From<Cartesian> for Cylindrical {
+    fn from(c: Cartesian) -> Self {
+        let rho: f64 = (c.x.powi(2) + c.y.powi(2)).sqrt();
+        let mut nt: f64 = (c.y / c.x).atan();
 
         // If we were in the wrong quadrants, the atan range doesn't work
-        if self.x.is_sign_negative() {
+        if c.x.is_sign_negative() {
             nt += PI;
         }
 
         Cylindrical {
             r: rho,
             theta: nt,
-            z: self.z
+            z: c.z
         }
     }
 }
@@ -188,12 +188,12 @@ impl Cylindrical {
 /// assert!((conv.y - expected.y).abs() < 1.0e-9);
 /// assert_eq!(conv.z, expected.z);
 /// ```
-impl Into<Cartesian> for Cylindrical {
-    fn into(self) -> Cartesian {
+impl From<Cylindrical> for Cartesian {
+    fn from(c: Cylindrical) -> Self {
         Cartesian {
-            x: self.r * self.theta.cos(),
-            y: self.r * self.theta.sin(),
-            z: self.z
+            x: c.r * c.theta.cos(),
+            y: c.r * c.theta.sin(),
+            z: c.z
         }
     }
 }
@@ -220,18 +220,18 @@ impl Into<Cartesian> for Cylindrical {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.phi - expected.phi).abs() < 1.0e-9);
 /// ```
-impl Into<Spherical> for Cylindrical {
-    fn into(self) -> Spherical {
-        let rho: f64 = (self.r.powi(2) + self.z.powi(2)).sqrt();
-        let mut np: f64 = (self.r / self.z).atan();
+impl From<Cylindrical> for Spherical {
+    fn from(c: Cylindrical) -> Self {
+        let rho: f64 = (c.r.powi(2) + c.z.powi(2)).sqrt();
+        let mut np: f64 = (c.r / c.z).atan();
 
-        if self.z.is_sign_negative() {
+        if c.z.is_sign_negative() {
             np += PI;
         }
 
         Spherical {
             r: rho,
-            theta: self.theta,
+            theta: c.theta,
             phi: np
         }
     }
@@ -190,12 +190,12 @@ impl Spherical {
 /// assert_eq!(conv.y, expected.y);
 /// assert!((conv.z - expected.z).abs() < 1.0e-15);
 /// ```
-impl Into<Cartesian> for Spherical {
-    fn into(self) -> Cartesian {
+impl From<Spherical> for Cartesian {
+    fn from(c: Spherical) -> Self {
         Cartesian {
-            x: self.r * self.theta.cos() * self.phi.sin(),
-            y: self.r * self.theta.sin() * self.phi.sin(),
-            z: self.r * self.phi.cos()
+            x: c.r * c.theta.cos() * c.phi.sin(),
+            y: c.r * c.theta.sin() * c.phi.sin(),
+            z: c.r * c.phi.cos()
         }
     }
 }
@@ -220,12 +220,12 @@ impl Into<Cartesian> for Spherical {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.z - expected.z).abs() < 1.0e-9);
 /// ```
-impl Into<Cylindrical> for Spherical {
-    fn into(self) -> Cylindrical {
+impl From<Spherical> for Cylindrical {
+    fn from(c: Spherical) -> Self {
         Cylindrical {
-            r: self.r * self.phi.sin(),
-            theta: self.theta,
-            z: self.r * self.phi.cos()
+            r: c.r * c.phi.sin(),
+            theta: c.theta,
+            z: c.r * c.phi.cos()
         }
     }
 }
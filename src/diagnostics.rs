@@ -0,0 +1,173 @@
+//!
+//! # Diagnostics
+//!
+//! Hooks reporting the progress of the iterative methods of the crate (series evaluations, products,
+//! integrators and solvers): iteration counts, residuals and step sizes. They help understanding why a
+//! computation is slow or does not converge, without modifying the calling code.
+//!
+//! The hooks are compiled with the `diagnostics` feature, enabled by default. Without it, reporting is a
+//! no-op optimized away, which removes the (small) overhead of the check in the inner loops.
+//!
+//! Observers are set for a single computation, on the current thread.
+//!
+#![cfg_attr(feature = "diagnostics", doc = r#"
+```
+# use scilib::diagnostics::{ self, Event };
+# use scilib::math::basic;
+let (res, events) = diagnostics::record(|| basic::erf(0.8));
+
+assert!((res.re - 0.742100964707661).abs() < 1.0e-12);
+assert!(events.iter().any(|e| matches!(e, Event::Step { method: "erf", .. })));
+assert!(matches!(events.last(), Some(Event::Converged { method: "erf", .. })));
+```
+"#)]
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "diagnostics")]
+use std::cell::RefCell;         // Thread local observer
+
+#[cfg(feature = "diagnostics")]
+use std::rc::Rc;                // Shared event log
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Type of the observers
+#[cfg(feature = "diagnostics")]
+type Observer = Box<dyn FnMut(&Event)>;
+
+#[cfg(feature = "diagnostics")]
+thread_local! {
+    /// Observer set by `observe` on the current thread
+    static OBSERVER: RefCell<Option<Observer>> = const { RefCell::new(None) };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Diagnostic event
+///
+/// `method` is the name of the reporting function, as found in the crate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    /// A step of an iterative method
+    Step {
+        method: &'static str,
+        /// Index of the iteration, from 0
+        iteration: usize,
+        /// Quantity compared to the tolerance (relative change, error estimate, ...)
+        residual: f64,
+        /// Size of the last update (term of a series, step of an integrator, ...)
+        step: f64
+    },
+    /// The method reached its tolerance
+    Converged {
+        method: &'static str,
+        /// Number of iterations performed
+        iterations: usize,
+        /// Final residual
+        residual: f64
+    },
+    /// The method stopped on its iteration cap before reaching its tolerance
+    Exhausted {
+        method: &'static str,
+        /// Number of iterations performed
+        iterations: usize,
+        /// Final residual
+        residual: f64
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Runs a computation with an observer
+///
+/// `observer` receives every event reported by the functions called by `f` on the current thread. The
+/// previous observer is restored afterwards, even if `f` panics. Functions called from within the observer
+/// itself are not reported.
+///
+/// ```
+/// # use scilib::diagnostics::{ self, Event };
+/// # use scilib::math::basic;
+/// use std::{ cell::Cell, rc::Rc };
+///
+/// let steps = Rc::new(Cell::new(0));
+/// let counter = steps.clone();
+///
+/// diagnostics::observe(move |e| if let Event::Step { .. } = e { counter.set(counter.get() + 1) }, || {
+///     basic::erf(0.5)
+/// });
+///
+/// assert!(steps.get() > 5);
+/// ```
+#[cfg(feature = "diagnostics")]
+pub fn observe<O, R, F>(observer: O, f: F) -> R
+where O: FnMut(&Event) + 'static, F: FnOnce() -> R {
+
+    /// Restores the previous observer when dropped
+    struct Guard(Option<Observer>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            OBSERVER.with(|o| *o.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _guard: Guard = Guard(OBSERVER.with(|o| o.borrow_mut().replace(Box::new(observer))));
+    f()
+}
+
+/// # Records the events of a computation
+///
+/// Returns the result of `f` along with every event it reported, in order.
+#[cfg(feature = "diagnostics")]
+pub fn record<R, F>(f: F) -> (R, Vec<Event>)
+where F: FnOnce() -> R {
+
+    let log: Rc<RefCell<Vec<Event>>> = Rc::new(RefCell::new(Vec::new()));
+    let sink: Rc<RefCell<Vec<Event>>> = log.clone();
+
+    let res: R = observe(move |e| sink.borrow_mut().push(*e), f);
+
+    let events: Vec<Event> = log.take();
+    (res, events)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reports an event to the observer of the current thread, if any
+///
+/// The observer is taken out during the call, so that functions it calls do not report to it.
+#[inline(always)]
+#[allow(unused_variables)]
+pub(crate) fn emit(event: Event) {
+    #[cfg(feature = "diagnostics")]
+    if let Some(mut observer) = OBSERVER.with(|o| o.borrow_mut().take()) {
+        observer(&event);
+        OBSERVER.with(|o| {
+            let mut slot = o.borrow_mut();
+            // The observer could have been replaced by a nested scope, which has priority
+            if slot.is_none() {
+                *slot = Some(observer);
+            }
+        });
+    }
+}
+
+/// Reports a step of an iterative method
+#[inline(always)]
+pub(crate) fn step(method: &'static str, iteration: usize, residual: f64, step: f64) {
+    emit(Event::Step { method, iteration, residual, step });
+}
+
+/// Reports the end of an iterative method, converged if the residual is below the tolerance
+#[inline(always)]
+pub(crate) fn finish(method: &'static str, iterations: usize, residual: f64, tolerance: f64) {
+    if residual < tolerance {
+        emit(Event::Converged { method, iterations, residual });
+    } else {
+        emit(Event::Exhausted { method, iterations, residual });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
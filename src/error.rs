@@ -0,0 +1,62 @@
+//!
+//! # Error handling
+//!
+//! Common error type of the crate. Functions that can fail on some inputs exist in two flavours: the plain
+//! version, which returns a special value (or panics) as documented, and a `try_` version returning a
+//! `Result`, for applications requiring predictable failure modes.
+//!
+//! ```
+//! # use scilib::Error;
+//! # use scilib::math::basic::{ factorial, try_factorial };
+//! assert_eq!(try_factorial(20_usize), Ok(factorial(20_usize)));
+//! assert!(matches!(try_factorial(21_usize), Err(Error::Overflow(_))));
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::{             // Formatter display
+    Display,                // The display itself
+    Result as DRes          // The associated result
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Scilib error
+///
+/// Each variant holds a short description of the failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is outside the domain of definition of the function (poles, invalid orders, ...)
+    Domain(&'static str),
+    /// An iterative method did not reach the required precision
+    Convergence(&'static str),
+    /// The result cannot be represented in the output type
+    Overflow(&'static str),
+    /// Invalid data was found while reading a file, on the given line (starting at 1)
+    Parse(usize),
+    /// A binary file does not follow the expected format
+    Format(&'static str)
+}
+
+/// # Display
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
+        match self {
+            Self::Domain(msg) => write!(f, "domain error: {}", msg),
+            Self::Convergence(msg) => write!(f, "convergence error: {}", msg),
+            Self::Overflow(msg) => write!(f, "overflow: {}", msg),
+            Self::Parse(line) => write!(f, "parse error: invalid data on line {}", line),
+            Self::Format(msg) => write!(f, "format error: {}", msg)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// # Result type
+///
+/// Shorthand for the results returned by the `try_` functions of the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
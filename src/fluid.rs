@@ -0,0 +1,260 @@
+//!
+//! # Fluid dynamics & heat transfer
+//!
+//! Dimensionless numbers commonly used to characterize flows and heat exchanges. The inputs are grouped
+//! into parameter structs so that each quantity is named explicitly, rather than passed as a long list of
+//! anonymous `f64`. All values are expected in SI units.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    SQRT_2                  // Square root of 2
+};
+
+use super::constant as cst;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Flow parameters
+///
+/// Describes a fluid moving at a given velocity past (or through) an object of characteristic length.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Flow {
+    /// Density of the fluid, in `kg.m-3`
+    pub density: f64,
+    /// Velocity of the flow, in `m.s-1`
+    pub velocity: f64,
+    /// Characteristic length of the problem, in `m`
+    pub length: f64,
+    /// Dynamic viscosity of the fluid, in `Pa.s`
+    pub viscosity: f64
+}
+
+/// Implementing required methods
+impl Flow {
+    /// # Reynolds number
+    ///
+    /// Ratio of the inertial forces to the viscous forces: `Re = rho * v * L / mu`.
+    ///
+    /// ```
+    /// # use scilib::fluid::Flow;
+    /// // Water at 20°C flowing at 2 m/s in a 5 cm pipe
+    /// let flow = Flow { density: 998.2, velocity: 2.0, length: 0.05, viscosity: 1.002e-3 };
+    ///
+    /// assert!((flow.reynolds() - 99_620.758483).abs() < 1.0e-6);
+    /// ```
+    pub fn reynolds(&self) -> f64 {
+        self.density * self.velocity * self.length / self.viscosity
+    }
+}
+
+/// # Thermal properties of a fluid
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Thermal {
+    /// Dynamic viscosity of the fluid, in `Pa.s`
+    pub viscosity: f64,
+    /// Specific heat capacity at constant pressure, in `J.kg-1.K-1`
+    pub heat_capacity: f64,
+    /// Thermal conductivity, in `W.m-1.K-1`
+    pub conductivity: f64
+}
+
+/// Implementing required methods
+impl Thermal {
+    /// # Prandtl number
+    ///
+    /// Ratio of the momentum diffusivity to the thermal diffusivity: `Pr = mu * cp / k`.
+    ///
+    /// ```
+    /// # use scilib::fluid::Thermal;
+    /// // Water at 20°C
+    /// let water = Thermal { viscosity: 1.002e-3, heat_capacity: 4182.0, conductivity: 0.598 };
+    ///
+    /// assert!((water.prandtl() - 7.007297659).abs() < 1.0e-8);
+    /// ```
+    pub fn prandtl(&self) -> f64 {
+        self.viscosity * self.heat_capacity / self.conductivity
+    }
+}
+
+/// # Natural convection parameters
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Convection {
+    /// Gravitational acceleration, in `m.s-2`
+    pub gravity: f64,
+    /// Thermal expansion coefficient, in `K-1`
+    pub expansion: f64,
+    /// Temperature difference between the wall and the fluid, in `K`
+    pub delta_t: f64,
+    /// Characteristic length, in `m`
+    pub length: f64,
+    /// Kinematic viscosity, in `m2.s-1`
+    pub kinematic_viscosity: f64,
+    /// Thermal diffusivity, in `m2.s-1`
+    pub diffusivity: f64
+}
+
+/// Implementing required methods
+impl Convection {
+    /// # Rayleigh number
+    ///
+    /// Characterizes buoyancy driven flows: `Ra = g * beta * dT * L^3 / (nu * alpha)`.
+    ///
+    /// ```
+    /// # use scilib::constant;
+    /// # use scilib::fluid::Convection;
+    /// let air = Convection {
+    ///     gravity: constant::EARTH_GRAVITY,
+    ///     expansion: 3.4e-3,
+    ///     delta_t: 20.0,
+    ///     length: 0.5,
+    ///     kinematic_viscosity: 1.5e-5,
+    ///     diffusivity: 2.1e-5
+    /// };
+    ///
+    /// assert!((air.rayleigh() - 264_623_888.888889).abs() < 1.0e-5);
+    /// ```
+    pub fn rayleigh(&self) -> f64 {
+        self.gravity * self.expansion * self.delta_t * self.length.powi(3) / (self.kinematic_viscosity * self.diffusivity)
+    }
+}
+
+/// # Gas state for rarefaction effects
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Gas {
+    /// Temperature of the gas, in `K`
+    pub temperature: f64,
+    /// Pressure of the gas, in `Pa`
+    pub pressure: f64,
+    /// Kinetic diameter of the molecules, in `m`
+    pub diameter: f64
+}
+
+/// Implementing required methods
+impl Gas {
+    /// # Mean free path
+    ///
+    /// Average distance travelled by a molecule between collisions, for hard spheres:
+    /// `lambda = k_B * T / (sqrt(2) * pi * d^2 * p)`.
+    ///
+    /// ```
+    /// # use scilib::constant;
+    /// # use scilib::fluid::Gas;
+    /// let air = Gas { temperature: 288.15, pressure: constant::ATM, diameter: 3.7e-10 };
+    ///
+    /// assert!((air.mean_free_path() - 6.455308105e-8).abs() < 1.0e-16);
+    /// ```
+    pub fn mean_free_path(&self) -> f64 {
+        cst::K_B * self.temperature / (SQRT_2 * PI * self.diameter.powi(2) * self.pressure)
+    }
+
+    /// # Knudsen number
+    ///
+    /// `length` is the characteristic length of the problem.
+    ///
+    /// Ratio of the mean free path to the characteristic length. Values above `~0.01` indicate that
+    /// the continuum hypothesis starts to break down.
+    ///
+    /// ```
+    /// # use scilib::constant;
+    /// # use scilib::fluid::Gas;
+    /// let air = Gas { temperature: 288.15, pressure: constant::ATM, diameter: 3.7e-10 };
+    ///
+    /// assert!((air.knudsen(1.0e-6) - 0.06455308105).abs() < 1.0e-10);
+    /// ```
+    pub fn knudsen(&self, length: f64) -> f64 {
+        self.mean_free_path() / length
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Speed of sound in an ideal gas
+///
+/// `gamma` is the heat capacity ratio, `temperature` is in `K` and `molar_mass` in `kg.mol-1`.
+///
+/// ```
+/// # use scilib::fluid::sound_speed;
+/// // Dry air at 15°C
+/// let c: f64 = sound_speed(1.4, 288.15, 0.0289647);
+///
+/// assert!((c - 340.295264).abs() < 1.0e-6);
+/// ```
+pub fn sound_speed(gamma: f64, temperature: f64, molar_mass: f64) -> f64 {
+    (gamma * cst::R * temperature / molar_mass).sqrt()
+}
+
+/// # Mach number
+///
+/// `velocity` is the speed of the flow and `sound_speed` the local speed of sound.
+///
+/// ```
+/// # use scilib::fluid::{ mach, sound_speed };
+/// let ma: f64 = mach(340.0, sound_speed(1.4, 288.15, 0.0289647));
+///
+/// assert!((ma - 0.99913233).abs() < 1.0e-8);
+/// ```
+pub fn mach(velocity: f64, sound_speed: f64) -> f64 {
+    velocity / sound_speed
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Nusselt correlations
+
+/// # Nusselt number: Dittus-Boelter correlation
+///
+/// `re` is the Reynolds number, `pr` the Prandtl number, and `heating` is true when the fluid is heated
+/// by the wall (exponent 0.4), false when it is cooled (exponent 0.3).
+///
+/// Valid for fully developed turbulent flow in smooth pipes, with `Re > 10 000` and `0.6 < Pr < 160`.
+///
+/// ```
+/// # use scilib::fluid::nusselt_dittus_boelter;
+/// let nu: f64 = nusselt_dittus_boelter(99_620.758483, 7.007297659, true);
+///
+/// assert!((nu - 499.60633).abs() < 1.0e-4);
+/// ```
+pub fn nusselt_dittus_boelter(re: f64, pr: f64, heating: bool) -> f64 {
+    let n: f64 = if heating { 0.4 } else { 0.3 };
+    0.023 * re.powf(0.8) * pr.powf(n)
+}
+
+/// # Nusselt number: Gnielinski correlation
+///
+/// `re` is the Reynolds number and `pr` the Prandtl number.
+///
+/// More accurate than Dittus-Boelter, and valid for `3000 < Re < 5e6` and `0.5 < Pr < 2000`. The friction
+/// factor is computed with Petukhov's smooth pipe formula.
+///
+/// ```
+/// # use scilib::fluid::nusselt_gnielinski;
+/// let nu: f64 = nusselt_gnielinski(99_620.758483, 7.007297659);
+///
+/// assert!((nu - 597.37494).abs() < 1.0e-4);
+/// ```
+pub fn nusselt_gnielinski(re: f64, pr: f64) -> f64 {
+    let f: f64 = (0.79 * re.ln() - 1.64).powi(-2);     // Petukhov friction factor
+    let f8: f64 = f / 8.0;
+    f8 * (re - 1000.0) * pr / (1.0 + 12.7 * f8.sqrt() * (pr.powf(2.0 / 3.0) - 1.0))
+}
+
+/// # Nusselt number: Churchill-Chu correlation
+///
+/// `ra` is the Rayleigh number and `pr` the Prandtl number.
+///
+/// Natural convection along a vertical plate, valid over the whole laminar and turbulent range.
+///
+/// ```
+/// # use scilib::fluid::nusselt_churchill_chu;
+/// let nu: f64 = nusselt_churchill_chu(264_623_888.888889, 0.71);
+///
+/// assert!((nu - 81.8146082).abs() < 1.0e-6);
+/// ```
+pub fn nusselt_churchill_chu(ra: f64, pr: f64) -> f64 {
+    let den: f64 = (1.0 + (0.492 / pr).powf(9.0 / 16.0)).powf(8.0 / 27.0);
+    (0.825 + 0.387 * ra.powf(1.0 / 6.0) / den).powi(2)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
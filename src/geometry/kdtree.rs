@@ -0,0 +1,241 @@
+//!
+//! # K-d tree
+//!
+//! Space partitioning structure over points of any dimension, allowing fast nearest neighbors and radius
+//! queries. The tree is built once in `O(n log n)` and stored implicitly: the points are reordered so that
+//! each median sits in the middle of its sub-slice, removing the need for explicit nodes.
+//!
+//! Results are given as `(index, distance)` pairs, the index referring to the slice used to build the tree.
+//!
+//! ```
+//! # use scilib::geometry::kdtree::KdTree;
+//! let tree = KdTree::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [5.0, 5.0]]);
+//!
+//! let (idx, dist) = tree.nearest(&[4.0, 4.5]).unwrap();
+//! assert_eq!(idx, 3);
+//! assert!((dist - 1.25f64.sqrt()).abs() < 1.0e-15);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::BinaryHeap;   // Tracking the k best candidates
+
+use std::cmp::Ordering;             // Ordering of the candidates
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # K-d tree structure
+///
+/// `N` is the dimension of the space.
+#[derive(Clone, Debug)]
+pub struct KdTree<const N: usize> {
+    /// Points, reordered as an implicit tree
+    points: Vec<[f64; N]>,
+    /// Original index of each point
+    index: Vec<usize>,
+    /// Splitting axis of each node
+    axes: Vec<usize>
+}
+
+/// Implementing required methods
+impl<const N: usize> KdTree<N> {
+    /// # Builds the tree
+    ///
+    /// `points` is the list of points to index. The splitting axis at each level is the one with the
+    /// largest spread, which keeps the cells compact for anisotropic data.
+    pub fn new(points: &[[f64; N]]) -> Self {
+        let mut nodes: Vec<Node<N>> = points.iter().enumerate().map(|(i, &p)| (p, i, 0)).collect();
+        build(&mut nodes);
+
+        Self {
+            points: nodes.iter().map(|n| n.0).collect(),
+            index: nodes.iter().map(|n| n.1).collect(),
+            axes: nodes.iter().map(|n| n.2).collect()
+        }
+    }
+
+    /// # Number of points in the tree
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// # Checks if the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// # Nearest neighbor
+    ///
+    /// Returns the index of the closest point to `query` and its distance, or `None` if the tree is empty.
+    pub fn nearest(&self, query: &[f64; N]) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    /// # K nearest neighbors
+    ///
+    /// Returns up to `k` points closest to `query`, sorted by increasing distance.
+    ///
+    /// ```
+    /// # use scilib::geometry::kdtree::KdTree;
+    /// let pts: Vec<[f64; 3]> = (0..100).map(|i| [i as f64, 0.0, 0.0]).collect();
+    /// let tree = KdTree::new(&pts);
+    /// let res = tree.k_nearest(&[41.8, 0.0, 1.0], 3);
+    ///
+    /// let idx: Vec<usize> = res.iter().map(|r| r.0).collect();
+    /// assert_eq!(idx, vec![42, 41, 43]);
+    /// ```
+    pub fn k_nearest(&self, query: &[f64; N], k: usize) -> Vec<(usize, f64)> {
+        let mut heap: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+
+        if k > 0 {
+            self.search_k(0, self.len(), query, k, &mut heap);
+        }
+
+        heap.into_sorted_vec().into_iter().map(|c| (self.index[c.pos], c.dist2.sqrt())).collect()
+    }
+
+    /// # Points within a radius
+    ///
+    /// Returns all the points at a distance lower or equal to `radius` from `query`, sorted by increasing
+    /// distance.
+    ///
+    /// ```
+    /// # use scilib::geometry::kdtree::KdTree;
+    /// let pts: Vec<[f64; 2]> = (0..10).flat_map(|i| (0..10).map(move |j| [i as f64, j as f64])).collect();
+    /// let tree = KdTree::new(&pts);
+    /// let res = tree.within_radius(&[5.0, 5.0], 1.0);
+    ///
+    /// assert_eq!(res.len(), 5);
+    /// assert_eq!(res[0], (55, 0.0));
+    /// ```
+    pub fn within_radius(&self, query: &[f64; N], radius: f64) -> Vec<(usize, f64)> {
+        let mut res: Vec<(usize, f64)> = vec![];
+        self.search_radius(0, self.len(), query, radius.powi(2), &mut res);
+
+        res.sort_by(|a, b| a.1.total_cmp(&b.1));
+        res.iter().map(|&(i, d)| (self.index[i], d.sqrt())).collect()
+    }
+
+    /// Recursive k nearest search in the sub-slice `[lo, hi)`
+    fn search_k(&self, lo: usize, hi: usize, query: &[f64; N], k: usize, heap: &mut BinaryHeap<Candidate>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid: usize = (lo + hi) / 2;
+        let dist2: f64 = distance2(&self.points[mid], query);
+
+        if heap.len() < k {
+            heap.push(Candidate { dist2, pos: mid });
+        } else if heap.peek().is_some_and(|c| dist2 < c.dist2) {
+            heap.pop();
+            heap.push(Candidate { dist2, pos: mid });
+        }
+
+        let axis: usize = self.axes[mid];
+        let diff: f64 = query[axis] - self.points[mid][axis];
+        let (near, far): ((usize, usize), (usize, usize)) = if diff < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.search_k(near.0, near.1, query, k, heap);
+
+        // The far side is only visited if it can hold a better candidate
+        if heap.len() < k || heap.peek().is_some_and(|c| diff.powi(2) < c.dist2) {
+            self.search_k(far.0, far.1, query, k, heap);
+        }
+    }
+
+    /// Recursive radius search in the sub-slice `[lo, hi)`
+    fn search_radius(&self, lo: usize, hi: usize, query: &[f64; N], r2: f64, res: &mut Vec<(usize, f64)>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid: usize = (lo + hi) / 2;
+        let dist2: f64 = distance2(&self.points[mid], query);
+
+        if dist2 <= r2 {
+            res.push((mid, dist2));
+        }
+
+        let axis: usize = self.axes[mid];
+        let diff: f64 = query[axis] - self.points[mid][axis];
+
+        if diff <= 0.0 || diff.powi(2) <= r2 {
+            self.search_radius(lo, mid, query, r2, res);
+        }
+        if diff >= 0.0 || diff.powi(2) <= r2 {
+            self.search_radius(mid + 1, hi, query, r2, res);
+        }
+    }
+}
+
+/// Point, original index and splitting axis of a node
+type Node<const N: usize> = ([f64; N], usize, usize);
+
+/// Builds the implicit tree by recursively placing the median of the widest axis in the middle
+fn build<const N: usize>(nodes: &mut [Node<N>]) {
+    if nodes.len() <= 1 {
+        return;
+    }
+
+    let axis: usize = spread_axis(nodes.iter().map(|n| &n.0));
+    let mid: usize = nodes.len() / 2;
+    nodes.select_nth_unstable_by(mid, |a, b| a.0[axis].total_cmp(&b.0[axis]));
+    nodes[mid].2 = axis;
+
+    let (left, right) = nodes.split_at_mut(mid);
+    build(left);
+    build(&mut right[1..]);
+}
+
+/// Axis with the largest extent among the points
+fn spread_axis<'a, const N: usize>(points: impl Iterator<Item = &'a [f64; N]>) -> usize {
+    let mut min: [f64; N] = [f64::INFINITY; N];
+    let mut max: [f64; N] = [f64::NEG_INFINITY; N];
+
+    for p in points {
+        for d in 0..N {
+            min[d] = min[d].min(p[d]);
+            max[d] = max[d].max(p[d]);
+        }
+    }
+
+    (0..N).max_by(|&a, &b| (max[a] - min[a]).total_cmp(&(max[b] - min[b]))).unwrap_or(0)
+}
+
+/// Squared euclidean distance
+fn distance2<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Candidate of the k nearest search, ordered by distance so that the heap top is the worst one
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Candidate {
+    /// Squared distance to the query
+    dist2: f64,
+    /// Position in the tree
+    pos: usize
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist2.total_cmp(&other.dist2)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
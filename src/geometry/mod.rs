@@ -0,0 +1,28 @@
+//!
+//! # Geometry
+//!
+//! This crate provides geometric primitives, with their intersection and distance computations.
+//!
+//! Currently available:
+//! - Planar: points, lines, segments, triangles and polygons in 2D
+//! - Spatial: lines, rays, segments, planes, spheres and triangles in 3D, using `Cartesian` points
+//! - KdTree: nearest neighbors and radius queries in any dimension
+//! - Tessellation: convex hull, Delaunay triangulation and Voronoi diagram of scattered points
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod kdtree;
+
+pub mod planar;
+
+pub mod spatial;
+
+pub mod tessellation;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relative tolerance used to detect degenerate configurations (parallel lines, flat triangles, ...)
+const PRECISION: f64 = 1.0e-12;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,560 @@
+//!
+//! # Planar geometry
+//!
+//! Primitives of the 2D plane: points, lines, segments, triangles and polygons, with the associated
+//! distance, intersection and containment tests.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{                 // Implementing basic operations
+    Add,                        // Addition
+    Sub,                        // Subtraction
+    Mul,                        // Multiplication
+    Div,                        // Division
+    Neg                         // Negation
+};
+
+use std::fmt::{                 // Formatter display
+    Display,                    // The display itself
+    Result as DRes              // The associated result
+};
+
+use super::PRECISION;           // Tolerance for degenerate cases
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Point of the plane
+///
+/// Also used as a 2D vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point {
+    /// x axis
+    pub x: f64,
+    /// y axis
+    pub y: f64
+}
+
+/// # Display for Point
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> DRes {
+        write!(f, "x={} :: y={}", self.x, self.y)?;
+        Ok(())
+    }
+}
+
+/// Implementing required methods
+impl Point {
+    /// # Creates a new entity
+    ///
+    /// Returns the origin.
+    pub const fn new() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0
+        }
+    }
+
+    /// # From each coordinate
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::Point;
+    /// let p = Point::from(2, -1.5);
+    ///
+    /// assert_eq!(p, Point { x: 2.0, y: -1.5 });
+    /// ```
+    pub fn from<T, U>(x: T, y: U) -> Self
+    where T: Into<f64>, U: Into<f64> {
+        Self {
+            x: x.into(),
+            y: y.into()
+        }
+    }
+
+    /// # Computes the vector norm
+    pub fn norm(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// # Distance between two points
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::Point;
+    /// let a = Point::from(1, 1);
+    /// let b = Point::from(4, 5);
+    ///
+    /// assert_eq!(a.distance(b), 5.0);
+    /// ```
+    pub fn distance(&self, other: Self) -> f64 {
+        (other - *self).norm()
+    }
+
+    /// # Dot product
+    pub fn dot(&self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// # Cross product
+    ///
+    /// Returns the `z` component of the 3D cross product, which is positive when `other` is
+    /// counter-clockwise from self.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::Point;
+    /// let u = Point::from(1, 0);
+    /// let v = Point::from(0, 1);
+    ///
+    /// assert_eq!(u.cross(v), 1.0);
+    /// assert_eq!(v.cross(u), -1.0);
+    /// ```
+    pub fn cross(&self, other: Self) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+/// # Orientation of three points
+///
+/// Returns twice the signed area of the triangle `(a, b, c)`: positive if the points are in
+/// counter-clockwise order, negative if clockwise, and zero if they are aligned.
+///
+/// ```
+/// # use scilib::geometry::planar::{ Point, orientation };
+/// let a = Point::from(0, 0);
+/// let b = Point::from(1, 0);
+///
+/// assert!(orientation(a, b, Point::from(0, 1)) > 0.0);
+/// assert!(orientation(a, b, Point::from(0, -1)) < 0.0);
+/// assert_eq!(orientation(a, b, Point::from(2, 0)), 0.0);
+/// ```
+pub fn orientation(a: Point, b: Point, c: Point) -> f64 {
+    (b - a).cross(c - a)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Infinite line
+///
+/// Defined by a point and a direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Line {
+    /// A point on the line
+    pub point: Point,
+    /// Direction of the line, not necessarily normalized
+    pub direction: Point
+}
+
+/// Implementing required methods
+impl Line {
+    /// # Line passing through two points
+    pub fn from_points(a: Point, b: Point) -> Self {
+        Self {
+            point: a,
+            direction: b - a
+        }
+    }
+
+    /// # Orthogonal projection of a point on the line
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Line };
+    /// let l = Line::from_points(Point::from(0, 0), Point::from(2, 2));
+    ///
+    /// assert_eq!(l.project(Point::from(2, 0)), Point::from(1, 1));
+    /// ```
+    pub fn project(&self, p: Point) -> Point {
+        let t: f64 = (p - self.point).dot(self.direction) / self.direction.dot(self.direction);
+        self.point + self.direction * t
+    }
+
+    /// # Distance from a point to the line
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Line };
+    /// let l = Line::from_points(Point::from(0, 1), Point::from(3, 1));
+    ///
+    /// assert_eq!(l.distance(Point::from(-5, 4)), 3.0);
+    /// ```
+    pub fn distance(&self, p: Point) -> f64 {
+        self.direction.cross(p - self.point).abs() / self.direction.norm()
+    }
+
+    /// # Intersection of two lines
+    ///
+    /// Returns `None` if the lines are parallel.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Line };
+    /// let l1 = Line::from_points(Point::from(0, 0), Point::from(1, 1));
+    /// let l2 = Line::from_points(Point::from(0, 2), Point::from(2, 0));
+    ///
+    /// assert_eq!(l1.intersection(&l2), Some(Point::from(1, 1)));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Point> {
+        let den: f64 = self.direction.cross(other.direction);
+
+        if den.abs() < PRECISION * self.direction.norm() * other.direction.norm() {
+            return None;
+        }
+
+        let t: f64 = (other.point - self.point).cross(other.direction) / den;
+        Some(self.point + self.direction * t)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Segment
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Segment {
+    /// First end
+    pub a: Point,
+    /// Second end
+    pub b: Point
+}
+
+/// Implementing required methods
+impl Segment {
+    /// # Creates a segment from its ends
+    pub fn new(a: Point, b: Point) -> Self {
+        Self { a, b }
+    }
+
+    /// # Length of the segment
+    pub fn length(&self) -> f64 {
+        self.a.distance(self.b)
+    }
+
+    /// # Middle of the segment
+    pub fn midpoint(&self) -> Point {
+        (self.a + self.b) / 2.0
+    }
+
+    /// # Closest point of the segment to a given point
+    pub fn closest(&self, p: Point) -> Point {
+        let d: Point = self.b - self.a;
+        let l2: f64 = d.dot(d);
+
+        if l2 == 0.0 {
+            return self.a;
+        }
+
+        let t: f64 = ((p - self.a).dot(d) / l2).clamp(0.0, 1.0);
+        self.a + d * t
+    }
+
+    /// # Distance from a point to the segment
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Segment };
+    /// let s = Segment::new(Point::from(0, 0), Point::from(2, 0));
+    ///
+    /// assert_eq!(s.distance(Point::from(1, 3)), 3.0);
+    /// assert_eq!(s.distance(Point::from(5, 4)), 5.0);  // Closest to the end
+    /// ```
+    pub fn distance(&self, p: Point) -> f64 {
+        self.closest(p).distance(p)
+    }
+
+    /// # Intersection of two segments
+    ///
+    /// Returns the crossing point, or `None` if the segments do not meet. Collinear overlapping
+    /// segments return one of the shared ends.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Segment };
+    /// let s1 = Segment::new(Point::from(0, 0), Point::from(2, 2));
+    /// let s2 = Segment::new(Point::from(0, 2), Point::from(2, 0));
+    /// let s3 = Segment::new(Point::from(3, 0), Point::from(4, 1));
+    ///
+    /// assert_eq!(s1.intersection(&s2), Some(Point::from(1, 1)));
+    /// assert_eq!(s1.intersection(&s3), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Point> {
+        let r: Point = self.b - self.a;
+        let s: Point = other.b - other.a;
+        let qp: Point = other.a - self.a;
+        let den: f64 = r.cross(s);
+        let scale: f64 = r.norm() * s.norm();
+
+        // Parallel segments: they only meet if collinear and overlapping
+        if den.abs() <= PRECISION * scale {
+            if qp.cross(r).abs() > PRECISION * r.norm() * qp.norm() {
+                return None;
+            }
+
+            for p in [other.a, other.b] {
+                if self.distance(p) <= PRECISION * scale.sqrt() {
+                    return Some(p);
+                }
+            }
+            for p in [self.a, self.b] {
+                if other.distance(p) <= PRECISION * scale.sqrt() {
+                    return Some(p);
+                }
+            }
+            return None;
+        }
+
+        let t: f64 = qp.cross(s) / den;
+        let u: f64 = qp.cross(r) / den;
+
+        if (-PRECISION..=1.0 + PRECISION).contains(&t) && (-PRECISION..=1.0 + PRECISION).contains(&u) {
+            Some(self.a + r * t)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Triangle
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle {
+    /// First vertex
+    pub a: Point,
+    /// Second vertex
+    pub b: Point,
+    /// Third vertex
+    pub c: Point
+}
+
+/// Implementing required methods
+impl Triangle {
+    /// # Creates a triangle from its vertices
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        Self { a, b, c }
+    }
+
+    /// # Area of the triangle
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Triangle };
+    /// let t = Triangle::new(Point::from(0, 0), Point::from(4, 0), Point::from(0, 3));
+    ///
+    /// assert_eq!(t.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> f64 {
+        orientation(self.a, self.b, self.c).abs() / 2.0
+    }
+
+    /// # Centroid of the triangle
+    pub fn centroid(&self) -> Point {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// # Center of the circumscribed circle
+    ///
+    /// Returns `None` for degenerate (flat) triangles.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Triangle };
+    /// let t = Triangle::new(Point::from(0, 0), Point::from(4, 0), Point::from(0, 3));
+    ///
+    /// assert_eq!(t.circumcenter(), Some(Point::from(2, 1.5)));
+    /// ```
+    pub fn circumcenter(&self) -> Option<Point> {
+        let b: Point = self.b - self.a;
+        let c: Point = self.c - self.a;
+        let d: f64 = 2.0 * b.cross(c);
+
+        if d.abs() < PRECISION * b.dot(b).max(c.dot(c)) {
+            return None;
+        }
+
+        let b2: f64 = b.dot(b);
+        let c2: f64 = c.dot(c);
+
+        Some(self.a + Point::from((c.y * b2 - b.y * c2) / d, (b.x * c2 - c.x * b2) / d))
+    }
+
+    /// # Point in triangle test
+    ///
+    /// Points on the edges are considered inside.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Triangle };
+    /// let t = Triangle::new(Point::from(0, 0), Point::from(4, 0), Point::from(0, 3));
+    ///
+    /// assert!(t.contains(Point::from(1, 1)));
+    /// assert!(t.contains(Point::from(2, 0)));
+    /// assert!(!t.contains(Point::from(3, 3)));
+    /// ```
+    pub fn contains(&self, p: Point) -> bool {
+        let d1: f64 = orientation(self.a, self.b, p);
+        let d2: f64 = orientation(self.b, self.c, p);
+        let d3: f64 = orientation(self.c, self.a, p);
+
+        let has_neg: bool = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_pos: bool = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+        !(has_neg && has_pos)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Simple polygon
+///
+/// Defined by the ordered list of its vertices, the last one being implicitly connected to the first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polygon {
+    /// Ordered vertices of the polygon
+    pub vertices: Vec<Point>
+}
+
+/// Implementing required methods
+impl Polygon {
+    /// # Creates a polygon from its vertices
+    pub fn new(vertices: &[Point]) -> Self {
+        Self {
+            vertices: vertices.to_vec()
+        }
+    }
+
+    /// Iterates over the edges of the polygon
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n: usize = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// # Signed area
+    ///
+    /// Computed with the shoelace formula, positive when the vertices are counter-clockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().fold(0.0, |res, (p, q)| res + p.cross(q)) / 2.0
+    }
+
+    /// # Area of the polygon
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Polygon };
+    /// // L shaped polygon
+    /// let poly = Polygon::new(&[
+    ///     Point::from(0, 0), Point::from(2, 0), Point::from(2, 1),
+    ///     Point::from(1, 1), Point::from(1, 2), Point::from(0, 2)
+    /// ]);
+    ///
+    /// assert_eq!(poly.area(), 3.0);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// # Perimeter of the polygon
+    pub fn perimeter(&self) -> f64 {
+        self.edges().fold(0.0, |res, (p, q)| res + p.distance(q))
+    }
+
+    /// # Centroid of the polygon
+    ///
+    /// Center of mass of the surface (not of the vertices).
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Polygon };
+    /// let poly = Polygon::new(&[
+    ///     Point::from(0, 0), Point::from(2, 0), Point::from(2, 1),
+    ///     Point::from(1, 1), Point::from(1, 2), Point::from(0, 2)
+    /// ]);
+    /// let c = poly.centroid();
+    ///
+    /// assert!((c.x - 5.0 / 6.0).abs() < 1.0e-15 && (c.y - 5.0 / 6.0).abs() < 1.0e-15);
+    /// ```
+    pub fn centroid(&self) -> Point {
+        let a: f64 = self.signed_area();
+        let sum: Point = self.edges().fold(Point::new(), |res, (p, q)| res + (p + q) * p.cross(q));
+        sum / (6.0 * a)
+    }
+
+    /// # Point in polygon test
+    ///
+    /// Uses the ray casting (even-odd) rule, so that it works for any simple polygon, convex or not.
+    ///
+    /// ```
+    /// # use scilib::geometry::planar::{ Point, Polygon };
+    /// let poly = Polygon::new(&[
+    ///     Point::from(0, 0), Point::from(2, 0), Point::from(2, 1),
+    ///     Point::from(1, 1), Point::from(1, 2), Point::from(0, 2)
+    /// ]);
+    ///
+    /// assert!(poly.contains(Point::from(0.5, 1.5)));
+    /// assert!(!poly.contains(Point::from(1.5, 1.5)));
+    /// ```
+    pub fn contains(&self, p: Point) -> bool {
+        let mut inside: bool = false;
+
+        for (a, b) in self.edges() {
+            // Checking if the horizontal ray going right crosses the edge
+            if (a.y > p.y) != (b.y > p.y) {
+                let x: f64 = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+                if p.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Operations on points
+
+/// # Addition
+impl Add for Point {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y
+        }
+    }
+}
+
+/// # Subtraction
+impl Sub for Point {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y
+        }
+    }
+}
+
+/// # Scalar multiplication
+impl<T: Into<f64>> Mul<T> for Point {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        let f: f64 = rhs.into();
+        Self {
+            x: self.x * f,
+            y: self.y * f
+        }
+    }
+}
+
+/// # Scalar division
+impl<T: Into<f64>> Div<T> for Point {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        let f: f64 = rhs.into();
+        Self {
+            x: self.x / f,
+            y: self.y / f
+        }
+    }
+}
+
+/// # Negation
+impl Neg for Point {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
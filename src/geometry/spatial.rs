@@ -0,0 +1,489 @@
+//!
+//! # Spatial geometry
+//!
+//! Primitives of the 3D space, built on top of the `Cartesian` coordinates: lines, rays, segments, planes,
+//! spheres and triangles, with the associated distance and intersection tests.
+//!
+//! ```
+//! # use scilib::coordinate::cartesian::Cartesian;
+//! # use scilib::geometry::spatial::{ Ray, Sphere };
+//! let ray = Ray::new(Cartesian::from(-5, 0, 0), Cartesian::from(1, 0, 0));
+//! let ball = Sphere::new(Cartesian::new(), 2.0);
+//!
+//! // The ray enters the sphere after travelling 3 units
+//! assert_eq!(ray.intersect_sphere(&ball), Some(3.0));
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;               // Pi
+
+use crate::coordinate::cartesian::*;    // Points of the space
+
+use super::PRECISION;                   // Tolerance for degenerate cases
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Infinite line
+///
+/// Defined by a point and a direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Line {
+    /// A point on the line
+    pub point: Cartesian,
+    /// Direction of the line, not necessarily normalized
+    pub direction: Cartesian
+}
+
+/// Implementing required methods
+impl Line {
+    /// # Line passing through two points
+    pub fn from_points(a: Cartesian, b: Cartesian) -> Self {
+        Self {
+            point: a,
+            direction: b - a
+        }
+    }
+
+    /// # Orthogonal projection of a point on the line
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Line;
+    /// let l = Line::from_points(Cartesian::new(), Cartesian::from(0, 0, 2));
+    ///
+    /// assert_eq!(l.project(Cartesian::from(3, 4, 1)), Cartesian::from(0, 0, 1));
+    /// ```
+    pub fn project(&self, p: Cartesian) -> Cartesian {
+        let t: f64 = (p - self.point).dot(&self.direction) / self.direction.dot(&self.direction);
+        self.point + self.direction * t
+    }
+
+    /// # Distance from a point to the line
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Line;
+    /// let l = Line::from_points(Cartesian::new(), Cartesian::from(0, 0, 2));
+    ///
+    /// assert_eq!(l.distance(Cartesian::from(3, 4, 1)), 5.0);
+    /// ```
+    pub fn distance(&self, p: Cartesian) -> f64 {
+        self.direction.cross(&(p - self.point)).norm() / self.direction.norm()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Segment
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Segment {
+    /// First end
+    pub a: Cartesian,
+    /// Second end
+    pub b: Cartesian
+}
+
+/// Implementing required methods
+impl Segment {
+    /// # Creates a segment from its ends
+    pub fn new(a: Cartesian, b: Cartesian) -> Self {
+        Self { a, b }
+    }
+
+    /// # Length of the segment
+    pub fn length(&self) -> f64 {
+        self.a.distance(self.b)
+    }
+
+    /// # Middle of the segment
+    pub fn midpoint(&self) -> Cartesian {
+        (self.a + self.b) / 2.0
+    }
+
+    /// # Closest point of the segment to a given point
+    pub fn closest(&self, p: Cartesian) -> Cartesian {
+        let d: Cartesian = self.b - self.a;
+        let l2: f64 = d.dot(&d);
+
+        if l2 == 0.0 {
+            return self.a;
+        }
+
+        let t: f64 = ((p - self.a).dot(&d) / l2).clamp(0.0, 1.0);
+        self.a + d * t
+    }
+
+    /// # Distance from a point to the segment
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Segment;
+    /// let s = Segment::new(Cartesian::new(), Cartesian::from(1, 0, 0));
+    ///
+    /// assert_eq!(s.distance(Cartesian::from(0.5, 0, 2)), 2.0);
+    /// assert_eq!(s.distance(Cartesian::from(4, 4, 0)), 5.0);
+    /// ```
+    pub fn distance(&self, p: Cartesian) -> f64 {
+        self.closest(p).distance(p)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Ray
+///
+/// Half line starting at an origin and going along a direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray {
+    /// Start of the ray
+    pub origin: Cartesian,
+    /// Unit direction of the ray
+    pub direction: Cartesian
+}
+
+/// Implementing required methods
+impl Ray {
+    /// # Creates a ray
+    ///
+    /// The `direction` is normalized, so that the distances returned by the intersection methods are
+    /// actual lengths along the ray.
+    pub fn new(origin: Cartesian, direction: Cartesian) -> Self {
+        Self {
+            origin,
+            direction: direction / direction.norm()
+        }
+    }
+
+    /// # Point along the ray at a given distance
+    pub fn at<T: Into<f64>>(&self, t: T) -> Cartesian {
+        self.origin + self.direction * t
+    }
+
+    /// # Intersection with a plane
+    ///
+    /// Returns the distance from the origin to the hit point, or `None` if the ray is parallel to
+    /// the plane or points away from it.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::{ Ray, Plane };
+    /// let ray = Ray::new(Cartesian::from(0, 0, 5), Cartesian::from(0, 0, -1));
+    /// let ground = Plane::new(Cartesian::new(), Cartesian::from(0, 0, 1));
+    ///
+    /// assert_eq!(ray.intersect_plane(&ground), Some(5.0));
+    /// assert_eq!(ray.at(5), Cartesian::new());
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f64> {
+        let den: f64 = self.direction.dot(&plane.normal);
+
+        if den.abs() < PRECISION {
+            return None;
+        }
+
+        let t: f64 = (plane.point - self.origin).dot(&plane.normal) / den;
+        if t >= 0.0 { Some(t) } else { None }
+    }
+
+    /// # Intersection with a sphere
+    ///
+    /// Returns the distance to the first hit of the sphere surface. If the origin is inside the sphere,
+    /// the exit point is returned.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::{ Ray, Sphere };
+    /// let ball = Sphere::new(Cartesian::new(), 1.0);
+    /// let inside = Ray::new(Cartesian::new(), Cartesian::from(1, 1, 0));
+    /// let miss = Ray::new(Cartesian::from(-5, 2, 0), Cartesian::from(1, 0, 0));
+    ///
+    /// assert!((inside.intersect_sphere(&ball).unwrap() - 1.0).abs() < 1.0e-15);
+    /// assert_eq!(miss.intersect_sphere(&ball), None);
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f64> {
+        let oc: Cartesian = self.origin - sphere.center;
+        let b: f64 = oc.dot(&self.direction);
+        let c: f64 = oc.dot(&oc) - sphere.radius.powi(2);
+        let delta: f64 = b.powi(2) - c;
+
+        if delta < 0.0 {
+            return None;
+        }
+
+        let sq: f64 = delta.sqrt();
+        if -b - sq >= 0.0 {
+            Some(-b - sq)
+        } else if -b + sq >= 0.0 {
+            Some(-b + sq)
+        } else {
+            None
+        }
+    }
+
+    /// # Intersection with a triangle
+    ///
+    /// Uses the Möller–Trumbore algorithm, and returns the distance to the hit point.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::{ Ray, Triangle };
+    /// let tri = Triangle::new(Cartesian::from(0, 0, 1), Cartesian::from(2, 0, 1), Cartesian::from(0, 2, 1));
+    /// let hit = Ray::new(Cartesian::from(0.5, 0.5, 0), Cartesian::from(0, 0, 1));
+    /// let miss = Ray::new(Cartesian::from(1.5, 1.5, 0), Cartesian::from(0, 0, 1));
+    ///
+    /// assert_eq!(hit.intersect_triangle(&tri), Some(1.0));
+    /// assert_eq!(miss.intersect_triangle(&tri), None);
+    /// ```
+    pub fn intersect_triangle(&self, tri: &Triangle) -> Option<f64> {
+        let e1: Cartesian = tri.b - tri.a;
+        let e2: Cartesian = tri.c - tri.a;
+        let p: Cartesian = self.direction.cross(&e2);
+        let det: f64 = e1.dot(&p);
+
+        if det.abs() < PRECISION * e1.norm() * e2.norm() {
+            return None;
+        }
+
+        let s: Cartesian = self.origin - tri.a;
+        let u: f64 = s.dot(&p) / det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q: Cartesian = s.cross(&e1);
+        let v: f64 = self.direction.dot(&q) / det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t: f64 = e2.dot(&q) / det;
+        if t >= 0.0 { Some(t) } else { None }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Plane
+///
+/// Defined by a point and its normal.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane {
+    /// A point of the plane
+    pub point: Cartesian,
+    /// Unit normal of the plane
+    pub normal: Cartesian
+}
+
+/// Implementing required methods
+impl Plane {
+    /// # Creates a plane
+    ///
+    /// The `normal` is normalized on creation.
+    pub fn new(point: Cartesian, normal: Cartesian) -> Self {
+        Self {
+            point,
+            normal: normal / normal.norm()
+        }
+    }
+
+    /// # Plane passing through three points
+    ///
+    /// The normal is oriented following the right hand rule on `(a, b, c)`. Returns `None` if the points
+    /// are aligned.
+    pub fn from_points(a: Cartesian, b: Cartesian, c: Cartesian) -> Option<Self> {
+        let n: Cartesian = (b - a).cross(&(c - a));
+
+        if n.norm() < PRECISION * (b - a).norm() * (c - a).norm() {
+            return None;
+        }
+
+        Some(Self::new(a, n))
+    }
+
+    /// # Signed distance from a point to the plane
+    ///
+    /// Positive on the side the normal points to.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Plane;
+    /// let p = Plane::from_points(Cartesian::new(), Cartesian::from(1, 0, 0), Cartesian::from(0, 1, 0)).unwrap();
+    ///
+    /// assert_eq!(p.signed_distance(Cartesian::from(4, 2, 3)), 3.0);
+    /// assert_eq!(p.signed_distance(Cartesian::from(4, 2, -1)), -1.0);
+    /// ```
+    pub fn signed_distance(&self, p: Cartesian) -> f64 {
+        (p - self.point).dot(&self.normal)
+    }
+
+    /// # Orthogonal projection of a point on the plane
+    pub fn project(&self, p: Cartesian) -> Cartesian {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// # Intersection with a line
+    ///
+    /// Returns `None` if the line is parallel to the plane.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::{ Plane, Line };
+    /// let p = Plane::new(Cartesian::from(0, 0, 2), Cartesian::from(0, 0, 1));
+    /// let l = Line::from_points(Cartesian::new(), Cartesian::from(1, 1, 1));
+    ///
+    /// assert_eq!(p.intersect_line(&l), Some(Cartesian::from(2, 2, 2)));
+    /// ```
+    pub fn intersect_line(&self, line: &Line) -> Option<Cartesian> {
+        let den: f64 = line.direction.dot(&self.normal);
+
+        if den.abs() < PRECISION * line.direction.norm() {
+            return None;
+        }
+
+        let t: f64 = (self.point - line.point).dot(&self.normal) / den;
+        Some(line.point + line.direction * t)
+    }
+
+    /// # Intersection with another plane
+    ///
+    /// Returns the line common to both planes, or `None` if they are parallel.
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Plane;
+    /// let p1 = Plane::new(Cartesian::new(), Cartesian::from(0, 0, 1));
+    /// let p2 = Plane::new(Cartesian::from(1, 0, 0), Cartesian::from(1, 0, 0));
+    /// let l = p1.intersect_plane(&p2).unwrap();
+    ///
+    /// assert_eq!(l.distance(Cartesian::from(1, 5, 0)), 0.0);
+    /// assert_eq!(l.distance(Cartesian::from(1, 0, 3)), 3.0);
+    /// ```
+    pub fn intersect_plane(&self, other: &Self) -> Option<Line> {
+        let dir: Cartesian = self.normal.cross(&other.normal);
+        let d2: f64 = dir.dot(&dir);
+
+        if d2 < PRECISION {
+            return None;
+        }
+
+        // Point of the line closest to the origin
+        let h1: f64 = self.normal.dot(&self.point);
+        let h2: f64 = other.normal.dot(&other.point);
+        let point: Cartesian = (other.normal.cross(&dir) * h1 + dir.cross(&self.normal) * h2) / d2;
+
+        Some(Line { point, direction: dir })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Sphere
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere {
+    /// Center of the sphere
+    pub center: Cartesian,
+    /// Radius of the sphere
+    pub radius: f64
+}
+
+/// Implementing required methods
+impl Sphere {
+    /// # Creates a sphere
+    pub fn new<T: Into<f64>>(center: Cartesian, radius: T) -> Self {
+        Self {
+            center,
+            radius: radius.into()
+        }
+    }
+
+    /// # Volume of the sphere
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Sphere;
+    /// let s = Sphere::new(Cartesian::new(), 3);
+    ///
+    /// assert!((s.volume() - 36.0 * std::f64::consts::PI).abs() < 1.0e-12);
+    /// ```
+    pub fn volume(&self) -> f64 {
+        4.0 / 3.0 * PI * self.radius.powi(3)
+    }
+
+    /// # Area of the surface
+    pub fn surface(&self) -> f64 {
+        4.0 * PI * self.radius.powi(2)
+    }
+
+    /// # Point in sphere test
+    ///
+    /// Points on the surface are considered inside.
+    pub fn contains(&self, p: Cartesian) -> bool {
+        self.center.distance(p) <= self.radius
+    }
+
+    /// # Sphere overlap test
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Sphere;
+    /// let s1 = Sphere::new(Cartesian::new(), 1);
+    /// let s2 = Sphere::new(Cartesian::from(1.5, 0, 0), 1);
+    /// let s3 = Sphere::new(Cartesian::from(0, 3, 0), 1);
+    ///
+    /// assert!(s1.intersects(&s2));
+    /// assert!(!s1.intersects(&s3));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.center.distance(other.center) <= self.radius + other.radius
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Triangle
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle {
+    /// First vertex
+    pub a: Cartesian,
+    /// Second vertex
+    pub b: Cartesian,
+    /// Third vertex
+    pub c: Cartesian
+}
+
+/// Implementing required methods
+impl Triangle {
+    /// # Creates a triangle from its vertices
+    pub fn new(a: Cartesian, b: Cartesian, c: Cartesian) -> Self {
+        Self { a, b, c }
+    }
+
+    /// # Area of the triangle
+    ///
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::geometry::spatial::Triangle;
+    /// let t = Triangle::new(Cartesian::from(1, 0, 0), Cartesian::from(0, 1, 0), Cartesian::from(0, 0, 1));
+    ///
+    /// assert!((t.area() - 3.0f64.sqrt() / 2.0).abs() < 1.0e-15);
+    /// ```
+    pub fn area(&self) -> f64 {
+        (self.b - self.a).cross(&(self.c - self.a)).norm() / 2.0
+    }
+
+    /// # Unit normal of the triangle
+    ///
+    /// Oriented following the right hand rule on `(a, b, c)`.
+    pub fn normal(&self) -> Cartesian {
+        let n: Cartesian = (self.b - self.a).cross(&(self.c - self.a));
+        n / n.norm()
+    }
+
+    /// # Centroid of the triangle
+    pub fn centroid(&self) -> Cartesian {
+        (self.a + self.b + self.c) / 3.0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,291 @@
+//!
+//! # Tessellation
+//!
+//! Algorithms working on sets of scattered points of the plane: convex hull, Delaunay triangulation and
+//! Voronoi diagram. Triangles are given as indices into the input slice, so that values attached to the
+//! points can be retrieved easily.
+//!
+//! ```
+//! # use scilib::geometry::planar::Point;
+//! # use scilib::geometry::tessellation::{ convex_hull, delaunay };
+//! let pts = vec![
+//!     Point::from(0, 0), Point::from(3, 0), Point::from(3, 2),
+//!     Point::from(0, 2), Point::from(1, 1)
+//! ];
+//!
+//! assert_eq!(convex_hull(&pts).len(), 4);
+//! assert_eq!(delaunay(&pts).len(), 4);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::planar::{    // 2D primitives
+    Point,              // Point of the plane
+    Polygon,            // Voronoi cells
+    orientation         // Orientation test
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Convex hull
+///
+/// Computes the convex hull with Andrew's monotone chain, a variant of the Graham scan working on points
+/// sorted by coordinates rather than by angle. Runs in `O(n log n)`.
+///
+/// The hull vertices are returned in counter-clockwise order, starting from the lowest-leftmost point.
+/// Points lying on the edges of the hull are not included.
+///
+/// ```
+/// # use scilib::geometry::planar::Point;
+/// # use scilib::geometry::tessellation::convex_hull;
+/// let pts = vec![
+///     Point::from(0, 0), Point::from(1, 1), Point::from(2, 0), Point::from(1, 0),
+///     Point::from(2, 2), Point::from(0, 2), Point::from(0.5, 1.5)
+/// ];
+/// let hull = convex_hull(&pts);
+///
+/// assert_eq!(hull, vec![Point::from(0, 0), Point::from(2, 0), Point::from(2, 2), Point::from(0, 2)]);
+/// ```
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+
+    let mut pts: Vec<Point> = points.to_vec();
+    pts.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    pts.dedup();
+
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    let mut hull: Vec<Point> = Vec::with_capacity(2 * pts.len());
+
+    // Lower hull, then upper hull going back
+    for pass in [pts.clone(), pts.iter().rev().copied().collect()] {
+        let start: usize = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2 && orientation(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point is the first of the next chain
+        hull.pop();
+    }
+
+    hull
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Delaunay triangulation
+///
+/// Uses the Bowyer–Watson algorithm: points are inserted one by one inside a large enclosing triangle,
+/// each insertion removing the triangles whose circumcircle contains the new point and re-triangulating
+/// the resulting cavity. The cavity is grown from the triangle containing the point through its edges, so
+/// that it stays connected and star-shaped even when the circumcircle tests are ambiguous, as for
+/// co-circular points. Runs in `O(n^2)` in the worst case.
+///
+/// Returns the triangles as indices of the points, in counter-clockwise order. The points are expected
+/// to be distinct; for co-circular points, one of the valid triangulations is returned.
+///
+/// ```
+/// # use scilib::geometry::planar::{ Point, orientation };
+/// # use scilib::geometry::tessellation::{ delaunay, convex_hull };
+/// let pts: Vec<Point> = (0..20).map(|i| {
+///     let t = i as f64;
+///     Point::from((2.3 * t).sin() * t, (1.7 * t).cos() * t)
+/// }).collect();
+/// let tri = delaunay(&pts);
+///
+/// // Euler's formula for a triangulation: 2n - h - 2 triangles
+/// assert_eq!(tri.len(), 2 * pts.len() - convex_hull(&pts).len() - 2);
+///
+/// // Vertices of a regular polygon, all on the same circle
+/// let poly: Vec<Point> = (0..12).map(|i| {
+///     let t = i as f64 * std::f64::consts::PI / 6.0;
+///     Point::from(t.cos(), t.sin())
+/// }).collect();
+/// let tri = delaunay(&poly);
+/// let area: f64 = tri.iter().map(|t| orientation(poly[t[0]], poly[t[1]], poly[t[2]]) / 2.0).sum();
+///
+/// assert_eq!(tri.len(), 10);
+/// assert!((area - 3.0).abs() < 1.0e-12);
+/// ```
+pub fn delaunay(points: &[Point]) -> Vec<[usize; 3]> {
+
+    let n: usize = points.len();
+    if n < 3 {
+        return vec![];
+    }
+
+    // Enclosing triangle, large enough not to interfere with the hull of the points
+    let (mut min, mut max): (Point, Point) = (points[0], points[0]);
+    for p in points {
+        min = Point::from(min.x.min(p.x), min.y.min(p.y));
+        max = Point::from(max.x.max(p.x), max.y.max(p.y));
+    }
+    let center: Point = (min + max) / 2.0;
+    let size: f64 = (max.x - min.x).max(max.y - min.y).max(f64::MIN_POSITIVE) * 1.0e3;
+
+    let mut pts: Vec<Point> = points.to_vec();
+    pts.push(center + Point::from(-2.0 * size, -size));
+    pts.push(center + Point::from(2.0 * size, -size));
+    pts.push(center + Point::from(0.0, 2.0 * size));
+
+    let mut tris: Vec<[usize; 3]> = vec![[n, n + 1, n + 2]];
+
+    for idx in 0..n {
+        let p: Point = pts[idx];
+
+        // Triangle containing the point, possibly on one of its edges
+        let start: usize = match tris.iter().position(|t| {
+            (0..3).all(|k| orientation(pts[t[k]], pts[t[(k + 1) % 3]], p) >= 0.0)
+        }) {
+            Some(s) => s,
+            None => continue
+        };
+
+        // Growing the cavity through the edges, to the neighbors whose circumcircle contain the point. The
+        // neighbors behind an edge not facing the point are always taken, to keep the cavity star-shaped.
+        let mut bad: Vec<bool> = vec![false; tris.len()];
+        let mut stack: Vec<usize> = vec![start];
+        bad[start] = true;
+
+        while let Some(t) = stack.pop() {
+            for k in 0..3 {
+                let (a, b): (usize, usize) = (tris[t][k], tris[t][(k + 1) % 3]);
+                let next: Option<usize> = tris.iter().position(|u| {
+                    (0..3).any(|m| u[m] == b && u[(m + 1) % 3] == a)
+                });
+
+                if let Some(u) = next {
+                    if !bad[u] && (orientation(pts[a], pts[b], p) <= 0.0
+                        || in_circle(pts[tris[u][0]], pts[tris[u][1]], pts[tris[u][2]], p)) {
+                        bad[u] = true;
+                        stack.push(u);
+                    }
+                }
+            }
+        }
+
+        let cavity: Vec<[usize; 3]> = (0..tris.len()).filter(|&i| bad[i]).map(|i| tris[i]).collect();
+        tris = (0..tris.len()).filter(|&i| !bad[i]).map(|i| tris[i]).collect();
+
+        // The boundary of the cavity is made of the edges belonging to a single bad triangle
+        let edges: Vec<(usize, usize)> = cavity.iter()
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .collect();
+
+        for &(a, b) in &edges {
+            if !edges.iter().any(|&(c, d)| c == b && d == a) {
+                tris.push([a, b, idx]);
+            }
+        }
+    }
+
+    // Removing the triangles connected to the enclosing one
+    tris.retain(|t| t.iter().all(|&i| i < n));
+    tris
+}
+
+/// Checks if `p` lies strictly inside the circumcircle of the counter-clockwise triangle `(a, b, c)`
+fn in_circle(a: Point, b: Point, c: Point, p: Point) -> bool {
+    let (ax, ay): (f64, f64) = (a.x - p.x, a.y - p.y);
+    let (bx, by): (f64, f64) = (b.x - p.x, b.y - p.y);
+    let (cx, cy): (f64, f64) = (c.x - p.x, c.y - p.y);
+
+    let det: f64 = (ax * ax + ay * ay) * (bx * cy - cx * by)
+        - (bx * bx + by * by) * (ax * cy - cx * ay)
+        + (cx * cx + cy * cy) * (ax * by - bx * ay);
+
+    det > 0.0
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Voronoi diagram
+///
+/// Computes the Voronoi cell of each point, clipped to the rectangle spanned by `min` and `max`. The cells
+/// are derived from the Delaunay triangulation: each one is the intersection of the half-planes bounded by
+/// the bisectors with the Delaunay neighbors of the point.
+///
+/// The `i`-th polygon is the cell of the `i`-th point, with vertices in counter-clockwise order.
+///
+/// ```
+/// # use scilib::geometry::planar::Point;
+/// # use scilib::geometry::tessellation::voronoi;
+/// let pts = vec![Point::from(1, 1), Point::from(3, 1), Point::from(1, 3), Point::from(3, 3)];
+/// let cells = voronoi(&pts, Point::from(0, 0), Point::from(4, 4));
+///
+/// // Each cell is one quadrant of the box
+/// for c in &cells {
+///     assert!((c.area() - 4.0).abs() < 1.0e-12);
+/// }
+/// assert!(cells[3].contains(Point::from(2.5, 3.5)));
+///
+/// // Co-circular points, whose cells still tile the box
+/// let ring: Vec<Point> = (0..12).map(|i| {
+///     let t = i as f64 * std::f64::consts::PI / 6.0;
+///     Point::from(2.0 + t.cos(), 2.0 + t.sin())
+/// }).collect();
+/// let cells = voronoi(&ring, Point::from(0, 0), Point::from(4, 4));
+/// assert!((cells.iter().map(|c| c.area()).sum::<f64>() - 16.0).abs() < 1.0e-12);
+/// ```
+pub fn voronoi(points: &[Point], min: Point, max: Point) -> Vec<Polygon> {
+
+    let n: usize = points.len();
+    let mut neighbors: Vec<Vec<usize>> = vec![vec![]; n];
+
+    if n == 2 {
+        neighbors = vec![vec![1], vec![0]];
+    }
+
+    for t in delaunay(points) {
+        for k in 0..3 {
+            let (a, b): (usize, usize) = (t[k], t[(k + 1) % 3]);
+            if !neighbors[a].contains(&b) {
+                neighbors[a].push(b);
+                neighbors[b].push(a);
+            }
+        }
+    }
+
+    // Degenerate sets (all points aligned) have no triangulation, falling back to all the pairs
+    if n > 2 && neighbors.iter().any(|v| v.is_empty()) {
+        neighbors = (0..n).map(|i| (0..n).filter(|&j| j != i).collect()).collect();
+    }
+
+    let frame: Vec<Point> = vec![min, Point::from(max.x, min.y), max, Point::from(min.x, max.y)];
+
+    (0..n).map(|i| {
+        let p: Point = points[i];
+        let cell: Vec<Point> = neighbors[i].iter().fold(frame.clone(), |poly, &j| {
+            let dir: Point = points[j] - p;
+            let mid: Point = (points[j] + p) / 2.0;
+            clip(&poly, mid, dir)
+        });
+        Polygon::new(&cell)
+    }).collect()
+}
+
+/// Clips a convex polygon to the half-plane `(x - mid) . dir <= 0` (Sutherland–Hodgman)
+fn clip(poly: &[Point], mid: Point, dir: Point) -> Vec<Point> {
+    let mut res: Vec<Point> = Vec::with_capacity(poly.len() + 1);
+    let side = |q: Point| (q - mid).dot(dir);
+
+    for k in 0..poly.len() {
+        let (a, b): (Point, Point) = (poly[k], poly[(k + 1) % poly.len()]);
+        let (sa, sb): (f64, f64) = (side(a), side(b));
+
+        if sa <= 0.0 {
+            res.push(a);
+        }
+        if (sa < 0.0 && sb > 0.0) || (sa > 0.0 && sb < 0.0) {
+            res.push(a + (b - a) * (sa / (sa - sb)));
+        }
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
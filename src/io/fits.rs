@@ -0,0 +1,198 @@
+//!
+//! # FITS images
+//!
+//! Minimal support for the Flexible Image Transport System, the standard format of astronomical data:
+//! reading and writing of the primary image HDU of a file. Extensions, tables and compression are not
+//! supported, and are ignored when reading.
+//!
+//! Images are written as 64 bits floats. Reading accepts every `BITPIX`, and applies `BSCALE` and `BZERO`.
+//! The axes are reversed compared to the FITS convention, so that a `NAXIS1 x NAXIS2` image gives an array
+//! of `NAXIS2` rows of `NAXIS1` values, as done by astropy.
+//!
+//! ```
+//! # use scilib::io::{ Array, fits };
+//! let image = Array::from_rows(&[vec![0.5, 1.0, 1.5], vec![2.0, 2.5, 3.0]]);
+//! let bytes = fits::to_bytes(&image);
+//!
+//! assert_eq!(bytes.len() % 2880, 0);
+//! assert!(bytes.starts_with(b"SIMPLE  =                    T"));
+//! assert_eq!(fits::from_bytes(&bytes), Ok(image));
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::fs;                    // File access
+
+use std::path::Path;            // File paths
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+use super::Array;               // Exchanged arrays
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Size of the FITS blocks
+const BLOCK: usize = 2880;
+
+/// Size of a header card
+const CARD: usize = 80;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Encodes an image
+///
+/// Produces a single primary HDU with `BITPIX = -64`.
+pub fn to_bytes(array: &Array) -> Vec<u8> {
+
+    let mut res: Vec<u8> = Vec::new();
+
+    push_card(&mut res, "SIMPLE", "T");
+    push_card(&mut res, "BITPIX", "-64");
+    push_card(&mut res, "NAXIS", &array.shape.len().to_string());
+    for (i, n) in array.shape.iter().rev().enumerate() {
+        push_card(&mut res, &format!("NAXIS{}", i + 1), &n.to_string());
+    }
+    res.extend_from_slice(format!("{:<80}", "END").as_bytes());
+    res.resize(res.len().div_ceil(BLOCK) * BLOCK, b' ');
+
+    for v in &array.data {
+        res.extend_from_slice(&v.to_be_bytes());
+    }
+    res.resize(res.len().div_ceil(BLOCK) * BLOCK, 0);
+
+    res
+}
+
+/// # Decodes an image
+///
+/// Reads the primary HDU, returning a `Format` error if it is invalid or does not hold an image.
+///
+/// ```
+/// # use scilib::io::fits;
+/// // A 16 bits image, with scaling
+/// let mut bytes = Vec::new();
+/// for card in ["SIMPLE  = T", "BITPIX  = 16", "NAXIS   = 1", "NAXIS1  = 3", "BSCALE  = 0.5", "BZERO   = 10", "END"] {
+///     bytes.extend(format!("{:<80}", card).bytes());
+/// }
+/// bytes.resize(2880, b' ');
+/// for v in [-2_i16, 0, 7] {
+///     bytes.extend(v.to_be_bytes());
+/// }
+/// bytes.resize(5760, 0);
+///
+/// assert_eq!(fits::from_bytes(&bytes).unwrap().data, vec![9.0, 10.0, 13.5]);
+///
+/// // Malformed headers are errors
+/// bytes[240..320].copy_from_slice(format!("{:<80}", "NAXIS0  = 3").as_bytes());
+/// assert!(fits::from_bytes(&bytes).is_err());
+/// bytes[240..320].copy_from_slice(format!("{:<80}", "NAXIS1  = 4611686018427387904").as_bytes());
+/// assert!(fits::from_bytes(&bytes).is_err());
+/// bytes[7..9].copy_from_slice("é".as_bytes());
+/// assert!(fits::from_bytes(&bytes).is_err());
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> Result<Array> {
+
+    let mut bitpix: Option<i64> = None;
+    let mut axes: Vec<usize> = Vec::new();
+    let mut naxis: Option<usize> = None;
+    let mut scale: f64 = 1.0;
+    let mut zero: f64 = 0.0;
+    let mut end: Option<usize> = None;
+
+    for (idx, card) in bytes.chunks_exact(CARD).enumerate() {
+        if !card.is_ascii() {
+            return Err(Error::Format("non ASCII FITS header"));
+        }
+        let card: &str = std::str::from_utf8(card).map_err(|_| Error::Format("non ASCII FITS header"))?;
+        let key: &str = card[..8].trim_end();
+
+        if idx == 0 && key != "SIMPLE" {
+            return Err(Error::Format("missing FITS SIMPLE card"));
+        }
+        if key == "END" {
+            end = Some(idx + 1);
+            break;
+        }
+
+        // Only value cards are of interest
+        if card.get(8..10) != Some("= ") {
+            continue;
+        }
+        let value: &str = card[10..].split('/').next().unwrap_or("").trim();
+        let invalid: Error = Error::Format("invalid FITS header value");
+
+        match key {
+            "BITPIX" => bitpix = Some(value.parse().map_err(|_| invalid)?),
+            "NAXIS" => naxis = Some(value.parse().map_err(|_| invalid)?),
+            "BSCALE" => scale = value.parse().map_err(|_| invalid)?,
+            "BZERO" => zero = value.parse().map_err(|_| invalid)?,
+            _ if key.starts_with("NAXIS") => {
+                // The standard allows up to 999 axes, numbered from 1
+                let n: usize = key[5..].parse().map_err(|_| invalid)?;
+                if n == 0 || n > 999 {
+                    return Err(Error::Format("invalid FITS axis number"));
+                }
+                if axes.len() < n {
+                    axes.resize(n, 0);
+                }
+                axes[n - 1] = value.parse().map_err(|_| invalid)?;
+            },
+            _ => ()
+        }
+    }
+
+    let end: usize = end.ok_or(Error::Format("missing FITS END card"))?;
+    let bitpix: i64 = bitpix.ok_or(Error::Format("missing FITS BITPIX card"))?;
+    let naxis: usize = naxis.ok_or(Error::Format("missing FITS NAXIS card"))?;
+    if axes.len() != naxis {
+        return Err(Error::Format("inconsistent FITS axes"));
+    }
+
+    let size: usize = match bitpix {
+        8 | 16 | 32 | 64 => bitpix as usize / 8,
+        -32 | -64 => (-bitpix) as usize / 8,
+        _ => return Err(Error::Format("invalid FITS BITPIX"))
+    };
+
+    // Data starts on the block following the header
+    let start: usize = (end * CARD).div_ceil(BLOCK) * BLOCK;
+    let length: usize = if naxis == 0 { 0 } else {
+        axes.iter().try_fold(size, |acc, &a| acc.checked_mul(a)).ok_or(Error::Format("invalid FITS axes"))?
+    };
+    let raw: &[u8] = bytes.get(start..).and_then(|b| b.get(..length)).ok_or(Error::Format("truncated FITS data"))?;
+
+    let data: Vec<f64> = raw.chunks_exact(size).map(|c| zero + scale * match bitpix {
+        8 => c[0] as f64,
+        16 => i16::from_be_bytes([c[0], c[1]]) as f64,
+        32 => i32::from_be_bytes([c[0], c[1], c[2], c[3]]) as f64,
+        64 => i64::from_be_bytes([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]]) as f64,
+        -32 => f32::from_be_bytes([c[0], c[1], c[2], c[3]]) as f64,
+        _ => f64::from_be_bytes([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]])
+    }).collect();
+
+    axes.reverse();
+    Ok(Array { shape: axes, data })
+}
+
+/// # Reads an image from a file
+pub fn read<P: AsRef<Path>>(path: P) -> std::io::Result<Array> {
+    from_bytes(&fs::read(path)?).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// # Writes an image to a file
+pub fn write<P: AsRef<Path>>(path: P, array: &Array) -> std::io::Result<()> {
+    fs::write(path, to_bytes(array))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Appends a header card, with the value right-aligned in columns 11 to 30
+fn push_card(res: &mut Vec<u8>, key: &str, value: &str) {
+    res.extend_from_slice(format!("{:<8}= {:>20}{:50}", key, value, "").as_bytes());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,386 @@
+//!
+//! # Data input and output
+//!
+//! Readers and writers for numeric tables stored as text: comma separated values, or columns separated by
+//! whitespace as commonly produced by simulation codes. Comment lines and an optional header with the
+//! column names are supported.
+//!
+//! Binary arrays can be exchanged with Python analysis stacks through the `Array` type:
+//! - Npy: NumPy `.npy` files (requires the `npy` feature)
+//! - Fits: minimal FITS image files (requires the `fits` feature)
+//!
+//! ```
+//! # use scilib::io::{ Format, Table };
+//! let text = "# Measurements\ntime,value\n0.0,1.5\n0.5,2.25\n1.0,3.0\n";
+//! let table = Table::parse(text, &Format::csv()).unwrap();
+//!
+//! assert_eq!(table.header, vec!["time", "value"]);
+//! assert_eq!(table.column_by_name("value"), Some(vec![1.5, 2.25, 3.0]));
+//!
+//! // Writing back the table, as whitespace separated columns
+//! let out = table.to_text(&Format::whitespace());
+//! assert_eq!(out, "# time value\n0 1.5\n0.5 2.25\n1 3\n");
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "fits")]
+pub mod fits;
+
+#[cfg(feature = "npy")]
+pub mod npy;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::fs;                    // File access
+
+use std::path::Path;            // File paths
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+use crate::math::complex::Complex;  // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Column separator
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Delimiter {
+    /// Any amount of spaces and tabs
+    #[default]
+    Whitespace,
+    /// A single character, such as `,` or `;`
+    Char(char)
+}
+
+/// # Text table format
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Format {
+    /// Separator between the columns
+    pub delimiter: Delimiter,
+    /// Lines starting with this character are ignored; the header is written after it
+    pub comment: Option<char>,
+    /// Whether the first non-comment line holds the column names
+    pub header: bool
+}
+
+/// Implementing required methods
+impl Format {
+    /// # Comma separated values
+    ///
+    /// With a header line, and `#` comments.
+    pub const fn csv() -> Self {
+        Self {
+            delimiter: Delimiter::Char(','),
+            comment: Some('#'),
+            header: true
+        }
+    }
+
+    /// # Whitespace separated columns
+    ///
+    /// Without header line, and `#` comments. When writing, the column names are stored as a comment.
+    pub const fn whitespace() -> Self {
+        Self {
+            delimiter: Delimiter::Whitespace,
+            comment: Some('#'),
+            header: false
+        }
+    }
+
+    /// Splits a line into its fields
+    fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self.delimiter {
+            Delimiter::Whitespace => line.split_whitespace().collect(),
+            Delimiter::Char(c) => line.split(c).map(str::trim).collect()
+        }
+    }
+
+    /// Separator used when writing
+    fn separator(&self) -> String {
+        match self.delimiter {
+            Delimiter::Whitespace => String::from(" "),
+            Delimiter::Char(c) => c.to_string()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Numeric table
+///
+/// The values are stored by rows; all the rows have the same length.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    /// Names of the columns, empty if the source had none
+    pub header: Vec<String>,
+    /// Values, by rows
+    pub rows: Vec<Vec<f64>>
+}
+
+/// Implementing required methods
+impl Table {
+    /// # From columns
+    ///
+    /// Builds a table from columns of the same length.
+    ///
+    /// ```
+    /// # use scilib::io::Table;
+    /// let table = Table::from_columns(&["x", "y"], &[vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(table.rows, vec![vec![1.0, 3.0], vec![2.0, 4.0]]);
+    /// ```
+    pub fn from_columns(names: &[&str], columns: &[Vec<f64>]) -> Self {
+
+        let len: usize = columns.first().map_or(0, Vec::len);
+        assert!(columns.iter().all(|c| c.len() == len), "Columns must have the same length");
+
+        Self {
+            header: names.iter().map(|n| n.to_string()).collect(),
+            rows: (0..len).map(|i| columns.iter().map(|c| c[i]).collect()).collect()
+        }
+    }
+
+    /// # Parses a table from text
+    ///
+    /// Returns a `Parse` error holding the line number if a value is not a number, or if the rows do not
+    /// all have the same number of columns. Empty lines are ignored.
+    ///
+    /// ```
+    /// # use scilib::io::{ Format, Table };
+    /// # use scilib::Error;
+    /// let table = Table::parse("1 2 3\n\n4 5 6  # trailing\n", &Format::whitespace());
+    /// assert_eq!(table, Err(Error::Parse(3)));
+    ///
+    /// let table = Table::parse("1 2 3\n\n4 5 6\n", &Format::whitespace()).unwrap();
+    /// assert_eq!(table.column(2), Some(vec![3.0, 6.0]));
+    /// ```
+    pub fn parse(text: &str, format: &Format) -> Result<Self> {
+
+        let mut table: Self = Self::default();
+        let mut header_pending: bool = format.header;
+
+        for (idx, line) in text.lines().enumerate() {
+            let line: &str = line.trim();
+
+            if line.is_empty() || format.comment.is_some_and(|c| line.starts_with(c)) {
+                continue;
+            }
+
+            let fields: Vec<&str> = format.split(line);
+
+            if header_pending {
+                table.header = fields.iter().map(|f| f.to_string()).collect();
+                header_pending = false;
+                continue;
+            }
+
+            let row: Vec<f64> = fields.iter()
+                .map(|f| f.parse::<f64>())
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|_| Error::Parse(idx + 1))?;
+
+            // Every row must match the first one, or the header
+            let expected: Option<usize> = table.rows.first().map(Vec::len)
+                .or((!table.header.is_empty()).then_some(table.header.len()));
+            if expected.is_some_and(|n| n != row.len()) {
+                return Err(Error::Parse(idx + 1));
+            }
+
+            table.rows.push(row);
+        }
+
+        Ok(table)
+    }
+
+    /// # Reads a table from a file
+    ///
+    /// I/O failures and invalid data are both reported as `std::io::Error`.
+    pub fn read<P: AsRef<Path>>(path: P, format: &Format) -> std::io::Result<Self> {
+        let text: String = fs::read_to_string(path)?;
+        Self::parse(&text, format).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// # Converts the table to text
+    ///
+    /// Values are written with the shortest representation that reads back to the same `f64`.
+    pub fn to_text(&self, format: &Format) -> String {
+
+        let sep: String = format.separator();
+        let mut res: String = String::new();
+
+        if !self.header.is_empty() {
+            // Without header line, the names are kept as a comment
+            if let (false, Some(c)) = (format.header, format.comment) {
+                res.push(c);
+                res.push(' ');
+            }
+            res += &self.header.join(&sep);
+            res.push('\n');
+        }
+
+        for row in &self.rows {
+            res += &row.iter().map(f64::to_string).collect::<Vec<String>>().join(&sep);
+            res.push('\n');
+        }
+
+        res
+    }
+
+    /// # Writes the table to a file
+    pub fn write<P: AsRef<Path>>(&self, path: P, format: &Format) -> std::io::Result<()> {
+        fs::write(path, self.to_text(format))
+    }
+
+    /// # Number of columns
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(self.header.len(), Vec::len)
+    }
+
+    /// # Column by index
+    pub fn column(&self, idx: usize) -> Option<Vec<f64>> {
+        if idx >= self.width() {
+            return None;
+        }
+        Some(self.rows.iter().map(|r| r[idx]).collect())
+    }
+
+    /// # Column by name
+    pub fn column_by_name(&self, name: &str) -> Option<Vec<f64>> {
+        self.column(self.header.iter().position(|h| h == name)?)
+    }
+
+    /// # Complex column
+    ///
+    /// Combines the columns holding the real and imaginary parts.
+    ///
+    /// ```
+    /// # use scilib::io::{ Format, Table };
+    /// # use scilib::math::complex::Complex;
+    /// let mut table = Table::default();
+    /// table.push_complex("z", &[Complex::from(1.0, -2.0), Complex::from(0.5, 3.0)]);
+    ///
+    /// assert_eq!(table.header, vec!["z_re", "z_im"]);
+    /// assert_eq!(table.complex_column(0, 1), Some(vec![Complex::from(1.0, -2.0), Complex::from(0.5, 3.0)]));
+    /// ```
+    pub fn complex_column(&self, re: usize, im: usize) -> Option<Vec<Complex>> {
+        Some(self.column(re)?.into_iter().zip(self.column(im)?).map(|(a, b)| Complex::from(a, b)).collect())
+    }
+
+    /// # Appends a column
+    ///
+    /// The column must have as many values as the table has rows, unless the table is empty.
+    pub fn push_column(&mut self, name: &str, values: &[f64]) {
+
+        if self.rows.is_empty() {
+            self.rows = vec![Vec::new(); values.len()];
+        }
+        assert_eq!(values.len(), self.rows.len(), "Column length must match the table");
+
+        self.header.push(name.to_string());
+        for (row, v) in self.rows.iter_mut().zip(values) {
+            row.push(*v);
+        }
+    }
+
+    /// # Appends a complex column
+    ///
+    /// Stored as two columns, with `_re` and `_im` suffixes.
+    pub fn push_complex(&mut self, name: &str, values: &[Complex]) {
+        self.push_column(&format!("{}_re", name), &values.iter().map(|z| z.re).collect::<Vec<f64>>());
+        self.push_column(&format!("{}_im", name), &values.iter().map(|z| z.im).collect::<Vec<f64>>());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Numeric array
+///
+/// An n-dimensional array of floats, stored in row-major order (the last index varies the fastest), as
+/// exchanged with binary file formats.
+///
+/// ```
+/// # use scilib::io::Array;
+/// let arr = Array::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+///
+/// assert_eq!(arr.shape, vec![2, 3]);
+/// assert_eq!(arr.get(&[1, 0]), Some(4.0));
+/// assert_eq!(arr.rows(), vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Array {
+    /// Size along each dimension
+    pub shape: Vec<usize>,
+    /// Values, in row-major order
+    pub data: Vec<f64>
+}
+
+/// Implementing required methods
+impl Array {
+    /// # New array
+    ///
+    /// Returns `None` if the number of values does not match the shape.
+    pub fn new(shape: Vec<usize>, data: Vec<f64>) -> Option<Self> {
+        if shape.iter().product::<usize>() != data.len() {
+            return None;
+        }
+        Some(Self { shape, data })
+    }
+
+    /// # One dimensional array
+    pub fn from_vec(data: Vec<f64>) -> Self {
+        Self {
+            shape: vec![data.len()],
+            data
+        }
+    }
+
+    /// # Two dimensional array from rows of the same length
+    pub fn from_rows(rows: &[Vec<f64>]) -> Self {
+
+        let cols: usize = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|r| r.len() == cols), "Rows must have the same length");
+
+        Self {
+            shape: vec![rows.len(), cols],
+            data: rows.concat()
+        }
+    }
+
+    /// # Number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// # Value at a multi-dimensional index
+    pub fn get(&self, index: &[usize]) -> Option<f64> {
+
+        if index.len() != self.shape.len() {
+            return None;
+        }
+
+        let mut flat: usize = 0;
+        for (i, n) in index.iter().zip(&self.shape) {
+            if i >= n {
+                return None;
+            }
+            flat = flat * n + i;
+        }
+
+        Some(self.data[flat])
+    }
+
+    /// # Rows of the array
+    ///
+    /// Splits the array along its last dimension; a one dimensional array gives a single row.
+    pub fn rows(&self) -> Vec<Vec<f64>> {
+        match self.shape.last() {
+            Some(&n) if n > 0 => self.data.chunks(n).map(<[f64]>::to_vec).collect(),
+            _ => Vec::new()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
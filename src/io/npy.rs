@@ -0,0 +1,229 @@
+//!
+//! # NumPy arrays
+//!
+//! Reading and writing of the `.npy` format used by `numpy.save` and `numpy.load`. Arrays are written as
+//! little-endian `f64` in C order; reading accepts any float or integer type of either endianness, in C or
+//! Fortran order, converted to `f64`.
+//!
+//! ```
+//! # use scilib::io::{ Array, npy };
+//! let arr = Array::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+//! let bytes = npy::to_bytes(&arr);
+//!
+//! assert_eq!(&bytes[..6], b"\x93NUMPY");
+//! assert_eq!(bytes.len() % 64, (6 * 8) % 64);
+//! assert_eq!(npy::from_bytes(&bytes), Ok(arr));
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::fs;                    // File access
+
+use std::path::Path;            // File paths
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+use super::Array;               // Exchanged arrays
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Magic string starting every file
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Alignment of the data section
+const ALIGNMENT: usize = 64;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Encodes an array
+///
+/// Produces a version 1.0 file, with `<f8` values in C order.
+pub fn to_bytes(array: &Array) -> Vec<u8> {
+
+    let shape: String = match array.shape.len() {
+        1 => format!("({},)", array.shape[0]),
+        _ => format!("({})", array.shape.iter().map(usize::to_string).collect::<Vec<String>>().join(", "))
+    };
+    let mut header: String = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}", shape);
+
+    // Padding with spaces so that the data is aligned, the header ending with a newline
+    let used: usize = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((ALIGNMENT - used % ALIGNMENT) % ALIGNMENT));
+    header.push('\n');
+
+    let mut res: Vec<u8> = Vec::with_capacity(used + array.data.len() * 8 + ALIGNMENT);
+    res.extend_from_slice(MAGIC);
+    res.extend_from_slice(&[1, 0]);
+    res.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    res.extend_from_slice(header.as_bytes());
+    for v in &array.data {
+        res.extend_from_slice(&v.to_le_bytes());
+    }
+
+    res
+}
+
+/// # Decodes an array
+///
+/// Returns a `Format` error if the bytes are not a valid `.npy` file with numeric values.
+///
+/// ```
+/// # use scilib::io::npy;
+/// // An int32 array, in Fortran order, as saved by NumPy
+/// let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+/// let header = "{'descr': '<i4', 'fortran_order': True, 'shape': (2, 2), }";
+/// bytes.extend(format!("{:<117}\n", header).bytes());
+/// for v in [1_i32, 3, 2, 4] {
+///     bytes.extend(v.to_le_bytes());
+/// }
+///
+/// let arr = npy::from_bytes(&bytes).unwrap();
+/// assert_eq!(arr.rows(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+///
+/// // A shape whose size overflows
+/// let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+/// let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }";
+/// bytes.extend(format!("{:<117}\n", header).bytes());
+/// assert!(npy::from_bytes(&bytes).is_err());
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> Result<Array> {
+
+    if bytes.len() < 10 || &bytes[..6] != MAGIC {
+        return Err(Error::Format("missing npy magic string"));
+    }
+
+    // The size of the header length field depends on the version
+    let (len, start): (usize, usize) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12),
+        _ => return Err(Error::Format("unsupported npy version"))
+    };
+
+    let header: &str = bytes.get(start..start + len)
+        .and_then(|h| std::str::from_utf8(h).ok())
+        .ok_or(Error::Format("invalid npy header"))?;
+
+    let descr: &str = header_value(header, "descr").ok_or(Error::Format("missing npy descr"))?;
+    let fortran: bool = header_value(header, "fortran_order").ok_or(Error::Format("missing npy order"))? == "True";
+    let shape: Vec<usize> = parse_shape(header_value(header, "shape").ok_or(Error::Format("missing npy shape"))?)?;
+
+    let descr: &str = descr.trim_matches(|c| c == '\'' || c == '"');
+    let (little, kind, size): (bool, char, usize) = parse_descr(descr)?;
+
+    let length: usize = shape.iter().try_fold(size, |acc, &a| acc.checked_mul(a))
+        .ok_or(Error::Format("invalid npy shape"))?;
+    let raw: &[u8] = bytes.get(start + len..).and_then(|b| b.get(..length)).ok_or(Error::Format("truncated npy data"))?;
+
+    let data: Vec<f64> = raw.chunks_exact(size).map(|c| decode(c, little, kind)).collect();
+
+    let array: Array = Array { shape, data };
+    Ok(if fortran { to_c_order(array) } else { array })
+}
+
+/// # Reads an array from a file
+pub fn read<P: AsRef<Path>>(path: P) -> std::io::Result<Array> {
+    from_bytes(&fs::read(path)?).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// # Writes an array to a file
+pub fn write<P: AsRef<Path>>(path: P, array: &Array) -> std::io::Result<()> {
+    fs::write(path, to_bytes(array))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Extracts the raw value associated to a key of the header dictionary
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+
+    let pos: usize = header.find(&format!("'{}'", key))? + key.len() + 2;
+    let rest: &str = header[pos..].trim_start().strip_prefix(':')?.trim_start();
+
+    // Tuples contain commas, so they end at the closing parenthesis
+    let end: usize = if rest.starts_with('(') { rest.find(')')? + 1 } else { rest.find([',', '}'])? };
+    Some(rest[..end].trim())
+}
+
+/// Parses a shape tuple, such as `(3,)` or `(2, 4)`
+fn parse_shape(text: &str) -> Result<Vec<usize>> {
+    text.trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|_| Error::Format("invalid npy shape")))
+        .collect()
+}
+
+/// Parses a type description, such as `<f8`, into endianness, kind and size
+fn parse_descr(descr: &str) -> Result<(bool, char, usize)> {
+
+    let mut chars = descr.chars();
+    let little: bool = match chars.next() {
+        Some('<') | Some('|') | Some('=') => true,
+        Some('>') => false,
+        _ => return Err(Error::Format("invalid npy descr"))
+    };
+    let kind: char = chars.next().ok_or(Error::Format("invalid npy descr"))?;
+    let size: usize = chars.as_str().parse().map_err(|_| Error::Format("invalid npy descr"))?;
+
+    match (kind, size) {
+        ('f', 4 | 8) | ('i' | 'u', 1 | 2 | 4 | 8) => Ok((little, kind, size)),
+        _ => Err(Error::Format("unsupported npy data type"))
+    }
+}
+
+/// Decodes a single value
+fn decode(bytes: &[u8], little: bool, kind: char) -> f64 {
+
+    let mut buf: [u8; 8] = [0; 8];
+    let n: usize = bytes.len();
+    if little {
+        buf[..n].copy_from_slice(bytes);
+    } else {
+        for (b, v) in buf[..n].iter_mut().zip(bytes.iter().rev()) {
+            *b = *v;
+        }
+    }
+
+    // Sign extension of the signed integers
+    if kind == 'i' && buf[n - 1] & 0x80 != 0 {
+        buf[n..].fill(0xff);
+    }
+
+    match (kind, n) {
+        ('f', 4) => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+        ('f', _) => f64::from_le_bytes(buf),
+        ('i', _) => i64::from_le_bytes(buf) as f64,
+        _ => u64::from_le_bytes(buf) as f64
+    }
+}
+
+/// Reorders Fortran (column-major) data into C order
+fn to_c_order(array: Array) -> Array {
+
+    let nd: usize = array.shape.len();
+    let mut data: Vec<f64> = vec![0.0; array.data.len()];
+    let mut index: Vec<usize> = vec![0; nd];
+
+    for v in data.iter_mut() {
+        // Column-major offset of the current row-major index
+        let offset: usize = index.iter().zip(&array.shape).rev().fold(0, |acc, (i, n)| acc * n + i);
+        *v = array.data[offset];
+
+        // Incrementing the row-major index
+        for d in (0..nd).rev() {
+            index[d] += 1;
+            if index[d] < array.shape[d] {
+                break;
+            }
+            index[d] = 0;
+        }
+    }
+
+    Array { shape: array.shape, data }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -10,41 +10,100 @@
 //! that will hopefully help many science developers.
 //!
 //! > **Before you use**: this crate is currently a work in progress and is thus missing many features. I will do my best to ensure
-//! both fast and correct computation, but it is evident that improvements could be found in the future. I will
-//! first implement concept I am familiar with, and work towards other domains later on.
+//! > both fast and correct computation, but it is evident that improvements could be found in the future. I will
+//! > first implement concept I am familiar with, and work towards other domains later on.
 //!
 //! ## Contents
 //!
-//! The scilib crate is sub-divided in themes, to simplify its use.
+//! The scilib crate is sub-divided in themes, to simplify its use. Apart from the core (math, coordinates,
+//! constants, ...), each theme is behind a cargo feature of the same name, none of them enabled by default.
+//! The math submodules beyond the core are grouped the same way: `special` (Airy, Coulomb, spheroidal and
+//! theta functions), `linalg` (eigenproblems), `solvers` (continuation, minimax, zeros), `ode` (differential
+//! equations) and `pde` (grids and Poisson solvers). Users select the ones they need, or `full` for all:
+//!
+//! ```toml
+//! scilib = { version = "0.3", features = ["bessel", "signal"] }
+//! ```
 //!
 //! ### General purpose
 //!
 //! - **Math**: Provides many base utilities, from complex numbers to bessel functions.
 //! - **Coordinate**: Provides support for coordinate systems, and their respective operations.
+//! - **Geometry**: 2D and 3D primitives, with their intersections and distances
 //! - **Constant**: Contains many useful constants for physics
+//! - **Diagnostics**: Iteration reports of the iterative methods, to debug convergence issues
+//! - **Error**: Common error type, returned by the non-panicking `try_` functions
+//! - **IO**: Reading and writing numeric tables (CSV or whitespace separated)
+//! - **Precision**: Speed versus accuracy policy of the iterative methods
 //! - **Signal**: Convolution and fast Fourier transform functions
+//! - **Stats**: Probability distributions and statistical tools
 //! - **Range**: Range generator to simplify vector creation
+//! - **Reference**: ULP comparisons against high-precision reference tables
+//! - **Parallel**: Multi-threaded batch evaluation (requires the `parallel` feature)
+//! - **Timeseries**: Detrending, differencing and autoregressive models
 //!
 //! ### Specific purpose
 //!
 //! - **Astronomy**: Astronomical toolbox (***wip***)
+//! - **Chemistry**: Periodic table data and molar masses of chemical formulas
+//! - **Fluid**: Dimensionless numbers for fluid dynamics and heat transfer
+//! - **Optics**: Free-space propagation of optical fields, diffraction patterns and ray tracing
 //! - **Quantum**: Quantum mechanics toolbox (***wip***)
 //!
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[cfg(feature = "astronomy")]
 pub mod astronomy;
 
+#[cfg(feature = "chemistry")]
+pub mod chemistry;
+
 pub mod constant;
 
 pub mod coordinate;
 
+pub mod diagnostics;
+
+pub mod error;
+
+#[cfg(feature = "fluid")]
+pub mod fluid;
+
+#[cfg(feature = "geometry")]
+pub mod geometry;
+
+#[cfg(feature = "io")]
+pub mod io;
+
 pub mod math;
 
+#[cfg(feature = "optics")]
+pub mod optics;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
+
 pub mod planck;
 
+pub mod precision;
+
+#[cfg(feature = "quantum")]
 pub mod quantum;
 
 pub mod range;
 
+pub mod reference;
+
+#[cfg(feature = "signal")]
 pub mod signal;
+
+#[cfg(feature = "stats")]
+pub mod stats;
+
+#[cfg(feature = "timeseries")]
+pub mod timeseries;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub use error::Error;   // Crate-wide error type
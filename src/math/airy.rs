@@ -0,0 +1,314 @@
+//!
+//! # Airy functions
+//!
+//! The Airy functions `Ai` and `Bi` are the solutions of the Airy equation:
+//!
+//! `w'' = z w`
+//!
+//! the simplest equation with a turning point: solutions oscillate for `z < 0` and grow or decay
+//! exponentially for `z > 0`. `Ai` is the solution decaying along the positive real axis. They describe
+//! the field near a turning point in the WKB approximation, the intensity near optical caustics, and are
+//! related to the Bessel functions of order `1/3`.
+//!
+//! The functions are computed for complex arguments from three representations:
+//! - the Maclaurin series close to the origin;
+//! - the asymptotic expansions for `|z| >= 9`, extended to the whole plane with the connection formula
+//!   `Ai(z) + w Ai(wz) + w^2 Ai(w^2 z) = 0`, `w = exp(2i pi / 3)`;
+//! - in between, where the series would lose the small values of `Ai` in cancellations, a Taylor
+//!   integration of the differential equation inward from the asymptotic region.
+//!
+//! `Bi` is obtained from `Ai` by `Bi(z) = exp(i pi / 6) Ai(wz) + exp(-i pi / 6) Ai(w^2 z)`. The relative
+//! accuracy is about `1.0e-14` over the whole plane.
+//!
+//! ```
+//! # use scilib::math::airy::{ airy_ai, airy_bi, airy_ai_prime, airy_bi_prime };
+//! let x: f64 = -2.5;
+//!
+//! // Wronskian
+//! let w = airy_ai(x) * airy_bi_prime(x) - airy_ai_prime(x) * airy_bi(x);
+//! assert!((w.re - 1.0 / std::f64::consts::PI).abs() < 1.0e-14 && w.im == 0.0);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use super::complex::Complex;    // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Value of Ai at the origin, `3^(-2/3) / Gamma(2/3)`
+const AI_ZERO: f64 = 0.355_028_053_887_817_2;
+
+/// Opposite of the derivative of Ai at the origin, `3^(-1/3) / Gamma(1/3)`
+const AI_PRIME_ZERO: f64 = 0.258_819_403_792_806_8;
+
+/// Radius beyond which the asymptotic expansions are accurate to the machine precision
+const ASYMPTOTIC_RADIUS: f64 = 9.0;
+
+/// Radius below which the Maclaurin series is used in every direction
+const SERIES_RADIUS: f64 = 2.0;
+
+/// Largest step of the Taylor integration
+const STEP: f64 = 0.5;
+
+/// Relative size of the last terms of the sums
+const PRECISION: f64 = 1.0e-17;
+
+/// Cap on the number of terms of the sums
+const MAX_TERMS: usize = 500;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Airy function Ai
+///
+/// `x` is the value to evaluate (complex input accepted). `Ai` decays as `exp(-2/3 x^(3/2))` for large
+/// positive `x`, and oscillates along the negative real axis.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::airy::airy_ai;
+/// assert!((airy_ai(0).re - 0.3550280538878172).abs() < 1.0e-16);
+/// assert!((airy_ai(1.5).re - 0.07174949700810541).abs() < 1.0e-15);
+/// assert!((airy_ai(-4.0).re - -0.07026553294928951).abs() < 1.0e-15);
+/// assert!((airy_ai(6.0).re - 9.947694360252889e-06).abs() < 1.0e-19);
+///
+/// let res = airy_ai(Complex::from(-3.0, 2.0));
+/// assert!((res.re - -4.419689554264167).abs() < 1.0e-13 && (res.im - 5.454622517782667).abs() < 1.0e-13);
+/// ```
+pub fn airy_ai<T: Into<Complex>>(x: T) -> Complex {
+    let z: Complex = x.into();
+    real_axis(z, ai_pair(z).0)
+}
+
+/// # Derivative of the Airy function Ai
+///
+/// `x` is the value to evaluate (complex input accepted).
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::airy::airy_ai_prime;
+/// assert!((airy_ai_prime(0).re - -0.2588194037928068).abs() < 1.0e-16);
+/// assert!((airy_ai_prime(-4.0).re - -0.7906285753685813).abs() < 1.0e-14);
+///
+/// let res = airy_ai_prime(Complex::from(12.0, -1.0));
+/// assert!((res.re - 4.984060461538999e-13).abs() < 1.0e-25 && (res.im - 1.5589372135915515e-13).abs() < 1.0e-25);
+/// ```
+pub fn airy_ai_prime<T: Into<Complex>>(x: T) -> Complex {
+    let z: Complex = x.into();
+    real_axis(z, ai_pair(z).1)
+}
+
+/// # Airy function Bi
+///
+/// `x` is the value to evaluate (complex input accepted). `Bi` grows as `exp(2/3 x^(3/2))` for large
+/// positive `x`, and oscillates along the negative real axis, a quarter period away from `Ai`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::airy::airy_bi;
+/// assert!((airy_bi(0).re - 0.6149266274460007).abs() < 1.0e-15);
+/// assert!((airy_bi(-4.0).re - 0.3922347057069993).abs() < 1.0e-14);
+/// assert!((airy_bi(5.0).re / 657.7920441711711 - 1.0).abs() < 1.0e-13);
+///
+/// let res = airy_bi(Complex::from(1.0, 1.0));
+/// assert!((res.re - 0.7166580733827684).abs() < 1.0e-14 && (res.im - 0.6198892904008447).abs() < 1.0e-14);
+/// ```
+pub fn airy_bi<T: Into<Complex>>(x: T) -> Complex {
+    let z: Complex = x.into();
+    real_axis(z, bi_pair(z).0)
+}
+
+/// # Derivative of the Airy function Bi
+///
+/// `x` is the value to evaluate (complex input accepted).
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::airy::airy_bi_prime;
+/// assert!((airy_bi_prime(0).re - 0.4482883573538264).abs() < 1.0e-15);
+/// assert!((airy_bi_prime(-10.0).re - 0.11941411339990923).abs() < 1.0e-13);
+///
+/// let res = airy_bi_prime(Complex::from(-1.0, 3.0));
+/// assert!((res.re - -7.987980444351661).abs() < 1.0e-13 && (res.im - -10.362590406744854).abs() < 1.0e-13);
+/// ```
+pub fn airy_bi_prime<T: Into<Complex>>(x: T) -> Complex {
+    let z: Complex = x.into();
+    real_axis(z, bi_pair(z).1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ai and its derivative, choosing the representation from the position of `z`
+fn ai_pair(z: Complex) -> (Complex, Complex) {
+
+    let r: f64 = z.modulus();
+    let theta: f64 = z.arg();
+
+    if r >= ASYMPTOTIC_RADIUS {
+        if theta.abs() <= 2.0 * PI / 3.0 + 1.0e-12 {
+            return asymptotic(z);
+        }
+
+        // Rotating into the sector where the expansion holds
+        let w: Complex = Complex::from_polar(2.0 * PI / 3.0, 1.0);
+        let w2: Complex = w.conjugate();
+        let (a1, d1): (Complex, Complex) = asymptotic(w * z);
+        let (a2, d2): (Complex, Complex) = asymptotic(w2 * z);
+        return (-(w * a1) - w2 * a2, -(w2 * d1) - w * d2);
+    }
+
+    // The series cancels when Ai is small, where the inward integration is stable instead
+    if r > SERIES_RADIUS && (1.5 * theta).cos() >= -1.0 / 3.0 {
+        return integrate(z);
+    }
+
+    let (ai, aip, _, _) = series(z);
+    (ai, aip)
+}
+
+/// Bi and its derivative, from the values of Ai
+fn bi_pair(z: Complex) -> (Complex, Complex) {
+
+    if z.modulus() <= SERIES_RADIUS {
+        let (_, _, bi, bip) = series(z);
+        return (bi, bip);
+    }
+
+    let w: Complex = Complex::from_polar(2.0 * PI / 3.0, 1.0);
+    let e: Complex = Complex::from_polar(PI / 6.0, 1.0);
+    let (a1, d1): (Complex, Complex) = ai_pair(w * z);
+    let (a2, d2): (Complex, Complex) = ai_pair(w.conjugate() * z);
+
+    (e * a1 + e.conjugate() * a2, e * w * d1 + (e * w).conjugate() * d2)
+}
+
+/// Maclaurin series of Ai, Ai', Bi and Bi'
+fn series(z: Complex) -> (Complex, Complex, Complex, Complex) {
+
+    let z2: Complex = z * z;
+    let z3: Complex = z2 * z;
+
+    // f = sum 3^k (1/3)_k z^3k / (3k)!, g = sum 3^k (2/3)_k z^(3k+1) / (3k+1)!, sg being the terms of g / z
+    let mut tf: Complex = Complex::from(1.0, 0.0);
+    let mut sg: Complex = Complex::from(1.0, 0.0);
+    let (mut f, mut g): (Complex, Complex) = (tf, z);
+    let (mut fp, mut gp): (Complex, Complex) = (Complex::new(), sg);
+
+    for k in 1..MAX_TERMS {
+        let kf: f64 = k as f64;
+
+        // The derivative of the term of f is the previous term times z^2 / (3k - 1)
+        fp += tf * z2 * (1.0 / (3.0 * kf - 1.0));
+        tf = tf * z3 * (1.0 / ((3.0 * kf - 1.0) * 3.0 * kf));
+        sg = sg * z3 * (1.0 / (3.0 * kf * (3.0 * kf + 1.0)));
+        f += tf;
+        g += sg * z;
+        gp += sg * (3.0 * kf + 1.0);
+
+        if tf.modulus() + sg.modulus() < PRECISION * (f.modulus() + gp.modulus()) {
+            break;
+        }
+    }
+
+    let sqrt_3: f64 = 3.0_f64.sqrt();
+    (
+        AI_ZERO * f - AI_PRIME_ZERO * g,
+        AI_ZERO * fp - AI_PRIME_ZERO * gp,
+        sqrt_3 * (AI_ZERO * f + AI_PRIME_ZERO * g),
+        sqrt_3 * (AI_ZERO * fp + AI_PRIME_ZERO * gp)
+    )
+}
+
+/// Asymptotic expansions of Ai and Ai', valid for `|arg z| <= 2 pi / 3`
+fn asymptotic(z: Complex) -> (Complex, Complex) {
+
+    let zeta: Complex = 2.0 / 3.0 * z.powf(1.5);
+    let t: Complex = -1.0 / zeta;
+
+    let mut u: f64 = 1.0;
+    let mut power: Complex = Complex::from(1.0, 0.0);
+    let (mut sum_u, mut sum_v): (Complex, Complex) = (power, power);
+    let mut last: f64 = f64::INFINITY;
+
+    for k in 1..MAX_TERMS {
+        let kf: f64 = k as f64;
+        u *= (6.0 * kf - 5.0) * (6.0 * kf - 3.0) * (6.0 * kf - 1.0) / (216.0 * kf * (2.0 * kf - 1.0));
+        let v: f64 = -(6.0 * kf + 1.0) / (6.0 * kf - 1.0) * u;
+        power *= t;
+
+        // Stopping at the smallest term of the divergent series
+        let term: Complex = power * u;
+        let size: f64 = term.modulus();
+        if size > last {
+            break;
+        }
+        last = size;
+
+        sum_u += term;
+        sum_v += power * v;
+        if size < PRECISION * sum_u.modulus() {
+            break;
+        }
+    }
+
+    let root: Complex = z.powf(0.25);
+    let factor: Complex = (-zeta).exp() * (0.5 / PI.sqrt());
+    (factor / root * sum_u, -(factor * root * sum_v))
+}
+
+/// Ai and Ai' from a Taylor integration of `w'' = z w` along the ray of `z`, from the asymptotic region
+fn integrate(z: Complex) -> (Complex, Complex) {
+
+    let r: f64 = z.modulus();
+    let start: Complex = z * (ASYMPTOTIC_RADIUS / r);
+    let steps: usize = ((ASYMPTOTIC_RADIUS - r) / STEP).ceil() as usize;
+    let h: Complex = (z - start) * (1.0 / steps as f64);
+
+    let (mut y, mut yp): (Complex, Complex) = ai_pair(start);
+    for s in 0..steps {
+        (y, yp) = taylor_step(start + h * s as f64, y, yp, h);
+    }
+
+    (y, yp)
+}
+
+/// One step of length `h` from `z0` of the Taylor series of the solution of `w'' = z w`
+fn taylor_step(z0: Complex, y: Complex, yp: Complex, h: Complex) -> (Complex, Complex) {
+
+    // d_k = c_k h^k, with (k + 2) (k + 1) c_(k+2) = z0 c_k + c_(k-1)
+    let zh2: Complex = z0 * h * h;
+    let h3: Complex = h * h * h;
+
+    let (mut a, mut b, mut c): (Complex, Complex, Complex) = (Complex::new(), y, yp * h);
+    let mut sum: Complex = b + c;
+    let mut sum_p: Complex = c;
+
+    for k in 0..MAX_TERMS {
+        let kf: f64 = k as f64;
+        let next: Complex = (zh2 * b + h3 * a) * (1.0 / ((kf + 1.0) * (kf + 2.0)));
+        sum += next;
+        sum_p += next * (kf + 2.0);
+
+        a = b;
+        b = c;
+        c = next;
+        if a.modulus() + b.modulus() + c.modulus() < PRECISION * (sum.modulus() + sum_p.modulus()) {
+            break;
+        }
+    }
+
+    (sum, sum_p / h)
+}
+
+/// Drops the rounding residue of the imaginary part for real arguments
+fn real_axis(z: Complex, res: Complex) -> Complex {
+    if z.im == 0.0 {
+        Complex::from(res.re, 0.0)
+    } else {
+        res
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -23,15 +23,15 @@
 //! ## Second kind: Y
 //! 
 //! Similar to the first kind, the Y equation are solution of Bessel's equation with a singularity at the origin.
-//! The Y function is itself based on the J function. The function is undefined for any integer order, in which
-//! case the limit has to be taken.
+//! The Y function is itself based on the J function. Its definition is undefined for any integer order, where
+//! the limit is computed from dedicated expansions, carrying the `ln(x/2)` and digamma terms.
 //! 
 //! ```rust
 //! # use scilib::math::complex::Complex;
 //! # use scilib::math::bessel::y;
 //! let c = Complex::from(2, -1.2);
 //! let res_f = y(c, 1.5);              // Not a problem
-//! let res_i = y(c, 1);                // The function takes the limit in this case
+//! let res_i = y(c, 1);                // Dedicated expansion in this case
 //! ```
 //! 
 //! ## Modified first kind: I
@@ -60,7 +60,9 @@
 //! 
 //! ## Hankel functions: H1 and H2
 //! 
-//! Hankel functions are two linearly independent solutions to Bessel's equation.
+//! Hankel functions are two linearly independent solutions to Bessel's equation. They are computed from their
+//! asymptotic expansions for large `|x|`, and from K elsewhere, rather than as `J ± iY`, whose terms cancel
+//! where the functions decay exponentially.
 //! 
 //! ```rust
 //! # use scilib::math::complex::Complex;
@@ -70,17 +72,171 @@
 //! let res_2 = hankel_second(c, -2.3);
 //! ```
 //! 
+//! ## Large arguments
+//! 
+//! The series of J, Y, I and K lose their precision to cancellations as `|x|` grows. Above `|x| = 17` (9 for
+//! K), and when `|x|` is large enough compared to the square of the order, the functions switch to Hankel's
+//! asymptotic expansions, which are accurate to about `1e-14` there. The other half-plane `Re(x) < 0` is
+//! reached through the analytic continuation formulas.
+//! 
+//! ```rust
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::bessel::{ j, jf, y, k };
+//! assert!((j(100.0, 0).re - 0.019985850304223122).abs() < 1.0e-15);
+//! assert!((y(45.0, 3).re - 0.11266340878488458).abs() < 1.0e-15);
+//! assert!((k(30.0, 1).re / 2.1677320018915495e-14 - 1.0).abs() < 1.0e-14);
+//! 
+//! let res = jf(Complex::from(-60.0, 10.0), 2.5);
+//! assert!((res.re - 1011.0092258203451).abs() < 1.0e-10 && (res.im - 477.3155196588819).abs() < 1.0e-10);
+//! ```
+//! 
+//! ## Large orders
+//! 
+//! For large orders the series converge slowly and lose their precision, while the functions change from
+//! monotonic to oscillating around the turning point `x = n`. From `|n| = 20`, J and Y switch to Olver's
+//! uniform expansions in Airy functions, and I and K to Debye's expansions (or Olver's close to the
+//! imaginary axis). Both are accurate to about `1e-13` for any argument, including the turning point.
+//! 
+//! ```rust
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::bessel::{ jf, y, i, k };
+//! assert!((jf(140.0, 150.5).re - 0.0035248739585566143).abs() < 1.0e-15);
+//! assert!((jf(150.5, 150.5).re - 0.08409173249663436).abs() < 1.0e-14);
+//! assert!((y(200.0, 150.5).re - -0.04674325027844902).abs() < 1.0e-14);
+//! assert!((i(100.0, 200).re / 1.3954058601082616e-30 - 1.0).abs() < 1.0e-13);
+//! assert!((k(100.0, 200).re / 1.6024474938521742e27 - 1.0).abs() < 1.0e-13);
+//! 
+//! let res = jf(Complex::from(50.0, 20.0), 60);
+//! assert!((res.re - 1.3379553755481526).abs() < 1.0e-12 && (res.im - 1.2521160242576175).abs() < 1.0e-12);
+//! ```
+//! 
+//! ## Error estimates
+//! 
+//! The `try_j`, `try_jf`, `try_y`, `try_i` and `try_k` functions return the result along with an estimate of
+//! its absolute error, from the last terms of the series or the accuracy of the expansions, and a `Result`
+//! error for the singular points, the series stopped by their term cap and the overflows.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::try_y;
+//! let (res, err) = try_y(8.0, 2.3).unwrap();
+//! assert!((res.re - -0.20130364335849599).abs() <= err);
+//! ```
+//! 
+//! ## Derivatives
+//! 
+//! The derivatives of J, Y, I, K and of the Hankel functions follow from the recurrence relations between
+//! consecutive orders, without numerical differentiation.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::{ j_prime, k_prime };
+//! let res_j = j_prime(2.5, 1);
+//! let res_k = k_prime(0.8, 0.5);
+//! ```
+//! 
+//! ## Zeros
+//! 
+//! The zeros of J, Y and of their derivatives are found by Newton's method, starting from their asymptotic
+//! expansions.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::{ j_zero, j_prime_zero };
+//! let res_j = j_zero(1, 2);
+//! let res_jp = j_prime_zero(1, 2);
+//! ```
+//! 
+//! ## Sequences of orders
+//! 
+//! Series such as Mie's or the addition theorems need every integer order up to some `n_max`. The sequence
+//! functions return them in a single pass of the recurrence between consecutive orders, in the direction in
+//! which it is stable: downward for J and I (Miller's algorithm), upward for Y and K.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::{ j, j_seq };
+//! let res = j_seq(7.5, 50);
+//! assert!((res[12].re - j(7.5, 12).re).abs() < 1.0e-10);
+//! ```
+//! 
+//! ## Spherical Bessel functions: j, y, h1, h2, i and k
+//! 
+//! The spherical Bessel functions are the radial solutions of the Helmholtz equation in spherical
+//! coordinates, related to the half-integer orders of the cylindrical functions. The spherical Hankel
+//! functions combine them into outgoing and incoming waves, and the modified functions i and k solve the
+//! modified Helmholtz equation, as in the expansions of screened potentials.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::{ sph_j, sph_y, sph_hankel_first, sph_i, sph_k };
+//! let res_j = sph_j(2.5, 3);
+//! let res_y = sph_y(2.5, 3);
+//! let res_h = sph_hankel_first(2.5, 3);
+//! let res_i = sph_i(2.5, 3);
+//! let res_k = sph_k(2.5, 3);
+//! ```
+//! 
+//! ## Struve functions: H and L
+//! 
+//! The Struve functions solve the inhomogeneous Bessel equation, and appear with Y and K in the radiation
+//! impedance of pistons and in many integrals of Bessel functions. L is the modified variant.
+//! 
+//! ```rust
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::bessel::{ struve_h, struve_l };
+//! let res_h = struve_h(Complex::from(1.5, -0.5), 1);
+//! let res_l = struve_l(2.0, 0.5);
+//! ```
+//! 
+//! ## Anger and Weber functions: J and E
+//! 
+//! The Anger and Weber functions generalize the integral representation of J to real orders, and appear in
+//! diffraction integrals and in the theory of ship waves.
+//! 
+//! ```rust
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::bessel::{ anger, weber };
+//! let res_j = anger(Complex::from(0.5, 1.0), 0.5);
+//! let res_e = weber(2.0, -1.5);
+//! ```
+//! 
+//! ## Integrals: Ki and the integrals of J and I
+//! 
+//! The Bickley–Naylor functions are the repeated integrals of `K_0`, which give the escape probabilities of
+//! the neutron transport in slabs and cylinders. The integrals of J and I from the origin, met in antenna and
+//! aperture problems, are computed from series of Bessel functions rather than by quadrature.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::{ bickley, i_integral, j_integral };
+//! let res_ki = bickley(0.5, 1);
+//! let res_j = j_integral(12.5, 0);
+//! let res_i = i_integral(2.0, 1);
+//! ```
+//! 
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use std::f64::consts::{     // Using std lib constants
     PI,                     // Pi
-    FRAC_PI_2               // Pi / 2
+    FRAC_PI_2,              // Pi / 2
+    FRAC_PI_3               // Pi / 3
 };
 
+use std::sync::OnceLock;    // Lazy table initialization
+
+use std::cell::Cell;        // Thread local settings
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
 use super::{                // Using parts from the crate
+    super::constant,        // Calling scilib constants
+    super::error::{         // Error handling
+        Error,              // The error type
+        Result              // The associated result
+    },
+    airy,                   // Airy functions of the uniform expansions
     basic,                  // Basic functions
-    complex::Complex        // Using Complex numbers
+    complex::Complex,       // Using Complex numbers
+    summation::CompensatedSum,  // Compensated sums of the real series
+    tables                  // Precomputed factorials
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -88,8 +244,196 @@ use super::{                // Using parts from the crate
 /// # Precision limit for Bessel computation
 const PRECISION_CONVERGENCE: f64 = 1.0e-8;
 
-/// # Limit when computing Bessel Y
-const DISTANCE_Y_LIM: f64 = 0.001;
+/// # Iteration cap of the series
+const MAX_ITERATIONS: usize = 10_000;
+
+/// # Precision of the integer order expansions of Y, and of the expansions of K
+const INTEGER_PRECISION: f64 = 1.0e-16;
+
+/// # Largest modulus for the logarithmic series of the integer orders of Y and K, and Temme's series of K
+const LOG_SERIES_LIMIT: f64 = 2.0;
+
+/// # Taylor coefficients of `1 / gamma(1 + x)`, for Temme's series of K
+const RECIPROCAL_GAMMA: [f64; 22] = [
+    1.0, 0.5772156649015329, -0.6558780715202539, -0.04200263503409524, 0.16653861138229148, -0.04219773455554433,
+    -0.009621971527876973, 0.0072189432466631, -0.0011651675918590652, -0.00021524167411495098,
+    0.0001280502823881162, -2.013485478078824e-05, -1.2504934821426706e-06, 1.133027231981696e-06,
+    -2.056338416977607e-07, 6.116095104481416e-09, 5.002007644469223e-09, -1.18127457048702e-09,
+    1.0434267116911005e-10, 7.782263439905071e-12, -3.696805618642206e-12, 5.100370287454476e-13
+];
+
+/// # Extra orders of Miller's backward recurrences
+const MILLER_START: usize = 40;
+
+/// # Argument above which the Hankel expansions replace the series
+const ASYMPTOTIC_LIMIT: f64 = 17.0;
+
+/// # Argument above which the Hankel expansion of K replaces Temme's series and Steed's continued fraction
+const ASYMPTOTIC_LIMIT_K: f64 = 9.0;
+
+/// # Ratio of the argument to the squared order required by the Hankel expansions
+const ASYMPTOTIC_ORDER: f64 = 0.5;
+
+/// # Term cap of the Hankel expansions
+const ASYMPTOTIC_TERMS: usize = 200;
+
+/// # Order above which the uniform expansions replace the series
+const UNIFORM_ORDER: f64 = 20.0;
+
+/// # Number of coefficients `A_k` and `B_k` in the uniform expansions of J and Y
+const UNIFORM_TERMS: usize = 4;
+
+/// # Term cap of the Debye expansions of I and K
+const DEBYE_TERMS: usize = 13;
+
+/// # Distance to the turning point below which the coefficients use their Taylor series
+const TURNING_RADIUS: f64 = 0.3;
+
+/// # Taylor coefficients in `zeta` of `B_0`, `A_1`, `B_1`, `A_2`, `B_2`, `A_3` and `B_3` at the turning point
+const TURNING_POINT: [&[f64]; 2 * UNIFORM_TERMS - 1] = [
+    &[0.01799887214135533, 0.008888888888888889, 0.0016256871626835734, -0.0003642848652199096,
+        -0.0003020604489992245, -5.844357254566871e-5, 1.676987092017009e-5, 1.301640251645854e-5,
+        2.446810161235558e-6, -7.726359892556074e-7, -5.790288733920437e-7, -1.0686924823038649e-7,
+        3.5246007722679215e-8, 2.5953663677903904e-8, 4.74028674970674e-9],
+    &[-0.0044444444444444444, -0.0014637074635031448, 0.0007064172724196897, 0.0006728876062209396,
+        0.00015400276720923506, -5.7663018476394256e-5, -4.988652219516832e-5, -1.0429604367829555e-5,
+        3.875233119897875e-6, 3.149058476155677e-6, 6.283287926118145e-7, -2.3288740817602857e-7,
+        -1.8282849503530237e-7, -3.551662329903235e-8, 1.3117001514284344e-8],
+    &[-0.0014928295321342952, -0.001394063079777366, -0.00038209541455316235, 0.0001690921480285997,
+        0.00017098534913549516, 4.1056073909885056e-5, -1.7066235326534388e-5, -1.5505462076725415e-5,
+        -3.4226070875631643e-6, 1.377200169743594e-6, 1.1775855270226163e-6, 2.475276240814876e-7,
+        -9.752250441852793e-8],
+    &[0.0006937355413546095, 0.00036866079061429277, -0.0002698633097062745, -0.0003513351434385577,
+        -0.00010447400839117906, 5.240810645254771e-5, 5.5302192195464635e-5, 1.393013001869328e-5,
+        -6.3002695153511275e-6, -5.982906208067455e-6, -1.3836191775567943e-6, 5.934375076425794e-7,
+        5.29906385066729e-7],
+    &[0.0005522130767214823, 0.0007110486511671225, 0.00025286016094456176, -0.0001514935008908382,
+        -0.00018614830193107866, -5.368400106135507e-5, 2.737712174855744e-5, 2.8968768839784512e-5,
+        7.3912685405113975e-6, -3.4621605971617314e-6, -3.3580620423380693e-6],
+    &[-0.00035421197145891434, -0.0002478905546627844, 0.00023412119028771853, 0.0003769634577989519,
+        0.00013525847749462153, -8.299629664485633e-5, -0.00010223189316621437, -2.9785770300620067e-5,
+        1.5692340623663527e-5, 1.690616194689123e-5],
+    &[-0.0004746177965713478, -0.000758562716590199, -0.0003256754833254582, 0.0002388346225258417,
+        0.0003425490836953001, 0.00011422583074436068, -6.794157763227058e-5, -8.152159978434478e-5]
+];
+
+/// Storage of the Debye polynomials
+static DEBYE: OnceLock<Vec<Vec<f64>>> = OnceLock::new();
+
+/// # Relative precision of the zeros
+const ZERO_PRECISION: f64 = 1.0e-15;
+
+/// # Iteration cap of the search of the zeros
+const ZERO_ITERATIONS: usize = 100;
+
+/// # Agreement of two steps of the trapezoidal rule of the Bickley–Naylor functions
+const BICKLEY_PRECISION: f64 = 1.0e-12;
+
+/// # Initial step of the trapezoidal rule of the Bickley–Naylor functions
+const BICKLEY_STEP: f64 = 0.5;
+
+/// # Halvings cap of the step of the trapezoidal rule of the Bickley–Naylor functions
+const BICKLEY_HALVINGS: usize = 30;
+
+/// # Precision of the continued fraction of the ratios of J
+const RATIO_PRECISION: f64 = 1.0e-15;
+
+/// # Relative accuracy of Hankel's asymptotic expansions
+const ASYMPTOTIC_ACCURACY: f64 = 1.0e-14;
+
+/// # Relative accuracy of the uniform expansions
+const LARGE_ORDER_ACCURACY: f64 = 1.0e-13;
+
+/// # Relative accuracy below which the Struve, Anger and Weber functions give up
+const SERIES_ACCURACY: f64 = 1.0e-8;
+
+/// # Argument above which the alternating series of the Struve, Anger and Weber functions are not tried
+const SERIES_LIMIT: f64 = 30.0;
+
+/// # Relative rounding error of the series and recurrences
+const ROUNDING_ACCURACY: f64 = 4.0 * f64::EPSILON;
+
+thread_local! {
+    /// Override set by `BesselConfig::scope` on the current thread
+    static CONFIG: Cell<Option<BesselConfig>> = const { Cell::new(None) };
+
+    /// Error estimate and exhaustion of the series summed since `tracked` started on the current thread
+    static ESTIMATE: Cell<Option<(f64, bool)>> = const { Cell::new(None) };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Settings of the Bessel series
+///
+/// Convergence settings of the series of this module, which otherwise follow the `Precision` policy. The
+/// `tolerance` is the relative size of the last term kept in the power series of J, I and of the Struve
+/// functions, and `max_terms` caps the number of terms of every series and continued fraction of the
+/// module (the zeros keeping their own settings): all the functions terminate, whatever the input.
+///
+/// ```
+/// # use scilib::math::bessel::{ BesselConfig, j, jf };
+/// // Fewer digits, for a quick preview
+/// let quick = BesselConfig { tolerance: 1.0e-4, max_terms: 1_000 };
+/// let res = quick.scope(|| jf(2.5, 1.5));
+/// assert!((res.re - 0.525080264664003).abs() < 1.0e-5);
+///
+/// // Hard cap on the number of terms, stopping the series of J_0 after x^6
+/// let capped = BesselConfig { tolerance: 0.0, max_terms: 3 };
+/// assert!((capped.scope(|| j(1.0, 0)).re - (1.0 - 1.0 / 4.0 + 1.0 / 64.0 - 1.0 / 2304.0)).abs() < 1.0e-15);
+///
+/// // Settings derived from the Precision policy outside of a scope
+/// assert_eq!(BesselConfig::current(), BesselConfig::default());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BesselConfig {
+    /// Relative size of the last term of the power series
+    pub tolerance: f64,
+    /// Largest number of terms of the series and continued fractions
+    pub max_terms: usize
+}
+
+/// # Default
+///
+/// The reference settings of the module, used with the `Default` precision policy.
+impl Default for BesselConfig {
+    fn default() -> Self {
+        Self { tolerance: PRECISION_CONVERGENCE, max_terms: MAX_ITERATIONS }
+    }
+}
+
+/// Implementing required methods
+impl BesselConfig {
+    /// # Settings currently in use
+    ///
+    /// Returns the settings of the innermost `scope` on this thread if any, the reference settings scaled
+    /// by the current `Precision` policy otherwise.
+    pub fn current() -> Self {
+        CONFIG.with(|c| c.get()).unwrap_or_else(|| {
+            let prec: Precision = Precision::current();
+            Self { tolerance: prec.tolerance(PRECISION_CONVERGENCE), max_terms: prec.iterations(MAX_ITERATIONS) }
+        })
+    }
+
+    /// # Runs a computation with these settings
+    ///
+    /// The settings apply to every Bessel function called by `f` on the current thread, and the previous
+    /// ones are restored afterwards, even if `f` panics.
+    pub fn scope<R, F>(self, f: F) -> R
+    where F: FnOnce() -> R {
+
+        /// Restores the previous settings when dropped
+        struct Guard(Option<BesselConfig>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                CONFIG.with(|c| c.set(self.0));
+            }
+        }
+
+        let _guard: Guard = Guard(CONFIG.with(|c| c.replace(Some(self))));
+        f()
+    }
+}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -101,7 +445,11 @@ const DISTANCE_Y_LIM: f64 = 0.001;
 /// 
 /// The bessel function depend on an infinite sum of terms; which we can't have.
 /// The criterion chosen here is check each new term impacts the results significantly enough.
-/// The default value selected in the program is defined by `const PRECISION_CONVERGENCE: f64 = 1.0e-8;`.
+/// The default value selected in the program is defined by `const PRECISION_CONVERGENCE: f64 = 1.0e-8;`,
+/// and is scaled by the current `Precision` policy, or set by a `BesselConfig` scope.
+/// 
+/// When the order exceeds `|x|`, the values become tiny and the function instead uses Miller's backward
+/// recurrence, normalized as in `j_seq`, which keeps the full relative precision down to the underflow.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
@@ -133,33 +481,58 @@ const DISTANCE_Y_LIM: f64 = 0.001;
 /// assert!(pos1 == -neg1);
 /// assert!(pos2 == neg2);
 /// 
+/// // Orders much larger than the argument
+/// assert!((j(2.0, 50).re / 3.2240958394363848e-65 - 1.0).abs() < 1.0e-14);
+/// assert!((j(2.0, 15).re / 7.1830163560187924e-13 - 1.0).abs() < 1.0e-14);
+/// 
 /// // The input is treated as complex
 /// let c: Complex = Complex::from(1, 2.5);
 /// let res: Complex = j(c, 2);
 /// ```
 pub fn j<T: Into<Complex>>(x: T, n: i32) -> Complex {
 
+    let x: Complex = x.into();
     let np: i32 = n.abs();                                      // Getting the positive value of n
-    let x2: Complex = x.into() / 2.0;                           // Halving x
+
+    if asymptotic(x, n as f64, ASYMPTOTIC_LIMIT) {
+        return asymptotic_jy(x, n as f64).0;
+    } else if np as f64 > x.modulus() {
+        // Miller's backward recurrence, where the order exceeds the argument
+        let res: Complex = miller_seq(x, np as usize, false)[np as usize];
+        return if n.is_negative() && np % 2 != 0 { -res } else { res };
+    } else if large_order(x, n as f64) {
+        return large_jy(x, n as f64).0;
+    }
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    let x2: Complex = x / 2.0;                                  // Halving x
     let mut k: i32 = 0;                                         // Order counter
     let mut d1: f64 = 1.0;                                      // First div
-    let mut d2: f64 = basic::factorial(np as usize) as f64;     // Second div
+    let mut d2: f64 = tables::factorial(np as usize);           // Second div
     let mut sg: f64 = 1.0;                                      // Sign of the term
 
     let mut term: Complex = x2.powi(np) / d2;                   // The term at each step
     let mut res: Complex = Complex::default();                  // The result of the operation
+    let mut total: f64 = 0.0;                                   // Sum of the moduli of the terms
 
-    // If the first term is already too small we exit directly
-    if term.modulus() < PRECISION_CONVERGENCE {
+    // If the first term vanishes, so does the sum
+    if term.modulus() == 0.0 {
         return res;
     }
 
     // Computing the terms of the infinite series
     'convergence: loop {
         res += term;
+        total += term.modulus();
+        diagnostics::step("bessel::j", k as usize, (term / res).modulus(), term.modulus());
 
         // If the changed compared to the final value is small we break
-        if (term / res).modulus().abs() < PRECISION_CONVERGENCE {
+        if (term / res).modulus().abs() < tol || k as usize >= max_iter {
+            diagnostics::finish("bessel::j", k as usize + 1, (term / res).modulus(), tol);
+            report(term.modulus() + f64::EPSILON * total, (term / res).modulus() >= tol);
             break 'convergence;
         }
 
@@ -221,26 +594,42 @@ pub fn jf<T, U>(x: T, order: U) -> Complex
         return j(x, n as i32);
     }
 
-    let x2: Complex = x.into() / 2.0;           // Halving x
+    let x: Complex = x.into();
+    if asymptotic(x, n, ASYMPTOTIC_LIMIT) {
+        return asymptotic_jy(x, n).0;
+    } else if large_order(x, n) {
+        return large_jy(x, n).0;
+    }
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    let x2: Complex = x / 2.0;                  // Halving x
     let mut k: f64 = 0.0;                       // Order counter
     let mut d1: f64 = 1.0;                      // First div
-    let mut d2: f64 = basic::gamma(n + 1.0);    // Second div
+    let mut d2: f64 = basic::gamma_complex((n + 1.0).into()).re;  // Second div
     let mut sg: f64 = 1.0;                      // Sign of the term
 
     let mut term: Complex = x2.powf(n) / d2;    // The term at each step
     let mut res: Complex = Complex::default();  // The result of the operation
+    let mut total: f64 = 0.0;                   // Sum of the moduli of the terms
     
-    // If the first term is already too small we exit directly
-    if term.modulus().abs() < PRECISION_CONVERGENCE {
+    // If the first term vanishes, so does the sum
+    if term.modulus() == 0.0 {
         return res;
     }
 
     // Computing the terms of the infinite series
     'convergence: loop {
         res += term;
+        total += term.modulus();
+        diagnostics::step("bessel::jf", k as usize, (term / res).modulus(), term.modulus());
 
         // If the changed compared to the final value is small we break
-        if (term / res).modulus().abs() < PRECISION_CONVERGENCE {
+        if (term / res).modulus().abs() < tol || k as usize >= max_iter {
+            diagnostics::finish("bessel::jf", k as usize + 1, (term / res).modulus(), tol);
+            report(term.modulus() + f64::EPSILON * total, (term / res).modulus() >= tol);
             break 'convergence;
         }
 
@@ -263,9 +652,10 @@ pub fn jf<T, U>(x: T, order: U) -> Complex
 /// 
 /// `x` is the value to evaluate, and `n` the order of the function.
 /// 
-/// Because the function is not continuous for integer values of `n`, we need to compute the limit around these points.
-/// We set the limit distance with `DISTANCE_Y_LIM`, compute the limit above and below the desired point and take the average.
-/// We achieve precision under `1.0e-5` for non-integer`n`, and integer `n` using this approach.
+/// Because the definition is not continuous for integer values of `n`, these orders use the limit expansions instead:
+/// the logarithmic series for `|x| <= 2`, and Neumann's series over J (from Miller's recurrence) beyond, followed
+/// by the upward recurrence. Integer orders are then accurate to double precision, and `Y_(-n) = (-1)^n Y_n`.
+/// We achieve precision under `1.0e-5` for non-integer`n`.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
@@ -280,8 +670,9 @@ pub fn jf<T, U>(x: T, order: U) -> Complex
 /// let res_int_p = y(0.5, 1);
 /// let res_int_n = y(0.5, -1);
 /// 
-/// assert!((res_int_p.re - -1.47147239).abs() < 1.0e-5);
-/// assert!((res_int_n.re - 1.47147239).abs() < 1.0e-5);
+/// assert!((res_int_p.re - -1.4714723926702430).abs() < 1.0e-15);
+/// assert!((res_int_n.re - 1.4714723926702430).abs() < 1.0e-15);
+/// assert!((y(7.5, 3).re - 0.15970759193793513).abs() < 1.0e-15);
 /// 
 /// // We can compute negative value with Y, the result is complex
 /// let res_neg = y(-1.2, 3.1);
@@ -298,9 +689,15 @@ where T: Into<Complex> + Copy, U: Into<f64> {
 
     let n: f64 = order.into();
 
-    // If n is whole, we have to take the limit, otherwise it's direct
+    if asymptotic(x.into(), n, ASYMPTOTIC_LIMIT) {
+        return asymptotic_jy(x.into(), n).1;
+    } else if large_order(x.into(), n) {
+        return large_jy(x.into(), n).1;
+    }
+
+    // If n is whole, we use the limit expansions, otherwise it's direct
     if n.fract() == 0.0 {
-        (y(x, n + DISTANCE_Y_LIM) + y(x, n - DISTANCE_Y_LIM)) / 2.0
+        integer_y(x.into(), n)
     } else {
         ((n * PI).cos() * jf(x, n) - jf(x, -n)) / (n * PI).sin()
     }
@@ -313,42 +710,71 @@ where T: Into<Complex> + Copy, U: Into<f64> {
 /// `x` is the value to evaluate, and `n` the order of the function.
 /// 
 /// We use a definition of I based on an infinite series (similar to J). This way, we ensure good precision in
-/// the computation.
+/// the computation. As for `j`, the integer orders larger than `|x|` use Miller's backward recurrence.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
 /// # use scilib::math::bessel::i;
 /// let res = i(1.2, 0);
 /// assert!((res.re - 1.39373).abs() < 1.0e-4 && res.im == 0.0);
+/// assert!((i(2.0, 15).re / 8.139432531237380e-13 - 1.0).abs() < 1.0e-14);
 /// 
 /// let c = Complex::from(-1.2, 0.5);
 /// let r2 = i(c, -1.6);
 /// assert!((r2.re - 0.549831).abs() < 1.0e-5 && (r2.im - -0.123202).abs() < 1.0e-5);
+/// 
+/// // Integer orders are symmetric
+/// assert!((i(1.2, 1).re - 0.714677941).abs() < 1.0e-8);
+/// assert_eq!(i(1.2, -1), i(1.2, 1));
 /// ```
 pub fn i<T, U>(x: T, order: U) -> Complex
 where T: Into<Complex>, U: Into<f64> + Copy {
     
     let n: f64 = order.into();
 
-    let x2: Complex = x.into() / 2.0;           // Halving x
+    // For negative integer orders, I(-n) = I(n)
+    if n < 0.0 && n.fract() == 0.0 {
+        return i(x, -n);
+    }
+
+    let x: Complex = x.into();
+    if asymptotic(x, n, ASYMPTOTIC_LIMIT) {
+        return asymptotic_ik(x, n).0;
+    } else if n.fract() == 0.0 && n > x.modulus() {
+        // Miller's backward recurrence, where the order exceeds the argument
+        return miller_seq(x, n as usize, true)[n as usize];
+    } else if large_order(x, n) {
+        return large_ik(x, n).0;
+    }
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    let x2: Complex = x / 2.0;                  // Halving x
     let mut k: f64 = 0.0;                       // Order counter
     let mut d1: f64 = 1.0;                      // First div
-    let mut d2: f64 = basic::gamma(n + 1.0);    // Second div
+    let mut d2: f64 = basic::gamma_complex((n + 1.0).into()).re;  // Second div
 
     let mut term: Complex = x2.powf(n) / d2;    // The term at each step
     let mut res: Complex = Complex::default();  // The result of the operation
+    let mut total: f64 = 0.0;                   // Sum of the moduli of the terms
     
-    // If the first term is already too small we exit directly
-    if term.modulus().abs() < PRECISION_CONVERGENCE {
+    // If the first term vanishes, so does the sum
+    if term.modulus() == 0.0 {
         return res;
     }
 
     // Computing the terms of the infinite series
     'convergence: loop {
         res += term;
+        total += term.modulus();
+        diagnostics::step("bessel::i", k as usize, (term / res).modulus(), term.modulus());
 
         // If the changed compared to the final value is small we break
-        if (term / res).modulus().abs() < PRECISION_CONVERGENCE {
+        if (term / res).modulus().abs() < tol || k as usize >= max_iter {
+            diagnostics::finish("bessel::i", k as usize + 1, (term / res).modulus(), tol);
+            report(term.modulus() + f64::EPSILON * total, (term / res).modulus() >= tol);
             break 'convergence;
         }
 
@@ -367,7 +793,11 @@ where T: Into<Complex>, U: Into<f64> + Copy {
 /// 
 /// `x` is the value to evaluate, and `n` the order of the function.
 /// 
-/// The definition of K is similar to Y, but is based on I and not J.
+/// The definition of K is similar to Y, but is based on I and not J. Integer orders use the logarithmic series
+/// for `|x| <= 2`, and Steed's continued fraction for `K_0` and `K_1` beyond, followed by the upward recurrence.
+/// Other orders `n = m + mu`, with `|mu| <= 1/2`, use Temme's series of `K_mu` and `K_(mu+1)` for `|x| <= 2`,
+/// and Steed's continued fraction beyond, followed by the same recurrence. This avoids the cancellation of
+/// the reflection formula `pi / 2 (I_(-n) - I_n) / sin(n pi)`.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
@@ -376,74 +806,2467 @@ where T: Into<Complex>, U: Into<f64> + Copy {
 /// let res = k(c1, -3.5);
 /// assert!((res.re - -0.32113627).abs() < 1.0e-5 && (res.im - 0.76751785).abs() < 1.0e-5);
 /// 
-/// // Similar to Y, we take the limit for integer orders
+/// // Similar to Y, integer orders use the limit expansions
 /// let c2 = Complex::from(-1.1, 0.6);
 /// let res_i = k(c2, 1);
 /// assert!((res_i.re - -1.6153940).abs() < 1.0e-5 && (res_i.im - -2.1056846).abs() < 1.0e-5);
+/// assert!((k(5.0, 2).re - 0.00530894371222346).abs() < 1.0e-18);
+/// assert!((k(12.0, 0).re - 2.2008253973114916e-6).abs() < 1.0e-21);
+/// assert!((k(10.0, 4.5).re / 4.6162268049400638e-5 - 1.0).abs() < 1.0e-13);
 /// ```
 pub fn k<T, U>(x: T, order: U) -> Complex
 where T: Into<Complex> + Copy, U: Into<f64> {
 
     let n: f64 = order.into();
 
-    // If n is whole, we have to take the limit, otherwise it's direct
+    // Integer orders use the limit expansions, more accurate than Hankel's until the J and Y limit
+    if n.fract() == 0.0 && !large_order(x.into(), n) && !asymptotic(x.into(), n, ASYMPTOTIC_LIMIT) {
+        return integer_k(x.into(), n);
+    } else if asymptotic(x.into(), n, ASYMPTOTIC_LIMIT_K) {
+        return asymptotic_ik(x.into(), n).1;
+    } else if large_order(x.into(), n) {
+        return large_ik(x.into(), n).1;
+    }
+
+    fractional_k(x.into(), n)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # J Bessel function, integer index, real argument
+///
+/// Real counterpart of `j`, for any real `x`: the power series is summed in real arithmetic, with its terms
+/// computed by recurrence, which avoids the overhead of the complex functions in the hot loops. The large
+/// arguments and orders use the complex expansions.
+///
+/// ```
+/// # use scilib::math::bessel::{ j, j_re };
+/// assert!((j_re(2.5, 3) - 0.216600391039114).abs() < 1.0e-8);
+/// assert!((j_re(-2.5, -3) - 0.216600391039114).abs() < 1.0e-8);
+/// assert!((j_re(25.0, 0) - 0.0962667832759581).abs() < 1.0e-14);
+/// assert!((j_re(1.2, 5) - j(1.2, 5).re).abs() < 1.0e-14);
+/// ```
+pub fn j_re(x: f64, n: i32) -> f64 {
+
+    let m: f64 = n.abs() as f64;
+    if asymptotic(x.into(), m, ASYMPTOTIC_LIMIT) || large_order(x.into(), m) {
+        return j(x, n).re;
+    }
+
+    let res: f64 = real_series(x, m, -1.0, "bessel::j");
+    if n < 0 && n % 2 != 0 { -res } else { res }
+}
+
+/// # J Bessel function, real index, real argument
+///
+/// Real counterpart of `jf`, for `x >= 0` (or any real `x` for integer orders, `NaN` being returned for
+/// the complex values otherwise), computed as `j_re`.
+///
+/// ```
+/// # use scilib::math::bessel::jf_re;
+/// assert!((jf_re(7.3, 1.5) - -0.120953010973631).abs() < 1.0e-8);
+/// assert!(jf_re(-1.0, 0.5).is_nan());
+/// ```
+pub fn jf_re(x: f64, n: f64) -> f64 {
+
+    if n.fract() == 0.0 {
+        return j_re(x, n as i32);
+    } else if x < 0.0 {
+        return f64::NAN;
+    } else if asymptotic(x.into(), n, ASYMPTOTIC_LIMIT) || large_order(x.into(), n) {
+        return jf(x, n).re;
+    }
+
+    real_series(x, n, -1.0, "bessel::jf")
+}
+
+/// # Y Bessel function, real argument
+///
+/// Real counterpart of `y`, for `x >= 0` (`NaN` being returned for negative `x`). Non-integer orders combine
+/// the real series of `jf_re`, while the integer orders use the expansions of `y`.
+///
+/// ```
+/// # use scilib::math::bessel::y_re;
+/// assert!((y_re(3.2, 0.5) - 0.445270446131186).abs() < 1.0e-8);
+/// assert!((y_re(0.5, 1) - -1.4714723926702430).abs() < 1.0e-15);
+/// ```
+pub fn y_re<U: Into<f64>>(x: f64, order: U) -> f64 {
+
+    let n: f64 = order.into();
+    if x < 0.0 {
+        return f64::NAN;
+    } else if n.fract() == 0.0 || asymptotic(x.into(), n, ASYMPTOTIC_LIMIT) || large_order(x.into(), n) {
+        return y(x, n).re;
+    }
+
+    ((n * PI).cos() * jf_re(x, n) - jf_re(x, -n)) / (n * PI).sin()
+}
+
+/// # I modified Bessel function, real argument
+///
+/// Real counterpart of `i`, for `x >= 0` (or any real `x` for integer orders, `NaN` being returned for
+/// the complex values otherwise), computed as `j_re`.
+///
+/// ```
+/// # use scilib::math::bessel::i_re;
+/// assert!((i_re(4.0, 2.5) - 4.75762687482347).abs() < 1.0e-7);
+/// assert!((i_re(-3.0, -2) - 2.24521244092995).abs() < 1.0e-8);
+/// ```
+pub fn i_re<U: Into<f64>>(x: f64, order: U) -> f64 {
+
+    let n: f64 = order.into();
     if n.fract() == 0.0 {
-        (k(x, n + DISTANCE_Y_LIM) + k(x, n - DISTANCE_Y_LIM)) / 2.0
+        let m: f64 = n.abs();
+        let res: f64 = if asymptotic(x.abs().into(), m, ASYMPTOTIC_LIMIT) || large_order(x.abs().into(), m) {
+            i(x.abs(), m).re
+        } else {
+            real_series(x.abs(), m, 1.0, "bessel::i")
+        };
+        return if x < 0.0 && m % 2.0 != 0.0 { -res } else { res };
+    } else if x < 0.0 {
+        return f64::NAN;
+    } else if asymptotic(x.into(), n, ASYMPTOTIC_LIMIT) || large_order(x.into(), n) {
+        return i(x, n).re;
+    }
+
+    real_series(x, n, 1.0, "bessel::i")
+}
+
+/// # K modified Bessel function, real argument
+///
+/// Real counterpart of `k`, for `x >= 0` (`NaN` being returned for negative `x`), using the expansions of `k`.
+///
+/// ```
+/// # use scilib::math::bessel::k_re;
+/// assert!((k_re(0.8, 1.3) - 1.138001985326).abs() < 1.0e-7);
+/// assert!((k_re(5.0, 2) - 0.00530894371222346).abs() < 1.0e-18);
+/// ```
+pub fn k_re<U: Into<f64>>(x: f64, order: U) -> f64 {
+
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    k(x, order).re
+}
+
+/// Power series of J (`sign = -1`) or I (`sign = 1`) in real arithmetic, for `x >= 0` or integer orders
+fn real_series(x: f64, n: f64, sign: f64, method: &'static str) -> f64 {
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    let x2: f64 = 0.5 * x;                                      // Halving x
+    let q: f64 = sign * x2 * x2;                                // Ratio of the powers
+    let mut term: f64 = if n.fract() == 0.0 && n >= 0.0 {
+        x2.powi(n as i32) / tables::factorial(n as usize)
     } else {
-        (FRAC_PI_2 / (n * PI).sin()) * (i(x, -n) - i(x, n))
+        x2.powf(n) / basic::gamma_complex((n + 1.0).into()).re
+    };
+    let mut res: CompensatedSum = CompensatedSum::new();
+
+    if term == 0.0 {
+        return 0.0;
+    }
+
+    let mut k: usize = 0;
+    loop {
+        res.add(term);
+        let residual: f64 = (term / res.value()).abs();
+        diagnostics::step(method, k, residual, term.abs());
+
+        if residual < tol || k >= max_iter {
+            diagnostics::finish(method, k + 1, residual, tol);
+            break;
+        }
+
+        k += 1;
+        term *= q / (k as f64 * (n + k as f64));
     }
+
+    res.value()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// # First Hankel function: H1
-/// 
-/// Computes the first kind of Hankel function, accepts complex input.
-/// 
+/// # J Bessel function over a slice
+///
+/// Evaluates `j` at each point of `x`, for a fixed integer order. The `parallel` feature provides the
+/// multi-threaded counterparts of these functions, such as `parallel::bessel_j`, for the large grids.
+///
+/// ```
+/// # use scilib::math::bessel::{ j, j_slice };
+/// let x: Vec<f64> = (0..500).map(|i| i as f64 * 0.1).collect();
+/// let res = j_slice(&x, 0);
+///
+/// assert_eq!(res.len(), 500);
+/// assert_eq!(res[124], j(12.4, 0));
+/// ```
+pub fn j_slice<T>(x: &[T], n: i32) -> Vec<Complex>
+where T: Into<Complex> + Copy {
+    x.iter().map(|&v| j(v, n)).collect()
+}
+
+/// # J Bessel function over a slice, real order
+///
+/// Evaluates `jf` at each point of `x`, for a fixed order.
+pub fn jf_slice<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy, U: Into<f64> + Copy {
+    x.iter().map(|&v| jf(v, order)).collect()
+}
+
+/// # Y Bessel function over a slice
+///
+/// Evaluates `y` at each point of `x`, for a fixed order.
+///
 /// ```
 /// # use scilib::math::complex::Complex;
-/// # use scilib::math::bessel::hankel_first;
-/// let c1 = Complex::from(-1.1, 2.3);
-/// let r1 = hankel_first(c1, 1);
-/// assert!((r1.re - -0.0112027).abs() < 1.0e-5 && (r1.im - 0.0551947).abs() < 1.0e-5);
-/// 
-/// let c2 = Complex::from(5.2, -3);
-/// let r2 = hankel_first(c2, -2.35);
-/// assert!((r2.re - -4.2809477).abs() < 1.0e-5 && (r2.im - 3.2123502).abs() < 1.0e-5);
+/// # use scilib::math::bessel::{ y, y_slice };
+/// let x: Vec<Complex> = (1..=4).map(|i| Complex::from(i, -0.5)).collect();
+/// assert_eq!(y_slice(&x, 1.5)[2], y(Complex::from(3, -0.5), 1.5));
 /// ```
-pub fn hankel_first<T, U>(x: T, order: U) -> Complex
-where T: Into<Complex> + Copy, U: Into<f64> {
+pub fn y_slice<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy, U: Into<f64> + Copy {
+    x.iter().map(|&v| y(v, order)).collect()
+}
 
-    let n: f64 = order.into();
-    let res_j = jf(x, n);
-    let res_y = Complex::i() * y(x, n);
+/// # I modified Bessel function over a slice
+///
+/// Evaluates `i` at each point of `x`, for a fixed order.
+pub fn i_slice<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy, U: Into<f64> + Copy {
+    x.iter().map(|&v| i(v, order)).collect()
+}
 
-    res_j + res_y
+/// # K modified Bessel function over a slice
+///
+/// Evaluates `k` at each point of `x`, for a fixed order.
+///
+/// ```
+/// # use scilib::math::bessel::{ k, k_slice };
+/// let x: Vec<f64> = vec![0.5, 1.0, 2.0, 4.0];
+/// assert_eq!(k_slice(&x, 2)[3], k(4.0, 2));
+/// ```
+pub fn k_slice<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy, U: Into<f64> + Copy {
+    x.iter().map(|&v| k(v, order)).collect()
 }
 
-/// # Second Hankel function: H2
-/// 
-/// Computes the second kind of Hankel function, accepts complex input.
-/// We simplify the computation by simply conjugating the first kind
-/// 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # J Bessel function with an error estimate, integer index
+///
+/// Same as `j`, but returns the result along with an estimate of its absolute error. The estimate follows
+/// the method used: the size of the last term and the rounding of the sum for the series, and the documented
+/// accuracy of the expansions, relative to the modulus of the Hankel functions where J oscillates.
+///
+/// Returns a `Domain` error for a non-finite argument, a `Convergence` error when a series stops on the term
+/// cap of `BesselConfig` before reaching its tolerance, and an `Overflow` error when the result exceeds `f64`.
+///
 /// ```
-/// # use scilib::math::complex::Complex;
-/// # use scilib::math::bessel::hankel_second;
-/// let c1 = Complex::from(-1.1, 2.3);
-/// let r1 = hankel_second(c1, 1);
-/// assert!((r1.re - -3.54421).abs() < 1.0e-5 && (r1.im - 2.2983539).abs() < 1.0e-5);
-/// 
-/// let c2 = Complex::from(5.2, -3);
-/// let r2 = hankel_second(c2, -2.35);
-/// assert!((r2.re - -0.0068184520).abs() < 1.0e-5 && (r2.im - -0.0193698).abs() < 1.0e-5);
-pub fn hankel_second<T, U>(x: T, order: U) -> Complex
-where T: Into<Complex> + Copy, U: Into<f64> {
-    
-    let n: f64 = order.into();
-    let res_j = jf(x, n);
-    let res_y = Complex::i() * y(x, n);
+/// # use scilib::Error;
+/// # use scilib::math::bessel::{ BesselConfig, try_j };
+/// let (res, err) = try_j(2.5, 1).unwrap();
+/// assert!((res.re - 0.49709410246427404).abs() <= err && err < 1.0e-8);
+///
+/// // The expansions are more accurate than the series
+/// let (res, err) = try_j(100.0, 3).unwrap();
+/// assert!((res.re - 0.07628420172033194).abs() <= err && err < 1.0e-14);
+///
+/// assert!(matches!(try_j(f64::NAN, 1), Err(Error::Domain(_))));
+///
+/// // A series stopped after two terms
+/// let capped = BesselConfig { tolerance: 1.0e-12, max_terms: 2 };
+/// assert!(matches!(capped.scope(|| try_j(5.0, 0)), Err(Error::Convergence(_))));
+/// ```
+pub fn try_j<T: Into<Complex>>(x: T, n: i32) -> Result<(Complex, f64)> {
+
+    let x: Complex = x.into();
+    if !finite(x) {
+        return Err(Error::Domain("Bessel functions require a finite argument"));
+    }
+
+    // The orders above the argument always use Miller's recurrence, accurate to the rounding
+    let miller: bool = n.unsigned_abs() as f64 > x.modulus() && !asymptotic(x, n as f64, ASYMPTOTIC_LIMIT);
+    match expansion_jy(x, n as f64).filter(|_| !miller) {
+        Some((res, _, error)) => checked(res, error),
+        None => estimate(|| j(x, n))
+    }
+}
+
+/// # J Bessel function with an error estimate, real index
+///
+/// Same as `jf`, with the error estimate and the errors of `try_j`. The negative non-integer orders are
+/// singular at the origin, where a `Domain` error is returned.
+///
+/// ```
+/// # use scilib::Error;
+/// # use scilib::math::bessel::try_jf;
+/// let (res, err) = try_jf(2.4, -1.75).unwrap();
+/// assert!((res.re - 0.11990699767495322).abs() <= err && err < 1.0e-8);
+///
+/// assert!(matches!(try_jf(0.0, -1.5), Err(Error::Domain(_))));
+/// ```
+pub fn try_jf<T, U>(x: T, order: U) -> Result<(Complex, f64)>
+where T: Into<Complex>, U: Into<f64> {
+
+    let (x, n): (Complex, f64) = (x.into(), order.into());
+    if !finite(x) || !n.is_finite() {
+        return Err(Error::Domain("Bessel functions require a finite argument and order"));
+    } else if n.fract() == 0.0 {
+        return try_j(x, n as i32);
+    } else if n < 0.0 && x == Complex::new() {
+        return Err(Error::Domain("J has a singularity at the origin for negative orders"));
+    }
+
+    match expansion_jy(x, n) {
+        Some((res, _, error)) => checked(res, error),
+        None => estimate(|| jf(x, n))
+    }
+}
+
+/// # Y Bessel function with an error estimate
+///
+/// Same as `y`, with the error estimate and the errors of `try_j`. Y being singular at the origin, `x = 0`
+/// returns a `Domain` error. For the non-integer orders, the errors of the two J are amplified by the
+/// reflection formula, which grows close to the integer orders.
+///
+/// ```
+/// # use scilib::Error;
+/// # use scilib::math::bessel::try_y;
+/// let (res, err) = try_y(1.0, 1.5).unwrap();
+/// assert!((res.re - -1.1024955751601792).abs() <= err && err < 1.0e-7);
+///
+/// let (res, err) = try_y(0.5, 1).unwrap();
+/// assert!((res.re - -1.4714723926702431).abs() <= err && err < 1.0e-14);
+///
+/// assert!(matches!(try_y(0.0, 1), Err(Error::Domain(_))));
+/// ```
+pub fn try_y<T, U>(x: T, order: U) -> Result<(Complex, f64)>
+where T: Into<Complex>, U: Into<f64> {
+
+    let (x, n): (Complex, f64) = (x.into(), order.into());
+    if !finite(x) || !n.is_finite() {
+        return Err(Error::Domain("Bessel functions require a finite argument and order"));
+    } else if x == Complex::new() {
+        return Err(Error::Domain("Y has a singularity at the origin"));
+    } else if let Some((_, res, error)) = expansion_jy(x, n) {
+        return checked(res, error);
+    } else if n.fract() == 0.0 {
+        let (res, error): (Complex, f64) = estimate(|| integer_y(x, n))?;
+        return checked(res, error + ROUNDING_ACCURACY * j(x, n as i32).modulus());
+    }
+
+    // Reflection formula, with the rounding of the difference
+    let (cos, sin): (f64, f64) = ((n * PI).cos(), (n * PI).sin());
+    let ((a, err_a), (b, err_b)): ((Complex, f64), (Complex, f64)) = (try_jf(x, n)?, try_jf(x, -n)?);
+    let res: Complex = (cos * a - b) / sin;
+    let rounding: f64 = f64::EPSILON * (cos.abs() * a.modulus() + b.modulus());
+
+    checked(res, (cos.abs() * err_a + err_b + rounding) / sin.abs())
+}
+
+/// # I modified Bessel function with an error estimate
+///
+/// Same as `i`, with the error estimate and the errors of `try_jf`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::try_i;
+/// let (res, err) = try_i(Complex::from(-1.2, 0.5), -1.6).unwrap();
+/// let exact = Complex::from(0.5498313096850604, -0.12320223035975445);
+/// assert!((res - exact).modulus() <= err && err < 1.0e-8);
+/// ```
+pub fn try_i<T, U>(x: T, order: U) -> Result<(Complex, f64)>
+where T: Into<Complex>, U: Into<f64> {
+
+    let (x, n): (Complex, f64) = (x.into(), order.into());
+    if !finite(x) || !n.is_finite() {
+        return Err(Error::Domain("Bessel functions require a finite argument and order"));
+    } else if n < 0.0 && n.fract() == 0.0 {
+        return try_i(x, -n);
+    } else if n < 0.0 && x == Complex::new() {
+        return Err(Error::Domain("I has a singularity at the origin for negative orders"));
+    }
+
+    let miller: bool = n.fract() == 0.0 && n > x.modulus() && !asymptotic(x, n, ASYMPTOTIC_LIMIT);
+    match expansion_ik(x, n, ASYMPTOTIC_LIMIT).filter(|_| !miller) {
+        Some((res, k_n, accuracy)) => {
+            // Close to the imaginary axis, I oscillates with the magnitude of K
+            let scale: f64 = if x.im.abs() > x.re.abs() { res.modulus() + k_n.modulus() / PI } else { res.modulus() };
+            checked(res, accuracy * scale)
+        },
+        None => estimate(|| i(x, n))
+    }
+}
+
+/// # K modified Bessel function with an error estimate
+///
+/// Same as `k`, with the error estimate and the errors of `try_y`, K being singular at the origin.
+///
+/// ```
+/// # use scilib::math::bessel::try_k;
+/// let (res, err) = try_k(5.0, 2).unwrap();
+/// assert!((res.re - 0.005308943712223460).abs() <= err && err < 1.0e-17);
+///
+/// let (res, err) = try_k(1.5, 0.3).unwrap();
+/// assert!((res.re - 0.21893795473217302).abs() <= err && err < 1.0e-14);
+/// ```
+pub fn try_k<T, U>(x: T, order: U) -> Result<(Complex, f64)>
+where T: Into<Complex>, U: Into<f64> {
+
+    let (x, n): (Complex, f64) = (x.into(), order.into());
+    if !finite(x) || !n.is_finite() {
+        return Err(Error::Domain("Bessel functions require a finite argument and order"));
+    } else if x == Complex::new() {
+        return Err(Error::Domain("K has a singularity at the origin"));
+    } else if n.fract() == 0.0 && !large_order(x, n) && !asymptotic(x, n, ASYMPTOTIC_LIMIT) {
+        return estimate(|| integer_k(x, n));
+    } else if let Some((i_n, res, accuracy)) = expansion_ik(x, n, ASYMPTOTIC_LIMIT_K) {
+        // In the left half-plane, K holds a multiple of I
+        let scale: f64 = if x.re < 0.0 { res.modulus() + PI * i_n.modulus() } else { res.modulus() };
+        return checked(res, accuracy * scale);
+    }
+
+    estimate(|| fractional_k(x, n))
+}
+
+/// Whether both parts of `z` are finite
+fn finite(z: Complex) -> bool {
+    z.re.is_finite() && z.im.is_finite()
+}
+
+/// J and Y from the expansions when they apply, with their absolute error
+fn expansion_jy(z: Complex, n: f64) -> Option<(Complex, Complex, f64)> {
+
+    let ((j_n, y_n), accuracy): ((Complex, Complex), f64) = if asymptotic(z, n, ASYMPTOTIC_LIMIT) {
+        (asymptotic_jy(z, n), ASYMPTOTIC_ACCURACY)
+    } else if large_order(z, n) {
+        (large_jy(z, n), LARGE_ORDER_ACCURACY)
+    } else {
+        return None;
+    };
+
+    // Past the turning point, J and Y oscillate with the magnitude of the Hankel functions
+    let i: Complex = Complex::i();
+    let scale: f64 = if z.re.abs() > n.abs() {
+        (j_n + i * y_n).modulus().max((j_n - i * y_n).modulus())
+    } else {
+        j_n.modulus().max(y_n.modulus())
+    };
+
+    Some((j_n, y_n, accuracy * scale))
+}
+
+/// I and K from the expansions when they apply, with their relative accuracy
+fn expansion_ik(z: Complex, n: f64, limit: f64) -> Option<(Complex, Complex, f64)> {
+    if asymptotic(z, n, limit) {
+        let (i_n, k_n): (Complex, Complex) = asymptotic_ik(z, n);
+        Some((i_n, k_n, ASYMPTOTIC_ACCURACY))
+    } else if large_order(z, n) {
+        let (i_n, k_n): (Complex, Complex) = large_ik(z, n);
+        Some((i_n, k_n, LARGE_ORDER_ACCURACY))
+    } else {
+        None
+    }
+}
+
+/// Result of a function built on the series, with the errors they report and the rounding of the result
+fn estimate<F>(f: F) -> Result<(Complex, f64)>
+where F: FnOnce() -> Complex {
+
+    let previous: Option<(f64, bool)> = ESTIMATE.with(|e| e.replace(Some((0.0, false))));
+    let res: Complex = f();
+    let (error, exhausted): (f64, bool) = ESTIMATE.with(|e| e.replace(previous)).unwrap_or_default();
+
+    if exhausted {
+        return Err(Error::Convergence("a Bessel series reached its term cap before its tolerance"));
+    }
+
+    checked(res, error + ROUNDING_ACCURACY * res.modulus())
+}
+
+/// Reports the error of a series to the enclosing `estimate`, if any
+fn report(error: f64, exhausted: bool) {
+    ESTIMATE.with(|e| {
+        if let Some((total, any)) = e.get() {
+            e.set(Some((total + error, any || exhausted)));
+        }
+    });
+}
+
+/// Checks that the result and its error are representable
+fn checked(res: Complex, error: f64) -> Result<(Complex, f64)> {
+    if finite(res) && error.is_finite() {
+        Ok((res, error))
+    } else {
+        Err(Error::Overflow("Bessel function exceeds f64"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # First Hankel function: H1
+/// 
+/// Computes the first kind of Hankel function, accepts complex input.
+///
+/// For large `|x|`, compared to the square of the order, H1 comes directly from its Hankel expansion. Out of
+/// the third quadrant, it is otherwise computed from `H1_n(x) = 2 / (i pi) exp(-i n pi / 2) K_n(-ix)`, which
+/// keeps its relative precision where it decays exponentially, in the upper half-plane. It is computed as
+/// `J + iY` in the third quadrant, where it grows.
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::hankel_first;
+/// let c1 = Complex::from(-1.1, 2.3);
+/// let r1 = hankel_first(c1, 1);
+/// assert!((r1.re - -0.0112027).abs() < 1.0e-5 && (r1.im - 0.0551947).abs() < 1.0e-5);
+/// 
+/// let c2 = Complex::from(5.2, -3);
+/// let r2 = hankel_first(c2, -2.35);
+/// assert!((r2.re - -4.2809477).abs() < 1.0e-5 && (r2.im - 3.2123502).abs() < 1.0e-5);
+///
+/// // Exponentially small in the upper half-plane, where J and iY cancel
+/// let r3 = hankel_first(Complex::from(30, 25), 0);
+/// assert!((r3 - Complex::from(-1.4699355427580317e-12, -9.852249142332454e-13)).modulus() < 1.0e-26);
+///
+/// // Same at higher order, before the Hankel expansion applies
+/// let r4 = hankel_first(Complex::from(0, 30), 10);
+/// assert!((r4 - Complex::from(0, 6.9027516535813481e-14)).modulus() < 1.0e-27);
+/// let r5 = hankel_first(Complex::from(5, 25), 10);
+/// assert!((r5 - Complex::from(1.0426394750212137e-11, 9.6077898819077765e-12)).modulus() < 1.0e-25);
+/// ```
+pub fn hankel_first<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+
+    let n: f64 = order.into();
+    let z: Complex = x.into();
+    if asymptotic(z, n, ASYMPTOTIC_LIMIT) {
+        return asymptotic_hankel(z, n).0;
+    }
+    if z.re > 0.0 || z.im >= 0.0 {
+        return hankel_k(z, n, 1.0);
+    }
+
+    let res_j = jf(x, n);
+    let res_y = Complex::i() * y(x, n);
+
+    res_j + res_y
+}
+
+/// # Second Hankel function: H2
+/// 
+/// Computes the second kind of Hankel function, accepts complex input.
+///
+/// As for H1, large arguments use the Hankel expansion of H2. Out of the second quadrant, the others use
+/// `H2_n(x) = -2 / (i pi) exp(i n pi / 2) K_n(ix)`, which keeps its relative precision in the lower
+/// half-plane, and `J - iY` in the second quadrant.
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::hankel_second;
+/// let c1 = Complex::from(-1.1, 2.3);
+/// let r1 = hankel_second(c1, 1);
+/// assert!((r1.re - -3.54421).abs() < 1.0e-5 && (r1.im - 2.2983539).abs() < 1.0e-5);
+/// 
+/// let c2 = Complex::from(5.2, -3);
+/// let r2 = hankel_second(c2, -2.35);
+/// assert!((r2.re - -0.0068184520).abs() < 1.0e-5 && (r2.im - -0.0193698).abs() < 1.0e-5);
+///
+/// // Exponentially small in the lower half-plane
+/// let r3 = hankel_second(Complex::from(25, -30), 3);
+/// assert!((r3 - Complex::from(1.2493493163238633e-14, -3.6592664516325814e-15)).modulus() < 1.0e-28);
+///
+/// let r4 = hankel_second(Complex::from(20, -20), 10);
+/// assert!((r4 - Complex::from(-4.1829440550334522e-10, 1.0029603310099112e-9)).modulus() < 1.0e-23);
+/// ```
+pub fn hankel_second<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    
+    let n: f64 = order.into();
+    let z: Complex = x.into();
+    if asymptotic(z, n, ASYMPTOTIC_LIMIT) {
+        return asymptotic_hankel(z, n).1;
+    }
+    if z.re > 0.0 || z.im < 0.0 {
+        return hankel_k(z, n, -1.0);
+    }
+
+    let res_j = jf(x, n);
+    let res_y = Complex::i() * y(x, n);
 
     res_j - res_y
 }
 
+/// H1 (`sign = 1`) or H2 (`sign = -1`) from K, at `-i sign z`, free of cancellation where they decay
+fn hankel_k(z: Complex, n: f64, sign: f64) -> Complex {
+    let factor: Complex = Complex::from(0.0, -2.0 * sign / PI) * Complex::from_polar(-0.5 * sign * n * PI, 1.0);
+    factor * k(Complex::from(0.0, -sign) * z, n)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Derivative of the J Bessel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `J'_n = (J_(n-1) - J_(n+1)) / 2`, which keeps the accuracy of `jf`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::j_prime;
+/// assert!((j_prime(2.5, 0).re - -0.497094102464274).abs() < 1.0e-8);
+///
+/// let res = j_prime(Complex::from(1.0, 1.0), 1.5);
+/// assert!((res.re - 0.499631170622678).abs() < 1.0e-5 && (res.im - -0.0313280470722216).abs() < 1.0e-5);
+/// ```
+pub fn j_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    (jf(x, n - 1.0) - jf(x, n + 1.0)) * 0.5
+}
+
+/// # Derivative of the Y Bessel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `Y'_n = (Y_(n-1) - Y_(n+1)) / 2`.
+///
+/// ```
+/// # use scilib::math::bessel::y_prime;
+/// assert!((y_prime(2.0, 1).re - 0.563891888420214).abs() < 1.0e-5);
+/// assert!((y_prime(3.0, 0.5).re - -0.0109999539217298).abs() < 1.0e-5);
+/// ```
+pub fn y_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    (y(x, n - 1.0) - y(x, n + 1.0)) * 0.5
+}
+
+/// # Derivative of the I modified Bessel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `I'_n = (I_(n-1) + I_(n+1)) / 2`.
+///
+/// ```
+/// # use scilib::math::bessel::i_prime;
+/// assert!((i_prime(1.5, 2).re - 0.531220270797).abs() < 1.0e-5);
+/// ```
+pub fn i_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    (i(x, n - 1.0) + i(x, n + 1.0)) * 0.5
+}
+
+/// # Derivative of the K modified Bessel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `K'_n = -(K_(n-1) + K_(n+1)) / 2`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::k_prime;
+/// assert!((k_prime(1.0, 0).re - -0.601907230197235).abs() < 1.0e-5);
+/// assert!((k_prime(10.0, 3.5).re / -3.5046796957014264e-5 - 1.0).abs() < 1.0e-13);
+///
+/// let res = k_prime(Complex::from(2.0, -1.0), 1.5);
+/// assert!((res.re - -0.00937048840778707).abs() < 1.0e-5 && (res.im - -0.210784077964642).abs() < 1.0e-5);
+/// ```
+pub fn k_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    -(k(x, n - 1.0) + k(x, n + 1.0)) * 0.5
+}
+
+/// # Derivative of the first Hankel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `H1'_n = (H1_(n-1) - H1_(n+1)) / 2`.
+///
+/// ```
+/// # use scilib::math::bessel::hankel_first_prime;
+/// let res = hankel_first_prime(3.0, 1);
+/// assert!((res.re - -0.373071607743912).abs() < 1.0e-5 && (res.im - 0.268625201748857).abs() < 1.0e-5);
+/// ```
+pub fn hankel_first_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    (hankel_first(x, n - 1.0) - hankel_first(x, n + 1.0)) * 0.5
+}
+
+/// # Derivative of the second Hankel function
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// Computed with the recurrence `H2'_n = (H2_(n-1) - H2_(n+1)) / 2`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::hankel_second_prime;
+/// let res = hankel_second_prime(Complex::from(1.0, 0.5), 0.5);
+/// assert!((res.re - -0.138375366681518).abs() < 1.0e-5 && (res.im - -1.10413125043308).abs() < 1.0e-5);
+/// ```
+pub fn hankel_second_prime<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex> + Copy, U: Into<f64> {
+    let n: f64 = order.into();
+    (hankel_second(x, n - 1.0) - hankel_second(x, n + 1.0)) * 0.5
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Zeros of the J Bessel function
+///
+/// `k`-th positive zero of `J_order`, for `order >= 0` and `k >= 1`. Returns `NaN` otherwise.
+///
+/// The starting point is McMahon's expansion in `1 / k`, or the uniform expansion in the order through
+/// the zeros of the Airy function when `k` is smaller than the order; it is refined by Newton's method on
+/// `jf` and `j_prime`, and shares their accuracy.
+///
+/// ```
+/// # use scilib::math::bessel::j_zero;
+/// assert!((j_zero(0, 1) - 2.404825557695773).abs() < 1.0e-12);
+/// assert!((j_zero(1, 3) - 10.17346813506272).abs() < 1.0e-12);
+/// assert!((j_zero(7.5, 2) - 15.431289210268378).abs() < 1.0e-9);
+/// ```
+pub fn j_zero<U: Into<f64>>(order: U, k: usize) -> f64 {
+    bessel_zero(order.into(), k, false, false)
+}
+
+/// # Zeros of the derivative of the J Bessel function
+///
+/// `k`-th positive zero of `J'_order`, for `order >= 0` and `k >= 1`, the origin being excluded for the
+/// order 0. Returns `NaN` otherwise.
+///
+/// These are the radial wave numbers of the transverse electric modes of a cylindrical cavity.
+///
+/// ```
+/// # use scilib::math::bessel::j_prime_zero;
+/// assert!((j_prime_zero(0, 1) - 3.831705970207512).abs() < 1.0e-12);
+/// assert!((j_prime_zero(1, 1) - 1.841183781340659).abs() < 1.0e-9);
+/// assert!((j_prime_zero(4, 2) - 9.282396285241612).abs() < 1.0e-9);
+/// ```
+pub fn j_prime_zero<U: Into<f64>>(order: U, k: usize) -> f64 {
+    bessel_zero(order.into(), k, false, true)
+}
+
+/// # Zeros of the Y Bessel function
+///
+/// `k`-th positive zero of `Y_order`, for `order >= 0` and `k >= 1`. Returns `NaN` otherwise.
+///
+/// For integer orders, the accuracy is limited by that of `y`.
+///
+/// ```
+/// # use scilib::math::bessel::y_zero;
+/// assert!((y_zero(0, 1) - 0.8935769662791675).abs() < 1.0e-5);
+/// assert!((y_zero(2.5, 2) - 7.451610064214503).abs() < 1.0e-8);
+/// ```
+pub fn y_zero<U: Into<f64>>(order: U, k: usize) -> f64 {
+    bessel_zero(order.into(), k, true, false)
+}
+
+/// # Zeros of the derivative of the Y Bessel function
+///
+/// `k`-th positive zero of `Y'_order`, for `order >= 0` and `k >= 1`. Returns `NaN` otherwise.
+///
+/// For integer orders, the accuracy is limited by that of `y`.
+///
+/// ```
+/// # use scilib::math::bessel::y_prime_zero;
+/// assert!((y_prime_zero(1, 1) - 3.683022856585178).abs() < 1.0e-5);
+/// ```
+pub fn y_prime_zero<U: Into<f64>>(order: U, k: usize) -> f64 {
+    bessel_zero(order.into(), k, true, true)
+}
+
+/// Zero `k` of `J_n`, `Y_n` (`second`) or of their derivatives (`derivative`), by Newton's method
+fn bessel_zero(n: f64, k: usize, second: bool, derivative: bool) -> f64 {
+
+    if k == 0 || n < 0.0 || n.is_nan() {
+        return f64::NAN;
+    }
+
+    // The origin is a zero of J'_0, not counted
+    let k: usize = if derivative && !second && n == 0.0 { k + 1 } else { k };
+    let kf: f64 = k as f64;
+
+    // Function and derivative whose root is sought
+    let value = |x: f64| -> (f64, f64) {
+        let (c, dc): (f64, f64) = if second {
+            (y(x, n).re, y_prime(x, n).re)
+        } else {
+            (jf(x, n).re, j_prime(x, n).re)
+        };
+        if derivative {
+            (dc, -dc / x - (1.0 - n * n / (x * x)) * c)
+        } else {
+            (c, dc)
+        }
+    };
+
+    // Airy zeros of the uniform expansion: a_k, a'_k for J and J', b_k, b'_k for Y and Y'
+    let (shift, airy_zero): (f64, f64) = match (second, derivative) {
+        (false, false) => (-0.25, -airy_t(3.0 * PI * (4.0 * kf - 1.0) / 8.0, false)),
+        (false, true) => (-0.75, -airy_t(3.0 * PI * (4.0 * kf - 3.0) / 8.0, true)),
+        (true, false) => (-0.75, -airy_t(3.0 * PI * (4.0 * kf - 3.0) / 8.0, false)),
+        (true, true) => (-0.25, -airy_t(3.0 * PI * (4.0 * kf - 1.0) / 8.0, true))
+    };
+
+    let mut x: f64 = if kf < n {
+        let (c1, c2): (f64, f64) = (2.0_f64.powf(-1.0 / 3.0), 2.0_f64.powf(-2.0 / 3.0));
+        let extra: f64 = if derivative { c2 / (5.0 * airy_zero) } else { 0.0 };
+        n - airy_zero * c1 * n.cbrt() + (0.3 * airy_zero * airy_zero * c2 + extra) / n.cbrt()
+    } else {
+        // McMahon's expansion
+        let mu: f64 = 4.0 * n * n;
+        let b: f64 = (kf + 0.5 * n + shift) * PI;
+        let b8: f64 = 8.0 * b;
+        if derivative {
+            b - (mu + 3.0) / b8 - 4.0 * (7.0 * mu * mu + 82.0 * mu - 9.0) / (3.0 * b8.powi(3))
+                - 32.0 * (83.0 * mu.powi(3) + 2075.0 * mu * mu - 3039.0 * mu + 3537.0) / (15.0 * b8.powi(5))
+        } else {
+            b - (mu - 1.0) / b8 - 4.0 * (mu - 1.0) * (7.0 * mu - 31.0) / (3.0 * b8.powi(3))
+                - 32.0 * (mu - 1.0) * (83.0 * mu * mu - 982.0 * mu + 3779.0) / (15.0 * b8.powi(5))
+        }
+    };
+
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(ZERO_PRECISION);              // Convergence tolerance
+    let max_iter: usize = prec.iterations(ZERO_ITERATIONS);     // Iteration cap
+    let mut last: f64 = f64::INFINITY;
+
+    for iter in 0..max_iter {
+        let (f, df): (f64, f64) = value(x);
+        if !(f / df).is_finite() {
+            break;
+        }
+        let step: f64 = (f / df).clamp(-1.0, 1.0);
+        x = (x - step).max(0.5 * x);
+
+        // Past the accuracy of the function, the steps stop decreasing
+        diagnostics::step("bessel::zero", iter, (step / x).abs(), step);
+        if (step / x).abs() < tol || step.abs() >= last {
+            diagnostics::finish("bessel::zero", iter + 1, (step / x).abs(), tol);
+            break;
+        }
+        last = step.abs();
+    }
+
+    x
+}
+
+/// Asymptotic magnitude of the zeros of Ai and Bi (`T`), or of their derivatives (`U`)
+fn airy_t(t: f64, derivative: bool) -> f64 {
+    let t2: f64 = t.powi(-2);
+    if derivative {
+        t.powf(2.0 / 3.0) * (1.0 - t2 * (7.0 / 48.0 - t2 * 35.0 / 288.0))
+    } else {
+        t.powf(2.0 / 3.0) * (1.0 + t2 * (5.0 / 48.0 - t2 * 5.0 / 36.0))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Sequence of J Bessel functions
+///
+/// `x` is the value to evaluate (complex input accepted). Returns `J_0(x)` to `J_n_max(x)` in a single
+/// pass of Miller's backward recurrence, which is stable for this kind. The sequence is normalized with
+/// the generating function `exp(-ix) = J_0 + 2 sum (-i)^k J_k` (`exp(ix)` below the real axis), which
+/// avoids cancellations for complex arguments.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::j_seq;
+/// let res = j_seq(2.5, 30);
+/// assert_eq!(res.len(), 31);
+/// assert!((res[0].re - -0.048383776468197996).abs() < 1.0e-15 && res[0].im == 0.0);
+/// assert!((res[3].re - 0.21660039103911352).abs() < 1.0e-15);
+/// assert!((res[30].re / 2.8955641962077116e-30 - 1.0).abs() < 1.0e-13);
+///
+/// let res = j_seq(Complex::from(3.0, -4.0), 5);
+/// assert!((res[5].re - -0.9852361734977384).abs() < 1.0e-14 && (res[5].im - 0.5942655412104944).abs() < 1.0e-14);
+/// ```
+pub fn j_seq<T: Into<Complex>>(x: T, n_max: usize) -> Vec<Complex> {
+    miller_seq(x.into(), n_max, false)
+}
+
+/// # Sequence of Y Bessel functions
+///
+/// `x` is the value to evaluate (complex input accepted). Returns `Y_0(x)` to `Y_n_max(x)`, from the upward
+/// recurrence, which is stable for this kind, starting from `y(x, 0)` and `y(x, 1)`.
+///
+/// ```
+/// # use scilib::math::bessel::{ y, y_seq };
+/// let res = y_seq(20.0, 40);
+/// assert!((res[0].re - y(20.0, 0).re).abs() < 1.0e-15);
+/// assert!((res[40].re - -9281227.196058271).abs() < 1.0e-6);
+/// ```
+pub fn y_seq<T: Into<Complex>>(x: T, n_max: usize) -> Vec<Complex> {
+    let z: Complex = x.into();
+    upward_seq(y(z, 0), y(z, 1), z, n_max, -1.0)
+}
+
+/// # Sequence of I modified Bessel functions
+///
+/// `x` is the value to evaluate (complex input accepted). Returns `I_0(x)` to `I_n_max(x)` in a single
+/// pass of Miller's backward recurrence, normalized with the generating function
+/// `exp(x) = I_0 + 2 sum I_k` (`exp(-x) = I_0 + 2 sum (-1)^k I_k` for `Re(x) < 0`).
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::i_seq;
+/// let res = i_seq(1.2, 10);
+/// assert!((res[0].re - 1.3937255841340644).abs() < 1.0e-15);
+/// assert!((res[10].re / 1.7216442955993953e-9 - 1.0).abs() < 1.0e-14);
+///
+/// let res = i_seq(Complex::from(-30.0, 5.0), 4);
+/// assert!((res[4].re / 146925156734.36497 - 1.0).abs() < 1.0e-13);
+/// assert!((res[4].im / 578158949759.7349 - 1.0).abs() < 1.0e-13);
+/// ```
+pub fn i_seq<T: Into<Complex>>(x: T, n_max: usize) -> Vec<Complex> {
+    miller_seq(x.into(), n_max, true)
+}
+
+/// # Sequence of K modified Bessel functions
+///
+/// `x` is the value to evaluate (complex input accepted). Returns `K_0(x)` to `K_n_max(x)`, from the upward
+/// recurrence, which is stable for this kind, starting from `k(x, 0)` and `k(x, 1)`.
+///
+/// ```
+/// # use scilib::math::bessel::k_seq;
+/// let res = k_seq(30.0, 25);
+/// assert!((res[0].re / 2.1324774964630564e-14 - 1.0).abs() < 1.0e-13);
+/// assert!((res[25].re / 3.7775319791336277e-10 - 1.0).abs() < 1.0e-13);
+/// ```
+pub fn k_seq<T: Into<Complex>>(x: T, n_max: usize) -> Vec<Complex> {
+    let z: Complex = x.into();
+    upward_seq(k(z, 0), k(z, 1), z, n_max, 1.0)
+}
+
+/// # Ratio of consecutive J Bessel functions
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order `v`.
+///
+/// Computes `J_(v+1)(x) / J_v(x)` from the continued fraction `1 / (2(v+1)/x - 1 / (2(v+2)/x - ...))`,
+/// evaluated by the modified Lentz method. The ratio is needed by the logarithmic derivatives of the Mie
+/// and scattering recursions, and stays accurate where the functions themselves underflow. The fraction
+/// converges after about `|x|` terms, and the ratio is infinite at the zeros of `J_v`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::j_ratio;
+/// assert!((j_ratio(2.5, 0).re - -10.273983114794838).abs() < 1.0e-12);
+/// assert!((j_ratio(3.0, -0.5).re - -0.14254654307427781).abs() < 1.0e-14);
+/// assert!((j_ratio(1000.0, 10).re - 0.25310008614971443).abs() < 1.0e-12);
+///
+/// // J_500(1) underflows, but not the ratio
+/// assert!((j_ratio(1.0, 500).re - 0.00099800498406172929).abs() < 1.0e-18);
+///
+/// let res = j_ratio(Complex::from(1, 2), 2.3);
+/// assert!((res - Complex::from(0.125272448414655, 0.29539899491677899)).modulus() < 1.0e-14);
+/// ```
+pub fn j_ratio<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex>, U: Into<f64> {
+
+    let (x, v): (Complex, f64) = (x.into(), order.into());
+    if x == Complex::new() {
+        return Complex::new();
+    }
+
+    let tol: f64 = Precision::current().tolerance(RATIO_PRECISION);    // Convergence tolerance
+    let max_iter: usize = BesselConfig::current().max_terms;           // Iteration cap
+    let tiny: Complex = Complex::from(1.0e-150, 0.0);                  // Guard against zero denominators
+
+    // Modified Lentz evaluation of the denominator 2(v+1)/x - 1 / (2(v+2)/x - ...)
+    let inv: Complex = 2.0 / x;
+    let mut f: Complex = (v + 1.0) * inv;
+    if f == Complex::new() {
+        f = tiny;
+    }
+    let (mut c, mut d): (Complex, Complex) = (f, Complex::new());
+
+    let mut k: usize = 2;
+    loop {
+        let b: Complex = (v + k as f64) * inv;
+        d = b - d;
+        if d == Complex::new() {
+            d = tiny;
+        }
+        c = b - 1.0 / c;
+        if c == Complex::new() {
+            c = tiny;
+        }
+        d = 1.0 / d;
+        let delta: Complex = c * d;
+        f *= delta;
+
+        let residual: f64 = (delta - 1.0).modulus();
+        diagnostics::step("bessel::j_ratio", k - 2, residual, f.modulus());
+        if residual < tol || k > max_iter {
+            diagnostics::finish("bessel::j_ratio", k - 1, residual, tol);
+            break;
+        }
+        k += 1;
+    }
+
+    1.0 / f
+}
+
+/// Miller's backward recurrence for J, or I (`modified`), normalized with the generating function
+fn miller_seq(z: Complex, n_max: usize, modified: bool) -> Vec<Complex> {
+
+    let mut res: Vec<Complex> = vec![Complex::new(); n_max + 1];
+    if z == Complex::new() {
+        res[0] = Complex::from(1.0, 0.0);
+        return res;
+    }
+
+    // Weight u of the generating function exp(u z) = f_0 + 2 sum u^k f_k, chosen for the sum to be dominant
+    let u: Complex = match (modified, z.im >= 0.0, z.re >= 0.0) {
+        (false, true, _) => -Complex::i(),
+        (false, false, _) => Complex::i(),
+        (true, _, true) => Complex::from(1.0, 0.0),
+        (true, _, false) => Complex::from(-1.0, 0.0)
+    };
+    let sign: f64 = if modified { 1.0 } else { -1.0 };
+
+    let start: usize = n_max + z.modulus() as usize + MILLER_START;
+    let mut next: Complex = Complex::new();
+    let mut curr: Complex = Complex::from(1.0, 0.0);
+    let mut weight: Complex = u.powi(start as i32);
+    let mut sum: Complex = Complex::new();
+
+    for k in (1..=start).rev() {
+        sum += 2.0 * weight * curr;
+        if k <= n_max {
+            res[k] = curr;
+        }
+
+        let prev: Complex = 2.0 * k as f64 / z * curr + sign * next;
+        next = curr;
+        curr = prev;
+        weight /= u;
+
+        // Rescaling to avoid overflows
+        if curr.modulus() > 1.0e100 {
+            curr *= 1.0e-100;
+            next *= 1.0e-100;
+            sum *= 1.0e-100;
+            res.iter_mut().skip(k).for_each(|r| *r *= 1.0e-100);
+        }
+    }
+    sum += curr;
+    res[0] = curr;
+
+    // The weights are complex for J, leaving rounding errors in the imaginary part on the real axis
+    let norm: Complex = (u * z).exp() / sum;
+    res.iter().map(|&r| if z.im == 0.0 { (r * norm).re.into() } else { r * norm }).collect()
+}
+
+/// Upward recurrence `f_(k+1) = 2k / z f_k + sign f_(k-1)`, stable for Y and K
+fn upward_seq(f0: Complex, f1: Complex, z: Complex, n_max: usize, sign: f64) -> Vec<Complex> {
+
+    let mut res: Vec<Complex> = vec![f0, f1];
+    for k in 1..n_max {
+        res.push(2.0 * k as f64 / z * res[k] + sign * res[k - 1]);
+    }
+    res.truncate(n_max + 1);
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Spherical Bessel function of the first kind: j
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// The spherical Bessel functions are the radial solutions of the Helmholtz equation in spherical
+/// coordinates, with `j_n(x) = sqrt(pi / 2x) J_(n+1/2)(x)`. The values come from Miller's backward
+/// recurrence, normalized with the closed forms of `j_0` or `j_1`, which is stable for every order.
+/// Negative orders use `j_(-n-1) = (-1)^(n+1) y_n`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_j;
+/// assert!((sph_j(2.0, 0).re - 2.0_f64.sin() / 2.0).abs() < 1.0e-15);
+/// assert!((sph_j(1.5, 3).re - 0.028324641582471801).abs() < 1.0e-15);
+/// assert!((sph_j(0.01, 5).re - 9.619972620034288e-15).abs() < 1.0e-28);
+///
+/// let res = sph_j(Complex::from(1.0, 2.0), 2);
+/// assert!((res.re - -0.15013127056923784).abs() < 1.0e-14 && (res.im - 0.38603772637600963).abs() < 1.0e-14);
+/// ```
+pub fn sph_j<T: Into<Complex>>(x: T, n: i32) -> Complex {
+
+    let z: Complex = x.into();
+
+    if n < 0 {
+        let res: Complex = sph_y(z, -n - 1);
+        return if n % 2 == 0 { res } else { -res };
+    }
+
+    if z == Complex::new() {
+        return if n == 0 { 1.0.into() } else { Complex::new() };
+    }
+
+    let j0: Complex = z.sin() / z;
+    if n == 0 {
+        return j0;
+    }
+    let j1: Complex = z.sin() / (z * z) - z.cos() / z;
+
+    // Miller's backward recurrence, from an order high enough for the solution to be dominant
+    let start: usize = n as usize + z.modulus() as usize + MILLER_START;
+    let mut next: Complex = Complex::new();
+    let mut curr: Complex = Complex::from(1.0, 0.0);
+    let mut res: Complex = Complex::new();
+    let mut f1: Complex = Complex::new();
+
+    for k in (1..=start).rev() {
+        let prev: Complex = (2 * k + 1) as f64 / z * curr - next;
+        next = curr;
+        curr = prev;
+
+        // Rescaling to avoid overflows, also in the squared moduli of the divisions
+        if curr.modulus() > 1.0e100 {
+            curr *= 1.0e-100;
+            next *= 1.0e-100;
+            res *= 1.0e-100;
+            f1 *= 1.0e-100;
+        }
+        if k - 1 == n as usize {
+            res = curr;
+        }
+        if k == 2 {
+            f1 = curr;
+        }
+    }
+
+    // Normalizing with the largest of the closed forms, as j_0 vanishes at multiples of pi
+    let norm: Complex = if j0.modulus() >= j1.modulus() { curr / j0 } else { f1 / j1 };
+    let scale: f64 = 1.0 / norm.modulus();
+    (res * scale) / (norm * scale)
+}
+
+/// # Spherical Bessel function of the second kind: y
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// Also written `n_n(x)`, with `y_n(x) = sqrt(pi / 2x) Y_(n+1/2)(x)`. The values come from the upward
+/// recurrence, which is stable for this kind, starting from the closed forms of `y_0` and `y_1`. Negative
+/// orders use `y_(-n-1) = (-1)^n j_n`. The function diverges at the origin.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_y;
+/// assert!((sph_y(2.0, 0).re - -2.0_f64.cos() / 2.0).abs() < 1.0e-15);
+/// assert!((sph_y(1.5, 3).re - -3.7892735647020435).abs() < 1.0e-13);
+///
+/// let res = sph_y(Complex::from(1.0, 2.0), 2);
+/// assert!((res.re - -0.22499497388743192).abs() < 1.0e-14 && (res.im - -0.10171042469570655).abs() < 1.0e-14);
+/// ```
+pub fn sph_y<T: Into<Complex>>(x: T, n: i32) -> Complex {
+
+    let z: Complex = x.into();
+
+    if n < 0 {
+        let res: Complex = sph_j(z, -n - 1);
+        return if n % 2 == 0 { -res } else { res };
+    }
+
+    if z == Complex::new() {
+        return Complex::from(f64::NEG_INFINITY, 0.0);
+    }
+
+    let mut prev: Complex = -z.cos() / z;
+    if n == 0 {
+        return prev;
+    }
+    let mut curr: Complex = -z.cos() / (z * z) - z.sin() / z;
+
+    for k in 1..n {
+        let next: Complex = (2 * k + 1) as f64 / z * curr - prev;
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+
+/// # Modified spherical Bessel function of the first kind: i
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// The modified spherical Bessel functions are the radial solutions of the modified Helmholtz equation,
+/// found in the expansions of the screened Coulomb (Yukawa) potential, with `i_n(x) = sqrt(pi / 2x) I_(n+1/2)(x)`.
+/// The values come from `i_n(x) = (-i)^n j_n(ix)`, for every order.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_i;
+/// assert!((sph_i(2.0, 0).re - 2.0_f64.sinh() / 2.0).abs() < 1.0e-15);
+/// assert!((sph_i(1.5, 3).re - 0.036372212190958201).abs() < 1.0e-16);
+/// assert!((sph_i(0.01, 5).re - 9.620046620108288e-15).abs() < 1.0e-28);
+/// assert!((sph_i(30.0, 4).re / 126974448137.32939 - 1.0).abs() < 1.0e-14);
+/// assert!((sph_i(1.5, -2).re - 0.37400425217732396).abs() < 1.0e-14);
+///
+/// let res = sph_i(Complex::from(1.0, 2.0), 2);
+/// assert!((res.re - -0.21890731036371971).abs() < 1.0e-14 && (res.im - 0.15881574297707539).abs() < 1.0e-14);
+/// ```
+pub fn sph_i<T: Into<Complex>>(x: T, n: i32) -> Complex {
+    let z: Complex = x.into();
+    Complex::i().powi(-n) * sph_j(Complex::i() * z, n)
+}
+
+/// # Modified spherical Bessel function of the second kind: k
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// Defined as `k_n(x) = sqrt(pi / 2x) K_(n+1/2)(x)`, which decays as `pi exp(-x) / 2x` at large `x`. The
+/// values come from the upward recurrence `k_(n+1) = k_(n-1) + (2n+1) k_n / x`, which is stable for this kind,
+/// starting from the closed forms of `k_0` and `k_1`. Negative orders use `k_(-n-1) = k_n`. The function
+/// diverges at the origin.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_k;
+/// let x = 2.0_f64;
+/// assert!((sph_k(x, 0).re - std::f64::consts::FRAC_PI_2 * (-x).exp() / x).abs() < 1.0e-16);
+/// assert!((sph_k(1.5, 3).re - 3.7645440899225953).abs() < 1.0e-14);
+/// assert!((sph_k(1.5, -3).re - 1.0125325483240084).abs() < 1.0e-14);
+///
+/// let res = sph_k(Complex::from(1.0, 2.0), 2);
+/// assert!((res.re - -0.33521247712359206).abs() < 1.0e-14 && (res.im - 0.42286692861773931).abs() < 1.0e-14);
+/// ```
+pub fn sph_k<T: Into<Complex>>(x: T, n: i32) -> Complex {
+
+    let z: Complex = x.into();
+
+    if n < 0 {
+        return sph_k(z, -n - 1);
+    }
+
+    if z == Complex::new() {
+        return Complex::from(f64::INFINITY, 0.0);
+    }
+
+    let mut prev: Complex = FRAC_PI_2 * (-z).exp() / z;
+    if n == 0 {
+        return prev;
+    }
+    let mut curr: Complex = prev * (1.0 + 1.0 / z);
+
+    for k in 1..n {
+        let next: Complex = (2 * k + 1) as f64 / z * curr + prev;
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Spherical Hankel function of the first kind: h1
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// Computed as `j_n + i y_n`, it behaves as an outgoing spherical wave `exp(ix) / x` at large `x`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_hankel_first;
+/// // h1_0(x) = -i exp(ix) / x
+/// let x = 2.5_f64;
+/// let res = sph_hankel_first(x, 0);
+/// assert!((res.re - x.sin() / x).abs() < 1.0e-15 && (res.im - -x.cos() / x).abs() < 1.0e-15);
+///
+/// let res = sph_hankel_first(Complex::from(1.0, 0.5), 2);
+/// assert!((res.re - -2.2251845438069155).abs() < 1.0e-13 && (res.im - -0.84318094411202732).abs() < 1.0e-13);
+/// ```
+pub fn sph_hankel_first<T: Into<Complex>>(x: T, n: i32) -> Complex {
+    let z: Complex = x.into();
+    sph_j(z, n) + Complex::i() * sph_y(z, n)
+}
+
+/// # Spherical Hankel function of the second kind: h2
+///
+/// `x` is the value to evaluate (complex input accepted), and `n` the order of the function.
+///
+/// Computed as `j_n - i y_n`, it behaves as an incoming spherical wave `exp(-ix) / x` at large `x`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::sph_hankel_second;
+/// let res = sph_hankel_second(Complex::from(1.0, 0.5), 2);
+/// assert!((res.re - 2.3288758214076951).abs() < 1.0e-13 && (res.im - 0.96241558577516981).abs() < 1.0e-13);
+/// ```
+pub fn sph_hankel_second<T: Into<Complex>>(x: T, n: i32) -> Complex {
+    let z: Complex = x.into();
+    sph_j(z, n) - Complex::i() * sph_y(z, n)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Struve function: H
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// The Struve functions are the particular solutions of the inhomogeneous Bessel equation with a
+/// right-hand side `4 (x/2)^(n+1) / (sqrt(pi) Gamma(n + 1/2))`. The value comes from the power series
+/// `sum (-1)^k (x/2)^(2k+n+1) / (Gamma(k + 3/2) Gamma(k + n + 3/2))`, with the same convergence criterion
+/// as `j`. The alternating series loses accuracy to cancellations for large `|x|`, where the expansion
+/// `H_n = Y_n + 1/pi sum Gamma(k + 1/2) (x/2)^(n-2k-1) / Gamma(n + 1/2 - k)`, up to its smallest term, takes
+/// over; `NaN` is returned where neither reaches an accuracy of `1e-8`, relative to the value or to the
+/// magnitude of the oscillations of Y. The orders
+/// `n = -3/2, -5/2, ...` use `H_(-m-1/2) = (-1)^m J_(m+1/2)`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::struve_h;
+/// assert!((struve_h(1.0, 0).re - 0.568656627048288).abs() < 1.0e-8);
+/// assert!((struve_h(2.5, 1).re - 0.863154206656535).abs() < 1.0e-8);
+/// assert!((struve_h(1.3, -2.5).re - 0.0906895213536789).abs() < 1.0e-6);
+///
+/// // Large arguments
+/// assert!((struve_h(50.0, 0).re - -0.085337674826119).abs() < 1.0e-13);
+/// assert!((struve_h(60.0, 1).re - 0.728666073805574).abs() < 1.0e-13);
+///
+/// let res = struve_h(Complex::from(1.0, 2.0), 1.5);
+/// assert!((res.re - -0.731709320432245).abs() < 1.0e-8 && (res.im - 0.492282771973983).abs() < 1.0e-8);
+/// ```
+pub fn struve_h<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex>, U: Into<f64> {
+    struve(x.into(), order.into(), -1.0, "bessel::struve_h")
+}
+
+/// # Modified Struve function: L
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// The modified Struve functions relate to H as I relates to J, with the series
+/// `sum (x/2)^(2k+n+1) / (Gamma(k + 3/2) Gamma(k + n + 3/2))`, whose terms all have the same sign for real
+/// positive `x`. The orders `n = -3/2, -5/2, ...` use `L_(-m-1/2) = I_(m+1/2)`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::struve_l;
+/// assert!((struve_l(1.0, 0).re - 0.710243185937891).abs() < 1.0e-8);
+/// assert!((struve_l(3.5, 2).re - 3.2212652849166).abs() < 1.0e-8);
+///
+/// let res = struve_l(Complex::from(-1.0, 0.5), -0.5);
+/// assert!((res.re - 0.36450566627688).abs() < 1.0e-8 && (res.im - 0.885677611461434).abs() < 1.0e-8);
+/// ```
+pub fn struve_l<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex>, U: Into<f64> {
+    struve(x.into(), order.into(), 1.0, "bessel::struve_l")
+}
+
+/// Struve functions, from the power series or, for H at large `|x|`, from its expansion over Y
+///
+/// The alternating series of H cancels for large `|x|`, as any series with complex terms may: the result is the
+/// most accurate of the two methods, and `NaN` when neither reaches `SERIES_ACCURACY`.
+fn struve(x: Complex, n: f64, sign: f64, method: &'static str) -> Complex {
+
+    // At the poles of Gamma(n + 3/2), the functions reduce to Bessel functions
+    if n <= -1.5 && (n + 0.5).fract() == 0.0 {
+        let m: f64 = -n - 0.5;
+        return if sign < 0.0 { (-1.0_f64).powf(m) * jf(x, -n) } else { i(x, -n) };
+    }
+
+    let mut expansion: Option<(Complex, f64)> = None;          // Large argument value, with its error
+    if sign < 0.0 && x.modulus() >= ASYMPTOTIC_LIMIT {
+        let (res, error): (Complex, f64) = struve_expansion(x, n);
+        if x.modulus() >= SERIES_LIMIT || error <= ASYMPTOTIC_ACCURACY * res.modulus() {
+            return accurate(res, error, x);
+        }
+        expansion = Some((res, error));
+    }
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    let x2: Complex = x * 0.5;                                  // Halving x
+    let gammas: f64 = 0.5 * PI.sqrt() * basic::gamma_complex((n + 1.5).into()).re;
+    let mut term: Complex = x2.powf(n + 1.0) * (1.0 / gammas);  // The term at each step
+    let mut res: Complex = Complex::default();                  // The result of the operation
+    let mut total: f64 = 0.0;                                   // Sum of the moduli of the terms
+
+    if x == Complex::new() {
+        return term;
+    }
+
+    let mut k: f64 = 0.0;                                       // Order counter
+    loop {
+        res += term;
+        total += term.modulus();
+        let residual: f64 = term.modulus() / res.modulus();
+        diagnostics::step(method, k as usize, residual, term.modulus());
+
+        // If the changed compared to the final value is small we break
+        if residual < tol || k as usize >= max_iter {
+            diagnostics::finish(method, k as usize + 1, residual, tol);
+            break;
+        }
+
+        k += 1.0;
+        term = term * x2 * x2 * (sign / ((k + 0.5) * (n + k + 0.5)));
+    }
+
+    // The rounding of the largest terms, against the truncation of the expansion
+    match expansion {
+        Some((large, error)) if error < f64::EPSILON * total => accurate(large, error, x),
+        _ => accurate(res, f64::EPSILON * total, x)
+    }
+}
+
+/// The value if its error is within `SERIES_ACCURACY`, `NaN` otherwise
+///
+/// The error is relative to the magnitude of the oscillations of J and Y, `sqrt(2 / (pi |x|)) exp(|Im(x)|)`,
+/// so that the zeros of the functions keep their absolute accuracy.
+fn accurate(res: Complex, error: f64, x: Complex) -> Complex {
+    let envelope: f64 = (2.0 / (PI * x.modulus())).sqrt() * x.im.abs().exp();
+    if error <= SERIES_ACCURACY * res.modulus().max(envelope) {
+        res
+    } else {
+        Complex::from(f64::NAN, f64::NAN)
+    }
+}
+
+/// Expansion of H for large `|x|`, `H_n = Y_n + 1/pi sum Gamma(k + 1/2) (x/2)^(n-2k-1) / Gamma(n + 1/2 - k)`,
+/// summed up to its smallest term, with its truncation error and the error of Y
+fn struve_expansion(x: Complex, n: f64) -> (Complex, f64) {
+
+    let x2: Complex = x * 0.5;                                  // Halving x
+    let q: Complex = 1.0 / (x2 * x2);                           // Ratio of the powers
+    let a: f64 = n + 0.5;                                       // Argument of the first reciprocal gamma
+    let recip: f64 = if a <= 0.0 && a.fract() == 0.0 { 0.0 } else { 1.0 / basic::gamma_complex(a.into()).re };
+
+    let mut term: Complex = PI.sqrt() * recip * x2.powf(n - 1.0);
+    let mut sum: Complex = Complex::new();
+    let mut error: f64 = term.modulus();
+
+    for k in 0..ASYMPTOTIC_TERMS {
+        sum += term;
+        let next: Complex = term * q * ((k as f64 + 0.5) * (n - 0.5 - k as f64));
+
+        // The expansion diverges past its smallest term, and ends for half-integer orders
+        if next.modulus() >= term.modulus() || next.modulus() < f64::EPSILON * sum.modulus() {
+            error = next.modulus().min(term.modulus());
+            break;
+        }
+        term = next;
+    }
+
+    match try_y(x, n) {
+        Ok((y_n, y_error)) => (y_n + sum / PI, y_error + error / PI),
+        Err(_) => (Complex::from(f64::NAN, f64::NAN), f64::INFINITY)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Anger function: J
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// The Anger function `J_n(x) = 1/pi int_0^pi cos(n t - x sin t) dt` generalizes the integral representation
+/// of the Bessel J, with which it coincides for integer orders. The value comes from the power series
+/// `J_n = cos(n pi/2) S_1 + sin(n pi/2) S_2`, with
+/// `S_1 = sum (-1)^k (x/2)^(2k) / (Gamma(k + 1 + n/2) Gamma(k + 1 - n/2))` and
+/// `S_2 = sum (-1)^k (x/2)^(2k+1) / (Gamma(k + 3/2 + n/2) Gamma(k + 3/2 - n/2))`, with the same convergence
+/// criterion as `j`. The alternating series lose accuracy to cancellations for large `|x|`, where
+/// `J_n = J_n + sin(n pi) A_n` takes over, with the expansion `A_n(x) = 1/(pi x) sum d_m / x^m` up to its
+/// smallest terms; as for `struve_h`, `NaN` is returned where neither reaches an accuracy of `1e-8`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::{ anger, j };
+/// assert!((anger(1.0, 0.5).re - 0.855165309679262).abs() < 1.0e-8);
+/// assert!((anger(2.0, -1.5).re - -0.10882159808008).abs() < 1.0e-8);
+/// assert!((anger(3.0, 4.2).re - 0.133335134377223).abs() < 1.0e-8);
+/// assert!((anger(40.0, 0.5).re - 0.101855758495355).abs() < 1.0e-13);
+///
+/// // Integer orders give back the Bessel functions
+/// assert!((anger(1.3, 2).re - j(1.3, 2).re).abs() < 1.0e-8);
+///
+/// let res = anger(Complex::from(1.0, 2.0), 0.3);
+/// assert!((res.re - 2.05574989436689).abs() < 1.0e-8 && (res.im - -0.650443687371505).abs() < 1.0e-8);
+/// ```
+pub fn anger<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex>, U: Into<f64> {
+
+    anger_weber(x.into(), order.into(), false, "bessel::anger")
+}
+
+/// # Weber function: E
+///
+/// `x` is the value to evaluate (complex input accepted), and `order` the real order of the function.
+///
+/// The Weber function `E_n(x) = 1/pi int_0^pi sin(n t - x sin t) dt` is the companion of the Anger function,
+/// computed from the same series as `E_n = sin(n pi/2) S_1 - cos(n pi/2) S_2`, and for large `|x|` from
+/// `E_n = -Y_n - cos(n pi) A_n - A_(-n)`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::weber;
+/// assert!((weber(1.0, 0.5).re - 0.0995075426400432).abs() < 1.0e-8);
+/// assert!((weber(1.3, 2).re - 0.189853163945967).abs() < 1.0e-8);
+/// assert!((weber(0.7, -3).re - -0.231573284302416).abs() < 1.0e-8);
+/// assert!((weber(60.0, 1).re - -0.0920463014379922).abs() < 1.0e-13);
+///
+/// let res = weber(Complex::from(1.0, 2.0), 0.3);
+/// assert!((res.re - -0.642172436848297).abs() < 1.0e-8 && (res.im - -1.74936981090967).abs() < 1.0e-8);
+/// ```
+pub fn weber<T, U>(x: T, order: U) -> Complex
+where T: Into<Complex>, U: Into<f64> {
+
+    anger_weber(x.into(), order.into(), true, "bessel::weber")
+}
+
+/// Anger function, or Weber function if `weber`, from the series `S_1` and `S_2` or, at large `|x|`, from their
+/// expansions over J and Y
+///
+/// As for the Struve functions, the result is the most accurate of the two methods, and `NaN` when neither
+/// reaches `SERIES_ACCURACY`. The reciprocal gammas of the series are carried along by their recurrence,
+/// vanishing at the poles until their argument reaches 1.
+fn anger_weber(x: Complex, n: f64, weber: bool, method: &'static str) -> Complex {
+
+    let mut expansion: Option<(Complex, f64)> = None;          // Large argument value, with its error
+    if x.modulus() >= ASYMPTOTIC_LIMIT {
+        let (res, error): (Complex, f64) = anger_weber_expansion(x, n, weber);
+        if x.modulus() >= SERIES_LIMIT || error <= ASYMPTOTIC_ACCURACY * res.modulus() {
+            return accurate(res, error, x);
+        }
+        expansion = Some((res, error));
+    }
+
+    let config: BesselConfig = BesselConfig::current();         // Series settings
+    let tol: f64 = config.tolerance;                            // Convergence tolerance
+    let max_iter: usize = config.max_terms;                     // Iteration cap
+
+    // Reciprocal gamma, zero at the poles
+    let recip = |a: f64| if a <= 0.0 && a.fract() == 0.0 { 0.0 } else { 1.0 / basic::gamma_complex(a.into()).re };
+    // Next reciprocal gamma 1 / Gamma(a + 1), from 1 / Gamma(a)
+    let next = |r: f64, a: f64| if a == 0.0 { 1.0 } else if r == 0.0 { 0.0 } else { r / a };
+
+    let x2: Complex = x * 0.5;                                  // Halving x
+    let q: Complex = -x2 * x2;                                  // Ratio of the powers
+    let (mut a, mut b): (f64, f64) = (1.0 + 0.5 * n, 1.0 - 0.5 * n);
+    let (mut c, mut d): (f64, f64) = (1.5 + 0.5 * n, 1.5 - 0.5 * n);
+    let (mut ra, mut rb, mut rc, mut rd): (f64, f64, f64, f64) = (recip(a), recip(b), recip(c), recip(d));
+
+    let mut power: Complex = Complex::unity();                  // (-x^2/4)^k
+    let (mut s_1, mut s_2): (Complex, Complex) = (Complex::new(), Complex::new());
+    let mut total: f64 = 0.0;                                   // Sum of the moduli of the terms
+
+    let mut k: usize = 0;
+    loop {
+        let t_1: Complex = power * (ra * rb);
+        let t_2: Complex = power * x2 * (rc * rd);
+        s_1 += t_1;
+        s_2 += t_2;
+        total += t_1.modulus() + t_2.modulus();
+
+        // Past the vanishing terms and the largest ones, the relative size of the last terms
+        let residual: f64 = (t_1.modulus() + t_2.modulus()) / (s_1.modulus() + s_2.modulus());
+        let decreasing: bool = a.min(b) > 0.0 && q.modulus() < (a * b).abs().min((c * d).abs());
+        diagnostics::step(method, k, residual, t_1.modulus() + t_2.modulus());
+
+        if (decreasing && residual < tol) || k >= max_iter {
+            diagnostics::finish(method, k + 1, residual, tol);
+            break;
+        }
+
+        (ra, rb, rc, rd) = (next(ra, a), next(rb, b), next(rc, c), next(rd, d));
+        (a, b, c, d) = (a + 1.0, b + 1.0, c + 1.0, d + 1.0);
+        power *= q;
+        k += 1;
+    }
+
+    let (s, c): (f64, f64) = (0.5 * n * PI).sin_cos();
+    let res: Complex = if weber { s * s_1 - c * s_2 } else { c * s_1 + s * s_2 };
+
+    // The rounding of the largest terms, against the truncation of the expansion
+    match expansion {
+        Some((large, error)) if error < f64::EPSILON * total => accurate(large, error, x),
+        _ => accurate(res, f64::EPSILON * total, x)
+    }
+}
+
+/// Expansions of the Anger and Weber functions for large `|x|`, `J_n = J_n + sin(n pi) A_n` and
+/// `E_n = -Y_n - cos(n pi) A_n - A_(-n)`, with their truncation error and the errors of J and Y
+///
+/// `A_n(x) = 1/(pi x) sum d_m / x^m`, with `d_0 = 1`, `d_1 = -n` and `d_(m+2) = (n^2 - (m+1)^2) d_m`, is summed
+/// up to its smallest pair of terms; the left half-plane follows from `J_n(-x) = J_(-n)(x)` and
+/// `E_n(-x) = -E_(-n)(x)`.
+fn anger_weber_expansion(x: Complex, n: f64, weber: bool) -> (Complex, f64) {
+
+    if x.re < 0.0 {
+        let (res, error): (Complex, f64) = anger_weber_expansion(-x, -n, weber);
+        return if weber { (-res, error) } else { (res, error) };
+    }
+
+    let q: Complex = 1.0 / (x * x);                             // Ratio of the powers
+    let (mut even, mut odd): (Complex, Complex) = (Complex::unity(), n / x);
+    let (mut e, mut o): (Complex, Complex) = (Complex::new(), Complex::new());
+    let mut error: f64 = even.modulus() + odd.modulus();
+
+    for k in 0..ASYMPTOTIC_TERMS {
+        e += even;
+        o += odd;
+
+        let m: f64 = 2.0 * k as f64;
+        let (next_even, next_odd): (Complex, Complex) = (even * q * (n * n - (m + 1.0).powi(2)),
+            odd * q * (n * n - (m + 2.0).powi(2)));
+        let (size, next): (f64, f64) = (even.modulus() + odd.modulus(), next_even.modulus() + next_odd.modulus());
+
+        // The expansion diverges past its smallest terms, and ends for half-integer orders
+        if next >= size || next < f64::EPSILON * (e.modulus() + o.modulus()) {
+            error = next.min(size);
+            break;
+        }
+        (even, odd) = (next_even, next_odd);
+    }
+
+    // A_n and A_(-n), the odd terms changing sign with the order
+    let norm: Complex = 1.0 / (PI * x);
+    let (a_n, a_m): (Complex, Complex) = (norm * (e - o), norm * (e + o));
+    let error: f64 = 2.0 * norm.modulus() * error;
+    let phase: Complex = rotation(1.0, n);
+
+    let bessel: Result<(Complex, f64)> = if weber { try_y(x, n) } else { try_jf(x, n) };
+    match bessel {
+        Ok((b, b_error)) if weber => (-b - phase.re * a_n - a_m, b_error + error),
+        Ok((b, b_error)) => (b + phase.im * a_n, b_error + error),
+        Err(_) => (Complex::from(f64::NAN, f64::NAN), f64::INFINITY)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Bickley–Naylor functions: Ki
+///
+/// `x` is the value to evaluate (`x >= 0`), and `n` the order of the function.
+///
+/// The Bickley–Naylor functions are the repeated integrals of `K_0`, with `Ki_0 = K_0` and
+/// `Ki_n(x) = int_x^inf Ki_(n-1)(t) dt`, and give the escape probabilities of the neutron transport in slabs
+/// and cylinders. From the first order, they are computed from `Ki_n(x) = int_0^inf exp(-x cosh t) / cosh^n t dt`
+/// by the trapezoidal rule, whose step is halved until two of them agree: the integrand being analytic and
+/// even, the rule converges exponentially. Returns `NaN` for a negative `x`.
+///
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::math::bessel::{ bickley, k };
+/// assert_eq!(bickley(1.5, 0), k(1.5, 0).re);
+/// assert!((bickley(0.0, 1) - PI / 2.0).abs() < 1.0e-15);
+/// assert!((bickley(0.0, 3) - PI / 4.0).abs() < 1.0e-15);
+/// assert!((bickley(0.5, 1) - 0.643693805863747546).abs() < 1.0e-15);
+/// assert!((bickley(2.5, 3) - 0.0443071241518836478).abs() < 1.0e-16);
+/// assert!((bickley(50.0, 2) / 3.3451523071605986e-23 - 1.0).abs() < 1.0e-14);
+///
+/// // The derivative gives back the previous order: Ki_n' = -Ki_(n-1)
+/// let h: f64 = 1.0e-4;
+/// let d: f64 = (bickley(1.0 + h, 2) - bickley(1.0 - h, 2)) / (2.0 * h);
+/// assert!((d + bickley(1.0, 1)).abs() < 1.0e-8);
+/// ```
+pub fn bickley<T: Into<f64>>(x: T, n: usize) -> f64 {
+
+    let x: f64 = x.into();
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    } else if n == 0 {
+        return k(x, 0).re;
+    }
+
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(BICKLEY_PRECISION);           // Convergence tolerance
+    let max_iter: usize = prec.iterations(BICKLEY_HALVINGS);    // Iteration cap
+
+    let f = |t: f64| (-x * t.cosh()).exp() / t.cosh().powi(n as i32);
+    // Sum of the integrand at t_0, t_0 + 2h, ..., stopped once it becomes negligible, f decreasing
+    let tail = |t_0: f64, h: f64, sum: f64| {
+        let mut res: f64 = 0.0;
+        let mut t: f64 = t_0;
+        loop {
+            let v: f64 = f(t);
+            res += v;
+            if v <= f64::EPSILON * (sum + res) * 0.125 {
+                return res;
+            }
+            t += 2.0 * h;
+        }
+    };
+
+    if f(0.0) == 0.0 {
+        return 0.0;
+    }
+
+    // Trapezoidal rule of step h, then adding the midpoints at each halving
+    let mut h: f64 = BICKLEY_STEP;
+    let mut res: f64 = h * (0.5 * f(0.0) + tail(h, 0.5 * h, 0.0));
+
+    let mut it: usize = 0;
+    loop {
+        let half: f64 = 0.5 * h;
+        let next: f64 = 0.5 * res + half * tail(half, half, res / h);
+        let residual: f64 = ((next - res) / next).abs();
+        diagnostics::step("bessel::bickley", it, residual, next - res);
+
+        res = next;
+        h = half;
+        if residual < tol || it + 1 >= max_iter {
+            diagnostics::finish("bessel::bickley", it + 1, residual, tol);
+            break;
+        }
+        it += 1;
+    }
+
+    res
+}
+
+/// # Integral of the J Bessel function
+///
+/// `x` is the upper bound (complex input accepted), and `n` the order of the function.
+///
+/// Computes `int_0^x J_n(t) dt` from Neumann's series `2 sum_k J_(n+2k+1)(x)`, whose terms are given by
+/// Miller's recurrence as in `j_seq`, which avoids the quadrature of the oscillating integrand. The
+/// integral tends to 1 as `x` grows along the real axis, and `int_0^x J_1 = 1 - J_0(x)`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::bessel::j_integral;
+/// assert!((j_integral(1.0, 0).re - 0.91973041008976024).abs() < 1.0e-15);
+/// assert!((j_integral(30.0, 0).re - 0.88424908882547488).abs() < 1.0e-14);
+/// assert!((j_integral(200.0, 0).re - 0.94577400053858584).abs() < 1.0e-14);
+/// assert!((j_integral(2.5, 3).re - 0.15626565958896354).abs() < 1.0e-15);
+/// assert!((j_integral(1.0, 10).re / 2.3998693254321489e-11 - 1.0).abs() < 1.0e-14);
+/// assert!((j_integral(5.0, 1).re - 1.1775967713143383).abs() < 1.0e-15);
+///
+/// let res = j_integral(Complex::from(2, 1), 0);
+/// assert!((res - Complex::from(1.7312643230821222, 0.21239252272068955)).modulus() < 1.0e-14);
+/// ```
+pub fn j_integral<T: Into<Complex>>(x: T, n: i32) -> Complex {
+
+    let x: Complex = x.into();
+    let m: usize = n.unsigned_abs() as usize;
+    let res: Complex = neumann_integral(j_seq(x, integral_terms(x, m)), m, 1.0);
+
+    // J_(-n) = (-1)^n J_n
+    if n < 0 && m % 2 == 1 { -res } else { res }
+}
+
+/// # Integral of the I modified Bessel function
+///
+/// `x` is the upper bound (complex input accepted), and `n` the order of the function.
+///
+/// Computes `int_0^x I_n(t) dt` from the alternating series `2 sum_k (-1)^k I_(n+2k+1)(x)`, the terms being
+/// given by Miller's recurrence as in `i_seq`. The terms vary slowly for large arguments, and their
+/// alternating sum keeps its precision.
+///
+/// ```
+/// # use scilib::math::bessel::i_integral;
+/// assert!((i_integral(1.0, 0).re - 1.0865210970235898).abs() < 1.0e-15);
+/// assert!((i_integral(5.0, 0).re - 31.848667776169885).abs() < 1.0e-13);
+/// assert!((i_integral(30.0, 0).re / 795538858184.72358 - 1.0).abs() < 1.0e-14);
+/// assert!((i_integral(3.0, -2).re - 1.7457789433028245).abs() < 1.0e-15);
+/// ```
+pub fn i_integral<T: Into<Complex>>(x: T, n: i32) -> Complex {
+    let x: Complex = x.into();
+    let m: usize = n.unsigned_abs() as usize;
+    neumann_integral(i_seq(x, integral_terms(x, m)), m, -1.0)
+}
+
+/// Highest order of the Neumann series of the integrals, past which the terms are negligible
+fn integral_terms(x: Complex, m: usize) -> usize {
+    let size: f64 = x.modulus();
+    m + size as usize + MILLER_START + (10.0 * size.cbrt()) as usize
+}
+
+/// Neumann series `2 sum_k sign^k f_(m+2k+1)` of the integral of `f_m`
+fn neumann_integral(seq: Vec<Complex>, m: usize, sign: f64) -> Complex {
+    let mut weight: f64 = 2.0;
+    seq.into_iter().skip(m + 1).step_by(2).fold(Complex::new(), |acc, v| {
+        let term: Complex = weight * v;
+        weight *= sign;
+        acc + term
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Whether `|z|` is large enough, with respect to the order, for the Hankel expansions
+fn asymptotic(z: Complex, n: f64, limit: f64) -> bool {
+    let m: f64 = z.modulus();
+    m >= limit && m >= ASYMPTOTIC_ORDER * n * n
+}
+
+/// Terms `a_k(n) / z^k` of the Hankel expansions, up to the smallest one
+fn hankel_terms(z: Complex, n: f64) -> Vec<Complex> {
+
+    let mu: f64 = 4.0 * n * n;
+    let inv: Complex = 1.0 / z;
+    let mut terms: Vec<Complex> = vec![Complex::from(1.0, 0.0)];
+
+    for k in 1..ASYMPTOTIC_TERMS {
+        let last: Complex = terms[k - 1];
+        let odd: f64 = (2 * k - 1) as f64;
+        let next: Complex = last * inv * ((mu - odd * odd) / (8.0 * k as f64));
+
+        // The expansion diverges past its smallest term, and ends for half-integer orders
+        if next.modulus() > last.modulus() || next.modulus() < f64::EPSILON * 1.0e-2 {
+            break;
+        }
+        terms.push(next);
+    }
+
+    terms
+}
+
+/// Phase `exp(i m n pi)`, exact for integer orders
+fn rotation(m: f64, n: f64) -> Complex {
+    if n.fract() == 0.0 {
+        Complex::from(if (n as i64) % 2 == 0 { 1.0 } else { -1.0 }, 0.0)
+    } else {
+        Complex::from_polar(m * n * PI, 1.0)
+    }
+}
+
+/// J and Y from their values `half` in the right half-plane, with `z = -z exp(i m pi)` in the left one
+fn continued_jy(z: Complex, n: f64, half: fn(Complex, f64) -> (Complex, Complex)) -> (Complex, Complex) {
+
+    if z.re >= 0.0 {
+        return half(z, n);
+    }
+
+    let m: f64 = if z.im >= 0.0 { 1.0 } else { -1.0 };
+    let (j_w, y_w): (Complex, Complex) = half(-z, n);
+    let y_z: Complex = rotation(-m, n) * y_w + Complex::from(0.0, 2.0 * m * (n * PI).cos()) * j_w;
+    (rotation(m, n) * j_w, y_z)
+}
+
+/// I and K from their values `half` in the right half-plane, with `z = -z exp(i m pi)` in the left one
+fn continued_ik(z: Complex, n: f64, half: fn(Complex, f64) -> (Complex, Complex)) -> (Complex, Complex) {
+
+    if z.re >= 0.0 {
+        return half(z, n);
+    }
+
+    let m: f64 = if z.im >= 0.0 { 1.0 } else { -1.0 };
+    let (i_w, k_w): (Complex, Complex) = half(-z, n);
+    (rotation(m, n) * i_w, rotation(-m, n) * k_w - Complex::from(0.0, PI * m) * i_w)
+}
+
+/// J and Y from the Hankel expansions, for large `|z|`
+fn asymptotic_jy(z: Complex, n: f64) -> (Complex, Complex) {
+    continued_jy(z, n, hankel_jy)
+}
+
+/// Hankel expansions of J and Y, for `Re(z) >= 0`
+fn hankel_jy(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let terms: Vec<Complex> = hankel_terms(z, n);
+    let (mut p, mut q): (Complex, Complex) = (Complex::new(), Complex::new());
+    for (k, &t) in terms.iter().enumerate() {
+        let sign: f64 = if (k / 2) % 2 == 0 { 1.0 } else { -1.0 };
+        if k % 2 == 0 { p += sign * t } else { q += sign * t }
+    }
+
+    let omega: Complex = z - (0.5 * n * PI + 0.25 * PI);
+    let norm: Complex = (2.0 / (PI * z)).powf(0.5);
+    let (c, s): (Complex, Complex) = (omega.cos(), omega.sin());
+
+    (norm * (p * c - q * s), norm * (p * s + q * c))
+}
+
+/// H1 and H2 from the Hankel expansions, for large `|z|`
+///
+/// In the left half-plane, the function decaying there comes from the decaying one at `-z`, and the other
+/// one from both, the decaying one being a small correction.
+fn asymptotic_hankel(z: Complex, n: f64) -> (Complex, Complex) {
+
+    if z.re >= 0.0 {
+        return hankel_h(z, n);
+    }
+
+    let (h1_w, h2_w): (Complex, Complex) = hankel_h(-z, n);
+    let cos: f64 = 2.0 * (n * PI).cos();
+    if z.im >= 0.0 {
+        (-rotation(-1.0, n) * h2_w, rotation(1.0, n) * h1_w + cos * h2_w)
+    } else {
+        (rotation(-1.0, n) * h2_w + cos * h1_w, -rotation(1.0, n) * h1_w)
+    }
+}
+
+/// Hankel expansions of H1 and H2, for `Re(z) >= 0`
+///
+/// Each one is a single exponential times a series, and keeps its relative precision where it decays.
+fn hankel_h(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let terms: Vec<Complex> = hankel_terms(z, n);
+    let (mut first, mut second): (Complex, Complex) = (Complex::new(), Complex::new());
+    let mut phase: Complex = Complex::from(1.0, 0.0);
+    for &t in &terms {
+        first += phase * t;
+        second += phase.conjugate() * t;
+        phase *= Complex::i();
+    }
+
+    let omega: Complex = z - (0.5 * n * PI + 0.25 * PI);
+    let norm: Complex = (2.0 / (PI * z)).powf(0.5);
+    let i: Complex = Complex::i();
+
+    (norm * (i * omega).exp() * first, norm * (-i * omega).exp() * second)
+}
+
+/// I and K from the Hankel expansions, for large `|z|`
+fn asymptotic_ik(z: Complex, n: f64) -> (Complex, Complex) {
+    continued_ik(z, n, hankel_ik)
+}
+
+/// Hankel expansions of I and K, for `Re(z) >= 0`
+///
+/// I keeps the exponentially small part of the expansion, needed close to the imaginary axis.
+fn hankel_ik(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let terms: Vec<Complex> = hankel_terms(z, n);
+    let (mut growing, mut decaying): (Complex, Complex) = (Complex::new(), Complex::new());
+    for (k, &t) in terms.iter().enumerate() {
+        decaying += t;
+        growing += if k % 2 == 0 { t } else { -t };
+    }
+
+    // Factor of the decaying part of I, averaged on the Stokes line of the real axis
+    let factor: Complex = if z.im > 0.0 {
+        Complex::i() * Complex::from_polar(n * PI, 1.0)
+    } else if z.im < 0.0 {
+        -Complex::i() * Complex::from_polar(-n * PI, 1.0)
+    } else {
+        Complex::from(-(n * PI).sin(), 0.0)
+    };
+
+    let norm: Complex = 1.0 / (2.0 * PI * z).powf(0.5);
+    let (e_p, e_m): (Complex, Complex) = (z.exp(), (-z).exp());
+
+    (norm * (e_p * growing + factor * e_m * decaying), PI * norm * e_m * decaying)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the order is large enough for the uniform expansions
+fn large_order(z: Complex, n: f64) -> bool {
+    n.abs() >= UNIFORM_ORDER && z.modulus() > 0.0
+}
+
+/// J and Y from the uniform expansions, negative orders following from the reflection formulas
+fn large_jy(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let (j_m, y_m): (Complex, Complex) = continued_jy(z, n.abs(), uniform_jy);
+    if n >= 0.0 {
+        return (j_m, y_m);
+    }
+
+    let r: Complex = rotation(1.0, -n);
+    (r.re * j_m - r.im * y_m, r.im * j_m + r.re * y_m)
+}
+
+/// I and K from the uniform expansions, negative orders following from the reflection formulas
+fn large_ik(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let (i_m, k_m): (Complex, Complex) = continued_ik(z, n.abs(), uniform_ik);
+    if n >= 0.0 {
+        return (i_m, k_m);
+    }
+
+    (i_m + 2.0 / PI * rotation(1.0, -n).im * k_m, k_m)
+}
+
+/// Uniform expansions of J and Y, for `Re(z) >= 0` and `n >= 0`
+///
+/// Olver's expansion is used for `|arg(z)| <= pi / 3`, and Debye's expansions of I and K closer to the
+/// imaginary axis, where they hold in turn.
+fn uniform_jy(z: Complex, n: f64) -> (Complex, Complex) {
+
+    if z.im < 0.0 {
+        let (j, y): (Complex, Complex) = uniform_jy(z.conjugate(), n);
+        return (j.conjugate(), y.conjugate());
+    }
+
+    if z.arg() > FRAC_PI_3 {
+        // J(iu) = exp(i n pi / 2) I(u), Y(iu) = exp(i (n + 1) pi / 2) I(u) - 2 / pi exp(-i n pi / 2) K(u)
+        let (i_u, k_u): (Complex, Complex) = uniform_ik(-Complex::i() * z, n);
+        let j: Complex = Complex::from_polar(n * FRAC_PI_2, 1.0) * i_u;
+        let y: Complex = Complex::from_polar((n + 1.0) * FRAC_PI_2, 1.0) * i_u
+            - 2.0 / PI * Complex::from_polar(-n * FRAC_PI_2, 1.0) * k_u;
+        return (j, y);
+    }
+
+    let (j, y, _): (Complex, Complex, Complex) = olver(z, n);
+    if z.im == 0.0 {
+        (j.re.into(), y.re.into())
+    } else {
+        (j, y)
+    }
+}
+
+/// Uniform expansions of I and K, for `Re(z) >= 0` and `n >= 0`
+///
+/// Debye's expansions are used for `|arg(z)| <= pi / 6`, and Olver's expansions of J and H2 closer to the
+/// imaginary axis, where the turning points `z = +-i n` lie.
+fn uniform_ik(z: Complex, n: f64) -> (Complex, Complex) {
+
+    if z.im < 0.0 {
+        let (i, k): (Complex, Complex) = uniform_ik(z.conjugate(), n);
+        return (i.conjugate(), k.conjugate());
+    }
+
+    if z.arg() > 0.5 * FRAC_PI_3 {
+        // I(z) = exp(i n pi / 2) J(u), K(z) = -i pi / 2 exp(-i n pi / 2) H2(u), with u = -iz = conj(w)
+        let w: Complex = Complex::i() * z.conjugate();
+        let (j_w, _, h_w): (Complex, Complex, Complex) = olver(w, n);
+        let i: Complex = Complex::from_polar(n * FRAC_PI_2, 1.0) * j_w.conjugate();
+        let k: Complex = Complex::from(0.0, -FRAC_PI_2) * Complex::from_polar(-n * FRAC_PI_2, 1.0) * h_w.conjugate();
+        return (i, k);
+    }
+
+    let (i, k): (Complex, Complex) = debye_ik(z, n);
+    if z.im == 0.0 {
+        (i.re.into(), k.re.into())
+    } else {
+        (i, k)
+    }
+}
+
+/// J, Y and H1 from Olver's uniform expansions in Airy functions, for `0 <= arg(z) <= pi / 3`
+///
+/// With `z = n s`, `zeta` maps the turning point `s = 1` to the origin, and the coefficients `A_k` and `B_k`
+/// are summed up to `k = 3`, which is accurate to about `1e-13` from `n = 20`.
+fn olver(z: Complex, n: f64) -> (Complex, Complex, Complex) {
+
+    let s: Complex = z / n;
+    let w: Complex = (1.0 - s * s).powf(0.5);
+
+    // zeta^(3/2) = 3/2 (atanh(w) - w), and the ratio zeta / w^2 which stays finite at the turning point
+    let (zeta, c, ratio): (Complex, Complex, Complex) = if w.modulus() < 0.5 {
+        // Series of (atanh(w) - w) / w^3, analytic in w^2 and giving zeta without choosing a branch
+        let w2: Complex = w * w;
+        let mut power: Complex = Complex::from(1.0, 0.0);
+        let mut series: Complex = Complex::new();
+        for k in 1..ASYMPTOTIC_TERMS {
+            let term: Complex = power / (2 * k + 1) as f64;
+            series += term;
+            if term.modulus() < f64::EPSILON * 1.0e-2 * series.modulus() {
+                break;
+            }
+            power *= w2;
+        }
+        let ratio: Complex = (1.5 * series).powf(2.0 / 3.0);
+        (ratio * w2, 1.5 * series * w2 * w, ratio)
+    } else {
+        let c: Complex = 1.5 * (0.5 * ((1.0 + w) / (1.0 - w)).ln() - w);
+
+        // Branch of zeta continuous from the interval 0 < s < 1, where it is positive
+        let mut arg: f64 = c.arg();
+        if s.im > 0.0 && arg > 0.0 {
+            arg -= 2.0 * PI;
+        } else if s.im <= 0.0 && arg < 0.0 {
+            arg += 2.0 * PI;
+        }
+        let zeta: Complex = if s.im == 0.0 {
+            Complex::from(c.modulus().powf(2.0 / 3.0) * if arg == 0.0 { 1.0 } else { -1.0 }, 0.0)
+        } else {
+            Complex::from_polar(2.0 * arg / 3.0, c.modulus().powf(2.0 / 3.0))
+        };
+        (zeta, c, zeta / (w * w))
+    };
+
+    // Sums of the coefficients A_k / n^2k and B_k / n^2k
+    let (mut sum_a, mut sum_b): (Complex, Complex) = (Complex::from(1.0, 0.0), Complex::new());
+    let n2: f64 = n * n;
+    if zeta.modulus() < TURNING_RADIUS {
+        for (k, coefs) in TURNING_POINT.iter().enumerate() {
+            let value: Complex = coefs.iter().rev().fold(Complex::new(), |acc, &a| acc * zeta + a);
+            let scale: f64 = n2.powi((k as i32 + 1) / 2);
+            if k % 2 == 0 { sum_b += value / scale } else { sum_a += value / scale }
+        }
+    } else {
+        // Closed forms from the Debye polynomials, with zeta^(-3/2) = 1 / c and zeta^(-1/2) = zeta / c
+        let (t, inv): (Complex, Complex) = (1.0 / w, 1.0 / c);
+        let mut lambda: Vec<f64> = vec![1.0];
+        for j in 1..2 * UNIFORM_TERMS {
+            let jf: f64 = j as f64;
+            let ratio: f64 = (6.0 * jf - 5.0) * (6.0 * jf - 3.0) * (6.0 * jf - 1.0) / ((2.0 * jf - 1.0) * 144.0 * jf);
+            lambda.push(lambda[j - 1] * ratio);
+        }
+        let mu = |j: usize| if j == 0 { 1.0 } else { -((6 * j + 1) as f64) / (6 * j - 1) as f64 * lambda[j] };
+
+        let mut scale: f64 = 1.0;
+        for k in 0..UNIFORM_TERMS {
+            let (mut a, mut b): (Complex, Complex) = (Complex::new(), Complex::new());
+            let mut power: Complex = Complex::from(1.0, 0.0);
+            for (j, &l) in lambda.iter().enumerate().take(2 * k + 2) {
+                if j <= 2 * k {
+                    a += mu(j) * power * debye(2 * k - j, t);
+                }
+                b += l * power * debye(2 * k + 1 - j, t);
+                power *= inv;
+            }
+            if k > 0 {
+                sum_a += a / scale;
+            }
+            sum_b -= zeta * inv * b / scale;
+            scale *= n2;
+        }
+    }
+
+    let phi: Complex = (4.0 * ratio).powf(0.25);
+    let arg_airy: Complex = n.powf(2.0 / 3.0) * zeta;
+    let (n1, n5): (f64, f64) = (n.powf(-1.0 / 3.0), n.powf(-5.0 / 3.0));
+    let rot: Complex = Complex::from_polar(2.0 * FRAC_PI_3, 1.0);
+
+    let j: Complex = phi * (airy::airy_ai(arg_airy) * sum_a * n1 + airy::airy_ai_prime(arg_airy) * sum_b * n5);
+    let y: Complex = -phi * (airy::airy_bi(arg_airy) * sum_a * n1 + airy::airy_bi_prime(arg_airy) * sum_b * n5);
+    let h: Complex = 2.0 * Complex::from_polar(-FRAC_PI_3, 1.0) * phi
+        * (airy::airy_ai(rot * arg_airy) * sum_a * n1 + rot * airy::airy_ai_prime(rot * arg_airy) * sum_b * n5);
+
+    (j, y, h)
+}
+
+/// I and K from Debye's expansions, for `|arg(z)| <= pi / 6`
+fn debye_ik(z: Complex, n: f64) -> (Complex, Complex) {
+
+    let s: Complex = z / n;
+    let root: Complex = (1.0 + s * s).powf(0.5);
+    let p: Complex = 1.0 / root;
+    let eta: Complex = root + (s / (1.0 + root)).ln();
+
+    let (mut growing, mut decaying): (Complex, Complex) = (Complex::new(), Complex::new());
+    let mut scale: f64 = 1.0;
+    for k in 0..DEBYE_TERMS {
+        let term: Complex = debye(k, p) / scale;
+        growing += term;
+        decaying += if k % 2 == 0 { term } else { -term };
+        if term.modulus() < f64::EPSILON * 1.0e-2 * growing.modulus() {
+            break;
+        }
+        scale *= n;
+    }
+
+    let norm: Complex = 1.0 / ((2.0 * PI * n).sqrt() * root.powf(0.5));
+    (norm * (n * eta).exp() * growing, PI * norm * (-n * eta).exp() * decaying)
+}
+
+/// Debye polynomial `u_k(t)`
+fn debye(k: usize, t: Complex) -> Complex {
+    debye_polynomials()[k].iter().rev().fold(Complex::new(), |acc, &c| acc * t + c)
+}
+
+/// Coefficients of the Debye polynomials, built on first use
+///
+/// `u_(k+1)(t) = t^2 (1 - t^2) u_k'(t) / 2 + int_0^t (1 - 5 s^2) u_k(s) ds / 8`, from `u_0 = 1`.
+fn debye_polynomials() -> &'static [Vec<f64>] {
+    DEBYE.get_or_init(|| {
+        let mut res: Vec<Vec<f64>> = vec![vec![1.0]];
+        for k in 1..DEBYE_TERMS.max(2 * UNIFORM_TERMS) {
+            let mut next: Vec<f64> = vec![0.0; 3 * k + 1];
+            for (p, &c) in res[k - 1].iter().enumerate() {
+                let pf: f64 = p as f64;
+                next[p + 1] += 0.5 * pf * c + c / (8.0 * (pf + 1.0));
+                next[p + 3] -= 0.5 * pf * c + 5.0 * c / (8.0 * (pf + 3.0));
+            }
+            res.push(next);
+        }
+        res
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Y for integer orders, with `Y_(-n) = (-1)^n Y_n`
+fn integer_y(z: Complex, n: f64) -> Complex {
+
+    let m: usize = n.abs() as usize;
+    let sign: f64 = if n < 0.0 && m % 2 == 1 { -1.0 } else { 1.0 };
+
+    if z == Complex::new() {
+        return Complex::from(sign * f64::NEG_INFINITY, 0.0);
+    }
+
+    if z.modulus() <= LOG_SERIES_LIMIT {
+        sign * log_y(z, m)
+    } else {
+        sign * neumann_y(z, m)
+    }
+}
+
+/// Logarithmic series of `Y_m`: `pi Y_m = 2 ln(z/2) J_m - sum_(k<m) (m-k-1)!/k! (z/2)^(2k-m) - S`
+///
+/// `S` being the series of J weighted by `psi(k+1) + psi(m+k+1)`.
+fn log_y(z: Complex, m: usize) -> Complex {
+
+    let h: Complex = z * 0.5;
+    let (j_m, s): (Complex, Complex) = log_series(z, m, -1.0, "bessel::y");
+    let (log, finite): (Complex, Complex) = (2.0 * h.ln() * j_m, finite_sum(h, m, 1.0));
+    report(ROUNDING_ACCURACY * (log.modulus() + finite.modulus() + s.modulus()) / PI, false);
+
+    (log - finite - s) / PI
+}
+
+/// Neumann's series of `Y_0` and `Y_1` over the J given by Miller's recurrence, then the upward recurrence
+///
+/// `Y_0 = 2/pi (ln(z/2) + gamma) J_0 - 4/pi sum (-1)^k J_2k / k`, and `Y_1 = -Y_0'`.
+fn neumann_y(z: Complex, m: usize) -> Complex {
+
+    let js: Vec<Complex> = miller_seq(z, z.modulus() as usize + MILLER_START, false);
+    let (mut s_0, mut s_1): (Complex, Complex) = (Complex::new(), Complex::new());
+    for k in 1..=(js.len() - 2) / 2 {
+        let sign: f64 = if k % 2 == 0 { 1.0 } else { -1.0 };
+        s_0 += sign * js[2 * k] / k as f64;
+        s_1 += sign * (js[2 * k - 1] - js[2 * k + 1]) / k as f64;
+    }
+
+    let log: Complex = (z * 0.5).ln() + constant::EULER_MASCHERONI;
+    let y_0: Complex = 2.0 / PI * (log * js[0] - 2.0 * s_0);
+    let y_1: Complex = 2.0 / PI * (log * js[1] - js[0] / z + s_1);
+
+    if m == 0 { y_0 } else { upward_seq(y_0, y_1, z, m, -1.0)[m] }
+}
+
+/// K for integer orders, with `K_(-n) = K_n`
+fn integer_k(z: Complex, n: f64) -> Complex {
+
+    let m: usize = n.abs() as usize;
+
+    if z == Complex::new() {
+        return Complex::from(f64::INFINITY, 0.0);
+    } else if z.modulus() <= LOG_SERIES_LIMIT {
+        return log_k(z, m);
+    }
+
+    let steed = |w: Complex| {
+        let (k_0, k_1): (Complex, Complex) = steed_k(w, 0.0);
+        if m == 0 { k_0 } else { upward_seq(k_0, k_1, w, m, 1.0)[m] }
+    };
+    if z.re >= 0.0 {
+        return steed(z);
+    }
+
+    // Analytic continuation to the left half-plane, with z = -z exp(i s pi)
+    let s: f64 = if z.im >= 0.0 { 1.0 } else { -1.0 };
+    let i_w: Complex = miller_seq(-z, m, true)[m];
+    rotation(-s, m as f64) * steed(-z) - Complex::from(0.0, PI * s) * i_w
+}
+
+/// K for non-integer orders, with `K_(-n) = K_n`, from `K_mu` and `K_(mu+1)` where `n = m + mu`, `|mu| <= 1/2`
+fn fractional_k(z: Complex, n: f64) -> Complex {
+
+    let n: f64 = n.abs();
+    let m: usize = n.round() as usize;
+    let mu: f64 = n - m as f64;
+
+    if z == Complex::new() {
+        return Complex::from(f64::INFINITY, 0.0);
+    } else if z.re < 0.0 && z.modulus() > LOG_SERIES_LIMIT {
+        // Analytic continuation to the left half-plane, with z = -z exp(i s pi)
+        let s: f64 = if z.im >= 0.0 { 1.0 } else { -1.0 };
+        return rotation(-s, n) * fractional_k(-z, n) - Complex::from(0.0, PI * s) * i(-z, n);
+    }
+
+    let (k_mu, k_next): (Complex, Complex) = if z.modulus() <= LOG_SERIES_LIMIT {
+        temme_k(z, mu)
+    } else {
+        steed_k(z, mu)
+    };
+    if m == 0 {
+        return k_mu;
+    }
+
+    // Upward recurrence K_(v+1) = 2v / z K_v + K_(v-1)
+    let (mut prev, mut curr): (Complex, Complex) = (k_mu, k_next);
+    for k in 1..m {
+        let next: Complex = 2.0 * (mu + k as f64) / z * curr + prev;
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Temme's series of `K_mu` and `K_(mu+1)`, for `|mu| <= 1/2` and small `|z|`
+fn temme_k(z: Complex, mu: f64) -> (Complex, Complex) {
+
+    let tol: f64 = Precision::current().tolerance(INTEGER_PRECISION);   // Convergence tolerance
+    let max_iter: usize = BesselConfig::current().max_terms;            // Iteration cap
+
+    // 1 / gamma(1 + mu) and 1 / gamma(1 - mu), and their half-difference and mean without cancellation
+    let (mut gam_1, mut gam_2): (f64, f64) = (0.0, 0.0);
+    for (k, &c) in RECIPROCAL_GAMMA.iter().enumerate() {
+        if k % 2 == 0 { gam_2 += c * mu.powi(k as i32) } else { gam_1 -= c * mu.powi(k as i32 - 1) }
+    }
+    let (gam_plus, gam_minus): (f64, f64) = (gam_2 - mu * gam_1, gam_2 + mu * gam_1);
+
+    let h: Complex = z * 0.5;
+    let d: Complex = -h.ln();
+    let e: Complex = mu * d;
+    let fact: f64 = if mu == 0.0 { 1.0 } else { PI * mu / (PI * mu).sin() };
+
+    // sinh(e) / e, by its series for small e
+    let sinhc: Complex = if e.modulus() < 0.5 {
+        let mut term: Complex = Complex::unity();
+        let mut res: Complex = term;
+        for j in 1..10 {
+            term = term * e * e / ((2 * j) * (2 * j + 1)) as f64;
+            res += term;
+        }
+        res
+    } else {
+        e.sinh() / e
+    };
+
+    let mut f: Complex = fact * (gam_1 * e.cosh() + gam_2 * sinhc * d);
+    let mut p: Complex = 0.5 * e.exp() / gam_plus;
+    let mut q: Complex = 0.5 * (-e).exp() / gam_minus;
+    let mut c: Complex = Complex::unity();
+    let q_2: Complex = h * h;
+    let (mut sum, mut sum_1): (Complex, Complex) = (f, p);
+    let mut scale: f64 = sum.modulus() + sum_1.modulus();
+
+    let mut k: usize = 1;
+    loop {
+        let kf: f64 = k as f64;
+        f = (kf * f + p + q) / (kf * kf - mu * mu);
+        c = c * q_2 / kf;
+        p /= kf - mu;
+        q /= kf + mu;
+        let del: Complex = c * f;
+        let del_1: Complex = c * (p - kf * f);
+        sum += del;
+        sum_1 += del_1;
+        scale += del.modulus() + del_1.modulus();
+
+        let residual: f64 = (del / sum).modulus().max((del_1 / sum_1).modulus());
+        diagnostics::step("bessel::k", k, residual, del.modulus());
+        if residual < tol || k >= max_iter {
+            diagnostics::finish("bessel::k", k, residual, tol);
+            report(ROUNDING_ACCURACY * scale, residual >= tol);
+            break;
+        }
+        k += 1;
+    }
+
+    (sum, sum_1 / h)
+}
+
+/// Logarithmic series of `K_m`: `K_m = 1/2 sum_(k<m) (m-k-1)!/k! (-1)^k (z/2)^(2k-m) + (-1)^m (S/2 - ln(z/2) I_m)`
+///
+/// `S` being the series of I weighted by `psi(k+1) + psi(m+k+1)`.
+fn log_k(z: Complex, m: usize) -> Complex {
+
+    let h: Complex = z * 0.5;
+    let parity: f64 = if m.is_multiple_of(2) { 1.0 } else { -1.0 };
+    let (i_m, s): (Complex, Complex) = log_series(z, m, 1.0, "bessel::k");
+    let (finite, log): (Complex, Complex) = (0.5 * finite_sum(h, m, -1.0), h.ln() * i_m);
+    report(ROUNDING_ACCURACY * (finite.modulus() + 0.5 * s.modulus() + log.modulus()), false);
+
+    finite + parity * (0.5 * s - log)
+}
+
+/// Series of J (`sign = -1`) or I (`sign = 1`), along with the same series weighted by `psi(k+1) + psi(m+k+1)`
+fn log_series(z: Complex, m: usize, sign: f64, method: &'static str) -> (Complex, Complex) {
+
+    let tol: f64 = Precision::current().tolerance(INTEGER_PRECISION);   // Convergence tolerance
+    let max_iter: usize = BesselConfig::current().max_terms;            // Iteration cap
+
+    let h: Complex = z * 0.5;
+    let q: Complex = sign * h * h;
+    let mut term: Complex = h.powi(m as i32) / tables::factorial(m);
+    let mut psi: f64 = (1..=m).map(|j| 1.0 / j as f64).sum::<f64>() - 2.0 * constant::EULER_MASCHERONI;
+    let (mut res, mut weighted): (Complex, Complex) = (Complex::new(), Complex::new());
+
+    let mut k: usize = 0;
+    loop {
+        res += term;
+        weighted += psi * term;
+        let residual: f64 = term.modulus() * psi.abs().max(1.0) / res.modulus();
+        diagnostics::step(method, k, residual, term.modulus());
+
+        if residual < tol || k >= max_iter {
+            diagnostics::finish(method, k + 1, residual, tol);
+            report(0.0, residual >= tol);
+            break;
+        }
+
+        k += 1;
+        term = term * q / (k * (m + k)) as f64;
+        psi += 1.0 / k as f64 + 1.0 / (m + k) as f64;
+    }
+
+    (res, weighted)
+}
+
+/// Finite part `sum_(k<m) (m-k-1)!/k! (sign z^2/4)^k (z/2)^(-m)` of the logarithmic series, with `h = z/2`
+fn finite_sum(h: Complex, m: usize, sign: f64) -> Complex {
+
+    let q: Complex = sign * h * h;
+    let mut power: Complex = Complex::unity();
+    let mut res: Complex = Complex::new();
+    for k in 0..m {
+        res += tables::factorial(m - k - 1) / tables::factorial(k) * power;
+        power *= q;
+    }
+
+    res / h.powi(m as i32)
+}
+
+/// Steed's continued fraction for `K_mu` and `K_(mu+1)`, for `|mu| <= 1/2` and `Re(z) >= 0`
+fn steed_k(z: Complex, mu: f64) -> (Complex, Complex) {
+
+    let tol: f64 = Precision::current().tolerance(INTEGER_PRECISION);   // Convergence tolerance
+    let max_iter: usize = BesselConfig::current().max_terms;            // Iteration cap
+
+    let a_1: f64 = 0.25 - mu * mu;
+    let (mut a, mut c): (f64, f64) = (-a_1, a_1);
+    let mut b: Complex = 2.0 * (1.0 + z);
+    let mut d: Complex = 1.0 / b;
+    let (mut h, mut del_h): (Complex, Complex) = (d, d);
+    let (mut q_1, mut q_2): (Complex, Complex) = (Complex::new(), Complex::unity());
+    let mut q: Complex = Complex::from(a_1, 0.0);
+    let mut s: Complex = 1.0 + q * del_h;
+
+    let mut k: usize = 2;
+    loop {
+        a -= 2.0 * (k - 1) as f64;
+        c = -a * c / k as f64;
+        let q_next: Complex = (q_1 - b * q_2) / a;
+        q_1 = q_2;
+        q_2 = q_next;
+        q += c * q_next;
+        b += 2.0;
+        d = 1.0 / (b + a * d);
+        del_h = (b * d - 1.0) * del_h;
+        h += del_h;
+        let del_s: Complex = q * del_h;
+        s += del_s;
+
+        let residual: f64 = (del_s / s).modulus();
+        diagnostics::step("bessel::k", k, residual, del_s.modulus());
+        if residual < tol || k >= max_iter {
+            diagnostics::finish("bessel::k", k + 1, residual, tol);
+            report(0.0, residual >= tol);
+            break;
+        }
+        k += 1;
+    }
+
+    let k_mu: Complex = (PI / (2.0 * z)).powf(0.5) * (-z).exp() / s;
+    let k_next: Complex = k_mu * (mu + z + 0.5 - a_1 * h) / z;
+
+    (k_mu, k_next)
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,430 @@
+//!
+//! # Combinatorics
+//!
+//! Lazy iterators over the arrangements of a set of items: permutations, combinations, combinations with
+//! repetitions, and integer partitions. Each iterator yields its elements in lexicographic order (reverse
+//! lexicographic for partitions) and only stores the current state, so large enumerations can be consumed
+//! without being collected.
+//!
+//! The associated counting functions follow the conventions of `factorial` and `binomial` in `math::basic`.
+//! Apart from `partition_count`, they are `const` and can be evaluated at compile time.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::basic;   // Basic functions
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Permutations iterator
+///
+/// Yields all the orderings of the given items, based on their position (repeated items are not merged).
+///
+/// ```
+/// # use scilib::math::combinatorics::Permutations;
+/// let res: Vec<Vec<char>> = Permutations::new(&['a', 'b', 'c']).collect();
+///
+/// assert_eq!(res.len(), 6);
+/// assert_eq!(res[0], vec!['a', 'b', 'c']);
+/// assert_eq!(res[1], vec!['a', 'c', 'b']);
+/// assert_eq!(res[5], vec!['c', 'b', 'a']);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Permutations<T> {
+    /// The items to arrange
+    items: Vec<T>,
+    /// Current arrangement, as indices of the items
+    indices: Vec<usize>,
+    /// Whether the enumeration is over
+    done: bool
+}
+
+/// Implementing required methods
+impl<T: Clone> Permutations<T> {
+    /// # Creates the iterator
+    ///
+    /// `items` are the elements to permute.
+    pub fn new(items: &[T]) -> Self {
+        Self {
+            items: items.to_vec(),
+            indices: (0..items.len()).collect(),
+            done: false
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.done {
+            return None;
+        }
+
+        let res: Vec<T> = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+        self.done = !next_permutation(&mut self.indices);
+
+        Some(res)
+    }
+}
+
+/// Moves the indices to the next lexicographic permutation, returns false when the last one is reached
+fn next_permutation(indices: &mut [usize]) -> bool {
+
+    let n: usize = indices.len();
+    if n < 2 {
+        return false;
+    }
+
+    // Finding the longest non-increasing suffix
+    let mut i: usize = n - 1;
+    while i > 0 && indices[i - 1] >= indices[i] {
+        i -= 1;
+    }
+
+    if i == 0 {
+        return false;
+    }
+
+    // Swapping the pivot with the rightmost element greater than it
+    let mut j: usize = n - 1;
+    while indices[j] <= indices[i - 1] {
+        j -= 1;
+    }
+    indices.swap(i - 1, j);
+    indices[i..].reverse();
+
+    true
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Combinations iterator
+///
+/// Yields all the subsets of `k` elements among the given items, without repetition.
+///
+/// ```
+/// # use scilib::math::combinatorics::Combinations;
+/// let res: Vec<Vec<usize>> = Combinations::new(&[1, 2, 3, 4], 2).collect();
+///
+/// assert_eq!(res, vec![
+///     vec![1, 2], vec![1, 3], vec![1, 4],
+///     vec![2, 3], vec![2, 4],
+///     vec![3, 4]
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Combinations<T> {
+    /// The items to choose from
+    items: Vec<T>,
+    /// Current selection, as increasing indices of the items
+    indices: Vec<usize>,
+    /// Whether the enumeration is over
+    done: bool
+}
+
+/// Implementing required methods
+impl<T: Clone> Combinations<T> {
+    /// # Creates the iterator
+    ///
+    /// `items` are the elements to choose from, and `k` the size of each combination.
+    pub fn new(items: &[T], k: usize) -> Self {
+        Self {
+            items: items.to_vec(),
+            indices: (0..k).collect(),
+            done: k > items.len()
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.done {
+            return None;
+        }
+
+        let res: Vec<T> = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        // Finding the rightmost index that can still be incremented
+        let n: usize = self.items.len();
+        let k: usize = self.indices.len();
+        let mut i: usize = k;
+
+        loop {
+            if i == 0 {
+                self.done = true;
+                break;
+            }
+            i -= 1;
+
+            if self.indices[i] < n - k + i {
+                self.indices[i] += 1;
+                for j in (i + 1)..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+                break;
+            }
+        }
+
+        Some(res)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Multiset combinations iterator
+///
+/// Yields all the multisets of `k` elements drawn from the given items, that is combinations where each
+/// item can be selected more than once.
+///
+/// ```
+/// # use scilib::math::combinatorics::MultisetCombinations;
+/// let res: Vec<Vec<char>> = MultisetCombinations::new(&['a', 'b', 'c'], 2).collect();
+///
+/// assert_eq!(res, vec![
+///     vec!['a', 'a'], vec!['a', 'b'], vec!['a', 'c'],
+///     vec!['b', 'b'], vec!['b', 'c'],
+///     vec!['c', 'c']
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultisetCombinations<T> {
+    /// The items to choose from
+    items: Vec<T>,
+    /// Current selection, as non-decreasing indices of the items
+    indices: Vec<usize>,
+    /// Whether the enumeration is over
+    done: bool
+}
+
+/// Implementing required methods
+impl<T: Clone> MultisetCombinations<T> {
+    /// # Creates the iterator
+    ///
+    /// `items` are the elements to choose from, and `k` the size of each combination.
+    pub fn new(items: &[T], k: usize) -> Self {
+        Self {
+            items: items.to_vec(),
+            indices: vec![0; k],
+            done: items.is_empty() && k > 0
+        }
+    }
+}
+
+impl<T: Clone> Iterator for MultisetCombinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.done {
+            return None;
+        }
+
+        let res: Vec<T> = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        // Finding the rightmost index that can still be incremented
+        let last: usize = self.items.len().saturating_sub(1);
+        match self.indices.iter().rposition(|&i| i < last) {
+            Some(i) => {
+                let val: usize = self.indices[i] + 1;
+                for idx in self.indices[i..].iter_mut() {
+                    *idx = val;
+                }
+            },
+            None => self.done = true
+        }
+
+        Some(res)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Integer partitions iterator
+///
+/// Yields all the ways of writing `n` as a sum of positive integers, each partition being sorted in
+/// decreasing order. Partitions are produced in reverse lexicographic order.
+///
+/// ```
+/// # use scilib::math::combinatorics::Partitions;
+/// let res: Vec<Vec<usize>> = Partitions::new(4).collect();
+///
+/// assert_eq!(res, vec![
+///     vec![4], vec![3, 1], vec![2, 2], vec![2, 1, 1], vec![1, 1, 1, 1]
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Partitions {
+    /// Current partition
+    parts: Vec<usize>,
+    /// Whether the enumeration is over
+    done: bool
+}
+
+/// Implementing required methods
+impl Partitions {
+    /// # Creates the iterator
+    ///
+    /// `n` is the integer to partition. The only partition of 0 is the empty one.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parts: if n == 0 { vec![] } else { vec![n] },
+            done: false
+        }
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.done {
+            return None;
+        }
+
+        let res: Vec<usize> = self.parts.clone();
+
+        // Removing the trailing ones, and counting them as the remainder to redistribute
+        let mut rem: usize = 0;
+        while self.parts.last() == Some(&1) {
+            self.parts.pop();
+            rem += 1;
+        }
+
+        match self.parts.pop() {
+            None => self.done = true,
+            Some(p) => {
+                // Decreasing the last part, and filling with the largest allowed values
+                let val: usize = p - 1;
+                rem += 1;
+                self.parts.push(val);
+                while rem > val {
+                    self.parts.push(val);
+                    rem -= val;
+                }
+                if rem > 0 {
+                    self.parts.push(rem);
+                }
+            }
+        }
+
+        Some(res)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Number of k-permutations of n
+///
+/// Returns `n! / (n - k)!`, the number of ordered selections of `k` elements among `n`.
+///
+/// ```
+/// # use scilib::math::combinatorics::permutation_count;
+/// assert_eq!(permutation_count(5, 2), 20);
+/// assert_eq!(permutation_count(5, 5), 120);
+/// assert_eq!(permutation_count(2, 3), 0);
+/// ```
+pub const fn permutation_count(n: usize, k: usize) -> usize {
+
+    if k > n {
+        return 0;
+    }
+
+    let mut res: usize = 1;
+    let mut val: usize = n - k + 1;
+
+    while val <= n {
+        res *= val;
+        val += 1;
+    }
+
+    res
+}
+
+/// # Number of k-combinations of n
+///
+/// Returns `k` among `n`, this is the same as `basic::binomial`.
+///
+/// ```
+/// # use scilib::math::combinatorics::{ combination_count, Combinations };
+/// assert_eq!(combination_count(6, 3), 20);
+/// assert_eq!(Combinations::new(&[0; 6], 3).count(), 20);
+/// ```
+pub const fn combination_count(n: usize, k: usize) -> usize {
+    basic::binomial(n, k)
+}
+
+/// # Number of multisets of size k from n items
+///
+/// Returns `(n + k - 1)` among `k`.
+///
+/// ```
+/// # use scilib::math::combinatorics::{ multiset_count, MultisetCombinations };
+/// assert_eq!(multiset_count(4, 3), 20);
+/// assert_eq!(MultisetCombinations::new(&[0; 4], 3).count(), 20);
+/// ```
+pub const fn multiset_count(n: usize, k: usize) -> usize {
+
+    if n == 0 {
+        return if k == 0 { 1 } else { 0 };
+    }
+
+    basic::binomial(n + k - 1, k)
+}
+
+/// # Number of partitions of n
+///
+/// Uses Euler's pentagonal number theorem recurrence:
+///
+/// p(n) = sum_k (-1)^(k+1) [p(n - k(3k-1)/2) + p(n - k(3k+1)/2)]
+///
+/// ```
+/// # use scilib::math::combinatorics::{ partition_count, Partitions };
+/// assert_eq!(partition_count(0), 1);
+/// assert_eq!(partition_count(10), 42);
+/// assert_eq!(partition_count(100), 190_569_292);
+/// assert_eq!(Partitions::new(15).count(), partition_count(15));
+/// ```
+pub fn partition_count(n: usize) -> usize {
+
+    let mut p: Vec<usize> = vec![0; n + 1];
+    p[0] = 1;
+
+    for m in 1..=n {
+        // Terms are grouped by sign to avoid negative intermediate values
+        let mut pos: usize = 0;
+        let mut neg: usize = 0;
+        let mut k: usize = 1;
+
+        loop {
+            let g1: usize = k * (3 * k - 1) / 2;
+            if g1 > m {
+                break;
+            }
+
+            let mut term: usize = p[m - g1];
+            let g2: usize = k * (3 * k + 1) / 2;
+            if g2 <= m {
+                term += p[m - g2];
+            }
+
+            if k % 2 == 1 {
+                pos += term;
+            } else {
+                neg += term;
+            }
+            k += 1;
+        }
+
+        p[m] = pos - neg;
+    }
+
+    p[n]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
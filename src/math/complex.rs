@@ -4,6 +4,10 @@
 //! The complex numbers module is secondary to other objectives of the crate. The functionalities will be
 //! added to match the need of the other function, such as spherical harmonics, or Bessel functions.
 //! 
+//! `Complex` works in double precision. `Complex32` keeps single precision data and arithmetic, and converts
+//! exactly to `Complex` for the special functions, which compute in double precision; `scalar::special`
+//! evaluates them on either type, and rounds the result back.
+//! 
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -20,7 +24,11 @@ use std::ops::{     // Implementing basic operations
 };
 
 use std::fmt::{     // Formatter display
+    Alignment,      // Alignment within the width
     Display,        // The display itself
+    Formatter,      // Formatting options
+    LowerExp,       // Scientific notation
+    UpperExp,       // Scientific notation, upper case
     Result as DRes  // The associated result
 };
 
@@ -39,11 +47,54 @@ pub struct Complex {
 
 /// # Display
 /// 
-/// Returns the complex in the for a + bi, whe the sign of b is always showing.
+/// Returns the complex in the form `a +bi`, where the sign of b is always showing. The formatting options
+/// apply to both parts: the precision sets their number of decimals, the `+` flag shows the sign of the
+/// real part, and the alternate flag `#` removes the space, as in `a+bi`. The width, fill and alignment
+/// apply to the whole number.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(3.14159, -2.71828);
+///
+/// assert_eq!(format!("{}", Complex::from(3.14, 2.72)), "3.14 +2.72i");
+/// assert_eq!(format!("{:.2}", c), "3.14 -2.72i");
+/// assert_eq!(format!("{:#.1}", c), "3.1-2.7i");
+/// assert_eq!(format!("{:+.0}", c), "+3 -3i");
+/// assert_eq!(format!("[{:>10.1}]", c), "[ 3.1 -2.7i]");
+/// assert_eq!(format!("[{:*^10}]", Complex::from(1, 2)), "[**1 +2i***]");
+/// ```
 impl Display for Complex {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
-        write!(f, "{}", format!("{} {:+}i", self.re, self.im))?;
-        Ok(())
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Plain)
+    }
+}
+
+/// # Scientific notation
+///
+/// Formats both parts in scientific notation, with the same options as `Display`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(1234.5, -0.00012);
+///
+/// assert_eq!(format!("{:.2e}", c), "1.23e3 -1.20e-4i");
+/// assert_eq!(format!("{:#e}", Complex::from(1500, 0.25)), "1.5e3+2.5e-1i");
+/// ```
+impl LowerExp for Complex {
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Lower)
+    }
+}
+
+/// # Scientific notation, upper case
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// assert_eq!(format!("{:E}", Complex::from(1500, 0.25)), "1.5E3 +2.5E-1i");
+/// ```
+impl UpperExp for Complex {
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Upper)
     }
 }
 
@@ -161,7 +212,8 @@ impl Complex {
 
     /// # Natural logarithm
     /// 
-    /// Computes the `ln` of self.
+    /// Computes the principal `ln` of self, whose imaginary part lies in `(-pi, pi]`, with the branch cut
+    /// along the negative real axis.
     /// 
     /// ```
     /// # use scilib::math::complex::Complex;
@@ -179,6 +231,35 @@ impl Complex {
         }
     }
 
+    /// # Square root
+    ///
+    /// Computes the principal square root of self, with a non-negative real part, and the branch cut along
+    /// the negative real axis: the sign of the imaginary part of the result follows that of self, zero
+    /// included.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let res = Complex::from(3, 2.1).sqrt();
+    /// assert!((res.re - 1.8250981733485065).abs() < 1.0e-15 && (res.im - 0.5753115176667816).abs() < 1.0e-15);
+    ///
+    /// assert_eq!(Complex::from(-4, 0).sqrt(), Complex::from(0, 2));
+    /// assert_eq!(Complex::from(-4, -0.0).sqrt(), Complex::from(0, -2));
+    /// ```
+    pub fn sqrt(&self) -> Self {
+
+        if self.re == 0.0 && self.im == 0.0 {
+            return Self::from(0.0, self.im);
+        }
+
+        // Computing the larger part first avoids the cancellation of re and |z|
+        let t: f64 = ((self.re.abs() + self.re.hypot(self.im)) / 2.0).sqrt();
+        if self.re >= 0.0 {
+            Self::from(t, self.im / (2.0 * t))
+        } else {
+            Self::from(self.im.abs() / (2.0 * t), t.copysign(self.im))
+        }
+    }
+
     /// # Cosine function
     /// 
     /// Computes the cosine value of the given complex number.
@@ -222,7 +303,7 @@ impl Complex {
     /// # Tangent function
     /// 
     /// Computes the tangent value of the given complex number.
-    /// Formula: `tan(x) = sin(x) / cos(x)`.
+    /// Formula: `tan(x) = -i tanh(ix)`, which tends to `±i` without overflowing for large imaginary parts.
     /// 
     /// ```
     /// # use scilib::math::complex::Complex;
@@ -231,9 +312,80 @@ impl Complex {
     /// 
     /// assert!((res.re - -0.0081436522788).abs() < 1.0e-10);
     /// assert!((res.im - 0.97157848523977).abs() < 1.0e-10);
+    ///
+    /// assert!((Complex::from(1, 400).tan() - Complex::i()).modulus() < 1.0e-15);
     /// ```
     pub fn tan(&self) -> Self {
-        self.sin() / self.cos()
+        let t: Self = Self::from(-self.im, self.re).tanh();
+        Self::from(t.im, -t.re)
+    }
+
+    /// # Hyperbolic sine function
+    ///
+    /// Computes the hyperbolic sine value of the given complex number.
+    /// Formula: `sinh(a + ib) = sinh(a)cos(b) + i cosh(a)sin(b)`.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let res = Complex::from(3, 2.1).sinh();
+    ///
+    /// assert!((res.re - -5.057485133471469).abs() < 1.0e-14);
+    /// assert!((res.im - 8.69050013501026).abs() < 1.0e-14);
+    /// ```
+    pub fn sinh(&self) -> Self {
+        Self {
+            re: self.re.sinh() * self.im.cos(),
+            im: self.re.cosh() * self.im.sin()
+        }
+    }
+
+    /// # Hyperbolic cosine function
+    ///
+    /// Computes the hyperbolic cosine value of the given complex number.
+    /// Formula: `cosh(a + ib) = cosh(a)cos(b) + i sinh(a)sin(b)`.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let res = Complex::from(3, 2.1).cosh();
+    ///
+    /// assert!((res.re - -5.082619940996432).abs() < 1.0e-14);
+    /// assert!((res.im - 8.647523471257132).abs() < 1.0e-14);
+    /// ```
+    pub fn cosh(&self) -> Self {
+        Self {
+            re: self.re.cosh() * self.im.cos(),
+            im: self.re.sinh() * self.im.sin()
+        }
+    }
+
+    /// # Hyperbolic tangent function
+    ///
+    /// Computes the hyperbolic tangent value of the given complex number.
+    /// Formula: `tanh(a + ib) = (sinh(2a) + i sin(2b)) / (cosh(2a) + cos(2b))`, replaced by its limit
+    /// `±1` for large real parts, where both terms overflow.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let res = Complex::from(3, 2.1).tanh();
+    ///
+    /// assert!((res.re - 1.0024240584391213).abs() < 1.0e-15);
+    /// assert!((res.im - -0.004331341269009217).abs() < 1.0e-15);
+    ///
+    /// assert_eq!(Complex::from(400, 0.3).tanh().re, 1.0);
+    /// ```
+    pub fn tanh(&self) -> Self {
+
+        // Past |a| = 20, e^(-4|a|) is below the precision, and tanh(a + ib) = ±1 + 4i sin(b)cos(b) e^(-2|a|)
+        if self.re.abs() > 20.0 {
+            let small: f64 = 4.0 * self.im.sin() * self.im.cos() * (-2.0 * self.re.abs()).exp();
+            return Self::from(self.re.signum(), small);
+        }
+
+        let d: f64 = (2.0 * self.re).cosh() + (2.0 * self.im).cos();
+        Self {
+            re: (2.0 * self.re).sinh() / d,
+            im: (2.0 * self.im).sin() / d
+        }
     }
 
     /// # Complex conjugation
@@ -274,7 +426,9 @@ impl Complex {
     /// # Modulus computation
     /// 
     /// The modulus of a complex number is defined as the square root of the
-    /// sum of its squared part.
+    /// sum of its squared part. It is computed without overflow or underflow of
+    /// the squares, so that the amplitudes of the decaying and growing fields keep
+    /// their precision.
     /// 
     /// ```
     /// # use scilib::math::complex::Complex;
@@ -283,9 +437,12 @@ impl Complex {
     /// 
     /// assert_eq!(c1.modulus(), 2.5);
     /// assert!((c2.modulus() - 17.7485210651).abs() < 1.0e-8);
+    ///
+    /// // Parts beyond the square root of the largest f64
+    /// assert!((Complex::from(3.0e200, 4.0e200).modulus() / 5.0e200 - 1.0).abs() < 1.0e-15);
     /// ```
     pub fn modulus(&self) -> f64 {
-        (self.re.powi(2) + self.im.powi(2)).sqrt()
+        self.re.hypot(self.im)
     }
 
     /// # The polar coordinates of the number
@@ -354,6 +511,75 @@ impl Complex {
         let (arg, norm): (f64, f64) = self.polar();
         Self::from_polar(arg * e, norm.powf(e))
     }
+
+    /// # Raising to a complex power
+    ///
+    /// Computes the principal value `z^w = exp(w ln(z))`, with the branch cut of `ln` along the negative
+    /// real axis. For `z = 0`, the result is 0 when `Re(w) > 0`, 1 when `w = 0`, and `NaN` otherwise.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(2.5, -3.6);
+    /// let res = c.powc(Complex::from(1.25, 0.5));
+    /// assert!((res.re - 9.173748640748178).abs() < 1.0e-13 && (res.im - -4.6127675186585115).abs() < 1.0e-13);
+    ///
+    /// // i^i is real
+    /// let res = Complex::i().powc(Complex::i());
+    /// assert!((res.re - 0.2078795763507619).abs() < 1.0e-15 && res.im.abs() < 1.0e-16);
+    ///
+    /// // Real exponents give the same as powf
+    /// assert!((c.powc(1.25) - c.powf(1.25)).modulus() < 1.0e-14);
+    /// ```
+    pub fn powc<T: Into<Self>>(&self, w: T) -> Self {
+
+        let w: Self = w.into();
+        if self.re == 0.0 && self.im == 0.0 {
+            return if w.re > 0.0 {
+                Self::new()
+            } else if w.re == 0.0 && w.im == 0.0 {
+                Self::unity()
+            } else {
+                Self::from(f64::NAN, f64::NAN)
+            };
+        }
+
+        (w * self.ln()).exp()
+    }
+
+    /// # Approximate equality
+    ///
+    /// Checks whether the distance between two numbers is within the absolute tolerance `abs_tol`, or
+    /// within `rel_tol` times the larger of their moduli: `|a - b| <= max(abs_tol, rel_tol max(|a|, |b|))`.
+    /// The absolute tolerance matters near zero, where no relative one can be met. Equal infinite numbers
+    /// compare equal, and `NaN` parts never do.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let a = Complex::from(1.0e6, -2.0e6);
+    /// let b = a + Complex::from(1.0e-4, 1.0e-4);
+    ///
+    /// assert!(a.approx_eq(b, 0.0, 1.0e-10));
+    /// assert!(!a.approx_eq(b, 0.0, 1.0e-12));
+    ///
+    /// // Near zero, only the absolute tolerance helps
+    /// assert!(Complex::from(1.0e-17, 0).approx_eq(Complex::new(), 1.0e-15, 1.0e-12));
+    /// assert!(!Complex::from(1.0e-17, 0).approx_eq(Complex::new(), 0.0, 1.0e-12));
+    /// assert!(!Complex::from(f64::NAN, 0).approx_eq(Complex::from(f64::NAN, 0), 1.0, 1.0));
+    /// assert!(!Complex::from(f64::INFINITY, 0).approx_eq(1.0, 0.0, 0.5));
+    /// ```
+    pub fn approx_eq<T: Into<Self>>(&self, other: T, abs_tol: f64, rel_tol: f64) -> bool {
+
+        let other: Self = other.into();
+        if *self == other {
+            return true;
+        }
+        if ![self.re, self.im, other.re, other.im].iter().all(|v| v.is_finite()) {
+            return false;
+        }
+
+        let scale: f64 = self.re.hypot(self.im).max(other.re.hypot(other.im));
+        (*self - other).modulus() <= abs_tol.max(rel_tol * scale)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -657,3 +883,408 @@ impl Neg for Complex {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Single precision complex structure
+///
+/// Complex number with `f32` parts, for the data kept in single precision, such as on GPUs or embedded
+/// targets. It converts exactly to `Complex`, so that it can be passed to every function of the crate taking
+/// complex arguments; the results are converted back with rounding.
+///
+/// ```
+/// # use scilib::math::complex::{ Complex, Complex32 };
+/// let c = Complex32::from(3.0, 2.1);
+/// let wide: Complex = c.into();
+/// assert_eq!(wide.re, 3.0);
+///
+/// // Arithmetic stays in single precision
+/// let res = c * c.conjugate() + 1.0;
+/// assert_eq!(res, Complex32::from(14.41, 0.0));
+///
+/// let back: Complex32 = Complex::from(0.1, -0.2).into();
+/// assert_eq!(back, Complex32::from(0.1, -0.2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex32 {
+    /// The real part of the number
+    pub re: f32,
+    /// The imaginary part of the number
+    pub im: f32
+}
+
+/// # Display
+///
+/// Same formatting options as `Complex`, the parts being shown in single precision.
+///
+/// ```
+/// # use scilib::math::complex::Complex32;
+/// assert_eq!(format!("{}", Complex32::from(0.1, -0.2)), "0.1 -0.2i");
+/// assert_eq!(format!("{:#.3e}", Complex32::from(0.1, -0.2)), "1.000e-1-2.000e-1i");
+/// ```
+impl Display for Complex32 {
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Plain)
+    }
+}
+
+/// # Scientific notation
+impl LowerExp for Complex32 {
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Lower)
+    }
+}
+
+/// # Scientific notation, upper case
+impl UpperExp for Complex32 {
+    fn fmt(&self, f: &mut Formatter) -> DRes {
+        write_parts(f, self.re, self.im, Notation::Upper)
+    }
+}
+
+/// Implementing required methods
+impl Complex32 {
+    /// # New Complex32
+    ///
+    /// Simply returns 0 +0i.
+    pub const fn new() -> Self {
+        Self {
+            re: 0.0,
+            im: 0.0
+        }
+    }
+
+    /// # Pure complex unity
+    ///
+    /// Simply returns 0 +1i.
+    pub const fn i() -> Self {
+        Self {
+            re: 0.0,
+            im: 1.0
+        }
+    }
+
+    /// # Pure real unity
+    ///
+    /// Simply returns 1 + 0i.
+    pub const fn unity() -> Self {
+        Self {
+            re: 1.0,
+            im: 0.0
+        }
+    }
+
+    /// # From both parts
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let c = Complex32::from(10.0, 1.5);
+    /// assert!(c.re == 10.0 && c.im == 1.5);
+    /// ```
+    pub fn from(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// # From polar coordinates
+    ///
+    /// Creates the complex number based on polar coordinates values, argument first.
+    pub fn from_polar(arg: f32, norm: f32) -> Self {
+        Self::from(arg.cos() * norm, arg.sin() * norm)
+    }
+
+    /// # Exponential
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let res = Complex32::from(2.0, 2.2).exp();
+    /// assert!((res.re - -4.3484677696).abs() < 1.0e-5 && (res.im - 5.97402528360).abs() < 1.0e-5);
+    /// ```
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.im, self.re.exp())
+    }
+
+    /// # Natural logarithm
+    ///
+    /// Principal value, with the branch cut along the negative real axis.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let res = Complex32::from(1.2, 5.35).ln();
+    /// assert!((res.re - 1.70163927393298).abs() < 1.0e-6 && (res.im - 1.35014914413455).abs() < 1.0e-6);
+    /// ```
+    pub fn ln(&self) -> Self {
+        Self::from(self.modulus().ln(), self.arg())
+    }
+
+    /// # Square root
+    ///
+    /// Principal value, with a non-negative real part, following `Complex::sqrt`.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let res = Complex32::from(3.0, 2.1).sqrt();
+    /// assert!((res.re - 1.8250981733485065).abs() < 1.0e-6 && (res.im - 0.5753115176667816).abs() < 1.0e-6);
+    /// assert_eq!(Complex32::from(-4.0, 0.0).sqrt(), Complex32::from(0.0, 2.0));
+    /// ```
+    pub fn sqrt(&self) -> Self {
+
+        if self.re == 0.0 && self.im == 0.0 {
+            return Self::from(0.0, self.im);
+        }
+
+        let t: f32 = ((self.re.abs() + self.re.hypot(self.im)) / 2.0).sqrt();
+        if self.re >= 0.0 {
+            Self::from(t, self.im / (2.0 * t))
+        } else {
+            Self::from(self.im.abs() / (2.0 * t), t.copysign(self.im))
+        }
+    }
+
+    /// # Cosine function
+    ///
+    /// Formula: `cos(a + ib) = cos(a)cosh(b) - i sin(a)sinh(b)`.
+    pub fn cos(&self) -> Self {
+        Self::from(self.re.cos() * self.im.cosh(), -self.re.sin() * self.im.sinh())
+    }
+
+    /// # Sinus function
+    ///
+    /// Formula: `sin(a + ib) = sin(a)cosh(b) + i cos(a)sinh(b)`.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let c = Complex32::from(3.0, 2.1);
+    /// let res = c.sin() * c.sin() + c.cos() * c.cos();
+    /// assert!((res - 1.0).modulus() < 1.0e-5);
+    /// ```
+    pub fn sin(&self) -> Self {
+        Self::from(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+
+    /// # Complex conjugation
+    pub fn conjugate(&self) -> Self {
+        Self::from(self.re, -self.im)
+    }
+
+    /// # Argument for polar coordinates
+    pub fn arg(&self) -> f32 {
+        self.im.atan2(self.re)
+    }
+
+    /// # Modulus computation
+    ///
+    /// Computed without overflow of the squares, which happens early in single precision.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// assert_eq!(Complex32::from(3.0e30, 4.0e30).modulus(), 5.0e30);
+    /// ```
+    pub fn modulus(&self) -> f32 {
+        self.re.hypot(self.im)
+    }
+
+    /// # Raising to an integer power
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex32;
+    /// let c = Complex32::from(3.0, 1.0);
+    /// assert_eq!(c.powi(4), Complex32::from(28.0, 96.0));
+    /// assert!((c.powi(-1) - Complex32::from(0.3, -0.1)).modulus() < 1.0e-7);
+    /// ```
+    pub fn powi(self, e: i32) -> Self {
+
+        // Binary exponentiation, inverting for negative exponents
+        let mut base: Self = if e < 0 { Self::unity() / self } else { self };
+        let mut n: u32 = e.unsigned_abs();
+        let mut res: Self = Self::unity();
+        while n > 0 {
+            if n & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            n >>= 1;
+        }
+
+        res
+    }
+
+    /// # Raising to a real power
+    pub fn powf(&self, e: f32) -> Self {
+        Self::from_polar(self.arg() * e, self.modulus().powf(e))
+    }
+}
+
+/// # Conversion from a scalar
+///
+/// Takes a scalar value and assigns it to the real part, as long as the type allows conversion to `f32`.
+impl<T: Into<f32>> From<T> for Complex32 {
+    fn from(val: T) -> Self {
+        Self {
+            re: val.into(),
+            im: 0.0
+        }
+    }
+}
+
+/// # Widening to double precision
+///
+/// Exact conversion, giving access to the functions of the crate working on `Complex`.
+///
+/// ```
+/// # use scilib::math::complex::{ Complex, Complex32 };
+/// let z: Complex = Complex32::from(1.5, 0.5).into();
+/// let res: Complex32 = z.exp().into();
+///
+/// let exact: Complex = Complex::from(1.5, 0.5).exp();
+/// assert!((res.re - exact.re as f32).abs() < 1.0e-6 && (res.im - exact.im as f32).abs() < 1.0e-6);
+/// ```
+impl From<Complex32> for Complex {
+    fn from(val: Complex32) -> Self {
+        Self {
+            re: val.re as f64,
+            im: val.im as f64
+        }
+    }
+}
+
+/// # Narrowing to single precision
+///
+/// Each part is rounded to the nearest `f32`.
+impl From<Complex> for Complex32 {
+    fn from(val: Complex) -> Self {
+        Self {
+            re: val.re as f32,
+            im: val.im as f32
+        }
+    }
+}
+
+/// # Addition
+impl<T: Into<Self>> Add<T> for Complex32 {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+        Self::from(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+/// # Assigning addition
+impl<T: Into<Self>> AddAssign<T> for Complex32 {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+/// # Subtraction
+impl<T: Into<Self>> Sub<T> for Complex32 {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+        Self::from(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+/// # Assigning subtraction
+impl<T: Into<Self>> SubAssign<T> for Complex32 {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+/// # Multiplication
+impl<T: Into<Self>> Mul<T> for Complex32 {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+        Self::from(self.re * rhs.re - self.im * rhs.im, self.re * rhs.im + self.im * rhs.re)
+    }
+}
+
+/// # Assigning multiplication
+impl<T: Into<Self>> MulAssign<T> for Complex32 {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// # Division
+///
+/// The operands are scaled by the largest part of the divisor, so that its squared modulus does not
+/// overflow or underflow in single precision.
+///
+/// ```
+/// # use scilib::math::complex::Complex32;
+/// let res = Complex32::from(2.1, 3.0) / Complex32::from(5.0, 0.5);
+/// assert!((res.re - 0.47524752475).abs() < 1.0e-7 && (res.im - 0.5524752475).abs() < 1.0e-7);
+///
+/// let big = Complex32::from(3.0e25, 4.0e25);
+/// assert!((big / big - 1.0).modulus() < 1.0e-7);
+/// ```
+impl<T: Into<Self>> Div<T> for Complex32 {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+        let scale: f32 = rhs.re.abs().max(rhs.im.abs());
+        let (a, b): (f32, f32) = (rhs.re / scale, rhs.im / scale);
+        let div: f32 = (a * a + b * b) * scale;
+        Self::from((self.re * a + self.im * b) / div, (self.im * a - self.re * b) / div)
+    }
+}
+
+/// # Assigning division
+impl<T: Into<Self>> DivAssign<T> for Complex32 {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// # Negation
+impl Neg for Complex32 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from(-self.re, -self.im)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Notation of the parts of a formatted complex number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Notation {
+    /// Decimal notation
+    Plain,
+    /// Scientific notation, with `e`
+    Lower,
+    /// Scientific notation, with `E`
+    Upper
+}
+
+/// Writes `re +imi` with the options of the formatter
+fn write_parts<T>(f: &mut Formatter, re: T, im: T, notation: Notation) -> DRes
+where T: Display + LowerExp + UpperExp {
+
+    let part = |v: T, plus: bool| -> String {
+        let s: String = match (notation, f.precision()) {
+            (Notation::Plain, Some(p)) => format!("{:.*}", p, v),
+            (Notation::Plain, None) => format!("{}", v),
+            (Notation::Lower, Some(p)) => format!("{:.*e}", p, v),
+            (Notation::Lower, None) => format!("{:e}", v),
+            (Notation::Upper, Some(p)) => format!("{:.*E}", p, v),
+            (Notation::Upper, None) => format!("{:E}", v)
+        };
+        if plus && !s.starts_with('-') { format!("+{}", s) } else { s }
+    };
+
+    let sep: &str = if f.alternate() { "" } else { " " };
+    let s: String = format!("{}{}{}i", part(re, f.sign_plus()), sep, part(im, true));
+
+    // Padding of the whole number, to the right by default as for the other numbers
+    let pad: usize = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (left, right): (usize, usize) = match f.align() {
+        Some(Alignment::Left) => (0, pad),
+        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (pad, 0)
+    };
+    let fill: String = f.fill().to_string();
+    write!(f, "{}{}{}", fill.repeat(left), s, fill.repeat(right))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,286 @@
+//!
+//! # Numerical continuation
+//!
+//! Tracks a branch of solutions of `f(x, lambda) = 0`, with `x` in `R^n` and `f` valued in `R^n`, as the
+//! parameter `lambda` varies. Solving the system again for each value of the parameter fails at folds
+//! (turning points), where the branch turns back and the Jacobian `df/dx` is singular. Pseudo-arclength
+//! continuation instead parametrizes the branch by its length in the `(x, lambda)` space:
+//!
+//! - the predictor steps along the unit tangent of the branch;
+//! - the corrector solves `f = 0` by Newton's method, constrained to the hyperplane orthogonal to the
+//!   tangent, which stays regular through folds.
+//!
+//! The step is halved when the corrector fails or meets a singular system, as at the branch points where
+//! several branches cross, and grows back after each accepted point. Folds are found from the sign changes
+//! of the `lambda` component of the tangent, and refined by the secant method. The Jacobians are computed
+//! by finite differences.
+//!
+//! ```
+//! # use scilib::math::continuation::continuation;
+//! // The circle x^2 + lambda^2 = 1 folds at lambda = -1 and 1
+//! let branch = continuation(|x: &[f64], l: f64| vec![x[0] * x[0] + l * l - 1.0], &[-0.8], 0.6, 0.1, 100);
+//!
+//! assert!(branch.folds.len() >= 2);
+//! assert!((branch.folds[0].lambda - 1.0).abs() < 1.0e-8 && branch.folds[0].x[0].abs() < 1.0e-6);
+//! assert!((branch.folds[1].lambda - -1.0).abs() < 1.0e-8);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+use super::eigen::solve;            // Bordered systems
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Precision of the corrector
+const CONTINUATION_PRECISION: f64 = 1.0e-12;
+
+/// # Iteration cap of the corrector
+const CORRECTOR_ITERATIONS: usize = 12;
+
+/// # Iteration cap of the fold refinement
+const FOLD_ITERATIONS: usize = 50;
+
+/// # Smallest step, relative to the initial one
+const MIN_STEP: f64 = 1.0e-6;
+
+/// # Largest step, relative to the initial one
+const MAX_STEP: f64 = 4.0;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Fold of a branch
+///
+/// Turning point of the branch, where `lambda` reaches a local extremum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fold {
+    /// Index of the point of the branch preceding the fold
+    pub index: usize,
+    /// Solution at the fold
+    pub x: Vec<f64>,
+    /// Parameter at the fold
+    pub lambda: f64
+}
+
+/// # Branch of solutions
+///
+/// Points found along the branch, in order, with the folds crossed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Branch {
+    /// Solutions along the branch
+    pub x: Vec<Vec<f64>>,
+    /// Parameters along the branch
+    pub lambda: Vec<f64>,
+    /// Folds crossed by the branch
+    pub folds: Vec<Fold>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Pseudo-arclength continuation
+///
+/// Follows the solutions of `f(x, lambda) = 0` from the guess `x0` at `lambda_0`, first refined at fixed
+/// `lambda_0`. The `step` is the initial arclength step, its sign setting whether `lambda` starts
+/// increasing or decreasing. At most `steps` points are computed after the first one; the continuation
+/// also stops when the step cannot be reduced further. The branch is empty if the initial guess does
+/// not converge, or converges to a point where the Jacobian `df/dx` is singular.
+///
+/// ```
+/// # use scilib::math::continuation::continuation;
+/// // Dispersion relation of capillary-gravity waves, omega^2 = k + k^3, tracked in k
+/// let f = |x: &[f64], k: f64| vec![x[0] * x[0] - k - k * k * k];
+/// let branch = continuation(f, &[1.0], 1.0, 0.2, 20);
+///
+/// let last: usize = branch.lambda.len() - 1;
+/// let k: f64 = branch.lambda[last];
+/// assert!(k > 3.0 && branch.folds.is_empty());
+/// assert!((branch.x[last][0] - (k + k * k * k).sqrt()).abs() < 1.0e-10);
+///
+/// // Two unknowns, with a fold at lambda = 1/4
+/// let g = |x: &[f64], l: f64| vec![x[0] - x[1] * x[1] - l, x[1] - x[0]];
+/// let branch = continuation(g, &[0.0, 0.0], 0.0, 0.05, 60);
+/// assert!((branch.folds[0].lambda - 0.25).abs() < 1.0e-8);
+/// assert!((branch.folds[0].x[0] - 0.5).abs() < 1.0e-5);
+/// ```
+pub fn continuation<F>(f: F, x0: &[f64], lambda_0: f64, step: f64, steps: usize) -> Branch
+where F: Fn(&[f64], f64) -> Vec<f64> {
+
+    let n: usize = x0.len();
+    let mut branch: Branch = Branch::default();
+    let eval = |u: &[f64]| f(&u[..n], u[n]);
+
+    // Refining the first point at fixed parameter, along the lambda axis
+    let mut axis: Vec<f64> = vec![0.0; n + 1];
+    axis[n] = 1.0;
+    let start: Vec<f64> = x0.iter().copied().chain([lambda_0]).collect();
+    let mut u: Vec<f64> = match correct(&eval, &start, &axis) {
+        Some(u) => u,
+        None => return branch
+    };
+
+    // Initial tangent, oriented by the sign of the step
+    let mut tangent: Vec<f64> = match tangent_at(&eval, &u, &axis) {
+        Some(t) => t,
+        None => return branch
+    };
+    if step < 0.0 {
+        tangent.iter_mut().for_each(|t| *t = -*t);
+    }
+
+    branch.x.push(u[..n].to_vec());
+    branch.lambda.push(u[n]);
+
+    let base: f64 = step.abs();
+    let mut ds: f64 = base;
+    while branch.lambda.len() <= steps {
+        let pred: Vec<f64> = u.iter().zip(&tangent).map(|(a, t)| a + ds * t).collect();
+
+        // The step is also reduced where the bordered system is singular, as at branch points
+        let next: Option<Vec<f64>> = correct(&eval, &pred, &tangent);
+        let next_tangent: Option<Vec<f64>> = next.as_ref().and_then(|v| tangent_at(&eval, v, &tangent));
+        let (next, next_tangent): (Vec<f64>, Vec<f64>) = match (next, next_tangent) {
+            (Some(v), Some(t)) => (v, t),
+            _ => {
+                ds *= 0.5;
+                if ds < MIN_STEP * base {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        // The lambda component of the tangent changes sign at folds
+        if tangent[n] * next_tangent[n] < 0.0 {
+            let fold: Vec<f64> = refine_fold(&eval, &u, &tangent, ds);
+            branch.folds.push(Fold { index: branch.lambda.len() - 1, x: fold[..n].to_vec(), lambda: fold[n] });
+        }
+
+        branch.x.push(next[..n].to_vec());
+        branch.lambda.push(next[n]);
+        u = next;
+        tangent = next_tangent;
+        ds = (ds * 1.5).min(MAX_STEP * base);
+    }
+
+    branch
+}
+
+/// Newton's method on `f(u) = 0`, `t . (u - pred) = 0`, from the predicted point `pred`
+fn correct<E>(eval: &E, pred: &[f64], t: &[f64]) -> Option<Vec<f64>>
+where E: Fn(&[f64]) -> Vec<f64> {
+
+    let prec: Precision = Precision::current();                     // Precision policy
+    let tol: f64 = prec.tolerance(CONTINUATION_PRECISION);          // Convergence tolerance
+    let max_iter: usize = prec.iterations(CORRECTOR_ITERATIONS);    // Iteration cap
+
+    let mut u: Vec<f64> = pred.to_vec();
+    for iter in 0..max_iter {
+        let res: Vec<f64> = eval(&u);
+        let plane: f64 = t.iter().zip(u.iter().zip(pred)).map(|(t, (a, p))| t * (a - p)).sum();
+        let rhs: Vec<f64> = res.iter().map(|r| -r).chain([-plane]).collect();
+
+        let delta: Vec<f64> = solve(bordered(eval, &u, t, &res, &rhs))?;
+        let size: f64 = delta.iter().map(|d| d * d).sum::<f64>().sqrt();
+        if !size.is_finite() {
+            return None;
+        }
+        u.iter_mut().zip(&delta).for_each(|(a, d)| *a += d);
+
+        let scale: f64 = u.iter().map(|v| v * v).sum::<f64>().sqrt().max(1.0);
+        diagnostics::step("continuation", iter, size / scale, size);
+        if size < tol * scale {
+            diagnostics::finish("continuation", iter + 1, size / scale, tol);
+            return Some(u);
+        }
+    }
+
+    None
+}
+
+/// Unit tangent of the branch at `u`, oriented along the previous tangent `t`, `None` if the bordered system
+/// is singular
+fn tangent_at<E>(eval: &E, u: &[f64], t: &[f64]) -> Option<Vec<f64>>
+where E: Fn(&[f64]) -> Vec<f64> {
+
+    let n: usize = u.len() - 1;
+    let mut rhs: Vec<f64> = vec![0.0; n + 1];
+    rhs[n] = 1.0;
+
+    let res: Vec<f64> = solve(bordered(eval, u, t, &eval(u), &rhs))?;
+    let norm: f64 = res.iter().map(|v| v * v).sum::<f64>().sqrt();
+    Some(res.iter().map(|v| v / norm).collect())
+}
+
+/// Fold between `u` and the next point, where the lambda component of the tangent vanishes
+///
+/// The points are found at arclength `s` from `u` along its tangent `t`, the secant method (falling
+/// back on bisection) being applied to the lambda component of their tangent.
+fn refine_fold<E>(eval: &E, u: &[f64], t: &[f64], ds: f64) -> Vec<f64>
+where E: Fn(&[f64]) -> Vec<f64> {
+
+    let n: usize = u.len() - 1;
+    let tol: f64 = Precision::current().tolerance(CONTINUATION_PRECISION);
+    let at = |s: f64| -> Option<(Vec<f64>, f64)> {
+        let pred: Vec<f64> = u.iter().zip(t).map(|(a, b)| a + s * b).collect();
+        let point: Vec<f64> = correct(eval, &pred, t)?;
+        let lambda_t: f64 = tangent_at(eval, &point, t)?[n];
+        Some((point, lambda_t))
+    };
+
+    let (mut s_0, mut g_0): (f64, f64) = (0.0, t[n]);
+    let (mut s_1, mut g_1): (f64, f64) = (ds, at(ds).map_or(-g_0, |p| p.1));
+    let mut best: Vec<f64> = u.to_vec();
+
+    for _ in 0..FOLD_ITERATIONS {
+        let secant: f64 = s_1 - g_1 * (s_1 - s_0) / (g_1 - g_0);
+        let s: f64 = if secant > s_0.min(s_1) && secant < s_0.max(s_1) { secant } else { 0.5 * (s_0 + s_1) };
+
+        let (point, g): (Vec<f64>, f64) = match at(s) {
+            Some(p) => p,
+            None => break
+        };
+        best = point;
+
+        // Keeping the bracket, the older end being replaced on the same side
+        if g * g_0 > 0.0 {
+            (s_0, g_0) = (s, g);
+        } else {
+            (s_1, g_1) = (s, g);
+        }
+        if g.abs() < tol || (s_1 - s_0).abs() < tol * ds {
+            break;
+        }
+    }
+
+    best
+}
+
+/// Augmented matrix `[df/dx df/dlambda; t^T | rhs]`, the Jacobian being computed by finite differences
+fn bordered<E>(eval: &E, u: &[f64], t: &[f64], res: &[f64], rhs: &[f64]) -> Vec<Vec<f64>>
+where E: Fn(&[f64]) -> Vec<f64> {
+
+    let m: usize = u.len();
+    let mut mat: Vec<Vec<f64>> = vec![vec![0.0; m + 1]; m];
+
+    for j in 0..m {
+        let h: f64 = f64::EPSILON.sqrt() * u[j].abs().max(1.0);
+        let mut shifted: Vec<f64> = u.to_vec();
+        shifted[j] += h;
+        for (row, (a, b)) in mat.iter_mut().zip(eval(&shifted).iter().zip(res)) {
+            row[j] = (a - b) / h;
+        }
+    }
+
+    mat[m - 1][..m].copy_from_slice(t);
+    for (row, r) in mat.iter_mut().zip(rhs) {
+        row[m] = *r;
+    }
+
+    mat
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,335 @@
+//!
+//! # Coulomb wave functions
+//!
+//! The Coulomb wave functions are the solutions of the radial Schrödinger equation in a Coulomb potential:
+//!
+//! `w'' + (1 - 2 eta / rho - L (L + 1) / rho^2) w = 0`
+//!
+//! where `eta` is the Sommerfeld parameter (positive for repulsive potentials) and `rho` the scaled radius.
+//! The regular solution `F_L` vanishes at the origin, the irregular one `G_L` diverges; at large `rho` they
+//! behave as `sin` and `cos` of `rho - eta ln(2 rho) - L pi / 2 + sigma_L`, where `sigma_L` is the Coulomb
+//! phase shift. They are the building blocks of the phase-shift analysis of charged particle scattering.
+//!
+//! The functions are computed with Steed's method: a continued fraction gives `F'/F`, a second complex
+//! continued fraction gives `(G' + iF') / (G + iF)`, and the Wronskian `F'G - FG' = 1` normalizes them.
+//! Below the turning point `eta + sqrt(eta^2 + L (L + 1))`, in the classically forbidden region, the second
+//! fraction does not give `G` reliably: there, `G` is computed at the turning point and integrated inward,
+//! where it grows, and `F` follows from `F'/F` and the Wronskian.
+//!
+//! ```
+//! # use scilib::math::coulomb::coulomb;
+//! let res = coulomb(0, 1.0, 5.0);
+//!
+//! assert!((res.f - 0.6849374120059440).abs() < 1.0e-13);
+//! assert!((res.g - -0.8984143590920205).abs() < 1.0e-13);
+//! // Wronskian
+//! assert!((res.fp * res.g - res.f * res.gp - 1.0).abs() < 1.0e-13);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use super::{                    // Using parts from the crate
+    basic,                      // Basic functions
+    complex::Complex            // Using Complex numbers
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Precision of the continued fractions
+const PRECISION: f64 = 1.0e-15;
+
+/// Iteration cap of the continued fractions
+const MAX_ITERATIONS: usize = 100_000;
+
+/// Precision of the inward integration below the turning point, limited by the rounding of the steps
+const INWARD_PRECISION: f64 = 1.0e-13;
+
+/// Number of halvings of the steps of the inward integration
+const INWARD_HALVINGS: usize = 12;
+
+/// Smallest value used in the modified Lentz method, small enough but with a square above the underflow
+const TINY: f64 = 1.0e-150;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Coulomb wave functions and derivatives
+///
+/// Values at a given order, Sommerfeld parameter and radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Coulomb {
+    /// Regular function F
+    pub f: f64,
+    /// Derivative of F with respect to rho
+    pub fp: f64,
+    /// Irregular function G
+    pub g: f64,
+    /// Derivative of G with respect to rho
+    pub gp: f64
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Coulomb wave functions
+///
+/// `l` is the angular momentum (any real `l > -1`), `eta` the Sommerfeld parameter and `rho > 0` the radius.
+///
+/// Returns `F_L`, `G_L` and their derivatives. The result holds `NaN` values if the continued fractions do
+/// not converge, see `try_coulomb` for a checked version.
+///
+/// ```
+/// # use scilib::math::coulomb::coulomb;
+/// // Attractive potential, higher order
+/// let res = coulomb(3, -2.0, 12.0);
+///
+/// assert!((res.f - -0.7469898262005741).abs() < 1.0e-12);
+/// assert!((res.gp - 0.8367066386443431).abs() < 1.0e-12);
+///
+/// // Without potential, F_0 = sin(rho) and G_0 = cos(rho)
+/// let res = coulomb(0, 0.0, 2.5);
+/// assert!((res.f - 2.5_f64.sin()).abs() < 1.0e-14 && (res.g - 2.5_f64.cos()).abs() < 1.0e-14);
+/// ```
+pub fn coulomb<T, U, V>(l: T, eta: U, rho: V) -> Coulomb
+where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+    try_coulomb(l, eta, rho).unwrap_or(Coulomb { f: f64::NAN, fp: f64::NAN, g: f64::NAN, gp: f64::NAN })
+}
+
+/// # Coulomb wave functions, checked
+///
+/// Same as `coulomb`, but returns a `Domain` error for invalid parameters, and a `Convergence` error when
+/// a continued fraction does not converge.
+///
+/// ```
+/// # use scilib::math::coulomb::try_coulomb;
+/// assert!(try_coulomb(1, 0.5, 3.0).is_ok());
+/// assert!(try_coulomb(1, 0.5, -3.0).is_err());
+///
+/// // Deep in the classically forbidden region, below the turning point at rho = 21.4
+/// let res = try_coulomb(5, 10.0, 0.5).unwrap();
+/// assert!((res.f / 6.463646329624831e-16 - 1.0).abs() < 1.0e-11);
+/// assert!((res.g / 61125703438539.700 - 1.0).abs() < 1.0e-11);
+/// assert!((res.fp * res.g - res.f * res.gp - 1.0).abs() < 1.0e-11);
+/// ```
+pub fn try_coulomb<T, U, V>(l: T, eta: U, rho: V) -> Result<Coulomb>
+where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+
+    let (l, eta, rho): (f64, f64, f64) = (l.into(), eta.into(), rho.into());
+
+    if rho <= 0.0 || l <= -1.0 {
+        return Err(Error::Domain("Coulomb functions require rho > 0 and l > -1"));
+    }
+
+    let prec: Precision = Precision::current();             // Precision policy
+    let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+    let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+    let turn: f64 = turning_point(l, eta);
+    if rho < turn {
+        return inward(l, eta, rho, turn, tol, max_iter);
+    }
+
+    steed(l, eta, rho, tol, max_iter)
+}
+
+/// # Coulomb phase shift
+///
+/// `l` is the angular momentum, `eta` the Sommerfeld parameter.
+///
+/// Returns `sigma_L = arg(gamma(L + 1 + i eta))`, the phase shift of the Coulomb wave functions.
+///
+/// ```
+/// # use scilib::math::coulomb::phase_shift;
+/// assert!((phase_shift(0, 1.0) - -0.3016403204675331).abs() < 1.0e-14);
+/// assert!((phase_shift(2, 1.0) - 0.9474054519307212).abs() < 1.0e-14);
+/// ```
+pub fn phase_shift<T, U>(l: T, eta: U) -> f64
+where T: Into<f64>, U: Into<f64> {
+    basic::ln_gamma_complex(Complex::from(l.into() + 1.0, eta.into())).im
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Classical turning point `eta + sqrt(eta^2 + L (L + 1))`, zero without one
+fn turning_point(l: f64, eta: f64) -> f64 {
+    (eta + (eta * eta + l * (l + 1.0)).max(0.0).sqrt()).max(0.0)
+}
+
+/// Steed's method, normalizing the two continued fractions with the Wronskian
+fn steed(l: f64, eta: f64, rho: f64, tol: f64, max_iter: usize) -> Result<Coulomb> {
+
+    let (f, sign): (f64, f64) = ratio_f(l, eta, rho, tol, max_iter)?;
+    let pq: Complex = ratio_h(l, eta, rho, tol, max_iter)?;
+
+    // Normalization with the Wronskian
+    let gam: f64 = (f - pq.re) / pq.im;
+    let w: f64 = sign / ((f - pq.re) * gam + pq.im).sqrt();
+    if !w.is_finite() || !gam.is_finite() {
+        return Err(Error::Convergence("Coulomb continued fractions gave no finite normalization"));
+    }
+
+    Ok(Coulomb {
+        f: w,
+        fp: f * w,
+        g: gam * w,
+        gp: (pq.re * gam - pq.im) * w
+    })
+}
+
+/// Classically forbidden region: G from the turning point, F from F'/F and the Wronskian
+///
+/// G grows towards the origin, and is integrated inward in `t = ln(rho)`, through `v = rho G' / G` and `ln|G|`:
+/// `v' = v - v^2 + 2 eta rho + L (L + 1) - rho^2` and `ln|G|' = v`, with Runge-Kutta steps halved until two
+/// successive results agree.
+fn inward(l: f64, eta: f64, rho: f64, turn: f64, tol: f64, max_iter: usize) -> Result<Coulomb> {
+
+    let (ratio, _): (f64, f64) = ratio_f(l, eta, rho, tol, max_iter)?;
+    let start: Coulomb = steed(l, eta, turn, tol, max_iter)?;
+
+    let ll: f64 = l * (l + 1.0);
+    let slope = |t: f64, v: f64| -> f64 {
+        let r: f64 = t.exp();
+        v - v * v + 2.0 * eta * r + ll - r * r
+    };
+    let (t0, t1): (f64, f64) = (turn.ln(), rho.ln());
+    let integrate = |steps: usize| -> (f64, f64) {
+        let h: f64 = (t1 - t0) / steps as f64;
+        let (mut v, mut lg): (f64, f64) = (turn * start.gp / start.g, 0.0);
+        for s in 0..steps {
+            let t: f64 = t0 + s as f64 * h;
+            let k1: f64 = slope(t, v);
+            let k2: f64 = slope(t + 0.5 * h, v + 0.5 * h * k1);
+            let k3: f64 = slope(t + 0.5 * h, v + 0.5 * h * k2);
+            let k4: f64 = slope(t + h, v + h * k3);
+            lg += h / 6.0 * (v + 2.0 * (v + 0.5 * h * k1) + 2.0 * (v + 0.5 * h * k2) + v + h * k3);
+            v += h / 6.0 * (k1 + 2.0 * k2 + 2.0 * k3 + k4);
+        }
+        (v, lg)
+    };
+
+    let mut steps: usize = 64;
+    let (mut v, mut lg): (f64, f64) = integrate(steps);
+    let mut converged: bool = false;
+    for it in 0..INWARD_HALVINGS {
+        steps *= 2;
+        let (v2, lg2): (f64, f64) = integrate(steps);
+        // Richardson extrapolation of the fourth order steps
+        let residual: f64 = (lg2 - lg).abs() / 15.0 + (v2 - v).abs() / (15.0 * (1.0 + v2.abs()));
+        v = v2 + (v2 - v) / 15.0;
+        lg = lg2 + (lg2 - lg) / 15.0;
+        diagnostics::step("coulomb::inward", it, residual, residual);
+        if residual < tol.max(INWARD_PRECISION) {
+            diagnostics::finish("coulomb::inward", it + 1, residual, tol.max(INWARD_PRECISION));
+            converged = true;
+            break;
+        }
+    }
+    if !converged || !v.is_finite() {
+        return Err(Error::Convergence("Coulomb inward integration of G did not converge"));
+    }
+
+    let g: f64 = start.g * lg.exp();
+    let gp: f64 = v / rho * g;
+    let f: f64 = 1.0 / (ratio * g - gp);
+
+    Ok(Coulomb { f, fp: ratio * f, g, gp })
+}
+
+/// Continued fraction for F'/F, with the sign of F
+fn ratio_f(l: f64, eta: f64, rho: f64, tol: f64, max_iter: usize) -> Result<(f64, f64)> {
+
+    let xi: f64 = 1.0 / rho;
+    let mut pk: f64 = l + 1.0;
+    let mut f: f64 = pk * xi + eta / pk;
+    if f.abs() < TINY {
+        f = TINY;
+    }
+
+    let mut c: f64 = f;
+    let mut d: f64 = 0.0;
+    let mut sign: f64 = 1.0;
+
+    for k in 0..max_iter {
+        let pk1: f64 = pk + 1.0;
+        let ek: f64 = eta / pk;
+        let rk2: f64 = 1.0 + ek * ek;
+        let tk: f64 = (pk + pk1) * (xi + ek / pk1);
+
+        d = tk - rk2 * d;
+        c = tk - rk2 / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        d = 1.0 / d;
+        if d < 0.0 {
+            sign = -sign;
+        }
+
+        let delta: f64 = c * d;
+        f *= delta;
+        pk = pk1;
+
+        diagnostics::step("coulomb::cf1", k, (delta - 1.0).abs(), delta);
+        if (delta - 1.0).abs() < tol {
+            diagnostics::finish("coulomb::cf1", k + 1, (delta - 1.0).abs(), tol);
+            return Ok((f, sign));
+        }
+    }
+
+    Err(Error::Convergence("Coulomb continued fraction for F'/F did not converge"))
+}
+
+/// Continued fraction for (G' + iF') / (G + iF)
+fn ratio_h(l: f64, eta: f64, rho: f64, tol: f64, max_iter: usize) -> Result<Complex> {
+
+    let i: Complex = Complex::i();
+
+    // Partial numerators (a + k)(c + k), with a = i eta - l and c = i eta + l + 1
+    let a: Complex = Complex::from(-l, eta);
+    let c: Complex = Complex::from(l + 1.0, eta);
+
+    // Modified Lentz method, the fraction starting with a null term
+    let mut res: Complex = Complex::from(TINY, 0.0);
+    let mut cl: Complex = res;
+    let mut dl: Complex = Complex::new();
+
+    for k in 0..max_iter {
+        let an: Complex = (a + k as f64) * (c + k as f64);
+        let bn: Complex = 2.0 * Complex::from(rho - eta, (k + 1) as f64);
+
+        dl = bn + an * dl;
+        if dl.modulus() < TINY {
+            dl = Complex::from(TINY, 0.0);
+        }
+        cl = bn + an / cl;
+        if cl.modulus() < TINY {
+            cl = Complex::from(TINY, 0.0);
+        }
+        dl = 1.0 / dl;
+
+        let delta: Complex = cl * dl;
+        res *= delta;
+
+        let residual: f64 = (delta - 1.0).modulus();
+        diagnostics::step("coulomb::cf2", k, residual, (delta - 1.0).modulus());
+        if residual < tol {
+            diagnostics::finish("coulomb::cf2", k + 1, residual, tol);
+            return Ok(i * (1.0 - eta / rho) + i / rho * res);
+        }
+    }
+
+    Err(Error::Convergence("Coulomb continued fraction for G and F did not converge"))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
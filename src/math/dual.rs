@@ -0,0 +1,262 @@
+//!
+//! # Dual numbers
+//!
+//! Numbers of the form `a + b ε` with `ε² = 0`. Evaluating a function on `x + ε` gives `f(x) + f'(x) ε`,
+//! which provides exact derivatives of any code written for generic scalars (forward mode automatic
+//! differentiation), without finite difference errors.
+//!
+//! ```
+//! # use scilib::math::dual::Dual;
+//! let x = Dual::variable(0.5);
+//! let res = (x * x).exp() / x;      // f(x) = exp(x²) / x
+//!
+//! // f'(x) = exp(x²) (2 - 1 / x²)
+//! assert!((res.du - 0.25_f64.exp() * (2.0 - 4.0)).abs() < 1.0e-14);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{     // Implementing basic operations
+    Add,            // Addition
+    AddAssign,      // Assigning addition
+    Sub,            // Subtraction
+    SubAssign,      // Assigning subtraction
+    Mul,            // Multiplication
+    MulAssign,      // Assigning multiplication
+    Div,            // Division
+    DivAssign,      // Assigning division
+    Neg             // Negation
+};
+
+use std::fmt::{     // Formatter display
+    Display,        // The display itself
+    Result as DRes  // The associated result
+};
+
+use super::scalar::{    // Generic scalars
+    Scalar,             // Ring operations
+    ComplexField,       // Elementary functions
+    RealField           // Ordered fields
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Dual number
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Dual {
+    /// The value
+    pub re: f64,
+    /// The derivative
+    pub du: f64
+}
+
+/// # Display
+impl Display for Dual {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
+        write!(f, "{} {:+}ε", self.re, self.du)
+    }
+}
+
+/// Implementing required methods
+impl Dual {
+    /// # From both parts
+    pub const fn from(re: f64, du: f64) -> Self {
+        Self { re, du }
+    }
+
+    /// # Constant
+    ///
+    /// A value with a null derivative.
+    pub const fn constant(re: f64) -> Self {
+        Self { re, du: 0.0 }
+    }
+
+    /// # Variable
+    ///
+    /// The variable of differentiation, with a unit derivative.
+    pub const fn variable(re: f64) -> Self {
+        Self { re, du: 1.0 }
+    }
+
+    /// Applies the chain rule, given the value and derivative of a function at `re`
+    fn chain(self, value: f64, derivative: f64) -> Self {
+        Self { re: value, du: self.du * derivative }
+    }
+
+    /// # Square root
+    pub fn sqrt(self) -> Self {
+        let s: f64 = self.re.sqrt();
+        self.chain(s, 0.5 / s)
+    }
+
+    /// # Exponential
+    pub fn exp(self) -> Self {
+        let e: f64 = self.re.exp();
+        self.chain(e, e)
+    }
+
+    /// # Natural logarithm
+    pub fn ln(self) -> Self {
+        self.chain(self.re.ln(), 1.0 / self.re)
+    }
+
+    /// # Sine
+    pub fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    /// # Cosine
+    pub fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    /// # Tangent
+    ///
+    /// ```
+    /// # use scilib::math::dual::Dual;
+    /// let res = Dual::variable(0.3).tan();
+    /// assert!((res.du - 1.0 / 0.3_f64.cos().powi(2)).abs() < 1.0e-14);
+    /// ```
+    pub fn tan(self) -> Self {
+        let t: f64 = self.re.tan();
+        self.chain(t, 1.0 + t * t)
+    }
+
+    /// # Integer power
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::constant(1.0);
+        }
+        self.chain(self.re.powi(n), n as f64 * self.re.powi(n - 1))
+    }
+
+    /// # Real power
+    pub fn powf(self, e: f64) -> Self {
+        self.chain(self.re.powf(e), e * self.re.powf(e - 1.0))
+    }
+
+    /// # Absolute value
+    pub fn abs(self) -> Self {
+        if self.re < 0.0 { -self } else { self }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Conversion from a constant
+impl<T: Into<f64>> From<T> for Dual {
+    fn from(value: T) -> Self {
+        Self::constant(value.into())
+    }
+}
+
+/// # Addition
+impl<T: Into<Self>> Add<T> for Dual {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self {
+        let rhs: Self = rhs.into();
+        Self::from(self.re + rhs.re, self.du + rhs.du)
+    }
+}
+
+/// # Assigning addition
+impl<T: Into<Self>> AddAssign<T> for Dual {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+/// # Subtraction
+impl<T: Into<Self>> Sub<T> for Dual {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self {
+        let rhs: Self = rhs.into();
+        Self::from(self.re - rhs.re, self.du - rhs.du)
+    }
+}
+
+/// # Assigning subtraction
+impl<T: Into<Self>> SubAssign<T> for Dual {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+/// # Multiplication
+impl<T: Into<Self>> Mul<T> for Dual {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        let rhs: Self = rhs.into();
+        Self::from(self.re * rhs.re, self.du * rhs.re + self.re * rhs.du)
+    }
+}
+
+/// # Assigning multiplication
+impl<T: Into<Self>> MulAssign<T> for Dual {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// # Division
+impl<T: Into<Self>> Div<T> for Dual {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        let rhs: Self = rhs.into();
+        Self::from(self.re / rhs.re, (self.du * rhs.re - self.re * rhs.du) / (rhs.re * rhs.re))
+    }
+}
+
+/// # Assigning division
+impl<T: Into<Self>> DivAssign<T> for Dual {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// # Negation
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from(-self.re, -self.du)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Dual numbers as scalars
+impl Scalar for Dual {
+    fn zero() -> Self { Self::constant(0.0) }
+    fn one() -> Self { Self::constant(1.0) }
+    fn from_f64(v: f64) -> Self { Self::constant(v) }
+    fn magnitude(self) -> f64 { self.re.abs() }
+    fn powi(self, n: i32) -> Self { Dual::powi(self, n) }
+}
+
+/// Implementing the complex field
+impl ComplexField for Dual {
+    type Real = Self;
+    fn re(self) -> Self { self }
+    fn im(self) -> Self { Self::zero() }
+    fn conj(self) -> Self { self }
+    fn from_real(v: Self) -> Self { v }
+    fn sqrt(self) -> Self { Dual::sqrt(self) }
+    fn exp(self) -> Self { Dual::exp(self) }
+    fn ln(self) -> Self { Dual::ln(self) }
+    fn sin(self) -> Self { Dual::sin(self) }
+    fn cos(self) -> Self { Dual::cos(self) }
+    fn powf(self, e: f64) -> Self { Dual::powf(self, e) }
+}
+
+/// Implementing the real field
+impl RealField for Dual {
+    fn to_f64(self) -> f64 { self.re }
+    fn abs(self) -> Self { Dual::abs(self) }
+    fn atan2(self, x: Self) -> Self {
+        let d: f64 = self.re * self.re + x.re * x.re;
+        Self::from(self.re.atan2(x.re), (x.re * self.du - self.re * x.du) / d)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
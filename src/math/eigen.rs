@@ -0,0 +1,247 @@
+//!
+//! # Symmetric eigenproblems
+//!
+//! Eigenvalues and eigenvectors of real symmetric matrices, by the cyclic Jacobi method, and the tracking
+//! of the eigenvalue branches of a matrix `A(lambda)` depending on a parameter.
+//!
+//! Sorting the eigenvalues at each value of the parameter swaps the branches wherever they cross, as is
+//! common for the modes of a system protected by a symmetry. The tracking instead matches the eigenvectors
+//! of consecutive steps by their overlap, so that each branch follows the same mode across the sweep.
+//!
+//! ```
+//! # use scilib::math::eigen::symmetric_eigen;
+//! let a = vec![vec![2.0, 1.0], vec![1.0, 2.0]];
+//! let (values, vectors) = symmetric_eigen(&a);
+//!
+//! assert!((values[0] - 1.0).abs() < 1.0e-15 && (values[1] - 3.0).abs() < 1.0e-15);
+//! assert!((vectors[1][0] - vectors[1][1]).abs() < 1.0e-15);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Relative size of the off-diagonal part at convergence
+const JACOBI_PRECISION: f64 = 1.0e-15;
+
+/// # Sweep cap of the Jacobi method
+const JACOBI_SWEEPS: usize = 50;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Eigenvalues and eigenvectors of a symmetric matrix
+///
+/// Returns the eigenvalues in ascending order, with the matching unit eigenvectors: `vectors[i]` belongs
+/// to `values[i]`. Only the upper triangle of `a` is read. The cyclic Jacobi method is accurate to the
+/// machine precision, even for the small eigenvalues, and suits matrices up to a few hundred rows.
+///
+/// ```
+/// # use scilib::math::eigen::symmetric_eigen;
+/// let a = vec![
+///     vec![4.0, -1.0, 0.0],
+///     vec![-1.0, 4.0, -1.0],
+///     vec![0.0, -1.0, 4.0]
+/// ];
+/// let (values, vectors) = symmetric_eigen(&a);
+///
+/// let r: f64 = 2.0_f64.sqrt();
+/// assert!((values[0] - (4.0 - r)).abs() < 1.0e-14);
+/// assert!((values[2] - (4.0 + r)).abs() < 1.0e-14);
+///
+/// // A v = lambda v
+/// let v: &Vec<f64> = &vectors[0];
+/// let av: f64 = -v[0] + 4.0 * v[1] - v[2];
+/// assert!((av - values[0] * v[1]).abs() < 1.0e-14);
+/// ```
+pub fn symmetric_eigen(a: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+
+    let n: usize = a.len();
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(JACOBI_PRECISION);            // Convergence tolerance
+    let max_iter: usize = prec.iterations(JACOBI_SWEEPS);       // Iteration cap
+
+    // Symmetrizing from the upper triangle, and starting from the identity for the eigenvectors
+    let mut m: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| a[i.min(j)][i.max(j)]).collect()).collect();
+    let mut v: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    let norm: f64 = m.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+
+    for sweep in 0..max_iter {
+        let off: f64 = (0..n).flat_map(|p| ((p + 1)..n).map(move |q| (p, q)))
+            .map(|(p, q)| 2.0 * m[p][q] * m[p][q]).sum::<f64>().sqrt();
+        let residual: f64 = if norm == 0.0 { 0.0 } else { off / norm };
+        diagnostics::step("eigen::jacobi", sweep, residual, off);
+        if residual < tol || sweep + 1 == max_iter {
+            diagnostics::finish("eigen::jacobi", sweep + 1, residual, tol);
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if m[p][q] == 0.0 {
+                    continue;
+                }
+
+                // Rotation zeroing m[p][q]
+                let theta: f64 = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+                let t: f64 = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c: f64 = 1.0 / t.hypot(1.0);
+                let s: f64 = t * c;
+
+                for row in m.iter_mut().chain(v.iter_mut()) {
+                    let (x, y): (f64, f64) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (top, bottom) = m.split_at_mut(q);
+                for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+                    (*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+                }
+            }
+        }
+    }
+
+    // Sorting the pairs, the eigenvectors being the columns of v
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| m[i][i].total_cmp(&m[j][j]));
+
+    let values: Vec<f64> = order.iter().map(|&i| m[i][i]).collect();
+    let vectors: Vec<Vec<f64>> = order.iter().map(|&i| v.iter().map(|row| row[i]).collect()).collect();
+
+    (values, vectors)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Eigenvalue branches
+///
+/// Eigenvalues and eigenvectors of `A(lambda)` along a sweep of the parameter, branch by branch:
+/// `values[b][s]` is the eigenvalue of branch `b` at the step `s`, and `vectors[b][s]` its eigenvector.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EigenBranches {
+    /// Values of the parameter
+    pub params: Vec<f64>,
+    /// Eigenvalues of each branch
+    pub values: Vec<Vec<f64>>,
+    /// Unit eigenvectors of each branch, with a continuous sign
+    pub vectors: Vec<Vec<Vec<f64>>>
+}
+
+/// # Eigenvalue tracking
+///
+/// Follows the eigenvalues of the symmetric matrices `a(lambda)` over the given `params`. The branches
+/// start in ascending order at the first parameter; at each following step, the eigenpairs are matched to
+/// the branches by decreasing overlap `|v_old . v_new|` of their eigenvectors, and the sign of each
+/// eigenvector is chosen for a positive overlap. The steps should be fine enough for the eigenvectors to
+/// change little from one to the next, except at crossings.
+///
+/// ```
+/// # use scilib::math::eigen::track_eigenvalues;
+/// // Two modes crossing at lambda = 0.5, in a rotated basis, and a third one
+/// let (c, s): (f64, f64) = (0.6, 0.8);
+/// let a = |l: f64| {
+///     let (d0, d1): (f64, f64) = (l, 1.0 - l);
+///     vec![
+///         vec![c * c * d0 + s * s * d1, c * s * (d0 - d1), 0.0],
+///         vec![c * s * (d0 - d1), s * s * d0 + c * c * d1, 0.0],
+///         vec![0.0, 0.0, 2.0]
+///     ]
+/// };
+///
+/// let params: Vec<f64> = (0..=20).map(|i| i as f64 * 0.05).collect();
+/// let branches = track_eigenvalues(a, &params);
+///
+/// // The first branch keeps following the mode of eigenvalue lambda, past the crossing
+/// assert!((branches.values[0][20] - 1.0).abs() < 1.0e-14);
+/// assert!((branches.values[1][20] - 0.0).abs() < 1.0e-14);
+/// assert!((branches.vectors[0][20][0].abs() - c).abs() < 1.0e-14);
+/// assert!((branches.values[2][10] - 2.0).abs() < 1.0e-14);
+/// ```
+pub fn track_eigenvalues<F>(a: F, params: &[f64]) -> EigenBranches
+where F: Fn(f64) -> Vec<Vec<f64>> {
+
+    let mut res: EigenBranches = EigenBranches { params: params.to_vec(), ..Default::default() };
+
+    for (step, &l) in params.iter().enumerate() {
+        let (values, mut vectors): (Vec<f64>, Vec<Vec<f64>>) = symmetric_eigen(&a(l));
+
+        if step == 0 {
+            res.values = values.iter().map(|&v| vec![v]).collect();
+            res.vectors = vectors.into_iter().map(|v| vec![v]).collect();
+            continue;
+        }
+
+        // Overlaps between the last eigenvectors of the branches and the new ones
+        let mut pairs: Vec<(f64, usize, usize)> = Vec::new();
+        for (b, branch) in res.vectors.iter().enumerate() {
+            let last: &Vec<f64> = &branch[step - 1];
+            for (j, v) in vectors.iter().enumerate() {
+                pairs.push((dot(last, v).abs(), b, j));
+            }
+        }
+        pairs.sort_by(|x, y| y.0.total_cmp(&x.0));
+
+        // Greedy matching by decreasing overlap
+        let n: usize = values.len();
+        let (mut taken_b, mut taken_j): (Vec<bool>, Vec<bool>) = (vec![false; n], vec![false; n]);
+        let mut matching: Vec<usize> = vec![0; n];
+        for (_, b, j) in pairs {
+            if !taken_b[b] && !taken_j[j] {
+                (taken_b[b], taken_j[j]) = (true, true);
+                matching[b] = j;
+            }
+        }
+
+        for (b, &j) in matching.iter().enumerate() {
+            if dot(&res.vectors[b][step - 1], &vectors[j]) < 0.0 {
+                vectors[j].iter_mut().for_each(|x| *x = -*x);
+            }
+            res.values[b].push(values[j]);
+            res.vectors[b].push(vectors[j].clone());
+        }
+    }
+
+    res
+}
+
+/// Dot product of two vectors
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Gaussian elimination with partial pivoting of the augmented matrix `mat`, `None` when the system is singular
+/// (a pivot is zero or not finite)
+#[cfg(any(feature = "solvers", feature = "timeseries"))]
+pub(crate) fn solve(mut mat: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let m: usize = mat.len();
+
+    for col in 0..m {
+        let piv: usize = (col..m).max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs())).unwrap_or(col);
+        mat.swap(col, piv);
+        if mat[col][col] == 0.0 || !mat[col][col].is_finite() {
+            return None;
+        }
+
+        for r in (col + 1)..m {
+            let f: f64 = mat[r][col] / mat[col][col];
+            let pivot: Vec<f64> = mat[col].clone();
+            for (v, p) in mat[r].iter_mut().zip(pivot).skip(col) {
+                *v -= f * p;
+            }
+        }
+    }
+
+    let mut res: Vec<f64> = vec![0.0; m];
+    for r in (0..m).rev() {
+        let s: f64 = ((r + 1)..m).fold(mat[r][m], |acc, c| acc - mat[r][c] * res[c]);
+        res[r] = s / mat[r][r];
+    }
+
+    Some(res)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,308 @@
+//!
+//! # Spherical harmonic transforms
+//!
+//! Expansion of a real field sampled on the sphere over the orthonormal spherical harmonics `Y_lm(θ, φ)`,
+//! including the Condon-Shortley phase as in `quantum::spherical_harmonics`:
+//!
+//! `f(θ, φ) = sum_(l <= l_max) sum_(|m| <= l) a_lm Y_lm(θ, φ)`
+//!
+//! For a real field, `a_l(-m) = (-1)^m conj(a_lm)`, so only the coefficients with `m >= 0` are stored. The
+//! samples lie on rings of constant colatitude `θ`, with `n_lon` equally spaced longitudes `φ_j = 2 pi j /
+//! n_lon` each. The transform is a Fourier transform along each ring, followed by a quadrature in `θ`:
+//!
+//! - on a Gauss-Legendre grid, with `n_lat` rings at the roots of `P_n_lat(cos θ)`, the transform of a field
+//!   limited to `l <= n_lat - 1` is exact;
+//! - on an equiangular latitude-longitude grid, with rings at `θ_i = pi (i + 1/2) / n_lat` and Fejér's
+//!   weights, it is exact up to `l <= (n_lat - 1) / 2`.
+//!
+//! In both cases, `n_lon > 2 l_max` is needed to avoid aliasing in longitude.
+//!
+//! ```
+//! # use scilib::math::harmonics::Grid;
+//! let grid = Grid::gauss_legendre(16, 32);
+//!
+//! // Sampling f = sin(θ)^2 cos(2φ) + cos(θ)
+//! let data: Vec<Vec<f64>> = grid.colatitudes().iter().map(|&t| {
+//!     grid.longitudes().iter().map(|&p| t.sin().powi(2) * (2.0 * p).cos() + t.cos()).collect()
+//! }).collect();
+//!
+//! let coefs = grid.forward(&data, 15);
+//! let back = grid.inverse(&coefs);
+//!
+//! assert!((coefs.get(1, 0).re - (4.0 * std::f64::consts::PI / 3.0).sqrt()).abs() < 1.0e-14);
+//! assert!((back[3][5] - data[3][5]).abs() < 1.0e-14);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use super::complex::Complex;    // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Grid of samples on the sphere
+///
+/// Rings of constant colatitude, with their quadrature weights, and the number of longitudes per ring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    /// Colatitudes of the rings, from the north pole
+    colatitudes: Vec<f64>,
+    /// Quadrature weights of the rings, in `cos θ`
+    weights: Vec<f64>,
+    /// Number of longitudes per ring
+    n_lon: usize
+}
+
+/// # Spherical harmonic coefficients
+///
+/// Coefficients `a_lm` of a real field, for `0 <= m <= l <= l_max`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coefficients {
+    /// Maximum degree
+    pub l_max: usize,
+    /// Coefficients, `values[l][m]`
+    pub values: Vec<Vec<Complex>>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementing required methods
+impl Grid {
+
+    /// # Gauss-Legendre grid
+    ///
+    /// `n_lat` rings at the Gauss-Legendre nodes in `cos θ`, with `n_lon` longitudes each.
+    ///
+    /// ```
+    /// # use scilib::math::harmonics::Grid;
+    /// let grid = Grid::gauss_legendre(4, 8);
+    ///
+    /// // The weights integrate over cos θ in [-1, 1]
+    /// assert!((grid.weights().iter().sum::<f64>() - 2.0).abs() < 1.0e-15);
+    /// assert!((grid.colatitudes()[0] - 0.8611363115940526_f64.acos()).abs() < 1.0e-15);
+    /// ```
+    pub fn gauss_legendre(n_lat: usize, n_lon: usize) -> Self {
+
+        let n: usize = n_lat.max(1);
+        let mut colatitudes: Vec<f64> = Vec::with_capacity(n);
+        let mut weights: Vec<f64> = Vec::with_capacity(n);
+
+        for i in 0..n {
+            // Newton's method on P_n, from the Chebyshev estimate of the root
+            let mut x: f64 = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+            let mut dp: f64 = 1.0;
+            for _ in 0..100 {
+                let (mut p0, mut p1): (f64, f64) = (1.0, x);
+                for k in 2..=n {
+                    let p2: f64 = ((2 * k - 1) as f64 * x * p1 - (k - 1) as f64 * p0) / k as f64;
+                    p0 = p1;
+                    p1 = p2;
+                }
+                if n == 1 {
+                    p0 = 1.0;
+                }
+                dp = n as f64 * (x * p1 - p0) / (x * x - 1.0);
+                let step: f64 = p1 / dp;
+                x -= step;
+                if step.abs() < 1.0e-16 {
+                    break;
+                }
+            }
+            colatitudes.push(x.acos());
+            weights.push(2.0 / ((1.0 - x * x) * dp * dp));
+        }
+
+        Self { colatitudes, weights, n_lon: n_lon.max(1) }
+    }
+
+    /// # Equiangular grid
+    ///
+    /// `n_lat` rings at the colatitudes `pi (i + 1/2) / n_lat`, avoiding the poles, with `n_lon` longitudes
+    /// each. The weights are those of Fejér's first rule.
+    ///
+    /// ```
+    /// # use scilib::math::harmonics::Grid;
+    /// let grid = Grid::equiangular(6, 12);
+    /// assert!((grid.colatitudes()[0] - std::f64::consts::PI / 12.0).abs() < 1.0e-15);
+    /// assert!((grid.weights().iter().sum::<f64>() - 2.0).abs() < 1.0e-15);
+    /// ```
+    pub fn equiangular(n_lat: usize, n_lon: usize) -> Self {
+
+        let n: usize = n_lat.max(1);
+        let colatitudes: Vec<f64> = (0..n).map(|i| PI * (i as f64 + 0.5) / n as f64).collect();
+        let weights: Vec<f64> = colatitudes.iter().map(|&t| {
+            let sum: f64 = (1..=(n / 2)).map(|k| (2.0 * k as f64 * t).cos() / (4.0 * (k * k) as f64 - 1.0)).sum();
+            2.0 / n as f64 * (1.0 - 2.0 * sum)
+        }).collect();
+
+        Self { colatitudes, weights, n_lon: n_lon.max(1) }
+    }
+
+    /// # Colatitudes of the rings
+    pub fn colatitudes(&self) -> &[f64] {
+        &self.colatitudes
+    }
+
+    /// # Quadrature weights of the rings
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// # Longitudes of the samples of a ring
+    pub fn longitudes(&self) -> Vec<f64> {
+        (0..self.n_lon).map(|j| 2.0 * PI * j as f64 / self.n_lon as f64).collect()
+    }
+
+    /// # Forward transform
+    ///
+    /// Coefficients up to the degree `l_max` of the field sampled as `data[ring][longitude]` on the grid.
+    ///
+    /// ```
+    /// # use scilib::math::harmonics::Grid;
+    /// let grid = Grid::equiangular(24, 24);
+    ///
+    /// // Field of a dipole along z: cos θ
+    /// let data: Vec<Vec<f64>> = grid.colatitudes().iter().map(|&t| vec![t.cos(); 24]).collect();
+    /// let coefs = grid.forward(&data, 8);
+    ///
+    /// assert!((coefs.get(1, 0).re - (4.0 * std::f64::consts::PI / 3.0).sqrt()).abs() < 1.0e-14);
+    /// assert!(coefs.get(3, 0).modulus() < 1.0e-14 && coefs.get(2, 1).modulus() < 1.0e-14);
+    /// ```
+    pub fn forward(&self, data: &[Vec<f64>], l_max: usize) -> Coefficients {
+
+        let mut values: Vec<Vec<Complex>> = (0..=l_max).map(|l| vec![Complex::new(); l + 1]).collect();
+        let dphi: f64 = 2.0 * PI / self.n_lon as f64;
+
+        for ((&theta, &w), ring) in self.colatitudes.iter().zip(&self.weights).zip(data) {
+            let legendre: Vec<Vec<f64>> = normalized_legendre(l_max, theta.cos());
+
+            for m in 0..=l_max {
+                // Fourier coefficient of the ring
+                let fm: Complex = ring.iter().enumerate().fold(Complex::new(), |acc, (j, &v)| {
+                    acc + v * Complex::from_polar(-(m as f64) * j as f64 * dphi, 1.0)
+                }) * dphi;
+
+                for (l, row) in values.iter_mut().enumerate().skip(m) {
+                    row[m] += w * legendre[l][m] * fm;
+                }
+            }
+        }
+
+        Coefficients { l_max, values }
+    }
+
+    /// # Inverse transform
+    ///
+    /// Values of the field of the `coefs` on the grid, as `data[ring][longitude]`.
+    ///
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// # use scilib::math::harmonics::{ Grid, Coefficients };
+    /// let grid = Grid::gauss_legendre(4, 8);
+    ///
+    /// // Y_00 is constant
+    /// let coefs = Coefficients { l_max: 0, values: vec![vec![Complex::from(1.0, 0.0)]] };
+    /// let data = grid.inverse(&coefs);
+    /// assert!((data[2][3] - 0.5 / std::f64::consts::PI.sqrt()).abs() < 1.0e-15);
+    /// ```
+    pub fn inverse(&self, coefs: &Coefficients) -> Vec<Vec<f64>> {
+
+        let dphi: f64 = 2.0 * PI / self.n_lon as f64;
+
+        self.colatitudes.iter().map(|&theta| {
+            let legendre: Vec<Vec<f64>> = normalized_legendre(coefs.l_max, theta.cos());
+
+            // Fourier coefficients of the ring, the negative orders being the conjugates
+            let fm: Vec<Complex> = (0..=coefs.l_max).map(|m| {
+                let sum: Complex = (m..=coefs.l_max).fold(Complex::new(), |acc, l| acc + legendre[l][m] * coefs.values[l][m]);
+                if m == 0 { sum } else { 2.0 * sum }
+            }).collect();
+
+            (0..self.n_lon).map(|j| {
+                fm.iter().enumerate().map(|(m, &c)| (c * Complex::from_polar(m as f64 * j as f64 * dphi, 1.0)).re).sum()
+            }).collect()
+        }).collect()
+    }
+}
+
+/// Implementing required methods
+impl Coefficients {
+
+    /// # Coefficient of degree `l` and order `m`
+    ///
+    /// Negative orders are obtained from the symmetry of real fields, `a_l(-m) = (-1)^m conj(a_lm)`.
+    /// Returns zero beyond `l_max`.
+    pub fn get(&self, l: usize, m: i32) -> Complex {
+
+        let mp: usize = m.unsigned_abs() as usize;
+        if l > self.l_max || mp > l {
+            return Complex::new();
+        }
+
+        let a: Complex = self.values[l][mp];
+        if m < 0 {
+            if mp.is_multiple_of(2) { a.conjugate() } else { -a.conjugate() }
+        } else {
+            a
+        }
+    }
+
+    /// # Angular power spectrum
+    ///
+    /// `C_l = sum_m |a_lm|^2 / (2l + 1)` for each degree, the mean power of the field per mode.
+    ///
+    /// ```
+    /// # use scilib::math::harmonics::Grid;
+    /// let grid = Grid::gauss_legendre(8, 16);
+    /// let data: Vec<Vec<f64>> = grid.colatitudes().iter().map(|&t| vec![t.cos(); 16]).collect();
+    ///
+    /// let power = grid.forward(&data, 4).power_spectrum();
+    /// assert!((power[1] - 4.0 * std::f64::consts::PI / 9.0).abs() < 1.0e-14);
+    /// assert!(power[0].abs() < 1.0e-28 && power[2].abs() < 1.0e-28);
+    /// ```
+    pub fn power_spectrum(&self) -> Vec<f64> {
+        self.values.iter().enumerate().map(|(l, row)| {
+            let sum: f64 = row.iter().enumerate().map(|(m, a)| {
+                let p: f64 = a.re * a.re + a.im * a.im;
+                if m == 0 { p } else { 2.0 * p }
+            }).sum();
+            sum / (2 * l + 1) as f64
+        }).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Orthonormal associated Legendre functions `sqrt((2l+1)/4pi (l-m)!/(l+m)!) P_l^m(x)`, for `m <= l <= l_max`
+///
+/// Computed with the stable recurrences in `l` at fixed `m`, starting from the sectoral values `l = m`.
+fn normalized_legendre(l_max: usize, x: f64) -> Vec<Vec<f64>> {
+
+    let s: f64 = (1.0 - x * x).max(0.0).sqrt();
+    let mut p: Vec<Vec<f64>> = (0..=l_max).map(|l| vec![0.0; l + 1]).collect();
+
+    let mut sectoral: f64 = 0.5 / PI.sqrt();
+    for m in 0..=l_max {
+        if m > 0 {
+            sectoral *= -((2 * m + 1) as f64 / (2 * m) as f64).sqrt() * s;
+        }
+        p[m][m] = sectoral;
+
+        if m < l_max {
+            p[m + 1][m] = ((2 * m + 3) as f64).sqrt() * x * sectoral;
+        }
+
+        for l in (m + 2)..=l_max {
+            let (lf, mf): (f64, f64) = (l as f64, m as f64);
+            let a: f64 = ((4.0 * lf * lf - 1.0) / (lf * lf - mf * mf)).sqrt();
+            let b: f64 = (((lf - 1.0).powi(2) - mf * mf) / (4.0 * (lf - 1.0).powi(2) - 1.0)).sqrt();
+            p[l][m] = a * (x * p[l - 1][m] - b * p[l - 2][m]);
+        }
+    }
+
+    p
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,320 @@
+//!
+//! # Hermitian matrices
+//!
+//! Operations on complex matrices, stored as rows of `Complex` like the real matrices of the crate, for the
+//! Hermitian (`A = A^H`) and unitary (`A^H A = I`) matrices of quantum mechanics: the conjugate transpose,
+//! checks of these properties up to a tolerance, and the solvers taking advantage of the symmetry.
+//!
+//! The eigenproblem of a Hermitian matrix `A = B + iC` is that of the real symmetric matrix
+//! `[[B, -C], [C, B]]`, whose eigenvalues are those of `A`, each one repeated twice. It is solved by the
+//! Jacobi method of `eigen`, which keeps the full precision on the small eigenvalues.
+//!
+//! ```
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::hermitian::{ hermitian_eigen, is_hermitian };
+//! // Pauli matrix Y
+//! let y = vec![
+//!     vec![Complex::new(), Complex::from(0, -1)],
+//!     vec![Complex::from(0, 1), Complex::new()]
+//! ];
+//! assert!(is_hermitian(&y, 0.0));
+//!
+//! let (values, _) = hermitian_eigen(&y);
+//! assert!((values[0] + 1.0).abs() < 1.0e-15 && (values[1] - 1.0).abs() < 1.0e-15);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::{                // Using parts from the crate
+    complex::Complex,       // Using Complex numbers
+    eigen                   // Real symmetric eigenproblem
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Conjugate transpose
+///
+/// The matrix `A^H`, of elements `conj(a_ji)`, for any rectangular matrix.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::conjugate_transpose;
+/// let a = vec![vec![Complex::from(1, 2), Complex::from(3, -1), Complex::from(0, 5)]];
+/// let h = conjugate_transpose(&a);
+///
+/// assert_eq!(h.len(), 3);
+/// assert_eq!(h[1][0], Complex::from(3, 1));
+/// ```
+pub fn conjugate_transpose(a: &[Vec<Complex>]) -> Vec<Vec<Complex>> {
+    let cols: usize = a.first().map_or(0, |r| r.len());
+    (0..cols).map(|j| a.iter().map(|row| row[j].conjugate()).collect()).collect()
+}
+
+/// # Hermitian check
+///
+/// Whether `a` is square, with `|a_ij - conj(a_ji)| <= tol` for all its elements, which bounds the imaginary
+/// parts of the diagonal by `tol / 2`. The tolerance is absolute, and should follow the size of the elements.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::is_hermitian;
+/// let a = vec![
+///     vec![Complex::from(2, 0), Complex::from(1, -1)],
+///     vec![Complex::from(1, 1.0 + 1.0e-12), Complex::from(3, 0)]
+/// ];
+/// assert!(is_hermitian(&a, 1.0e-10));
+/// assert!(!is_hermitian(&a, 0.0));
+/// ```
+pub fn is_hermitian(a: &[Vec<Complex>], tol: f64) -> bool {
+
+    let n: usize = a.len();
+    if a.iter().any(|r| r.len() != n) {
+        return false;
+    }
+
+    (0..n).all(|i| (i..n).all(|j| (a[i][j] - a[j][i].conjugate()).modulus() <= tol))
+}
+
+/// # Unitarity check
+///
+/// Whether `a` is square, with `|(A^H A)_ij - delta_ij| <= tol` for all the elements of the product.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::is_unitary;
+/// // Hadamard gate with a phase
+/// let s: f64 = 0.5_f64.sqrt();
+/// let u = vec![
+///     vec![Complex::from(s, 0), Complex::from(0, s)],
+///     vec![Complex::from(s, 0), Complex::from(0, -s)]
+/// ];
+/// assert!(is_unitary(&u, 1.0e-15));
+///
+/// let v = vec![vec![Complex::from(1, 0), Complex::from(1, 0)], vec![Complex::new(), Complex::from(1, 0)]];
+/// assert!(!is_unitary(&v, 1.0e-3));
+/// ```
+pub fn is_unitary(a: &[Vec<Complex>], tol: f64) -> bool {
+
+    let n: usize = a.len();
+    if a.iter().any(|r| r.len() != n) {
+        return false;
+    }
+
+    (0..n).all(|i| (0..n).all(|j| {
+        let product: Complex = (0..n).fold(Complex::new(), |acc, k| acc + a[k][i].conjugate() * a[k][j]);
+        (product - if i == j { 1.0 } else { 0.0 }).modulus() <= tol
+    }))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Eigenvalues and eigenvectors of a Hermitian matrix
+///
+/// Returns the real eigenvalues in ascending order, with the matching orthonormal eigenvectors: `vectors[i]`
+/// belongs to `values[i]`, and its largest component is real and positive. Only the upper triangle of `a` is
+/// read.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::hermitian_eigen;
+/// let a = vec![
+///     vec![Complex::from(2, 0), Complex::from(0, 1), Complex::new()],
+///     vec![Complex::from(0, -1), Complex::from(2, 0), Complex::new()],
+///     vec![Complex::new(), Complex::new(), Complex::from(3, 0)]
+/// ];
+/// let (values, vectors) = hermitian_eigen(&a);
+/// assert!((values[0] - 1.0).abs() < 1.0e-14);
+/// assert!((values[1] - 3.0).abs() < 1.0e-14 && (values[2] - 3.0).abs() < 1.0e-14);
+///
+/// // A v = lambda v, for the degenerate pair too
+/// for (l, v) in values.iter().zip(&vectors) {
+///     for (r, row) in a.iter().enumerate() {
+///         let av: Complex = row.iter().zip(v).fold(Complex::new(), |acc, (x, y)| acc + *x * *y);
+///         assert!((av - *l * v[r]).modulus() < 1.0e-14);
+///     }
+/// }
+///
+/// // Orthogonal eigenvectors of the degenerate pair
+/// let overlap: Complex = vectors[1].iter().zip(&vectors[2])
+///     .fold(Complex::new(), |acc, (x, y)| acc + x.conjugate() * *y);
+/// assert!(overlap.modulus() < 1.0e-14);
+/// ```
+pub fn hermitian_eigen(a: &[Vec<Complex>]) -> (Vec<f64>, Vec<Vec<Complex>>) {
+
+    let n: usize = a.len();
+
+    // Real symmetric embedding, from the upper triangle
+    let elem = |i: usize, j: usize| if i <= j { a[i][j] } else { a[j][i].conjugate() };
+    let real: Vec<Vec<f64>> = (0..2 * n).map(|i| (0..2 * n).map(|j| {
+        let z: Complex = elem(i % n, j % n);
+        match (i < n, j < n) {
+            (true, true) | (false, false) => z.re,
+            (true, false) => -z.im,
+            (false, true) => z.im
+        }
+    }).collect()).collect();
+    let (values, vectors): (Vec<f64>, Vec<Vec<f64>>) = eigen::symmetric_eigen(&real);
+
+    // Each eigenvalue appears twice, with the vectors (x, y) and (-y, x) of the same complex vector x + iy
+    // up to a phase: the eigenvectors of each cluster are orthonormalized in the complex sense, keeping half
+    let scale: f64 = values.iter().fold(0.0, |m: f64, v| m.max(v.abs())).max(f64::MIN_POSITIVE);
+    let gap: f64 = f64::EPSILON.sqrt() * scale;
+    let mut res: Vec<(f64, Vec<Complex>)> = Vec::with_capacity(n);
+
+    let mut start: usize = 0;
+    while start < 2 * n {
+        let mut end: usize = start + 1;
+        while end < 2 * n && values[end] - values[end - 1] <= gap {
+            end += 1;
+        }
+
+        let mut candidates: Vec<Vec<Complex>> = vectors[start..end].iter()
+            .map(|v| (0..n).map(|k| Complex::from(v[k], v[n + k])).collect()).collect();
+        while res.len() < end / 2 && !candidates.is_empty() {
+            // Largest remaining candidate, projected out of the others
+            let best: usize = (0..candidates.len())
+                .max_by(|&p, &q| norm(&candidates[p]).total_cmp(&norm(&candidates[q]))).unwrap_or(0);
+            let mut u: Vec<Complex> = candidates.swap_remove(best);
+            let size: f64 = norm(&u);
+            u.iter_mut().for_each(|x| *x /= size);
+
+            for c in candidates.iter_mut() {
+                let p: Complex = inner(&u, c);
+                c.iter_mut().zip(&u).for_each(|(x, y)| *x -= p * *y);
+            }
+
+            // Rayleigh quotient as the eigenvalue, and the phase setting the largest component real
+            let au: Vec<Complex> = (0..n).map(|i| {
+                (0..n).fold(Complex::new(), |acc, j| acc + elem(i, j) * u[j])
+            }).collect();
+            let top: Complex = u.iter().fold(Complex::new(), |m, &x| if x.modulus() > m.modulus() { x } else { m });
+            let phase: Complex = top.conjugate() / top.modulus();
+            res.push((inner(&u, &au).re, u.iter().map(|x| *x * phase).collect()));
+        }
+
+        start = end;
+    }
+
+    res.sort_by(|p, q| p.0.total_cmp(&q.0));
+    res.into_iter().unzip()
+}
+
+/// # Cholesky factorization of a Hermitian matrix
+///
+/// Lower triangular `L` with a real positive diagonal such that `A = L L^H`, or `None` if `a` is not
+/// positive definite. Only the lower triangle of `a` is read.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::{ cholesky, conjugate_transpose };
+/// let a = vec![
+///     vec![Complex::from(4, 0), Complex::from(2, -2)],
+///     vec![Complex::from(2, 2), Complex::from(6, 0)]
+/// ];
+/// let l = cholesky(&a).unwrap();
+/// let lh = conjugate_transpose(&l);
+///
+/// let prod: Complex = l[1][0] * lh[0][1] + l[1][1] * lh[1][1];
+/// assert!((prod - a[1][1]).modulus() < 1.0e-14);
+/// assert_eq!(l[0][0], Complex::from(2, 0));
+///
+/// // Indefinite matrix
+/// let b = vec![vec![Complex::from(1, 0), Complex::from(0, 2)], vec![Complex::from(0, -2), Complex::from(1, 0)]];
+/// assert!(cholesky(&b).is_none());
+/// ```
+pub fn cholesky(a: &[Vec<Complex>]) -> Option<Vec<Vec<Complex>>> {
+
+    let n: usize = a.len();
+    let mut l: Vec<Vec<Complex>> = vec![vec![Complex::new(); n]; n];
+
+    for c in 0..n {
+        let diag: f64 = a[c][c].re - l[c][..c].iter().map(|x| x.re * x.re + x.im * x.im).sum::<f64>();
+        if diag <= 0.0 || !diag.is_finite() {
+            return None;
+        }
+        let d: f64 = diag.sqrt();
+        l[c][c] = Complex::from(d, 0);
+
+        for r in (c + 1)..n {
+            let s: Complex = (0..c).fold(a[r][c], |acc, k| acc - l[r][k] * l[c][k].conjugate());
+            l[r][c] = s / d;
+        }
+    }
+
+    Some(l)
+}
+
+/// # Hermitian linear system
+///
+/// Solves `A x = b` for a Hermitian `a`: by the Cholesky factorization when `a` is positive definite, and
+/// through the eigendecomposition otherwise. Returns `None` when `a` is singular to the machine precision.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::hermitian::hermitian_solve;
+/// // Positive definite
+/// let a = vec![
+///     vec![Complex::from(4, 0), Complex::from(2, -2)],
+///     vec![Complex::from(2, 2), Complex::from(6, 0)]
+/// ];
+/// let x = hermitian_solve(&a, &[Complex::from(1, 0), Complex::from(0, 1)]).unwrap();
+/// let r: Complex = a[1][0] * x[0] + a[1][1] * x[1];
+/// assert!((r - Complex::from(0, 1)).modulus() < 1.0e-14);
+///
+/// // Indefinite
+/// let b = vec![vec![Complex::from(1, 0), Complex::from(0, 2)], vec![Complex::from(0, -2), Complex::from(1, 0)]];
+/// let x = hermitian_solve(&b, &[Complex::from(1, 0), Complex::new()]).unwrap();
+/// assert!((b[0][0] * x[0] + b[0][1] * x[1] - 1.0).modulus() < 1.0e-14);
+///
+/// // Singular
+/// let c = vec![vec![Complex::from(1, 0), Complex::from(1, 0)], vec![Complex::from(1, 0), Complex::from(1, 0)]];
+/// assert!(hermitian_solve(&c, &[Complex::from(1, 0), Complex::new()]).is_none());
+/// ```
+pub fn hermitian_solve(a: &[Vec<Complex>], b: &[Complex]) -> Option<Vec<Complex>> {
+
+    let n: usize = a.len();
+    assert!(b.len() == n, "The right-hand side must have one value per row of the matrix");
+
+    if let Some(l) = cholesky(a) {
+        // L y = b, then L^H x = y
+        let mut y: Vec<Complex> = Vec::with_capacity(n);
+        for (r, row) in l.iter().enumerate() {
+            y.push((0..r).fold(b[r], |acc, c| acc - row[c] * y[c]) / row[r].re);
+        }
+        let mut x: Vec<Complex> = vec![Complex::new(); n];
+        for r in (0..n).rev() {
+            x[r] = ((r + 1)..n).fold(y[r], |acc, c| acc - l[c][r].conjugate() * x[c]) / l[r][r].re;
+        }
+        return Some(x);
+    }
+
+    // x = sum v_i (v_i^H b) / lambda_i
+    let (values, vectors): (Vec<f64>, Vec<Vec<Complex>>) = hermitian_eigen(a);
+    let largest: f64 = values.iter().fold(0.0, |m: f64, v| m.max(v.abs()));
+    if values.iter().any(|v| v.abs() <= n as f64 * f64::EPSILON * largest) {
+        return None;
+    }
+
+    let mut x: Vec<Complex> = vec![Complex::new(); n];
+    for (l, v) in values.iter().zip(&vectors) {
+        let c: Complex = inner(v, b) / *l;
+        x.iter_mut().zip(v).for_each(|(xi, vi)| *xi += c * *vi);
+    }
+
+    Some(x)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Inner product `u^H v`
+fn inner(u: &[Complex], v: &[Complex]) -> Complex {
+    u.iter().zip(v).fold(Complex::new(), |acc, (x, y)| acc + x.conjugate() * *y)
+}
+
+/// Euclidean norm of a complex vector
+fn norm(u: &[Complex]) -> f64 {
+    u.iter().map(|x| x.re * x.re + x.im * x.im).sum::<f64>().sqrt()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,321 @@
+//!
+//! # Functions of matrices applied to vectors
+//!
+//! The exponential `exp(tA) v` solves the linear systems `dy/dt = A y`, as the Schrödinger equation
+//! (`A = -iH`) or the discretized diffusion equations. For large sparse matrices `exp(tA)` itself is dense,
+//! and out of reach: only the product with `v` is computed here, from the action of `A` on vectors.
+//!
+//! The Krylov method projects `A` on the space spanned by `v, Av, A^2 v, ...`, built by the Arnoldi
+//! process, where the exponential of the small projected matrix is computed directly. The time is split in
+//! steps for which the projection is accurate, the size of each step being set by an estimate of its error.
+//! The trigonometric and hyperbolic functions follow from exponentials of `±tA` and `±itA`.
+//!
+//! The matrix is given as a function returning `Ax` for a vector `x`, so that any storage can be used.
+//!
+//! ```
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::krylov::expm_multiply;
+//! // Diffusion on a chain of 200 points, applied to one of its modes
+//! let n: usize = 200;
+//! let laplacian = |x: &[Complex]| -> Vec<Complex> {
+//!     (0..n).map(|i| {
+//!         let left = if i > 0 { x[i - 1] } else { Complex::new() };
+//!         let right = if i + 1 < n { x[i + 1] } else { Complex::new() };
+//!         left + right - 2.0 * x[i]
+//!     }).collect()
+//! };
+//!
+//! let k: f64 = 3.0 * std::f64::consts::PI / (n + 1) as f64;
+//! let mode: Vec<Complex> = (1..=n).map(|j| Complex::from((k * j as f64).sin(), 0)).collect();
+//! let decay: f64 = (2.0 * (k.cos() - 1.0) * 50.0).exp();
+//!
+//! let res = expm_multiply(laplacian, &mode, 50);
+//! for (r, m) in res.iter().zip(&mode) {
+//!     assert!((*r - decay * *m).modulus() < 1.0e-12);
+//! }
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+use super::complex::Complex;        // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Largest dimension of the Krylov spaces
+const KRYLOV_DIMENSION: usize = 30;
+
+/// # Relative precision of the Krylov approximation
+const KRYLOV_PRECISION: f64 = 1.0e-13;
+
+/// # Cap of the number of time steps
+const KRYLOV_STEPS: usize = 10_000;
+
+/// # Largest number of Taylor terms of the exponential of the projected matrix
+const TAYLOR_TERMS: usize = 40;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Exponential of a matrix applied to a vector
+///
+/// `a` returns the product of the matrix with a vector, `v` is the vector and `t` the (complex) time:
+/// returns `exp(tA) v`. The matrix only needs to be square of the size of `v`, and is never formed.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::krylov::expm_multiply;
+/// // Spin precession under the Pauli matrix X: exp(-iθX) = cos(θ) - i sin(θ) X
+/// let pauli_x = |x: &[Complex]| vec![x[1], x[0]];
+/// let theta: f64 = 0.7;
+///
+/// let res = expm_multiply(pauli_x, &[Complex::from(1, 0), Complex::new()], Complex::from(0, -theta));
+/// assert!((res[0] - theta.cos()).modulus() < 1.0e-15);
+/// assert!((res[1] - Complex::from(0, -theta.sin())).modulus() < 1.0e-15);
+///
+/// // Dense matrices are given through their product
+/// let m: Vec<Vec<f64>> = vec![vec![0.0, 1.0], vec![-1.0, 0.0]];
+/// let prod = |x: &[Complex]| m.iter().map(|row| x[0] * row[0] + x[1] * row[1]).collect();
+/// let res = expm_multiply(prod, &[Complex::from(1, 0), Complex::new()], 2.0);
+/// assert!((res[0] - 2f64.cos()).modulus() < 1.0e-14 && (res[1] + 2f64.sin()).modulus() < 1.0e-14);
+/// ```
+pub fn expm_multiply<F, T>(a: F, v: &[Complex], t: T) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex>, T: Into<Complex> {
+    krylov_exp(&a, v, t.into())
+}
+
+/// # Cosine of a matrix applied to a vector
+///
+/// `cos(tA) v`, the solution of `d²y/dt² = -A² y` starting at rest from `v`, as for the vibrations of
+/// a structure. Computed as the mean of `exp(itA) v` and `exp(-itA) v`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::krylov::cosm_multiply;
+/// let diag = |x: &[Complex]| vec![x[0], 2.0 * x[1], 3.0 * x[2]];
+/// let res = cosm_multiply(diag, &[Complex::from(1, 0); 3], 0.5);
+/// for (k, r) in res.iter().enumerate() {
+///     assert!((*r - (0.5 * (k + 1) as f64).cos()).modulus() < 1.0e-14);
+/// }
+/// ```
+pub fn cosm_multiply<F>(a: F, v: &[Complex], t: f64) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex> {
+
+    let plus: Vec<Complex> = krylov_exp(&a, v, Complex::from(0, t));
+    let minus: Vec<Complex> = krylov_exp(&a, v, Complex::from(0, -t));
+    plus.iter().zip(&minus).map(|(p, m)| (*p + *m) / 2.0).collect()
+}
+
+/// # Sine of a matrix applied to a vector
+///
+/// `sin(tA) v`, computed as `(exp(itA) v - exp(-itA) v) / 2i`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::krylov::sinm_multiply;
+/// let diag = |x: &[Complex]| vec![x[0], 2.0 * x[1], 3.0 * x[2]];
+/// let res = sinm_multiply(diag, &[Complex::from(1, 0); 3], 0.5);
+/// for (k, r) in res.iter().enumerate() {
+///     assert!((*r - (0.5 * (k + 1) as f64).sin()).modulus() < 1.0e-14);
+/// }
+/// ```
+pub fn sinm_multiply<F>(a: F, v: &[Complex], t: f64) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex> {
+
+    let plus: Vec<Complex> = krylov_exp(&a, v, Complex::from(0, t));
+    let minus: Vec<Complex> = krylov_exp(&a, v, Complex::from(0, -t));
+    plus.iter().zip(&minus).map(|(p, m)| (*p - *m) / Complex::from(0, 2)).collect()
+}
+
+/// # Hyperbolic cosine of a matrix applied to a vector
+///
+/// `cosh(tA) v`, the mean of `exp(tA) v` and `exp(-tA) v`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::krylov::coshm_multiply;
+/// let diag = |x: &[Complex]| vec![x[0], -2.0 * x[1]];
+/// let res = coshm_multiply(diag, &[Complex::from(1, 0); 2], 1.5);
+/// assert!((res[0] - 1.5f64.cosh()).modulus() < 1.0e-14);
+/// assert!((res[1] - 3f64.cosh()).modulus() < 1.0e-13);
+/// ```
+pub fn coshm_multiply<F>(a: F, v: &[Complex], t: f64) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex> {
+
+    let plus: Vec<Complex> = krylov_exp(&a, v, Complex::from(t, 0));
+    let minus: Vec<Complex> = krylov_exp(&a, v, Complex::from(-t, 0));
+    plus.iter().zip(&minus).map(|(p, m)| (*p + *m) / 2.0).collect()
+}
+
+/// # Hyperbolic sine of a matrix applied to a vector
+///
+/// `sinh(tA) v`, computed as `(exp(tA) v - exp(-tA) v) / 2`: the relative precision is lost for the small
+/// values of `tA`, where the result is close to `tAv`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::krylov::sinhm_multiply;
+/// let diag = |x: &[Complex]| vec![x[0], -2.0 * x[1]];
+/// let res = sinhm_multiply(diag, &[Complex::from(1, 0); 2], 1.5);
+/// assert!((res[0] - 1.5f64.sinh()).modulus() < 1.0e-14);
+/// assert!((res[1] + 3f64.sinh()).modulus() < 1.0e-13);
+/// ```
+pub fn sinhm_multiply<F>(a: F, v: &[Complex], t: f64) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex> {
+
+    let plus: Vec<Complex> = krylov_exp(&a, v, Complex::from(t, 0));
+    let minus: Vec<Complex> = krylov_exp(&a, v, Complex::from(-t, 0));
+    plus.iter().zip(&minus).map(|(p, m)| (*p - *m) / 2.0).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Krylov approximation of `exp(tA) v`, by steps whose size follows the estimate of the projection error
+fn krylov_exp<F>(a: &F, v: &[Complex], t: Complex) -> Vec<Complex>
+where F: Fn(&[Complex]) -> Vec<Complex> {
+
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(KRYLOV_PRECISION);            // Relative tolerance
+    let max_steps: usize = prec.iterations(KRYLOV_STEPS);       // Step cap
+    let dim: usize = KRYLOV_DIMENSION.min(v.len());             // Dimension of the Krylov spaces
+
+    let mut w: Vec<Complex> = v.to_vec();
+    let mut done: f64 = 0.0;        // Fraction of the time already covered
+    let mut tau: f64 = 1.0;         // Fraction of the time of the next step
+    let mut steps: usize = 0;
+
+    while done < 1.0 && steps < max_steps {
+        let beta: f64 = norm(&w);
+        if beta == 0.0 {
+            break;
+        }
+
+        // Arnoldi process, with one reorthogonalization of each vector
+        let mut basis: Vec<Vec<Complex>> = vec![w.iter().map(|x| *x / beta).collect()];
+        let mut h: Vec<Vec<Complex>> = vec![vec![Complex::new(); dim]; dim];
+        let mut next: f64 = 0.0;    // Norm of the part of the last product left out of the space
+        let mut m: usize = dim;
+        for j in 0..dim {
+            let mut p: Vec<Complex> = a(&basis[j]);
+            assert!(p.len() == w.len(), "The matrix must be square, of the size of the vector");
+            let size: f64 = norm(&p);
+
+            for _ in 0..2 {
+                for (i, q) in basis.iter().enumerate() {
+                    let c: Complex = inner(q, &p);
+                    h[i][j] += c;
+                    p.iter_mut().zip(q).for_each(|(x, y)| *x -= c * *y);
+                }
+            }
+
+            // The space is invariant when the product lies inside it, and the projection exact
+            let s: f64 = norm(&p);
+            if s <= 16.0 * f64::EPSILON * size {
+                m = j + 1;
+                next = 0.0;
+                break;
+            }
+
+            next = s;
+            if j + 1 < dim {
+                h[j + 1][j] = Complex::from(s, 0);
+                basis.push(p.iter().map(|x| *x / s).collect());
+            }
+        }
+
+        // Step size fitted to the error estimate, without rebuilding the space
+        tau = tau.min(1.0 - done);
+        loop {
+            let scaled: Complex = tau * t;
+            let (e, phi): (Vec<Complex>, Complex) = projected_exp(&h, m, scaled);
+            let err: f64 = beta * next * scaled.modulus() * phi.modulus();
+            let allowed: f64 = tol * beta * tau;
+            steps += 1;
+            diagnostics::step("krylov::expm_multiply", steps - 1, err / beta, tau);
+
+            if err <= allowed || steps >= max_steps {
+                w = vec![Complex::new(); w.len()];
+                for (q, c) in basis.iter().zip(&e) {
+                    w.iter_mut().zip(q).for_each(|(x, y)| *x += beta * *c * *y);
+                }
+                done = if 1.0 - done - tau <= f64::EPSILON { 1.0 } else { done + tau };
+                tau *= if err == 0.0 { 2.0 } else { (0.9 * (allowed / err).powf(1.0 / m as f64)).min(2.0) };
+                break;
+            }
+
+            tau *= (0.9 * (allowed / err).powf(1.0 / m as f64)).clamp(0.1, 0.5);
+        }
+    }
+
+    diagnostics::finish("krylov::expm_multiply", steps, 1.0 - done, tol);
+    w
+}
+
+/// First column of `exp(tH)`, for the `m` first rows and columns of `h`, and the last component of
+/// `phi(tH) e1`, where `phi(z) = (e^z - 1) / z`, used in the error estimate
+///
+/// Both come from the exponential of `[[tH, e1], [0, 0]]`, computed by scaling and squaring of its Taylor
+/// series.
+fn projected_exp(h: &[Vec<Complex>], m: usize, t: Complex) -> (Vec<Complex>, Complex) {
+
+    let size: usize = m + 1;
+    let mut mat: Vec<Vec<Complex>> = vec![vec![Complex::new(); size]; size];
+    for i in 0..m {
+        for j in 0..m {
+            mat[i][j] = t * h[i][j];
+        }
+    }
+    mat[0][m] = Complex::from(1, 0);
+
+    // Scaling down to a norm below 1/2
+    let norm_1: f64 = (0..size).map(|j| mat.iter().map(|row| row[j].modulus()).sum::<f64>()).fold(0.0, f64::max);
+    let squarings: i32 = if norm_1 > 0.5 { (norm_1 / 0.5).log2().ceil() as i32 } else { 0 };
+    let scale: f64 = 2f64.powi(-squarings);
+    mat.iter_mut().for_each(|row| row.iter_mut().for_each(|x| *x *= scale));
+
+    let mut res: Vec<Vec<Complex>> = identity(size);
+    let mut term: Vec<Vec<Complex>> = identity(size);
+    for k in 1..=TAYLOR_TERMS {
+        term = product(&term, &mat);
+        term.iter_mut().for_each(|row| row.iter_mut().for_each(|x| *x /= k as f64));
+        res.iter_mut().zip(&term).for_each(|(r, s)| r.iter_mut().zip(s).for_each(|(x, y)| *x += *y));
+
+        let small: f64 = term.iter().flatten().map(|x| x.modulus()).fold(0.0, f64::max);
+        if small <= f64::EPSILON * 1.0e-3 {
+            break;
+        }
+    }
+
+    for _ in 0..squarings {
+        res = product(&res, &res);
+    }
+
+    ((0..m).map(|i| res[i][0]).collect(), res[m - 1][m])
+}
+
+/// Identity matrix of size `n`
+fn identity(n: usize) -> Vec<Vec<Complex>> {
+    (0..n).map(|i| (0..n).map(|j| Complex::from(if i == j { 1.0 } else { 0.0 }, 0)).collect()).collect()
+}
+
+/// Product of two square matrices
+fn product(a: &[Vec<Complex>], b: &[Vec<Complex>]) -> Vec<Vec<Complex>> {
+    a.iter().map(|row| {
+        (0..b.len()).map(|j| row.iter().zip(b).fold(Complex::new(), |acc, (x, r)| acc + *x * r[j])).collect()
+    }).collect()
+}
+
+/// Hermitian inner product `<a, b>`, conjugating `a`
+fn inner(a: &[Complex], b: &[Complex]) -> Complex {
+    a.iter().zip(b).fold(Complex::new(), |acc, (x, y)| acc + x.conjugate() * *y)
+}
+
+/// Euclidean norm
+fn norm(a: &[Complex]) -> f64 {
+    a.iter().map(|x| x.modulus().powi(2)).sum::<f64>().sqrt()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,387 @@
+//!
+//! # Lattice sums
+//!
+//! Sums over the points of a periodic lattice, such as the electrostatic energy of an ionic crystal, only
+//! converge slowly or conditionally when summed directly. Ewald's method splits each term into a short
+//! range part, summed over the lattice, and a smooth long range part, summed over the reciprocal lattice;
+//! both then converge like Gaussians.
+//!
+//! The lattices are given by their basis vectors `a_i`, the points being the integer combinations
+//! `n_1 a_1 + ... + n_d a_d`. The reciprocal lattice is spanned by the vectors `b_j` with
+//! `a_i . b_j = 2 pi delta_ij`.
+//!
+//! ```
+//! # use scilib::math::lattice::madelung;
+//! // Rock salt structure, in its conventional cubic cell
+//! let cell = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+//! let mut positions: Vec<[f64; 3]> = Vec::new();
+//! let mut charges: Vec<f64> = Vec::new();
+//! for f in [[0.0, 0.0, 0.0], [0.5, 0.5, 0.0], [0.5, 0.0, 0.5], [0.0, 0.5, 0.5]] {
+//!     positions.push(f);
+//!     charges.push(1.0);
+//!     positions.push([f[0] + 0.5, f[1], f[2]]);
+//!     charges.push(-1.0);
+//! }
+//!
+//! assert!((madelung(&cell, &positions, &charges, 0) - 1.7475645946331822).abs() < 1.0e-12);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use super::basic;               // Gamma functions
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Decay, in powers of `e`, of the last terms kept in the direct and reciprocal sums
+const CUTOFF: f64 = 40.0;
+
+/// Iteration cap of the continued fraction of the incomplete gamma function
+const MAX_ITERATIONS: usize = 10_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Ewald potentials
+///
+/// Electrostatic potential at each of the point charges of a periodic crystal, created by all the others
+/// and their periodic images, in units where the potential of a unit charge is `1 / r`. The `cell` gives
+/// the three vectors of the unit cell, and `positions` the Cartesian coordinates of the `charges` in it.
+///
+/// The total charge of the cell should vanish; otherwise, a uniform compensating background is implied.
+///
+/// ```
+/// # use scilib::math::lattice::ewald_potentials;
+/// // CsCl structure: two opposite charges on interpenetrating cubic lattices
+/// let cell = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+/// let res = ewald_potentials(&cell, &[[0.0, 0.0, 0.0], [0.5, 0.5, 0.5]], &[1.0, -1.0]);
+///
+/// assert!((res[0] - -2.0353615094526).abs() < 1.0e-12);
+/// assert!((res[1] - 2.0353615094526).abs() < 1.0e-12);
+/// ```
+pub fn ewald_potentials(cell: &[[f64; 3]; 3], positions: &[[f64; 3]], charges: &[f64]) -> Vec<f64> {
+
+    let basis: Vec<Vec<f64>> = cell.iter().map(|v| v.to_vec()).collect();
+    let (reciprocal, volume): (Vec<Vec<f64>>, f64) = reciprocal(&basis);
+    let eta: f64 = PI.sqrt() / volume.cbrt();
+    let total: f64 = charges.iter().sum();
+
+    let direct: Vec<i64> = limits(&reciprocal, CUTOFF.sqrt() / eta / (2.0 * PI), 1);
+    let recip: Vec<i64> = limits(&basis, 2.0 * eta * CUTOFF.sqrt() / (2.0 * PI), 0);
+
+    positions.iter().zip(charges).map(|(ri, &qi)| {
+        let mut phi: f64 = -2.0 * eta * qi / PI.sqrt() - PI * total / (volume * eta * eta);
+
+        for (rj, &qj) in positions.iter().zip(charges) {
+            let d: Vec<f64> = (0..3).map(|k| rj[k] - ri[k]).collect();
+            let d: Vec<f64> = wrap(&d, &basis, &reciprocal);
+
+            // Short range part, over the lattice
+            for_each_point(&direct, |n| {
+                let r: f64 = norm(&combine(&basis, n, &d));
+                if r > 0.0 {
+                    phi += qj * erfc(eta * r) / r;
+                }
+            });
+
+            // Long range part, over the reciprocal lattice
+            for_each_point(&recip, |n| {
+                let g: Vec<f64> = combine(&reciprocal, n, &[0.0; 3]);
+                let g2: f64 = dot(&g, &g);
+                if g2 > 0.0 {
+                    phi += qj * 4.0 * PI / volume * (-g2 / (4.0 * eta * eta)).exp() / g2 * dot(&g, &d).cos();
+                }
+            });
+        }
+
+        phi
+    }).collect()
+}
+
+/// # Ewald energy
+///
+/// Electrostatic energy per unit cell of a periodic crystal of point charges, `1/2 sum q_i phi_i` with the
+/// potentials of [`ewald_potentials`].
+///
+/// ```
+/// # use scilib::math::lattice::ewald_energy;
+/// let cell = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+/// let res = ewald_energy(&cell, &[[0.0, 0.0, 0.0], [0.5, 0.5, 0.5]], &[1.0, -1.0]);
+///
+/// assert!((res - -2.0353615094526).abs() < 1.0e-12);
+/// ```
+pub fn ewald_energy(cell: &[[f64; 3]; 3], positions: &[[f64; 3]], charges: &[f64]) -> f64 {
+    0.5 * ewald_potentials(cell, positions, charges).iter().zip(charges).map(|(p, q)| p * q).sum::<f64>()
+}
+
+/// # Madelung constant
+///
+/// Madelung constant of the charge of index `site`, `-phi_i r_0 / q_i`, where `r_0` is the distance to its
+/// nearest neighbour in the crystal. The convention fixes the values for the classical structures, e.g.
+/// `1.74756` for rock salt and `1.76267` for CsCl.
+///
+/// ```
+/// # use scilib::math::lattice::madelung;
+/// let cell = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+/// let res = madelung(&cell, &[[0.0, 0.0, 0.0], [0.5, 0.5, 0.5]], &[1.0, -1.0], 0);
+///
+/// assert!((res - 1.7626747730709884).abs() < 1.0e-12);
+/// ```
+pub fn madelung(cell: &[[f64; 3]; 3], positions: &[[f64; 3]], charges: &[f64], site: usize) -> f64 {
+
+    let basis: Vec<Vec<f64>> = cell.iter().map(|v| v.to_vec()).collect();
+    let (reciprocal, _): (Vec<Vec<f64>>, f64) = reciprocal(&basis);
+
+    // Nearest neighbour, among the first images of the charges
+    let mut r_0: f64 = f64::INFINITY;
+    for rj in positions {
+        let d: Vec<f64> = (0..3).map(|k| rj[k] - positions[site][k]).collect();
+        let d: Vec<f64> = wrap(&d, &basis, &reciprocal);
+        for_each_point(&[1, 1, 1], |n| {
+            let r: f64 = norm(&combine(&basis, n, &d));
+            if r > 0.0 {
+                r_0 = r_0.min(r);
+            }
+        });
+    }
+
+    -ewald_potentials(cell, positions, charges)[site] * r_0 / charges[site]
+}
+
+/// # Epstein zeta function
+///
+/// Sum `Z(s) = sum' |L|^(-s)` over the non-zero points `L` of the lattice spanned by the vectors of
+/// `basis`, in any dimension `d`. The sum converges for `s > d`, and the function is continued
+/// analytically to `s > 0`, with a pole at `s = d`.
+///
+/// The sum is split with the incomplete gamma function between the lattice and its reciprocal, both parts
+/// converging like Gaussians. Returns `NaN` for `s <= 0`.
+///
+/// ```
+/// # use scilib::math::lattice::epstein_zeta;
+/// // Square lattice: sum' (m^2 + n^2)^(-2) = 4 zeta(2) beta(2)
+/// let res = epstein_zeta(&[vec![1.0, 0.0], vec![0.0, 1.0]], 4.0);
+/// assert!((res - 6.026812039691940).abs() < 1.0e-12);
+///
+/// // One dimension: 2 zeta(s), also below the pole
+/// let res = epstein_zeta(&[vec![1.0]], 0.5);
+/// assert!((res - 2.0 * -1.4603545088095868).abs() < 1.0e-12);
+/// ```
+pub fn epstein_zeta(basis: &[Vec<f64>], s: f64) -> f64 {
+
+    if s <= 0.0 || s.is_nan() {
+        return f64::NAN;
+    }
+
+    let d: f64 = basis.len() as f64;
+    let (reciprocal, volume): (Vec<Vec<f64>>, f64) = reciprocal(basis);
+    let eta: f64 = PI.sqrt() / volume.powf(1.0 / d);
+    let zeros: Vec<f64> = vec![0.0; basis.len()];
+
+    let direct: Vec<i64> = limits(&reciprocal, CUTOFF.sqrt() / eta / (2.0 * PI), 0);
+    let recip: Vec<i64> = limits(basis, 2.0 * eta * CUTOFF.sqrt() / (2.0 * PI), 0);
+
+    let mut res: f64 = 2.0 * PI.powf(0.5 * d) / volume * eta.powf(s - d) / (s - d) - 2.0 * eta.powf(s) / s;
+
+    for_each_point(&direct, |n| {
+        let r2: f64 = norm_squared(&combine(basis, n, &zeros));
+        if r2 > 0.0 {
+            res += upper_gamma(0.5 * s, eta * eta * r2) * r2.powf(-0.5 * s);
+        }
+    });
+
+    for_each_point(&recip, |n| {
+        let g2: f64 = norm_squared(&combine(&reciprocal, n, &zeros));
+        if g2 > 0.0 {
+            res += PI.powf(0.5 * d) / volume * (0.25 * g2).powf(0.5 * (s - d)) * upper_gamma(0.5 * (d - s), 0.25 * g2 / (eta * eta));
+        }
+    });
+
+    res / basic::gamma_complex((0.5 * s).into()).re
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reciprocal basis `b_j`, with `a_i . b_j = 2 pi delta_ij`, and volume of the cell, by Gauss-Jordan elimination
+fn reciprocal(basis: &[Vec<f64>]) -> (Vec<Vec<f64>>, f64) {
+
+    let d: usize = basis.len();
+    let mut a: Vec<Vec<f64>> = basis.to_vec();
+    let mut inv: Vec<Vec<f64>> = (0..d).map(|i| (0..d).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    let mut det: f64 = 1.0;
+
+    for c in 0..d {
+        let pivot: usize = (c..d).max_by(|&p, &q| a[p][c].abs().total_cmp(&a[q][c].abs())).unwrap_or(c);
+        if pivot != c {
+            a.swap(c, pivot);
+            inv.swap(c, pivot);
+            det = -det;
+        }
+        let p: f64 = a[c][c];
+        det *= p;
+        for k in 0..d {
+            a[c][k] /= p;
+            inv[c][k] /= p;
+        }
+        for r in 0..d {
+            if r != c {
+                let factor: f64 = a[r][c];
+                for k in 0..d {
+                    a[r][k] -= factor * a[c][k];
+                    inv[r][k] -= factor * inv[c][k];
+                }
+            }
+        }
+    }
+
+    // The rows of the basis are the a_i, so that b_j is 2 pi times the column j of the inverse
+    let recip: Vec<Vec<f64>> = (0..d).map(|j| (0..d).map(|k| 2.0 * PI * inv[k][j]).collect()).collect();
+    (recip, det.abs())
+}
+
+/// Number of cells to sum along each direction to cover a ball of the given radius, the ball radius being
+/// scaled by `2 pi` over the lengths of the `dual` vectors
+fn limits(dual: &[Vec<f64>], radius: f64, extra: i64) -> Vec<i64> {
+    dual.iter().map(|v| (radius * norm(v)).ceil() as i64 + extra).collect()
+}
+
+/// Calls `visit` on every integer vector with `|n_i| <= limits[i]`
+fn for_each_point<F>(limits: &[i64], mut visit: F)
+where F: FnMut(&[i64]) {
+
+    let mut n: Vec<i64> = limits.iter().map(|&l| -l).collect();
+    loop {
+        visit(&n);
+
+        // Incrementing the counter, the first index the fastest
+        let mut k: usize = 0;
+        loop {
+            if k == n.len() {
+                return;
+            }
+            if n[k] < limits[k] {
+                n[k] += 1;
+                break;
+            }
+            n[k] = -limits[k];
+            k += 1;
+        }
+    }
+}
+
+/// Point `sum n_i v_i + shift`
+fn combine(vectors: &[Vec<f64>], n: &[i64], shift: &[f64]) -> Vec<f64> {
+    let mut res: Vec<f64> = shift.to_vec();
+    for (v, &c) in vectors.iter().zip(n) {
+        for (r, x) in res.iter_mut().zip(v) {
+            *r += c as f64 * x;
+        }
+    }
+    res
+}
+
+/// Brings a displacement back to the cell centred on the origin
+fn wrap(d: &[f64], basis: &[Vec<f64>], reciprocal: &[Vec<f64>]) -> Vec<f64> {
+    let n: Vec<i64> = reciprocal.iter().map(|b| -(dot(b, d) / (2.0 * PI)).round() as i64).collect();
+    combine(basis, &n, d)
+}
+
+/// Scalar product
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Squared Euclidean norm
+fn norm_squared(a: &[f64]) -> f64 {
+    dot(a, a)
+}
+
+/// Euclidean norm
+fn norm(a: &[f64]) -> f64 {
+    norm_squared(a).sqrt()
+}
+
+/// Complementary error function of a real argument, `Gamma(1/2, x^2) / sqrt(pi)`
+fn erfc(x: f64) -> f64 {
+    upper_gamma(0.5, x * x) / PI.sqrt()
+}
+
+/// Upper incomplete gamma function `Gamma(a, x)`, for any real `a` and `x > 0`
+///
+/// The continued fraction holds for `x >= 1`. Below, the lower incomplete series gives the values for
+/// `a > 0`, the exponential integral for `a = 0`, and the recurrence `Gamma(a, x) = (Gamma(a + 1, x) -
+/// x^a exp(-x)) / a` the negative values.
+fn upper_gamma(a: f64, x: f64) -> f64 {
+
+    if x >= 1.0 {
+        // Modified Lentz's method
+        let tiny: f64 = f64::MIN_POSITIVE;
+        let mut b: f64 = x + 1.0 - a;
+        let mut c: f64 = 1.0 / tiny;
+        let mut d: f64 = 1.0 / b;
+        let mut h: f64 = d;
+        for i in 1..MAX_ITERATIONS {
+            let an: f64 = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let del: f64 = d * c;
+            h *= del;
+            if (del - 1.0).abs() < f64::EPSILON {
+                break;
+            }
+        }
+        return (-x + a * x.ln()).exp() * h;
+    }
+
+    if a > 0.0 {
+        // Gamma(a) minus the series of the lower function
+        let mut term: f64 = 1.0 / a;
+        let mut sum: f64 = term;
+        for k in 1..MAX_ITERATIONS {
+            term *= x / (a + k as f64);
+            sum += term;
+            if term < sum * f64::EPSILON {
+                break;
+            }
+        }
+        return basic::gamma_complex(a.into()).re - sum * (-x + a * x.ln()).exp();
+    }
+
+    // Starting from the first non-negative parameter a + m
+    let m: f64 = (-a).ceil();
+    let top: f64 = a + m;
+    let mut res: f64 = if top == 0.0 {
+        // Exponential integral E1(x) = -gamma - ln(x) - sum (-x)^k / (k k!)
+        let mut term: f64 = 1.0;
+        let mut sum: f64 = 0.0;
+        for k in 1..MAX_ITERATIONS {
+            term *= -x / k as f64;
+            sum += term / k as f64;
+            if term.abs() < f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+        -0.5772156649015329 - x.ln() - sum
+    } else {
+        upper_gamma(top, x)
+    };
+
+    let mut b: f64 = top;
+    while b > a + 0.5 {
+        res = (res - x.powf(b - 1.0) * (-x).exp()) / (b - 1.0);
+        b -= 1.0;
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,188 @@
+//!
+//! # Log-space arithmetic
+//!
+//! Probabilities and amplitudes spanning hundreds of orders of magnitude, as the likelihoods of large
+//! samples, underflow in floating point. They are kept as logarithms instead, and the sums become
+//! `ln(e^a + e^b)`, computed as `max(a, b) + ln(1 + e^(-|a - b|))` to stay exact for any difference. The
+//! matrix-vector products of the Markov chains follow, with sums of exponentials on each row.
+//!
+//! `-inf` stands for a probability of zero, and is handled as such by all the functions.
+//!
+//! ```
+//! # use scilib::math::logspace::{ log_add_exp, log_sum_exp };
+//! // Probabilities of 1e-400 and 3e-400, which underflow as f64
+//! let (a, b): (f64, f64) = (-400.0 * 10f64.ln(), 3f64.ln() - 400.0 * 10f64.ln());
+//! assert!((log_add_exp(a, b) - (4f64.ln() - 400.0 * 10f64.ln())).abs() < 1.0e-12);
+//!
+//! let terms: Vec<f64> = vec![-1000.0; 4];
+//! assert!((log_sum_exp(&terms) - (-1000.0 + 4f64.ln())).abs() < 1.0e-12);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::summation;       // Accurate sums
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Logarithm of a sum of exponentials
+///
+/// `ln(e^a + e^b)`, exact even when the difference is large, or the values very negative.
+///
+/// ```
+/// # use scilib::math::logspace::log_add_exp;
+/// assert!((log_add_exp(0.0, 0.0) - 2f64.ln()).abs() < 1.0e-15);
+/// assert!((log_add_exp(0.0, -50.0) - (-50f64).exp()).abs() < 1.0e-35);
+/// assert_eq!(log_add_exp(f64::NEG_INFINITY, 2.5), 2.5);
+/// assert_eq!(log_add_exp(f64::NEG_INFINITY, f64::NEG_INFINITY), f64::NEG_INFINITY);
+/// ```
+pub fn log_add_exp(a: f64, b: f64) -> f64 {
+
+    let (hi, lo): (f64, f64) = if a >= b { (a, b) } else { (b, a) };
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    } else if lo == f64::NEG_INFINITY || hi == f64::INFINITY {
+        return hi;
+    }
+
+    hi + (lo - hi).exp().ln_1p()
+}
+
+/// # Logarithm of a difference of exponentials
+///
+/// `ln(e^a - e^b)`, for `a >= b`: `-inf` when `a = b`, `NaN` when `a < b`.
+///
+/// ```
+/// # use scilib::math::logspace::{ log_add_exp, log_sub_exp };
+/// assert!((log_sub_exp(2f64.ln(), 0.0)).abs() < 1.0e-15);
+/// assert!((log_sub_exp(log_add_exp(-800.0, -801.0), -801.0) - -800.0).abs() < 1.0e-12);
+/// assert_eq!(log_sub_exp(1.0, 1.0), f64::NEG_INFINITY);
+/// assert!(log_sub_exp(0.0, 1.0).is_nan());
+/// ```
+pub fn log_sub_exp(a: f64, b: f64) -> f64 {
+
+    if a.is_nan() || b.is_nan() || a < b {
+        return f64::NAN;
+    } else if a == b {
+        return f64::NEG_INFINITY;
+    } else if b == f64::NEG_INFINITY {
+        return a;
+    }
+
+    // ln(1 - e^d) loses its precision as d tends to 0 in the form ln_1p(-e^d)
+    let d: f64 = b - a;
+    a + if d > -std::f64::consts::LN_2 { (-d.exp_m1()).ln() } else { (-d.exp()).ln_1p() }
+}
+
+/// # Logarithm of the sum of the exponentials of a slice
+///
+/// `ln(sum e^(x_i))`, with the largest value factored out, and the others summed with compensation. Returns
+/// `-inf` for an empty slice, the sum of no probability.
+///
+/// ```
+/// # use scilib::math::logspace::log_sum_exp;
+/// let x: Vec<f64> = (0..10).map(|k| -(k as f64)).collect();
+/// let exact: f64 = (1.0 - (-10f64).exp()).ln() - (1.0 - (-1f64).exp()).ln();
+/// assert!((log_sum_exp(&x) - exact).abs() < 1.0e-15);
+///
+/// assert_eq!(log_sum_exp(&[]), f64::NEG_INFINITY);
+/// assert_eq!(log_sum_exp(&[f64::NEG_INFINITY, 3.0]), 3.0);
+/// ```
+pub fn log_sum_exp(x: &[f64]) -> f64 {
+    sum_exp(x.iter().copied())
+}
+
+/// # Normalization of log-probabilities
+///
+/// Shifts the values so that their exponentials sum to 1, the log-space counterpart of dividing by the
+/// total. The result is `NaN` everywhere when all the probabilities vanish.
+///
+/// ```
+/// # use scilib::math::logspace::{ log_normalize, log_sum_exp };
+/// let res = log_normalize(&[-1000.0, -1001.0, -1002.0]);
+/// assert!(log_sum_exp(&res).abs() < 1.0e-13);
+/// assert!((res[0] - res[1] - 1.0).abs() < 1.0e-12);
+/// ```
+pub fn log_normalize(x: &[f64]) -> Vec<f64> {
+    let total: f64 = log_sum_exp(x);
+    x.iter().map(|v| v - total).collect()
+}
+
+/// # Log-domain dot product
+///
+/// `ln(sum e^(a_i + b_i))`, the logarithm of the dot product of two vectors given by their logarithms.
+///
+/// ```
+/// # use scilib::math::logspace::log_dot;
+/// let a: Vec<f64> = [0.2, 0.3, 0.5].iter().map(|v: &f64| v.ln()).collect();
+/// let b: Vec<f64> = [1.0, 2.0, 4.0].iter().map(|v: &f64| v.ln() - 700.0).collect();
+/// assert!((log_dot(&a, &b) - (2.8f64.ln() - 700.0)).abs() < 1.0e-12);
+/// ```
+pub fn log_dot(a: &[f64], b: &[f64]) -> f64 {
+    assert!(a.len() == b.len(), "Vectors must have the same length");
+    sum_exp(a.iter().zip(b).map(|(x, y)| x + y))
+}
+
+/// # Log-domain matrix-vector product
+///
+/// Logarithm of the product of a matrix and a vector given by their logarithms, row by row: the
+/// `i`-th value is `ln(sum_j e^(m_ij + v_j))`.
+///
+/// ```
+/// # use scilib::math::logspace::log_mat_vec;
+/// let m: Vec<Vec<f64>> = vec![vec![0.9f64.ln(), 0.1f64.ln()], vec![0.2f64.ln(), 0.8f64.ln()]];
+/// let v: Vec<f64> = vec![0.5f64.ln() - 900.0, 0.5f64.ln() - 900.0];
+/// let res = log_mat_vec(&m, &v);
+/// assert!((res[0] - (0.5f64.ln() - 900.0)).abs() < 1.0e-12);
+/// ```
+pub fn log_mat_vec(m: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    m.iter().map(|row| log_dot(row, v)).collect()
+}
+
+/// # Log-domain vector-matrix product
+///
+/// Logarithm of the product of a row vector and a matrix, the `j`-th value being `ln(sum_i e^(v_i + m_ij))`:
+/// the propagation of a distribution by a transition matrix, as in the forward pass of a hidden Markov model.
+///
+/// ```
+/// # use scilib::math::logspace::log_vec_mat;
+/// let m: Vec<Vec<f64>> = vec![vec![0.9f64.ln(), 0.1f64.ln()], vec![0.2f64.ln(), 0.8f64.ln()]];
+/// let res = log_vec_mat(&[0.0, f64::NEG_INFINITY], &m);
+/// assert!((res[0] - 0.9f64.ln()).abs() < 1.0e-15 && (res[1] - 0.1f64.ln()).abs() < 1.0e-15);
+/// ```
+pub fn log_vec_mat(v: &[f64], m: &[Vec<f64>]) -> Vec<f64> {
+
+    assert!(v.len() == m.len(), "The vector must have one value per row of the matrix");
+    let cols: usize = m.first().map_or(0, |r| r.len());
+    assert!(m.iter().all(|r| r.len() == cols), "All the rows of the matrix must have the same length");
+
+    (0..cols).map(|j| sum_exp(v.iter().zip(m).map(|(x, row)| x + row[j]))).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logarithm of the sum of the exponentials of the values, the largest one being factored out
+fn sum_exp<I>(values: I) -> f64
+where I: Iterator<Item = f64> + Clone {
+
+    let mut top: f64 = f64::NEG_INFINITY;
+    let mut pos: usize = 0;
+    for (idx, v) in values.clone().enumerate() {
+        if v.is_nan() {
+            return f64::NAN;
+        } else if v > top {
+            (top, pos) = (v, idx);
+        }
+    }
+
+    if !top.is_finite() {
+        return top;
+    }
+
+    // Sum of the others, relative to the largest
+    let others = values.enumerate().filter(|&(idx, _)| idx != pos);
+    let rest: f64 = summation::neumaier_sum(others.map(|(_, v)| (v - top).exp()));
+    top + rest.ln_1p()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
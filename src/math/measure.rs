@@ -0,0 +1,456 @@
+//!
+//! # Measures with uncertainties
+//!
+//! A `Measure` holds a value and keeps track of how it depends on every independent source of uncertainty
+//! that was used to build it. Operations propagate these dependencies to first order, which means that
+//! correlations are handled automatically: reusing the same quantity twice (for example `m / m`) correctly
+//! cancels out its uncertainty, where a naive quadratic sum would not.
+//!
+//! ```
+//! # use scilib::constant;
+//! # use scilib::math::measure::Measure;
+//! let m_e = Measure::constant(constant::ELECTRON_MASS, constant::ELECTRON_MASS_UNC);
+//!
+//! // Rest energy of the electron, the speed of light is exact
+//! let energy = m_e.clone() * constant::C.powi(2);
+//! assert!((energy.relative_uncertainty() - 3.073_753_496e-10).abs() < 1.0e-18);
+//!
+//! // Both terms are fully correlated, the ratio is exact
+//! let ratio = energy / m_e;
+//! assert_eq!(ratio.uncertainty(), 0.0);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{     // Implementing basic operations
+    Add,            // Addition
+    Sub,            // Subtraction
+    Mul,            // Multiplication
+    Div,            // Division
+    Neg             // Negation
+};
+
+use std::fmt::{     // Formatter display
+    Display,        // The display itself
+    Result as DRes  // The associated result
+};
+
+use std::sync::atomic::{    // Generating unique identifiers
+    AtomicUsize,            // Thread safe counter
+    Ordering                // Memory ordering
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Counter used to give each new independent measure its own identifier
+static NEXT_SOURCE: AtomicUsize = AtomicUsize::new(0);
+
+/// # Source of uncertainty
+///
+/// Constants are identified by their value, so that two uses of the same constant are correlated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Source {
+    /// A tabulated constant, identified by the bits of its value
+    Constant(u64),
+    /// An independent measurement
+    Measured(usize)
+}
+
+/// # Measure structure
+///
+/// The value is stored alongside the linear contribution of each source of uncertainty, that is the partial
+/// derivative of the value with respect to the source, times the standard uncertainty of the source.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Measure {
+    /// The central value
+    pub value: f64,
+    /// Contribution of each source, sorted by source
+    sources: Vec<(Source, f64)>
+}
+
+/// # Display
+///
+/// Shows the measure as `value ± uncertainty`.
+impl Display for Measure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
+        write!(f, "{} ± {}", self.value, self.uncertainty())?;
+        Ok(())
+    }
+}
+
+/// Implementing required methods
+impl Measure {
+    /// # New independent measure
+    ///
+    /// `value` is the measured value and `uncertainty` its standard uncertainty.
+    ///
+    /// Each call creates a new source of uncertainty, independent from all the others.
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let a = Measure::new(2.0, 0.3);
+    /// let b = Measure::new(1.0, 0.4);
+    /// let c = a + b;
+    ///
+    /// assert_eq!(c.value, 3.0);
+    /// assert!((c.uncertainty() - 0.5).abs() < 1.0e-15);
+    /// ```
+    pub fn new(value: f64, uncertainty: f64) -> Self {
+        let id: usize = NEXT_SOURCE.fetch_add(1, Ordering::Relaxed);
+        Self::with_source(value, uncertainty, Source::Measured(id))
+    }
+
+    /// # Measure from a tabulated constant
+    ///
+    /// `value` is the constant and `uncertainty` its standard uncertainty, typically found in the
+    /// `constant` module under the `_UNC` suffix.
+    ///
+    /// Constants are identified by their value, meaning that building the same constant twice yields
+    /// two fully correlated measures.
+    ///
+    /// ```
+    /// # use scilib::constant;
+    /// # use scilib::math::measure::Measure;
+    /// let g1 = Measure::constant(constant::G, constant::G_UNC);
+    /// let g2 = Measure::constant(constant::G, constant::G_UNC);
+    ///
+    /// assert_eq!((g1 - g2).uncertainty(), 0.0);
+    /// ```
+    pub fn constant(value: f64, uncertainty: f64) -> Self {
+        Self::with_source(value, uncertainty, Source::Constant(value.to_bits()))
+    }
+
+    /// # Exact value
+    ///
+    /// Creates a measure without any uncertainty.
+    ///
+    /// ```
+    /// # use scilib::constant;
+    /// # use scilib::math::measure::Measure;
+    /// let c = Measure::exact(constant::C);
+    ///
+    /// assert_eq!(c.uncertainty(), 0.0);
+    /// ```
+    pub fn exact(value: f64) -> Self {
+        Self {
+            value,
+            sources: Vec::new()
+        }
+    }
+
+    /// Builds the measure for a single source
+    fn with_source(value: f64, uncertainty: f64, source: Source) -> Self {
+        if uncertainty == 0.0 {
+            return Self::exact(value);
+        }
+
+        Self {
+            value,
+            sources: vec![(source, uncertainty.abs())]
+        }
+    }
+
+    /// # Standard uncertainty
+    ///
+    /// Quadratic sum of the contribution of all the independent sources.
+    pub fn uncertainty(&self) -> f64 {
+        self.sources.iter().fold(0.0, |res, (_, d)| res + d.powi(2)).sqrt()
+    }
+
+    /// # Relative uncertainty
+    ///
+    /// Uncertainty divided by the absolute value of the measure.
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let m = Measure::new(-4.0, 0.2);
+    ///
+    /// assert_eq!(m.relative_uncertainty(), 0.05);
+    /// ```
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty() / self.value.abs()
+    }
+
+    /// # Covariance between two measures
+    ///
+    /// Sum of the products of the contributions of the sources shared by both measures.
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let a = Measure::new(1.0, 0.1);
+    /// let b = Measure::new(2.0, 0.2);
+    ///
+    /// assert_eq!(a.covariance(&b), 0.0);
+    /// assert!((a.covariance(&a) - 0.01).abs() < 1.0e-15);
+    /// ```
+    pub fn covariance(&self, other: &Self) -> f64 {
+        let mut res: f64 = 0.0;
+
+        for (s, d) in &self.sources {
+            if let Ok(idx) = other.sources.binary_search_by(|(o, _)| o.cmp(s)) {
+                res += d * other.sources[idx].1;
+            }
+        }
+
+        res
+    }
+
+    /// # Correlation coefficient between two measures
+    ///
+    /// Returns the covariance normalized by both uncertainties, in the range `[-1, 1]`.
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let a = Measure::new(1.0, 0.1);
+    /// let b = -2.0 * a.clone();
+    ///
+    /// assert!((a.correlation(&b) - -1.0).abs() < 1.0e-15);
+    /// ```
+    pub fn correlation(&self, other: &Self) -> f64 {
+        self.covariance(other) / (self.uncertainty() * other.uncertainty())
+    }
+
+    /// Creates a new measure from a function of self, knowing its derivative
+    fn chain(&self, value: f64, derivative: f64) -> Self {
+        Self {
+            value,
+            sources: self.sources.iter().map(|(s, d)| (*s, d * derivative)).collect()
+        }
+    }
+
+    /// Creates a new measure from a function of self and other, knowing the partial derivatives
+    fn combine(&self, da: f64, other: &Self, db: f64, value: f64) -> Self {
+        let mut sources: Vec<(Source, f64)> = Vec::with_capacity(self.sources.len() + other.sources.len());
+        let mut a = self.sources.iter().peekable();
+        let mut b = other.sources.iter().peekable();
+
+        // Merging both sorted lists, adding the contributions of shared sources
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some((sa, va)), Some((sb, vb))) => {
+                    if sa == sb {
+                        sources.push((*sa, va * da + vb * db));
+                        a.next();
+                        b.next();
+                    } else if sa < sb {
+                        sources.push((*sa, va * da));
+                        a.next();
+                    } else {
+                        sources.push((*sb, vb * db));
+                        b.next();
+                    }
+                },
+                (Some((sa, va)), None) => {
+                    sources.push((*sa, va * da));
+                    a.next();
+                },
+                (None, Some((sb, vb))) => {
+                    sources.push((*sb, vb * db));
+                    b.next();
+                },
+                (None, None) => break
+            }
+        }
+
+        Self {
+            value,
+            sources
+        }
+    }
+
+    /// # Raising to an integer power
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let m = Measure::new(2.0, 0.1);
+    /// let res = m.powi(3);
+    ///
+    /// assert_eq!(res.value, 8.0);
+    /// assert!((res.uncertainty() - 1.2).abs() < 1.0e-14);
+    /// ```
+    pub fn powi(&self, n: i32) -> Self {
+        self.chain(self.value.powi(n), n as f64 * self.value.powi(n - 1))
+    }
+
+    /// # Raising to a real power
+    ///
+    /// ```
+    /// # use scilib::math::measure::Measure;
+    /// let m = Measure::new(4.0, 0.2);
+    /// let res = m.powf(1.5);
+    ///
+    /// assert_eq!(res.value, 8.0);
+    /// assert!((res.uncertainty() - 0.6).abs() < 1.0e-14);
+    /// ```
+    pub fn powf(&self, e: f64) -> Self {
+        self.chain(self.value.powf(e), e * self.value.powf(e - 1.0))
+    }
+
+    /// # Square root
+    pub fn sqrt(&self) -> Self {
+        let r: f64 = self.value.sqrt();
+        self.chain(r, 0.5 / r)
+    }
+
+    /// # Exponential
+    pub fn exp(&self) -> Self {
+        let e: f64 = self.value.exp();
+        self.chain(e, e)
+    }
+
+    /// # Natural logarithm
+    pub fn ln(&self) -> Self {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    /// # Sinus function
+    pub fn sin(&self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    /// # Cosine function
+    pub fn cos(&self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Here comes the list of implementations for the operations
+
+/// # Conversion from a scalar
+///
+/// The resulting measure is exact.
+impl From<f64> for Measure {
+    fn from(value: f64) -> Self {
+        Self::exact(value)
+    }
+}
+
+/// # Addition
+impl Add for Measure {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(1.0, &rhs, 1.0, self.value + rhs.value)
+    }
+}
+
+/// # Addition of a scalar
+impl Add<f64> for Measure {
+    type Output = Self;
+    fn add(self, rhs: f64) -> Self::Output {
+        self.chain(self.value + rhs, 1.0)
+    }
+}
+
+/// # Addition to f64 (real): `f64 + m`
+impl Add<Measure> for f64 {
+    type Output = Measure;
+    fn add(self, rhs: Measure) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// # Subtraction
+///
+/// ```
+/// # use scilib::math::measure::Measure;
+/// let a = Measure::new(2.0, 0.3);
+/// let res = a.clone() - a;
+///
+/// assert_eq!(res.value, 0.0);
+/// assert_eq!(res.uncertainty(), 0.0);
+/// ```
+impl Sub for Measure {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(1.0, &rhs, -1.0, self.value - rhs.value)
+    }
+}
+
+/// # Subtraction of a scalar
+impl Sub<f64> for Measure {
+    type Output = Self;
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.chain(self.value - rhs, 1.0)
+    }
+}
+
+/// # Subtraction to f64 (real): `f64 - m`
+impl Sub<Measure> for f64 {
+    type Output = Measure;
+    fn sub(self, rhs: Measure) -> Self::Output {
+        rhs.chain(self - rhs.value, -1.0)
+    }
+}
+
+/// # Multiplication
+///
+/// ```
+/// # use scilib::math::measure::Measure;
+/// let a = Measure::new(2.0, 0.2);
+/// let b = Measure::new(3.0, 0.6);
+/// let res = a * b;
+///
+/// // Relative uncertainties add in quadrature
+/// assert_eq!(res.value, 6.0);
+/// assert!((res.relative_uncertainty() - (0.1_f64.powi(2) + 0.2_f64.powi(2)).sqrt()).abs() < 1.0e-15);
+/// ```
+impl Mul for Measure {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.combine(rhs.value, &rhs, self.value, self.value * rhs.value)
+    }
+}
+
+/// # Multiplication by a scalar
+impl Mul<f64> for Measure {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.chain(self.value * rhs, rhs)
+    }
+}
+
+/// # Multiplication to f64 (real): `f64 * m`
+impl Mul<Measure> for f64 {
+    type Output = Measure;
+    fn mul(self, rhs: Measure) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// # Division
+impl Div for Measure {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let v: f64 = self.value / rhs.value;
+        self.combine(1.0 / rhs.value, &rhs, -v / rhs.value, v)
+    }
+}
+
+/// # Division by a scalar
+impl Div<f64> for Measure {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        self.chain(self.value / rhs, 1.0 / rhs)
+    }
+}
+
+/// # Division to f64 (real): `f64 / m`
+impl Div<Measure> for f64 {
+    type Output = Measure;
+    fn div(self, rhs: Measure) -> Self::Output {
+        let v: f64 = self / rhs.value;
+        rhs.chain(v, -v / rhs.value)
+    }
+}
+
+/// # Negation
+impl Neg for Measure {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,383 @@
+//!
+//! # Minimax approximation
+//!
+//! Best uniform approximations of a function on an interval, by polynomials or rational functions, built
+//! with the Remez exchange algorithm. They minimize the largest error over the interval, absolute or
+//! relative, and are the usual way to build the fast kernels of the special functions.
+//!
+//! The error of the best approximation equioscillates: it reaches its largest magnitude with alternating
+//! signs at `m + n + 2` points, for a numerator of degree `m` and a denominator of degree `n`. The algorithm
+//! solves for an approximation levelling the error over a reference set of points, then moves the reference
+//! to the extrema of the new error, until the error is levelled at its extrema.
+//!
+//! ```
+//! # use scilib::math::minimax::remez;
+//! // Best quadratic approximation of exp on [0, 1]
+//! let approx = remez(|x: f64| x.exp(), 0.0, 1.0, 2, 0);
+//!
+//! let worst: f64 = (0..=1000).map(|i| i as f64 / 1000.0)
+//!     .map(|x| (approx.eval(x) - x.exp()).abs())
+//!     .fold(0.0, f64::max);
+//! assert!((worst - approx.error).abs() < 1.0e-10);
+//! assert!(approx.error < 0.0088);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;           // Chebyshev nodes
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+use super::eigen::solve;            // Levelling system
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Relative spread of the extrema of the error at convergence
+const REMEZ_PRECISION: f64 = 1.0e-8;
+
+/// # Iteration cap of the exchange
+const REMEZ_ITERATIONS: usize = 50;
+
+/// # Iteration cap of the levelled error of the rational approximations
+const LEVEL_ITERATIONS: usize = 30;
+
+/// # Points of the search grid per reference point
+const GRID_DENSITY: usize = 64;
+
+/// # Relative shift of the initial reference
+const REFERENCE_SHIFT: f64 = 0.01;
+
+/// # Golden section steps refining each extremum
+const GOLDEN_STEPS: usize = 40;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Minimax approximation
+///
+/// Rational function `p(x) / q(x)` approximating a function on `[lower, upper]`, stored as Chebyshev series
+/// of the variable `t = (2x - lower - upper) / (upper - lower)`, the denominator being normalized by its first
+/// coefficient. `error` is the largest error reached on the interval, relative for `remez_relative`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Minimax {
+    /// Lower bound of the interval
+    pub lower: f64,
+    /// Upper bound of the interval
+    pub upper: f64,
+    /// Largest error over the interval
+    pub error: f64,
+    /// Chebyshev coefficients of the numerator
+    num: Vec<f64>,
+    /// Chebyshev coefficients of the denominator
+    den: Vec<f64>
+}
+
+/// Implementing required methods
+impl Minimax {
+
+    /// # Evaluation
+    ///
+    /// Value of the approximation at `x`, by Clenshaw's recurrence, accurate even for high degrees.
+    pub fn eval(&self, x: f64) -> f64 {
+        let t: f64 = self.reduce(x);
+        clenshaw(&self.num, t) / clenshaw(&self.den, t)
+    }
+
+    /// # Coefficients of the numerator
+    ///
+    /// Coefficients of `p(x) = c_0 + c_1 x + ...`, in increasing powers of `x`, for a fast evaluation by
+    /// Horner's scheme. These coefficients can be ill-conditioned for high degrees, or for intervals far from
+    /// the origin compared to their width, where `eval` should be preferred.
+    ///
+    /// ```
+    /// # use scilib::math::minimax::remez;
+    /// // The best quadratic approximation of x^3 on [-1, 1] is 3x / 4, with the error 1 / 4
+    /// let approx = remez(|x: f64| x.powi(3), -1.0, 1.0, 2, 0);
+    /// let p: Vec<f64> = approx.numerator();
+    ///
+    /// assert!(p[0].abs() < 1.0e-8 && (p[1] - 0.75).abs() < 1.0e-8 && p[2].abs() < 1.0e-8);
+    /// assert_eq!(approx.denominator(), vec![1.0]);
+    /// assert!((approx.error - 0.25).abs() < 1.0e-8);
+    /// ```
+    pub fn numerator(&self) -> Vec<f64> {
+        self.monomials(&self.num)
+    }
+
+    /// # Coefficients of the denominator
+    ///
+    /// Coefficients of `q(x)`, in increasing powers of `x`, matching those of the numerator.
+    pub fn denominator(&self) -> Vec<f64> {
+        self.monomials(&self.den)
+    }
+
+    /// Mapping of `x` to `[-1, 1]`
+    fn reduce(&self, x: f64) -> f64 {
+        (2.0 * x - self.lower - self.upper) / (self.upper - self.lower)
+    }
+
+    /// Monomial coefficients in `x` of a Chebyshev series in `t`
+    fn monomials(&self, coef: &[f64]) -> Vec<f64> {
+
+        // t = alpha x + beta
+        let alpha: f64 = 2.0 / (self.upper - self.lower);
+        let beta: f64 = -(self.upper + self.lower) / (self.upper - self.lower);
+
+        let mut res: Vec<f64> = vec![0.0; coef.len()];
+        let (mut prev, mut curr): (Vec<f64>, Vec<f64>) = (vec![], vec![1.0]);
+        for &c in coef {
+            res.iter_mut().zip(&curr).for_each(|(r, t)| *r += c * t);
+
+            // T_(k+1) = 2 (alpha x + beta) T_k - T_(k-1)
+            let mut next: Vec<f64> = vec![0.0; curr.len() + 1];
+            for (i, &t) in curr.iter().enumerate() {
+                next[i] += 2.0 * beta * t;
+                next[i + 1] += 2.0 * alpha * t;
+            }
+            if prev.is_empty() {
+                next = vec![beta, alpha];
+            } else {
+                next.iter_mut().zip(&prev).for_each(|(n, p)| *n -= p);
+            }
+            (prev, curr) = (curr, next);
+        }
+
+        res
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Minimax approximation, absolute error
+///
+/// Best approximation of `f` on `[lower, upper]` by a rational function with a numerator of degree `m`
+/// and a denominator of degree `n` (`n = 0` for a polynomial), minimizing the largest absolute error. The
+/// function should be continuous, and the degrees suited to it: a denominator vanishing on the interval
+/// shows as an infinite `error`. The rational approximations of the even and odd functions should use the
+/// degrees of the matching parity, the best approximation being degenerate otherwise, which the exchange
+/// may fail to reach.
+///
+/// ```
+/// # use scilib::math::minimax::remez;
+/// // The rational approximation beats the polynomial of the same number of coefficients
+/// let poly = remez(|x: f64| x.exp(), -1.0, 1.0, 4, 0);
+/// let rat = remez(|x: f64| x.exp(), -1.0, 1.0, 2, 2);
+///
+/// assert!((poly.error - 5.466676e-4).abs() < 1.0e-9);
+/// assert!(rat.error < 1.0e-4);
+/// assert!((rat.eval(0.3) - 0.3_f64.exp()).abs() <= rat.error);
+/// ```
+pub fn remez<F>(f: F, lower: f64, upper: f64, m: usize, n: usize) -> Minimax
+where F: Fn(f64) -> f64 {
+    exchange(&f, &|_| 1.0, lower, upper, m, n)
+}
+
+/// # Minimax approximation, relative error
+///
+/// Same as `remez`, minimizing the largest relative error `|r(x) / f(x) - 1|`, as needed for the kernels
+/// of the special functions. The function must not vanish on the interval.
+///
+/// ```
+/// # use scilib::math::minimax::remez_relative;
+/// // Kernel of the Gamma function on [1, 2]
+/// let approx = remez_relative(|x: f64| scilib::math::basic::gamma_complex(x.into()).re, 1.0, 2.0, 5, 0);
+///
+/// assert!(approx.error < 4.0e-5);
+/// assert!((approx.eval(1.5) / 0.886226925452758 - 1.0).abs() <= approx.error);
+/// ```
+pub fn remez_relative<F>(f: F, lower: f64, upper: f64, m: usize, n: usize) -> Minimax
+where F: Fn(f64) -> f64 {
+    exchange(&f, &|x| 1.0 / f(x).abs(), lower, upper, m, n)
+}
+
+/// Remez exchange for the weighted error `w(x) (f(x) - r(x))`
+fn exchange<F, W>(f: &F, w: &W, lower: f64, upper: f64, m: usize, n: usize) -> Minimax
+where F: Fn(f64) -> f64, W: Fn(f64) -> f64 {
+
+    assert!(upper > lower, "The interval must not be empty!");
+
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(REMEZ_PRECISION);             // Convergence tolerance
+    let max_iter: usize = prec.iterations(REMEZ_ITERATIONS);    // Iteration cap
+
+    let size: usize = m + n + 2;
+    let mut res: Minimax = Minimax { lower, upper, error: f64::INFINITY, num: vec![0.0; m + 1], den: vec![1.0] };
+
+    // Initial reference at the extrema of the Chebyshev polynomial of degree m + n + 1, slightly shifted to
+    // break the symmetry, which leaves the levelled error undetermined for the even and odd functions
+    let mut reference: Vec<f64> = (0..size).map(|i| {
+        let t: f64 = -(PI * i as f64 / (size - 1) as f64).cos();
+        0.5 * (lower + upper) + 0.5 * (upper - lower) * (t + REFERENCE_SHIFT * (1.0 - t * t))
+    }).collect();
+
+    let mut best: Minimax = res.clone();
+    for it in 0..max_iter {
+        // A singular levelling system stops the exchange, keeping the best approximation so far
+        (res.num, res.den) = match level(f, w, &res, &reference, m, n) {
+            Some(coef) => coef,
+            None => break
+        };
+
+        // Alternating extrema of the new error, the reference points completing them if needed
+        let err = |x: f64| w(x) * (f(x) - res.eval(x));
+        let extrema: Vec<(f64, f64)> = alternation(&err, lower, upper, &reference);
+        let largest: f64 = extrema.iter().map(|e| e.1.abs()).fold(0.0, f64::max);
+        let smallest: f64 = extrema.iter().map(|e| e.1.abs()).fold(f64::INFINITY, f64::min);
+        res.error = largest;
+        if largest < best.error {
+            best = res.clone();
+        }
+
+        // Converged once levelled, or stalled at the rounding errors when the error grows again
+        let residual: f64 = if largest > 0.0 { (largest - smallest) / largest } else { 0.0 };
+        diagnostics::step("minimax::remez", it, residual, largest);
+
+        if residual < tol || extrema.len() < size || largest > 2.0 * best.error || it + 1 == max_iter {
+            diagnostics::finish("minimax::remez", it + 1, residual, tol);
+            break;
+        }
+        reference = extrema.iter().map(|e| e.0).collect();
+    }
+
+    best
+}
+
+/// Coefficients levelling the weighted error over the reference, iterating on the denominator for the
+/// rational approximations, `None` if the system is singular
+fn level<F, W>(f: &F, w: &W, prev: &Minimax, reference: &[f64], m: usize, n: usize) -> Option<(Vec<f64>, Vec<f64>)>
+where F: Fn(f64) -> f64, W: Fn(f64) -> f64 {
+
+    let size: usize = m + n + 2;
+    let mut approx: Minimax = prev.clone();
+    let mut level: f64 = f64::INFINITY;
+
+    for _ in 0..LEVEL_ITERATIONS {
+        // p(x_i) - f_i (q(x_i) - 1) - s_i E q_prev(x_i) / w_i = f_i, q having the first coefficient 1
+        let mat: Vec<Vec<f64>> = reference.iter().enumerate().map(|(i, &x)| {
+            let t: f64 = approx.reduce(x);
+            let cheb: Vec<f64> = chebyshev(t, m.max(n) + 1);
+            let sign: f64 = if i % 2 == 0 { 1.0 } else { -1.0 };
+
+            let mut row: Vec<f64> = cheb[..=m].to_vec();
+            row.extend(cheb[1..=n].iter().map(|c| -f(x) * c));
+            row.push(-sign * clenshaw(&approx.den, t) / w(x));
+            row.push(f(x));
+            row
+        }).collect();
+
+        let sol: Vec<f64> = solve(mat)?;
+        approx.num = sol[..=m].to_vec();
+        approx.den = std::iter::once(1.0).chain(sol[(m + 1)..(size - 1)].iter().copied()).collect();
+
+        let new_level: f64 = sol[size - 1];
+        if n == 0 || (new_level - level).abs() <= REMEZ_PRECISION * new_level.abs() {
+            break;
+        }
+        level = new_level;
+    }
+
+    Some((approx.num, approx.den))
+}
+
+/// Extrema of the error with alternating signs, among the local extrema and the reference points, keeping
+/// as many as the reference
+fn alternation<E>(err: &E, lower: f64, upper: f64, reference: &[f64]) -> Vec<(f64, f64)>
+where E: Fn(f64) -> f64 {
+
+    let size: usize = reference.len();
+
+    // Chebyshev distributed grid, denser near the ends
+    let points: usize = GRID_DENSITY * size;
+    let grid: Vec<f64> = (0..points)
+        .map(|i| 0.5 * (lower + upper) - 0.5 * (upper - lower) * (PI * i as f64 / (points - 1) as f64).cos())
+        .collect();
+    let values: Vec<f64> = grid.iter().map(|&x| err(x)).collect();
+
+    // Local extrema of |err|, refined by golden section search between the neighbors
+    let mut extrema: Vec<(f64, f64)> = Vec::new();
+    for i in 0..points {
+        let left: f64 = if i == 0 { 0.0 } else { values[i - 1].abs() };
+        let right: f64 = if i + 1 == points { 0.0 } else { values[i + 1].abs() };
+        if values[i].abs() < left || values[i].abs() < right || values[i] == 0.0 {
+            continue;
+        }
+
+        let x: f64 = if i == 0 || i + 1 == points {
+            grid[i]
+        } else {
+            golden(|x| values[i].signum() * err(x), grid[i - 1], grid[i + 1])
+        };
+        extrema.push((x, err(x)));
+    }
+    extrema.extend(reference.iter().map(|&x| (x, err(x))));
+    extrema.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Keeping the largest of consecutive extrema of the same sign
+    let mut res: Vec<(f64, f64)> = Vec::new();
+    for e in extrema {
+        match res.last_mut() {
+            Some(last) if last.1.signum() == e.1.signum() => {
+                if e.1.abs() > last.1.abs() {
+                    *last = e;
+                }
+            },
+            _ => res.push(e)
+        }
+    }
+
+    // Dropping the smaller extremum at the ends, which preserves the alternation
+    while res.len() > size {
+        if res[0].1.abs() < res[res.len() - 1].1.abs() {
+            res.remove(0);
+        } else {
+            res.pop();
+        }
+    }
+
+    res
+}
+
+/// Maximum of a unimodal function on `[a, b]`, by golden section search
+fn golden<G>(g: G, mut a: f64, mut b: f64) -> f64
+where G: Fn(f64) -> f64 {
+
+    let ratio: f64 = 0.5 * (5.0_f64.sqrt() - 1.0);
+    let (mut c, mut d): (f64, f64) = (b - ratio * (b - a), a + ratio * (b - a));
+    let (mut gc, mut gd): (f64, f64) = (g(c), g(d));
+
+    for _ in 0..GOLDEN_STEPS {
+        if gc > gd {
+            (b, d, gd) = (d, c, gc);
+            c = b - ratio * (b - a);
+            gc = g(c);
+        } else {
+            (a, c, gc) = (c, d, gd);
+            d = a + ratio * (b - a);
+            gd = g(d);
+        }
+    }
+
+    if gc > gd { c } else { d }
+}
+
+/// Chebyshev polynomials `T_0(t)` to `T_(k-1)(t)`
+fn chebyshev(t: f64, k: usize) -> Vec<f64> {
+    let mut res: Vec<f64> = vec![1.0, t];
+    for j in 2..k {
+        res.push(2.0 * t * res[j - 1] - res[j - 2]);
+    }
+    res.truncate(k);
+    res
+}
+
+/// Sum of a Chebyshev series, by Clenshaw's recurrence
+fn clenshaw(coef: &[f64], t: f64) -> f64 {
+    let (mut b1, mut b2): (f64, f64) = (0.0, 0.0);
+    for &c in coef.iter().skip(1).rev() {
+        (b1, b2) = (2.0 * t * b1 - b2 + c, b1);
+    }
+    coef.first().copied().unwrap_or(0.0) + t * b1 - b2
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
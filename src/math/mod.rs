@@ -6,12 +6,86 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[cfg(feature = "special")]
+pub mod airy;
+
 pub mod basic;
 
+#[cfg(feature = "bessel")]
 pub mod bessel;
 
+pub mod combinatorics;
+
 pub mod complex;
 
+#[cfg(feature = "solvers")]
+pub mod continuation;
+
+#[cfg(feature = "special")]
+pub mod coulomb;
+
+pub mod dual;
+
+#[cfg(feature = "linalg")]
+pub mod eigen;
+
+#[cfg(feature = "pde")]
+pub mod grid;
+
+#[cfg(feature = "special")]
+pub mod harmonics;
+
+#[cfg(feature = "linalg")]
+pub mod hermitian;
+
+#[cfg(feature = "linalg")]
+pub mod krylov;
+
+pub mod laplace;
+
+#[cfg(feature = "special")]
+pub mod lattice;
+
+pub mod logspace;
+
+pub mod measure;
+
+#[cfg(feature = "solvers")]
+pub mod minimax;
+
+pub mod number_theory;
+
+#[cfg(feature = "ode")]
+pub mod ode;
+
+#[cfg(feature = "bessel")]
+pub mod oscillatory;
+
+#[cfg(feature = "pde")]
+pub mod poisson;
+
 pub mod polynomial;
 
+pub mod rational;
+
+#[cfg(feature = "solvers")]
+pub mod roots;
+
+pub mod scalar;
+
+#[cfg(feature = "ode")]
+pub mod sde;
+
+#[cfg(feature = "special")]
+pub mod spheroidal;
+
+pub mod spline;
+
+pub mod summation;
+
+pub mod tables;
+
+#[cfg(feature = "special")]
+pub mod theta;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,434 @@
+//!
+//! # Number theory
+//!
+//! Integer utilities: prime sieve, primality testing, factorization, greatest common divisor and
+//! modular arithmetic. All functions work on unsigned 64-bit integers, intermediate products are done
+//! in 128 bits to avoid overflows.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Bases for which the Miller-Rabin test is deterministic for every 64-bit integer
+const MR_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Primes below this limit are factorized by trial division only
+const TRIAL_LIMIT: u64 = 1_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Sieve of Eratosthenes
+///
+/// `n` is the upper bound (included) of the search.
+///
+/// Returns all the prime numbers lower or equal to `n`, in increasing order.
+///
+/// ```
+/// # use scilib::math::number_theory::sieve;
+/// let primes: Vec<usize> = sieve(30);
+///
+/// assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// assert_eq!(sieve(1_000_000).len(), 78_498);
+/// ```
+pub fn sieve(n: usize) -> Vec<usize> {
+
+    if n < 2 {
+        return vec![];
+    }
+
+    // Marking all numbers as potential primes
+    let mut is_prime: Vec<bool> = vec![true; n + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    let mut p: usize = 2;
+    while p * p <= n {
+        if is_prime[p] {
+            // Crossing all the multiples of p, starting at p²
+            for mult in (p * p..=n).step_by(p) {
+                is_prime[mult] = false;
+            }
+        }
+        p += 1;
+    }
+
+    is_prime.iter().enumerate().filter(|(_, &v)| v).map(|(i, _)| i).collect()
+}
+
+/// # Primality test
+///
+/// `n` is the number to test.
+///
+/// Uses the Miller-Rabin test with a set of bases making it deterministic over the whole `u64` range.
+///
+/// ```
+/// # use scilib::math::number_theory::is_prime;
+/// assert!(is_prime(2));
+/// assert!(is_prime(1_000_000_007));
+/// assert!(is_prime(18_446_744_073_709_551_557));  // Largest 64-bit prime
+/// assert!(!is_prime(1));
+/// assert!(!is_prime(561));                        // Carmichael number
+/// assert!(!is_prime(3_215_031_751));              // Strong pseudoprime to bases 2, 3, 5, 7
+/// ```
+pub fn is_prime(n: u64) -> bool {
+
+    if n < 2 {
+        return false;
+    }
+
+    // Dealing with small primes and their multiples directly
+    for p in MR_BASES {
+        if n == p {
+            return true;
+        }
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+
+    // Writing n - 1 = d * 2^s
+    let s: u32 = (n - 1).trailing_zeros();
+    let d: u64 = (n - 1) >> s;
+
+    'witness: for a in MR_BASES {
+        let mut x: u64 = mod_pow(a, d, n);
+
+        if x == 1 || x == n - 1 {
+            continue 'witness;
+        }
+
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+
+        // a is a witness of the compositeness of n
+        return false;
+    }
+
+    true
+}
+
+/// # Prime factorization
+///
+/// `n` is the number to factorize.
+///
+/// Returns the prime factors and their multiplicity, sorted by increasing factor. Small factors are
+/// removed by trial division, the remaining ones are found with Pollard's rho algorithm (Brent's variant).
+///
+/// ```
+/// # use scilib::math::number_theory::factorize;
+/// assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(1), vec![]);
+///
+/// // Product of two large primes
+/// let f = factorize(1_000_000_007 * 998_244_353);
+/// assert_eq!(f, vec![(998_244_353, 1), (1_000_000_007, 1)]);
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+
+    let mut factors: Vec<u64> = Vec::new();
+    let mut rem: u64 = n;
+
+    // Trial division for the small factors
+    let mut p: u64 = 2;
+    while p < TRIAL_LIMIT && p * p <= rem {
+        while rem.is_multiple_of(p) {
+            factors.push(p);
+            rem /= p;
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+
+    // Splitting what remains
+    if rem > 1 {
+        let mut stack: Vec<u64> = vec![rem];
+
+        while let Some(val) = stack.pop() {
+            if val == 1 {
+                continue;
+            } else if is_prime(val) {
+                factors.push(val);
+            } else {
+                let d: u64 = pollard_rho(val);
+                stack.push(d);
+                stack.push(val / d);
+            }
+        }
+    }
+
+    factors.sort_unstable();
+
+    // Grouping identical factors
+    let mut res: Vec<(u64, u32)> = Vec::new();
+    for f in factors {
+        match res.last_mut() {
+            Some((last, count)) if *last == f => *count += 1,
+            _ => res.push((f, 1))
+        }
+    }
+
+    res
+}
+
+/// Finds a non-trivial divisor of a composite number, using Brent's cycle detection
+fn pollard_rho(n: u64) -> u64 {
+
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+
+    // Iterating over the constant of the polynomial until a divisor is found
+    for c in 1..n {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+
+        let mut y: u64 = 2;
+        let mut r: u64 = 1;
+        let mut q: u64 = 1;
+        let mut g: u64 = 1;
+        let mut x: u64 = y;
+        let mut ys: u64 = y;
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+
+            let mut k: u64 = 0;
+            while k < r && g == 1 {
+                ys = y;
+                // Batching the products to reduce the number of gcd calls
+                for _ in 0..(r - k).min(128) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += 128;
+            }
+            r *= 2;
+        }
+
+        // If the batch overshot, we backtrack step by step
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+
+    n
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Greatest common divisor
+///
+/// Computed with the Euclidean algorithm. By convention, `gcd(0, 0) = 0`.
+///
+/// ```
+/// # use scilib::math::number_theory::gcd;
+/// assert_eq!(gcd(48, 18), 6);
+/// assert_eq!(gcd(17, 5), 1);
+/// assert_eq!(gcd(0, 9), 9);
+/// ```
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut x, mut y): (u64, u64) = (a, b);
+
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    x
+}
+
+/// # Least common multiple
+///
+/// By convention, the result is 0 if either number is 0.
+///
+/// ```
+/// # use scilib::math::number_theory::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(21, 6), 42);
+/// assert_eq!(lcm(0, 3), 0);
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// # Extended Euclidean algorithm
+///
+/// Returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the greatest common divisor of `a` and `b`.
+///
+/// The whole `i64` range is accepted, except for the pairs made of `i64::MIN` and `0` or `i64::MIN`, whose gcd
+/// `2^63` doesn't fit: these panic.
+///
+/// ```
+/// # use scilib::math::number_theory::extended_gcd;
+/// let (g, x, y) = extended_gcd(240, 46);
+///
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, g);
+/// assert_eq!(extended_gcd(i64::MIN, 1), (1, 0, 1));
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+
+    // The intermediate coefficients can exceed i64 for the extreme values
+    let (g, x, y): (i128, i128, i128) = extended_gcd_i128(a as i128, b as i128);
+    let (g, x, y): (i128, i128, i128) = if g < 0 { (-g, -x, -y) } else { (g, x, y) };
+
+    assert!(g <= i64::MAX as i128, "The gcd of i64::MIN and {} exceeds i64", if a == i64::MIN { b } else { a });
+    (g as i64, x as i64, y as i64)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Modular multiplication
+///
+/// Computes `a * b mod m` without overflow.
+///
+/// ```
+/// # use scilib::math::number_theory::mul_mod;
+/// assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+/// ```
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// # Modular exponentiation
+///
+/// Computes `base^exp mod m` by binary exponentiation.
+///
+/// ```
+/// # use scilib::math::number_theory::mod_pow;
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(2, 0, 7), 1);
+/// ```
+pub fn mod_pow(base: u64, exp: u64, m: u64) -> u64 {
+
+    if m == 1 {
+        return 0;
+    }
+
+    let mut res: u64 = 1;
+    let mut b: u64 = base % m;
+    let mut e: u64 = exp;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mul_mod(res, b, m);
+        }
+        b = mul_mod(b, b, m);
+        e >>= 1;
+    }
+
+    res
+}
+
+/// # Modular inverse
+///
+/// Returns `x` such that `a * x = 1 mod m`, if it exists (that is when `a` and `m` are coprime).
+///
+/// ```
+/// # use scilib::math::number_theory::mod_inverse;
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(6, 9), None);
+/// ```
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+
+    let (g, x, _) = extended_gcd_i128(a as i128, m as i128);
+
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+/// # Chinese remainder theorem
+///
+/// `residues` and `moduli` describe the system `x = residues[i] mod moduli[i]`.
+///
+/// Returns `(x, m)` where `m` is the least common multiple of the moduli and `x` the unique solution
+/// in `[0, m)`. The moduli do not have to be coprime, `None` is returned when the system has no solution
+/// (or when `m` overflows, or a modulus is zero).
+///
+/// ```
+/// # use scilib::math::number_theory::crt;
+/// assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+///
+/// // Non coprime moduli
+/// assert_eq!(crt(&[3, 5], &[4, 6]), Some((11, 12)));
+/// assert_eq!(crt(&[1, 2], &[4, 6]), None);
+///
+/// // Overflowing and invalid moduli
+/// assert_eq!(crt(&[0, 0], &[u64::MAX, u64::MAX - 1]), None);
+/// assert_eq!(crt(&[1, 1], &[3, 0]), None);
+/// ```
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Option<(u64, u64)> {
+
+    assert_eq!(residues.len(), moduli.len(), "Residues and moduli must have the same length");
+
+    let mut x: u64 = 0;
+    let mut m: u64 = 1;
+
+    for (&r, &n) in residues.iter().zip(moduli) {
+        if n == 0 {
+            return None;
+        }
+
+        // Solving x + m * k = r mod n
+        let (g, p, _) = extended_gcd_i128(m as i128, n as i128);
+        let diff: i128 = (r % n) as i128 - (x % n) as i128;
+        if diff % g != 0 {
+            return None;
+        }
+
+        // The new modulus must fit before the step is taken
+        let step: u64 = (n as i128 / g) as u64;
+        let lcm: u128 = m as u128 * step as u128;
+        if lcm > u64::MAX as u128 {
+            return None;
+        }
+
+        let (d, p): (u64, u64) = ((diff / g).rem_euclid(step as i128) as u64, p.rem_euclid(step as i128) as u64);
+        let k: u64 = mul_mod(d, p, step);
+
+        x = ((x as u128 + m as u128 * k as u128) % lcm) as u64;
+        m = lcm as u64;
+    }
+
+    Some((x, m))
+}
+
+/// Extended Euclidean algorithm on wider integers, to handle the full `u64` range
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+
+    let (mut old_r, mut r): (i128, i128) = (a, b);
+    let (mut old_s, mut s): (i128, i128) = (1, 0);
+    let (mut old_t, mut t): (i128, i128) = (0, 1);
+
+    while r != 0 {
+        let q: i128 = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+
+    (old_r, old_s, old_t)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,366 @@
+//!
+//! # Ordinary differential equations
+//!
+//! Integrators for initial value problems `y' = f(t, y)`, with `y` in `R^n`.
+//!
+//! The Gragg-Bulirsch-Stoer method takes large steps, each one computed with the modified midpoint rule
+//! for an increasing number of substeps, and extrapolated to a vanishing substep (Richardson extrapolation
+//! in `h^2`). Both the step and the order of the extrapolation adapt to the problem. It excels on smooth
+//! problems solved to a high accuracy, such as long-term orbit integrations, where low order methods need
+//! many tiny steps.
+//!
+//! Delay differential equations `y'(t) = f(t, y(t), y(t - tau_1), ...)`, with constant delays, are solved by
+//! the method of steps: an embedded Runge-Kutta method with a dense output, the delayed values being
+//! interpolated in the steps already taken, or given by the history before the initial time.
+//!
+//! ```
+//! # use scilib::math::ode::bulirsch_stoer;
+//! // Harmonic oscillator, over 50 periods
+//! let f = |_t: f64, y: &[f64]| vec![y[1], -y[0]];
+//! let sol = bulirsch_stoer(f, 0.0, &[1.0, 0.0], 100.0 * std::f64::consts::PI, 1.0e-12);
+//!
+//! let end: &Vec<f64> = sol.y.last().unwrap();
+//! assert!((end[0] - 1.0).abs() < 1.0e-9 && end[1].abs() < 1.0e-9);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Largest number of columns of the extrapolation table
+const MAX_COLUMNS: usize = 9;
+
+/// # Number of columns used at first
+const FIRST_COLUMNS: usize = 5;
+
+/// # Step cap of the integrators
+const MAX_STEPS: usize = 100_000;
+
+/// # Multiples of the delays kept as breakpoints of the delay equations
+const BREAKPOINT_ORDER: usize = 3;
+
+/// # Iteration cap of the stages of the steps longer than a delay
+const DELAY_ITERATIONS: usize = 10;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Solution of an initial value problem
+///
+/// Values `y[i]` of the solution at the times `t[i]` reached by the integrator, starting with the initial
+/// condition.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OdeSolution {
+    /// Times of the steps
+    pub t: Vec<f64>,
+    /// State at each time
+    pub y: Vec<Vec<f64>>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Gragg-Bulirsch-Stoer integrator
+///
+/// Integrates `y' = f(t, y)` from `y0` at `t0` up to `t_end` (which may lie before `t0`). Each component
+/// is kept within `tol (1 + |y_i|)` of the exact step, so that `tol` is a relative tolerance for large
+/// components, and an absolute one for small components. The substeps follow the sequence `2, 4, 6, ...`,
+/// with up to 9 columns in the extrapolation table (order 18), and the integration stops after a number
+/// of steps set by the `Precision` policy.
+///
+/// ```
+/// # use scilib::math::ode::bulirsch_stoer;
+/// // Eccentric Kepler orbit (e = 0.6, GM = 1), over 20 revolutions
+/// let f = |_t: f64, y: &[f64]| {
+///     let r3: f64 = y[0].hypot(y[1]).powi(3);
+///     vec![y[2], y[3], -y[0] / r3, -y[1] / r3]
+/// };
+/// let y0 = [0.4, 0.0, 0.0, 2.0];
+/// let sol = bulirsch_stoer(f, 0.0, &y0, 40.0 * std::f64::consts::PI, 1.0e-13);
+///
+/// // Back at the periapsis, with the energy conserved
+/// let end: &Vec<f64> = sol.y.last().unwrap();
+/// assert!((end[0] - 0.4).abs() < 1.0e-8 && end[1].abs() < 1.0e-7);
+/// let energy: f64 = 0.5 * (end[2] * end[2] + end[3] * end[3]) - 1.0 / end[0].hypot(end[1]);
+/// assert!((energy - -0.5).abs() < 1.0e-10);
+///
+/// // Backward integration of y' = y
+/// let sol = bulirsch_stoer(|_t: f64, y: &[f64]| vec![y[0]], 1.0, &[1.0], 0.0, 1.0e-12);
+/// assert!((sol.y.last().unwrap()[0] - (-1.0_f64).exp()).abs() < 1.0e-12);
+/// ```
+pub fn bulirsch_stoer<F>(f: F, t0: f64, y0: &[f64], t_end: f64, tol: f64) -> OdeSolution
+where F: Fn(f64, &[f64]) -> Vec<f64> {
+
+    let max_iter: usize = Precision::current().iterations(MAX_STEPS);  // Step cap
+
+    // Substeps, and the cumulated number of evaluations of f to build each column
+    let substeps: Vec<usize> = (1..=MAX_COLUMNS).map(|j| 2 * j).collect();
+    let work: Vec<f64> = substeps.iter().scan(1.0, |acc, &n| { *acc += n as f64; Some(*acc) }).collect();
+
+    let mut sol: OdeSolution = OdeSolution { t: vec![t0], y: vec![y0.to_vec()] };
+    let span: f64 = t_end - t0;
+    let mut h: f64 = 0.1 * span;
+    let mut columns: usize = FIRST_COLUMNS;
+    let (mut t, mut y): (f64, Vec<f64>) = (t0, y0.to_vec());
+    let mut err: f64 = 0.0;
+
+    for step in 0..max_iter {
+        if (t_end - t) * span.signum() <= 0.0 {
+            break;
+        }
+        let last: bool = (t + h - t_end) * span.signum() >= 0.0;
+        if last {
+            h = t_end - t;
+        }
+
+        let mut table: Vec<Vec<Vec<f64>>> = Vec::with_capacity(MAX_COLUMNS);
+        let mut h_opt: Vec<f64> = vec![h; MAX_COLUMNS];
+        let mut cost: Vec<f64> = vec![f64::INFINITY; MAX_COLUMNS];
+        let mut accepted: Option<usize> = None;
+
+        let top: usize = (columns + 1).min(MAX_COLUMNS);
+        for j in 0..top {
+            // New row of the table, from the midpoint rule and Neville's extrapolation in h^2
+            let mut row: Vec<Vec<f64>> = vec![midpoint(&f, t, &y, h, substeps[j])];
+            for k in 1..=j {
+                let ratio: f64 = (substeps[j] as f64 / substeps[j - k] as f64).powi(2) - 1.0;
+                let next: Vec<f64> = row[k - 1].iter().zip(&table[j - 1][k - 1])
+                    .map(|(a, b)| a + (a - b) / ratio).collect();
+                row.push(next);
+            }
+            table.push(row);
+
+            if j == 0 {
+                continue;
+            }
+
+            // Scaled difference of the last two orders, and optimal step for this order
+            let (high, low): (&Vec<f64>, &Vec<f64>) = (&table[j][j], &table[j][j - 1]);
+            err = (high.iter().zip(low).zip(&y)
+                .map(|((a, b), c)| ((a - b) / (tol * (1.0 + a.abs().max(c.abs())))).powi(2))
+                .sum::<f64>() / y.len().max(1) as f64).sqrt();
+            let factor: f64 = (0.94 * (0.65 / err).powf(1.0 / (2 * j + 1) as f64)).clamp(0.1, 4.0);
+            h_opt[j] = h * if factor.is_finite() { factor } else { 4.0 };
+            cost[j] = work[j] / h_opt[j].abs();
+
+            if err <= 1.0 && j + 2 >= columns {
+                accepted = Some(j);
+                break;
+            }
+        }
+
+        diagnostics::step("ode::bulirsch_stoer", step, err, h.abs());
+        match accepted {
+            Some(j) => {
+                t = if last { t_end } else { t + h };
+                y = table[j][j].clone();
+                sol.t.push(t);
+                sol.y.push(y.clone());
+
+                // Next order, from the work per unit step of the neighbouring orders
+                if j >= 2 && cost[j - 1] < 0.8 * cost[j] {
+                    columns = j;
+                    h = h_opt[j - 1];
+                } else if cost[j] < 0.9 * cost[j - 1] && j + 1 < MAX_COLUMNS {
+                    columns = j + 2;
+                    h = h_opt[j] * work[j + 1] / work[j];
+                } else {
+                    columns = j + 1;
+                    h = h_opt[j];
+                }
+            },
+            None => h = h_opt[top - 1].abs().min(0.5 * h.abs()) * h.signum()
+        }
+    }
+    diagnostics::finish("ode::bulirsch_stoer", sol.t.len(), err, tol);
+
+    sol
+}
+
+/// Modified midpoint rule over the step `h`, with `n` substeps
+fn midpoint<F>(f: &F, t: f64, y: &[f64], h: f64, n: usize) -> Vec<f64>
+where F: Fn(f64, &[f64]) -> Vec<f64> {
+
+    let dt: f64 = h / n as f64;
+    let mut prev: Vec<f64> = y.to_vec();
+    let mut curr: Vec<f64> = y.iter().zip(f(t, y)).map(|(a, d)| a + dt * d).collect();
+
+    for m in 1..n {
+        let next: Vec<f64> = prev.iter().zip(f(t + m as f64 * dt, &curr)).map(|(a, d)| a + 2.0 * dt * d).collect();
+        prev = curr;
+        curr = next;
+    }
+
+    let slope: Vec<f64> = f(t + h, &curr);
+    curr.iter().zip(&prev).zip(slope).map(|((c, p), d)| 0.5 * (c + p + dt * d)).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Delay differential equation integrator
+///
+/// Integrates `y'(t) = f(t, y(t), z)` from `t0` up to `t_end > t0`, where `z[i] = y(t - delays[i])` for the
+/// positive constant `delays`, and `y(t) = history(t)` for `t <= t0`. The steps come from the Bogacki-Shampine
+/// 3(2) pair, each component being kept within `tol (1 + |y_i|)` of the exact step. The delayed values
+/// are interpolated by cubic Hermite polynomials in the steps already taken. When a step is longer than a
+/// delay, some of them fall within the step itself: its stages are then iterated, the delayed values being
+/// taken from the dense output of the previous iterate. The steps also stop at the multiples of the delays
+/// from `t0`, where the derivatives of the solution jump. The integration stops after a number of steps set
+/// by the `Precision` policy.
+///
+/// Panics if a delay is not finite and positive.
+///
+/// ```
+/// # use scilib::math::ode::dde;
+/// // y'(t) = -y(t - 1), with y = 1 before 0, is a polynomial on each interval
+/// let sol = dde(|_t: f64, _y: &[f64], z: &[Vec<f64>]| vec![-z[0][0]], |_t: f64| vec![1.0], &[1.0], 0.0, 3.0, 1.0e-10);
+///
+/// let end: &Vec<f64> = sol.y.last().unwrap();
+/// assert!((end[0] - -1.0 / 6.0).abs() < 1.0e-9);
+/// assert!(sol.t.iter().any(|&t| t == 2.0));
+///
+/// // Hutchinson's delayed logistic equation, oscillating for r tau > pi / 2
+/// let f = |_t: f64, y: &[f64], z: &[Vec<f64>]| vec![1.8 * y[0] * (1.0 - z[0][0])];
+/// let sol = dde(f, |_t: f64| vec![0.5], &[1.0], 0.0, 40.0, 1.0e-8);
+/// let late: Vec<f64> = sol.t.iter().zip(&sol.y).filter(|(t, _)| **t > 20.0).map(|(_, y)| y[0]).collect();
+/// assert!(late.iter().cloned().fold(0.0, f64::max) > 1.5);
+/// assert!(late.iter().cloned().fold(1.0, f64::min) < 0.5);
+///
+/// // A tiny delay does not limit the steps
+/// let sol = dde(|_t: f64, _y: &[f64], z: &[Vec<f64>]| vec![-z[0][0]], |_t: f64| vec![1.0], &[1.0e-9], 0.0, 1.0, 1.0e-10);
+/// assert_eq!(*sol.t.last().unwrap(), 1.0);
+/// assert!((sol.y.last().unwrap()[0] - (-1.0_f64).exp()).abs() < 1.0e-8);
+/// ```
+pub fn dde<F, H>(f: F, history: H, delays: &[f64], t0: f64, t_end: f64, tol: f64) -> OdeSolution
+where F: Fn(f64, &[f64], &[Vec<f64>]) -> Vec<f64>, H: Fn(f64) -> Vec<f64> {
+
+    assert!(delays.iter().all(|&d| d > 0.0 && d.is_finite()), "The delays must be finite and positive");
+
+    let max_iter: usize = Precision::current().iterations(MAX_STEPS);  // Step cap
+    let min_delay: f64 = delays.iter().cloned().fold(f64::INFINITY, f64::min);
+
+    // Breakpoints, where the low derivatives of the solution jump
+    let mut breaks: Vec<f64> = delays.iter()
+        .flat_map(|&d| (1..=BREAKPOINT_ORDER).map(move |m| t0 + m as f64 * d))
+        .filter(|&b| b < t_end).collect();
+    breaks.sort_by(f64::total_cmp);
+    breaks.dedup();
+
+    let mut sol: OdeSolution = OdeSolution { t: vec![t0], y: vec![history(t0)] };
+    let mut slopes: Vec<Vec<f64>> = Vec::new();
+
+    // Right-hand side, with the delayed values from the history or the dense output
+    let rhs = |sol: &OdeSolution, slopes: &[Vec<f64>], t: f64, y: &[f64]| -> Vec<f64> {
+        let z: Vec<Vec<f64>> = delays.iter().map(|d| delayed(sol, slopes, &history, t - d)).collect();
+        f(t, y, &z)
+    };
+
+    let mut k_1: Vec<f64> = rhs(&sol, &slopes, t0, &sol.y[0]);
+    slopes.push(k_1.clone());
+
+    let mut h: f64 = 0.01 * (t_end - t0);
+    let mut err: f64 = 0.0;
+    let mut next_break: usize = 0;
+
+    for step in 0..max_iter {
+        let (t, y): (f64, Vec<f64>) = (sol.t[sol.t.len() - 1], sol.y[sol.y.len() - 1].clone());
+        if t >= t_end {
+            break;
+        }
+
+        // Landing on the next breakpoint, or on the end
+        while next_break < breaks.len() && breaks[next_break] <= t {
+            next_break += 1;
+        }
+        let target: f64 = if next_break < breaks.len() { breaks[next_break] } else { t_end };
+        let landing: bool = t + h >= target;
+        let h_step: f64 = if landing { target - t } else { h };
+
+        let t_new: f64 = if landing { target } else { t + h_step };
+        let stage = |c: f64, k: &[f64]| -> Vec<f64> { y.iter().zip(k).map(|(a, d)| a + c * h_step * d).collect() };
+        let attempt = |sol: &OdeSolution, slopes: &[Vec<f64>]| {
+            let k_2: Vec<f64> = rhs(sol, slopes, t + 0.5 * h_step, &stage(0.5, &k_1));
+            let k_3: Vec<f64> = rhs(sol, slopes, t + 0.75 * h_step, &stage(0.75, &k_2));
+            let y_new: Vec<f64> = (0..y.len())
+                .map(|i| y[i] + h_step * (2.0 * k_1[i] + 3.0 * k_2[i] + 4.0 * k_3[i]) / 9.0).collect();
+            let k_4: Vec<f64> = rhs(sol, slopes, t_new, &y_new);
+            (k_2, k_3, y_new, k_4)
+        };
+
+        // The first attempt extrapolates the last step, then the step is iterated on its own dense output
+        let (mut k_2, mut k_3, mut y_new, mut k_4) = attempt(&sol, &slopes);
+        let mut settled: bool = h_step <= min_delay;
+        if !settled {
+            for _ in 0..DELAY_ITERATIONS {
+                sol.t.push(t_new);
+                sol.y.push(y_new.clone());
+                slopes.push(k_4.clone());
+                let next = attempt(&sol, &slopes);
+                sol.t.pop();
+                sol.y.pop();
+                slopes.pop();
+
+                let change: f64 = (0..y.len()).map(|i| {
+                    (next.2[i] - y_new[i]).abs() / (tol * (1.0 + y_new[i].abs()))
+                }).fold(0.0, f64::max);
+                (k_2, k_3, y_new, k_4) = next;
+                if change <= 0.01 {
+                    settled = true;
+                    break;
+                }
+            }
+        }
+
+        err = ((0..y.len()).map(|i| {
+            let e: f64 = h_step * (-5.0 * k_1[i] / 72.0 + k_2[i] / 12.0 + k_3[i] / 9.0 - k_4[i] / 8.0);
+            (e / (tol * (1.0 + y[i].abs().max(y_new[i].abs())))).powi(2)
+        }).sum::<f64>() / y.len().max(1) as f64).sqrt();
+        // Iterations failing to settle reject the step
+        if !settled {
+            err = f64::INFINITY;
+        }
+        diagnostics::step("ode::dde", step, err, h_step);
+
+        let factor: f64 = (0.9 * err.powf(-1.0 / 3.0)).clamp(0.2, 5.0);
+        if err <= 1.0 {
+            sol.t.push(t_new);
+            sol.y.push(y_new);
+            slopes.push(k_4.clone());
+            k_1 = k_4;
+        }
+        h = h_step * if factor.is_finite() { factor } else { 5.0 };
+    }
+    diagnostics::finish("ode::dde", sol.t.len(), err, tol);
+
+    sol
+}
+
+/// Solution at `t`, from the history before the first step, or the cubic Hermite interpolation of the steps
+fn delayed<H>(sol: &OdeSolution, slopes: &[Vec<f64>], history: &H, t: f64) -> Vec<f64>
+where H: Fn(f64) -> Vec<f64> {
+
+    if t <= sol.t[0] {
+        return history(t);
+    }
+
+    // Interval of the steps, the last one being used for the (short) extrapolations of the stages
+    let last: usize = slopes.len() - 1;
+    if last == 0 {
+        return sol.y[0].iter().zip(&slopes[0]).map(|(y, d)| y + (t - sol.t[0]) * d).collect();
+    }
+    let i: usize = sol.t[..=last].partition_point(|&s| s <= t).clamp(1, last) - 1;
+
+    let h: f64 = sol.t[i + 1] - sol.t[i];
+    let s: f64 = (t - sol.t[i]) / h;
+    let (h00, h10): (f64, f64) = (2.0 * s * s * s - 3.0 * s * s + 1.0, s * s * s - 2.0 * s * s + s);
+    let (h01, h11): (f64, f64) = (-2.0 * s * s * s + 3.0 * s * s, s * s * s - s * s);
+
+    (0..sol.y[i].len()).map(|j| {
+        h00 * sol.y[i][j] + h * h10 * slopes[i][j] + h01 * sol.y[i + 1][j] + h * h11 * slopes[i + 1][j]
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,288 @@
+//!
+//! # Oscillatory integrals
+//!
+//! Quadrature of integrals of a smooth function `f` against a rapidly oscillating kernel, `exp(i w x)` or a
+//! Bessel function `J_n(w x)`. General quadrature rules need several points per period, and become costly
+//! and inaccurate as `w` grows. The methods of this module only sample `f`, and get more accurate as the
+//! frequency grows.
+//!
+//! - Filon's method interpolates `f` by piecewise parabolas, and integrates them exactly against the
+//!   exponential. It needs a small number of points per panel of `f`, independently of `w`.
+//! - Levin's method looks for an antiderivative of the form `p(x) exp(i w x)` (or `p . (J_n, J_n+1)` for
+//!   the Bessel kernel), with a non-oscillatory `p` found by Chebyshev collocation of the differential
+//!   equation `p' + i w p = f`. The integral then only involves the values at the bounds.
+//!
+//! Both methods degrade when `w (b - a)` is small, where the integrand does not oscillate and an ordinary
+//! quadrature is preferable.
+//!
+//! ```
+//! # use scilib::math::oscillatory::{ filon_fourier, levin_fourier };
+//! // Integral of x^2 exp(1000 i x) over [0, 1]
+//! let f = |x: f64| x * x;
+//! let filon = filon_fourier(f, 0.0, 1.0, 1000.0, 200);
+//! let levin = levin_fourier(f, 0.0, 1.0, 1000.0, 8);
+//!
+//! assert!((levin.re - 0.0008280026449255029).abs() < 1.0e-15 && (levin.im - -0.0005607261924514864).abs() < 1.0e-15);
+//! assert!((filon - levin).modulus() < 1.0e-9);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use super::{                    // Using parts from the crate
+    bessel,                     // Bessel kernels
+    complex::Complex            // Using Complex numbers
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Below this value of `w h`, Filon's coefficients use their Taylor expansions
+const FILON_SMALL: f64 = 1.0 / 6.0;
+
+/// Number of points of the Gauss-Legendre rule near the origin of the Bessel integrals
+const GAUSS_POINTS: usize = 32;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Filon's method for Fourier integrals
+///
+/// Integral of `f(x) exp(i omega x)` over `[a, b]`, with `n` panels (rounded up to an even number), that is
+/// `n + 1` evaluations of `f`. The error is that of the piecewise parabolic interpolation of `f`, whatever
+/// the frequency.
+///
+/// ```
+/// # use scilib::math::oscillatory::filon_fourier;
+/// // The cosine and sine integrals of exp(-x) over [0, 2]
+/// let res = filon_fourier(|x| (-x).exp(), 0.0, 2.0, 50.0, 400);
+///
+/// assert!((res.re - -0.0010168568739964970).abs() < 1.0e-10);
+/// assert!((res.im - 0.017686293761053327).abs() < 1.0e-10);
+/// ```
+pub fn filon_fourier<F>(f: F, a: f64, b: f64, omega: f64, n: usize) -> Complex
+where F: Fn(f64) -> f64 {
+
+    let n: usize = n.max(2).div_ceil(2) * 2;
+    let h: f64 = (b - a) / n as f64;
+    let theta: f64 = omega * h;
+
+    let (alpha, beta, gamma): (f64, f64, f64) = if theta.abs() < FILON_SMALL {
+        let t2: f64 = theta * theta;
+        (
+            theta * t2 * (2.0 / 45.0 - t2 * (2.0 / 315.0 - t2 * 2.0 / 4725.0)),
+            2.0 / 3.0 + t2 * (2.0 / 15.0 - t2 * (4.0 / 105.0 - t2 * 2.0 / 567.0)),
+            4.0 / 3.0 - t2 * (2.0 / 15.0 - t2 * (1.0 / 210.0 - t2 / 11340.0))
+        )
+    } else {
+        let (s, c): (f64, f64) = theta.sin_cos();
+        let t3: f64 = theta.powi(3);
+        (
+            (theta * theta + theta * s * c - 2.0 * s * s) / t3,
+            2.0 * (theta * (1.0 + c * c) - 2.0 * s * c) / t3,
+            4.0 * (s - theta * c) / t3
+        )
+    };
+
+    // Sums of the samples times the kernel, over the even and odd points
+    let mut even: Complex = Complex::new();
+    let mut odd: Complex = Complex::new();
+    for j in 0..=n {
+        let x: f64 = a + j as f64 * h;
+        let v: Complex = f(x) * Complex::from_polar(omega * x, 1.0);
+        if j % 2 == 0 { even += v } else { odd += v }
+    }
+
+    let (fa, fb): (f64, f64) = (f(a), f(b));
+    even -= 0.5 * (fa * Complex::from_polar(omega * a, 1.0) + fb * Complex::from_polar(omega * b, 1.0));
+
+    // The boundary term is -i (f(b) exp(i w b) - f(a) exp(i w a))
+    let boundary: Complex = Complex::from(0.0, -1.0) * (fb * Complex::from_polar(omega * b, 1.0) - fa * Complex::from_polar(omega * a, 1.0));
+
+    h * (alpha * boundary + beta * even + gamma * odd)
+}
+
+/// # Levin's method for Fourier integrals
+///
+/// Integral of `f(x) exp(i omega x)` over `[a, b]`, collocating the non-oscillatory part of the
+/// antiderivative on `n` Chebyshev points. The error decreases with both `n` and `omega`; a few points are
+/// enough for smooth functions and large frequencies.
+///
+/// ```
+/// # use scilib::math::oscillatory::levin_fourier;
+/// let res = levin_fourier(|x| 1.0 / (4.0 + x * x), -1.0, 3.0, 200.0, 24);
+///
+/// assert!((res.re - -0.0008563936207552799).abs() < 1.0e-13);
+/// assert!((res.im - 0.0008696399617571620).abs() < 1.0e-13);
+/// ```
+pub fn levin_fourier<F>(f: F, a: f64, b: f64, omega: f64, n: usize) -> Complex
+where F: Fn(f64) -> f64 {
+
+    let n: usize = n.max(2);
+    let scale: f64 = 2.0 / (b - a);
+    let io: Complex = Complex::from(0.0, omega);
+
+    // Collocation of p' + i w p = f, p being a Chebyshev series
+    let mut mat: Vec<Vec<Complex>> = vec![vec![Complex::new(); n]; n];
+    let mut rhs: Vec<Complex> = vec![Complex::new(); n];
+    for (j, (row, r)) in mat.iter_mut().zip(rhs.iter_mut()).enumerate() {
+        let (t, x): (f64, f64) = lobatto(j, n, a, b);
+        for (k, m) in row.iter_mut().enumerate() {
+            let (tk, dk): (f64, f64) = chebyshev(k, t);
+            *m = scale * dk + io * tk;
+        }
+        *r = f(x).into();
+    }
+
+    let c: Vec<Complex> = solve(mat, rhs);
+    let p_b: Complex = c.iter().fold(Complex::new(), |acc, &v| acc + v);
+    let p_a: Complex = c.iter().enumerate().fold(Complex::new(), |acc, (k, &v)| if k % 2 == 0 { acc + v } else { acc - v });
+
+    p_b * Complex::from_polar(omega * b, 1.0) - p_a * Complex::from_polar(omega * a, 1.0)
+}
+
+/// # Levin's method for Bessel integrals
+///
+/// Integral of `f(x) J_order(omega x)` over `[a, b]`, with `0 <= a < b`, collocating on `n` Chebyshev
+/// points. The antiderivative is sought as `p1 J_n(w x) + p2 J_(n+1)(w x)`, where `(J_n, J_n+1)` solve a
+/// first order system. When `a = 0`, the part of the interval below `1 / omega`, where the kernel does not
+/// oscillate yet, is integrated with a Gauss-Legendre rule.
+///
+/// The accuracy is limited by that of `bessel::jf` at the bounds.
+///
+/// ```
+/// # use scilib::math::oscillatory::levin_bessel;
+/// let res = levin_bessel(|x| 1.0 / (1.0 + x), 0.0, 1.0, 25.0, 0.0, 24);
+/// assert!((res - 0.037323019567771327).abs() < 1.0e-8);
+///
+/// let res = levin_bessel(|x| x.sqrt(), 1.0, 2.0, 12.0, 1.5, 12);
+/// assert!((res - 0.008037976939123519).abs() < 1.0e-7);
+/// ```
+pub fn levin_bessel<F>(f: F, a: f64, b: f64, omega: f64, order: f64, n: usize) -> f64
+where F: Fn(f64) -> f64 {
+
+    let n: usize = n.max(2);
+
+    // Near the origin, the system is singular and the kernel smooth
+    if a <= 0.0 {
+        let split: f64 = b.min(1.0 / omega.abs());
+        let head: f64 = gauss_legendre(|x| f(x) * bessel::jf(omega * x, order).re, 0.0, split);
+        return if split < b { head + levin_bessel(f, split, b, omega, order, n) } else { head };
+    }
+
+    let scale: f64 = 2.0 / (b - a);
+
+    // Collocation of p1' + (n / x) p1 + w p2 = f and p2' - w p1 - ((n + 1) / x) p2 = 0
+    let mut mat: Vec<Vec<Complex>> = vec![vec![Complex::new(); 2 * n]; 2 * n];
+    let mut rhs: Vec<Complex> = vec![Complex::new(); 2 * n];
+    for j in 0..n {
+        let (t, x): (f64, f64) = lobatto(j, n, a, b);
+        for k in 0..n {
+            let (tk, dk): (f64, f64) = chebyshev(k, t);
+            mat[j][k] = (scale * dk + order / x * tk).into();
+            mat[j][n + k] = (omega * tk).into();
+            mat[n + j][k] = (-omega * tk).into();
+            mat[n + j][n + k] = (scale * dk - (order + 1.0) / x * tk).into();
+        }
+        rhs[j] = f(x).into();
+    }
+
+    let c: Vec<Complex> = solve(mat, rhs);
+    let at = |sign: f64| -> (f64, f64) {
+        (0..n).fold((0.0, 0.0), |(p1, p2), k| {
+            let s: f64 = sign.powi(k as i32);
+            (p1 + s * c[k].re, p2 + s * c[n + k].re)
+        })
+    };
+
+    let ((p1_b, p2_b), (p1_a, p2_a)) = (at(1.0), at(-1.0));
+    let w_b: (f64, f64) = (bessel::jf(omega * b, order).re, bessel::jf(omega * b, order + 1.0).re);
+    let w_a: (f64, f64) = (bessel::jf(omega * a, order).re, bessel::jf(omega * a, order + 1.0).re);
+
+    (p1_b * w_b.0 + p2_b * w_b.1) - (p1_a * w_a.0 + p2_a * w_a.1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Chebyshev-Lobatto point `j` of `n`, on `[-1, 1]` and mapped to `[a, b]`
+fn lobatto(j: usize, n: usize, a: f64, b: f64) -> (f64, f64) {
+    let t: f64 = (PI * j as f64 / (n - 1) as f64).cos();
+    (t, 0.5 * (a + b) + 0.5 * (b - a) * t)
+}
+
+/// Chebyshev polynomial `T_k` and its derivative at `t`
+fn chebyshev(k: usize, t: f64) -> (f64, f64) {
+
+    let kf: f64 = k as f64;
+    if (1.0 - t.abs()) < 1.0e-15 {
+        let sign: f64 = if t > 0.0 || k.is_multiple_of(2) { 1.0 } else { -1.0 };
+        return (sign, sign * t.signum() * kf * kf);
+    }
+
+    let theta: f64 = t.acos();
+    ((kf * theta).cos(), kf * (kf * theta).sin() / theta.sin())
+}
+
+/// Solves a dense linear system by Gaussian elimination with partial pivoting
+fn solve(mut mat: Vec<Vec<Complex>>, mut rhs: Vec<Complex>) -> Vec<Complex> {
+
+    let n: usize = rhs.len();
+
+    for c in 0..n {
+        let pivot: usize = (c..n).max_by(|&p, &q| mat[p][c].modulus().total_cmp(&mat[q][c].modulus())).unwrap_or(c);
+        mat.swap(c, pivot);
+        rhs.swap(c, pivot);
+
+        for r in (c + 1)..n {
+            let factor: Complex = mat[r][c] / mat[c][c];
+            let (top, bottom) = mat.split_at_mut(r);
+            for (m, &v) in bottom[0][c..].iter_mut().zip(&top[c][c..]) {
+                *m -= factor * v;
+            }
+            let v: Complex = rhs[c];
+            rhs[r] -= factor * v;
+        }
+    }
+
+    let mut x: Vec<Complex> = vec![Complex::new(); n];
+    for r in (0..n).rev() {
+        let s: Complex = ((r + 1)..n).fold(rhs[r], |acc, k| acc - mat[r][k] * x[k]);
+        x[r] = s / mat[r][r];
+    }
+    x
+}
+
+/// Gauss-Legendre quadrature of `f` over `[a, b]`, the nodes being found by Newton's method
+fn gauss_legendre<F>(f: F, a: f64, b: f64) -> f64
+where F: Fn(f64) -> f64 {
+
+    let n: usize = GAUSS_POINTS;
+    let mut res: f64 = 0.0;
+
+    for i in 0..n {
+        // Legendre polynomial P_n and its derivative, refining the Chebyshev guess of the root
+        let mut t: f64 = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+        let mut dp: f64 = 1.0;
+        for _ in 0..100 {
+            let (mut p0, mut p1): (f64, f64) = (1.0, t);
+            for k in 2..=n {
+                let p2: f64 = ((2 * k - 1) as f64 * t * p1 - (k - 1) as f64 * p0) / k as f64;
+                p0 = p1;
+                p1 = p2;
+            }
+            dp = n as f64 * (t * p1 - p0) / (t * t - 1.0);
+            let step: f64 = p1 / dp;
+            t -= step;
+            if step.abs() < 1.0e-16 {
+                break;
+            }
+        }
+
+        let weight: f64 = 2.0 / ((1.0 - t * t) * dp * dp);
+        res += weight * f(0.5 * (a + b) + 0.5 * (b - a) * t);
+    }
+
+    0.5 * (b - a) * res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,163 @@
+//!
+//! # Poisson equation
+//!
+//! Direct solution of `laplacian(u) = f` on rectangular grids in one to three dimensions, as met in
+//! electrostatics (`f = -rho / epsilon_0`) or in the pressure projection of incompressible flows. The
+//! Laplacian is the second order finite difference one, which is diagonalized by sine transforms for the
+//! Dirichlet conditions and by Fourier transforms for the periodic ones: the solution costs a transform
+//! along each axis, a division, and the inverse transforms.
+//!
+//! The fields are stored as flat vectors in row-major order, as in the `grid` module. With the Dirichlet
+//! condition, the grid holds the interior points only, and `u` vanishes on the points just outside it;
+//! non-zero boundary values are handled by moving their contribution `-u_b / h^2` to the source of the
+//! neighboring points.
+//!
+//! ```
+//! # use std::f64::consts::PI;
+//! # use scilib::math::poisson::{ poisson, Condition };
+//! // u = sin(pi x) sin(pi y) on the unit square, f = -2 pi^2 u
+//! let n: usize = 31;
+//! let h: f64 = 1.0 / (n + 1) as f64;
+//! let exact: Vec<f64> = (0..n * n).map(|p| {
+//!     (PI * h * (p / n + 1) as f64).sin() * (PI * h * (p % n + 1) as f64).sin()
+//! }).collect();
+//! let source: Vec<f64> = exact.iter().map(|u| -2.0 * PI * PI * u).collect();
+//!
+//! let res = poisson(&source, &[n, n], &[h, h], Condition::Dirichlet);
+//! for (r, e) in res.iter().zip(&exact) {
+//!     assert!((r - e).abs() < 1.0e-3);
+//! }
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Eigenvalues of the Laplacian
+
+use crate::signal::{            // Diagonalizing transforms
+    dst,                        // Sine transform
+    idst,                       // Inverse sine transform
+    fft,                        // Fourier transform
+    ifft                        // Inverse Fourier transform
+};
+
+use super::complex::Complex;    // Fourier coefficients
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Boundary condition
+///
+/// Condition imposed on the solution at the edges of the domain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Condition {
+    /// The solution vanishes on the points just outside the grid
+    #[default]
+    Dirichlet,
+    /// The solution repeats with the period of the grid
+    Periodic
+}
+
+/// # Poisson solver
+///
+/// Solves `laplacian(u) = source` on a grid of the given `shape` and `spacing`, with the discrete Laplacian
+/// of second order, and the boundary `condition`.
+///
+/// With the periodic condition, the solution is only defined up to a constant, and exists only for a
+/// source of zero mean: the mean of the source is removed, and the solution of zero mean is returned.
+///
+/// ```
+/// # use scilib::math::grid::{ Grid, Boundary };
+/// # use scilib::math::poisson::{ poisson, Condition };
+/// // Periodic box: the solver inverts the discrete Laplacian of the grid module
+/// let (n, h): (usize, f64) = (16, std::f64::consts::TAU / 16.0);
+/// let u: Vec<f64> = (0..n * n * n).map(|p| {
+///     let (x, y, z) = (h * (p / (n * n)) as f64, h * (p / n % n) as f64, h * (p % n) as f64);
+///     x.cos() + (2.0 * y).sin() * z.cos()
+/// }).collect();
+///
+/// let grid = Grid { boundary: Boundary::Periodic, ..Grid::new(&[n, n, n], &[h, h, h]) };
+/// let res = poisson(&grid.laplacian(&u), &[n, n, n], &[h, h, h], Condition::Periodic);
+/// for (r, e) in res.iter().zip(&u) {
+///     assert!((r - e).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn poisson(source: &[f64], shape: &[usize], spacing: &[f64], condition: Condition) -> Vec<f64> {
+
+    assert!(!shape.is_empty() && shape.len() <= 3, "The grid must have one to three dimensions");
+    assert!(shape.len() == spacing.len(), "The shape and the spacing must have the same length");
+    assert!(source.len() == shape.iter().product(), "The source must have one value per point of the grid");
+    assert!(spacing.iter().all(|&h| h > 0.0), "The spacing must be positive");
+
+    // Eigenvalues of the one-dimensional Laplacian along each axis
+    let eigen: Vec<Vec<f64>> = shape.iter().zip(spacing).map(|(&n, &h)| (0..n).map(|k| {
+        let theta: f64 = match condition {
+            Condition::Dirichlet => PI * (k + 1) as f64 / (n + 1) as f64,
+            Condition::Periodic => 2.0 * PI * k as f64 / n as f64
+        };
+        (2.0 * theta.cos() - 2.0) / (h * h)
+    }).collect()).collect();
+
+    // Sum of the eigenvalues of each mode
+    let lambda = |p: usize| -> f64 {
+        let mut rest: usize = p;
+        let mut res: f64 = 0.0;
+        for (axis, &n) in shape.iter().enumerate().rev() {
+            res += eigen[axis][rest % n];
+            rest /= n;
+        }
+        res
+    };
+
+    match condition {
+        Condition::Dirichlet => {
+            let mut data: Vec<f64> = source.to_vec();
+            for axis in 0..shape.len() {
+                along(&mut data, shape, axis, |line| dst(line, 1));
+            }
+            for (p, v) in data.iter_mut().enumerate() {
+                *v /= lambda(p);
+            }
+            for axis in 0..shape.len() {
+                along(&mut data, shape, axis, |line| idst(line, 1));
+            }
+            data
+        },
+        Condition::Periodic => {
+            let mut data: Vec<Complex> = source.iter().map(|&v| Complex::from(v, 0.0)).collect();
+            for axis in 0..shape.len() {
+                along(&mut data, shape, axis, fft);
+            }
+            // The constant mode is the mean of the source, left out
+            data[0] = Complex::new();
+            for (p, v) in data.iter_mut().enumerate().skip(1) {
+                *v /= lambda(p);
+            }
+            for axis in 0..shape.len() {
+                along(&mut data, shape, axis, ifft);
+            }
+            data.iter().map(|v| v.re).collect()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Applies a transform to every line of the flat `data` along the given axis
+fn along<T, F>(data: &mut [T], shape: &[usize], axis: usize, transform: F)
+where T: Copy, F: Fn(&[T]) -> Vec<T> {
+
+    let n: usize = shape[axis];
+    let stride: usize = shape[axis + 1..].iter().product();
+
+    for block in 0..data.len() / (n * stride) {
+        for offset in 0..stride {
+            let start: usize = block * n * stride + offset;
+            let line: Vec<T> = (0..n).map(|i| data[start + i * stride]).collect();
+            for (i, v) in transform(&line).into_iter().enumerate() {
+                data[start + i * stride] = v;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
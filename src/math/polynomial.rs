@@ -5,8 +5,18 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use super::{            // Using parts from the crate
+    super::error::{     // Error handling
+        Error,          // The error type
+        Result          // The associated result
+    },
     basic,              // Basic functions
-    complex::Complex    // Using Complex numbers
+    complex::Complex,   // Using Complex numbers
+    rational::Rational, // Using exact fractions
+    scalar::{           // Generic scalars
+        Scalar,         // Ring operations
+        RealField       // Ordered fields
+    },
+    tables              // Precomputed factorials
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -30,7 +40,7 @@ pub struct Legendre {
 impl std::fmt::Display for Legendre {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         
-        let mut s: String = String::from(format!("{} * ( ", self.pre_f));
+        let mut s: String = format!("{} * ( ", self.pre_f);
 
         for (f, p) in self.factor.iter().zip(&self.power) {
             match p {
@@ -62,6 +72,27 @@ impl Legendre {
         // Checking that the range is good
         assert!(m >= -(l as i32) && m <= l as i32);
 
+        Self::build(l, m)
+    }
+
+    /// Same as `new`, but returns a `Domain` error if `|m| > l` instead of panicking.
+    ///
+    /// ```
+    /// # use scilib::math::polynomial::Legendre;
+    /// assert!(Legendre::try_new(3, -2).is_ok());
+    /// assert!(Legendre::try_new(2, 3).is_err());
+    /// ```
+    pub fn try_new(l: usize, m: i32) -> Result<Self> {
+        if m.unsigned_abs() as usize > l {
+            return Err(Error::Domain("Legendre order m must satisfy |m| <= l"));
+        }
+
+        Ok(Self::build(l, m))
+    }
+
+    /// Computes the factors, assuming valid orders
+    fn build(l: usize, m: i32) -> Self {
+
         // Initializing the vectors
         let mut factor: Vec<f64> = Vec::new();
         let mut power: Vec<i32> = Vec::new();
@@ -76,7 +107,7 @@ impl Legendre {
         // Computing the pre-factor associated to m
         let mut pre_f: f64 = (-1.0_f64).powi(m);    // Condon–Shortley phase
         if m < 0 {
-            pre_f *= (-1_f64).powi(m) * (basic::factorial((l as i32 - m) as usize) / basic::factorial((l as i32 + m) as usize)) as f64;
+            pre_f *= (-1_f64).powi(m) * tables::factorial((l as i32 - m) as usize) / tables::factorial((l as i32 + m) as usize);
         }
 
         // Returning associated struct
@@ -147,17 +178,36 @@ impl Legendre {
     /// assert_eq!(res21, 0.7261843774138907);
     /// ```
     pub fn compute(&self, x: f64) -> f64 {
+        self.evaluate(x)
+    }
+
+    /// Computes the value of `x` for the given polynomial, for any real field.
+    ///
+    /// Returns: the result of the polynomial Plm(x)
+    ///
+    /// ```
+    /// # use scilib::math::polynomial::Legendre;
+    /// # use scilib::math::dual::Dual;
+    /// let p30 = Legendre::new(3, 0);      // l=3, m=0
+    ///
+    /// // The derivative of P3(x) = (5x^3 - 3x) / 2
+    /// let res = p30.evaluate(Dual::variable(0.4));
+    /// assert!((res.du - (15.0 * 0.16 - 3.0) / 2.0).abs() < 1.0e-14);
+    ///
+    /// // Single precision
+    /// assert!((p30.evaluate(0.4_f32) - -0.44).abs() < 1.0e-6);
+    /// ```
+    pub fn evaluate<S: RealField>(&self, x: S) -> S {
         // Iterates through the values of the factors and powers
-        let pre: f64 = self.pre_f * (1.0 - x.powi(2)).powf(self.m as f64 / 2.0);
-        pre * self.factor.iter().zip(&self.power).fold(0.0, |res, (f, p)| res + f * x.powi(*p))
+        let pre: S = S::from_f64(self.pre_f) * (S::one() - x.powi(2)).powf(self.m as f64 / 2.0);
+        pre * sum_terms(&self.factor, &self.power, x)
     }
 
     /// Computes the value of `z` for the given polynomial (z: complex).
     /// 
     /// Returns: the result of the polynomial Plm(z)
     pub fn compute_complex(&self, z: Complex) -> Complex {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(Complex::new(), |res, (f, p)| res + *f * z.powi(*p))
+        sum_terms(&self.factor, &self.power, z)
     }
 }
 
@@ -217,7 +267,7 @@ impl Laguerre {
         // Going through the powers of the order
         for i in (0..=l).rev() {
             power.push(i as i32);
-            let coef: f64 = (-1.0_f64).powi(i as i32) * basic::binomial(l + m as usize, l - i) as f64 / basic::factorial(i) as f64;
+            let coef: f64 = (-1.0_f64).powi(i as i32) * basic::binomial(l + m as usize, l - i) as f64 / tables::factorial(i);
             factor.push(coef);
         }
 
@@ -276,19 +326,21 @@ impl Laguerre {
     /// assert_eq!(res, 2.42);
     /// ```
     pub fn compute(&self, x: f64) -> f64 {
-        println!("{}", self);
-        // Iterates through the values of the factors and powers
-        let res: f64 = self.factor.iter().zip(&self.power).fold(0.0, |res, (f, p)| res + f * x.powi(*p));
-        println!("res {}", res);
-        res
+        self.evaluate(x)
+    }
+
+    /// Computes the value of `x` for the given polynomial, for any scalar.
+    ///
+    /// Returns: the result of the polynomial Llm(x)
+    pub fn evaluate<S: Scalar>(&self, x: S) -> S {
+        sum_terms(&self.factor, &self.power, x)
     }
 
     /// Computes the value of `z` for the given polynomial (z: complex).
     /// 
     /// Returns: the result of the polynomial Plm(z)
     pub fn compute_complex(&self, z: Complex) -> Complex {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(Complex::new(), |res, (f, p)| res + *f * z.powi(*p))
+        sum_terms(&self.factor, &self.power, z)
     }
 }
 
@@ -362,6 +414,42 @@ impl Bernoulli {
         res
     }
 
+    /// Exact Bernoulli number generator
+    ///
+    /// Following the Bm- convention, computed with the Akiyama–Tanigawa algorithm. The values are exact
+    /// up to `m = 56`, after which the intermediate fractions overflow.
+    /// ```
+    /// # use scilib::math::polynomial::Bernoulli;
+    /// # use scilib::math::rational::Rational;
+    /// assert_eq!(Bernoulli::gen_rational(1), Rational::new(-1, 2));
+    /// assert_eq!(Bernoulli::gen_rational(3), Rational::zero());
+    /// assert_eq!(Bernoulli::gen_rational(12), Rational::new(-691, 2730));
+    /// assert_eq!(Bernoulli::gen_rational(30), Rational::new(8_615_841_276_005, 14_322));
+    /// ```
+    pub fn gen_rational(m: usize) -> Rational {
+
+        // All odd numbers above 1 are null
+        if m > 1 && m % 2 == 1 {
+            return Rational::zero();
+        }
+
+        let mut a: Vec<Rational> = Vec::with_capacity(m + 1);
+
+        for k in 0..=m {
+            a.push(Rational::new(1, k as i128 + 1));
+            for j in (1..=k).rev() {
+                a[j - 1] = (a[j - 1] - a[j]) * j as i128;
+            }
+        }
+
+        // The algorithm produces the Bm+ convention
+        if m == 1 {
+            -a[0]
+        } else {
+            a[0]
+        }
+    }
+
     /// Produces the factors and powers for nth order polynomial.
     /// 
     /// Returns: `Self`, the corresponding struct
@@ -407,16 +495,41 @@ impl Bernoulli {
     /// assert!((res - 7.5).abs() <= 1.0e-8);
     /// ```
     pub fn compute(&self, x: f64) -> f64 {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(0.0, |res, (f, p)| res + f * x.powi(*p))
+        self.evaluate(x)
+    }
+
+    /// Computes the value of `x` for the given polynomial, for any scalar.
+    ///
+    /// Returns: the result of the polynomial Bn(x)
+    pub fn evaluate<S: Scalar>(&self, x: S) -> S {
+        sum_terms(&self.factor, &self.power, x)
     }
 
     /// Computes the value of `z` for the given polynomial (z: complex).
     /// 
     /// Returns: the result of the polynomial Bn(z)
     pub fn compute_complex(&self, z: Complex) -> Complex {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(Complex::new(), |res, (f, p)| res + *f * z.powi(*p))
+        sum_terms(&self.factor, &self.power, z)
+    }
+
+    /// Computes the exact value of `x` for the given polynomial (x: rational).
+    ///
+    /// The factors are recomputed exactly from the Bernoulli numbers, rather than using the stored `f64` ones.
+    ///
+    /// Returns: the result of the polynomial Bn(x)
+    ///
+    /// ```
+    /// # use scilib::math::polynomial::Bernoulli;
+    /// # use scilib::math::rational::Rational;
+    /// let p = Bernoulli::new(3);          // n=3
+    /// let res = p.compute_rational(Rational::new(5, 2));
+    ///
+    /// assert_eq!(res, Rational::new(15, 2));
+    /// ```
+    pub fn compute_rational(&self, x: Rational) -> Rational {
+        (0..=self.n).fold(Rational::zero(), |res, k| {
+            res + Self::gen_rational(self.n - k) * basic::binomial(self.n, k) as i128 * x.powi(k as i32)
+        })
     }
 }
 
@@ -477,7 +590,7 @@ impl Euler {
         let mut res: f64 = 0.0;
         
         // All odd Euler numbers are 0
-        if m % 2 != 0 {
+        if !m.is_multiple_of(2) {
             return res;
         } else if m == 0 {
             return 1.0;
@@ -554,17 +667,29 @@ impl Euler {
     /// assert!((res - -2.74576).abs() <= 1.0e-8);
     /// ```
     pub fn compute(&self, x: f64) -> f64 {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(0.0, |res, (f, p)| res + f * x.powi(*p))
+        self.evaluate(x)
+    }
+
+    /// Computes the value of `x` for the given polynomial, for any scalar.
+    ///
+    /// Returns: the result of the polynomial En(x)
+    pub fn evaluate<S: Scalar>(&self, x: S) -> S {
+        sum_terms(&self.factor, &self.power, x)
     }
 
     /// Computes the value of `z` for the given polynomial (z: complex).
     /// 
     /// Returns: the result of the polynomial En(x)
     pub fn compute_complex(&self, z: Complex) -> Complex {
-        // Iterates through the values of the factors and powers
-        self.factor.iter().zip(&self.power).fold(Complex::new(), |res, (f, p)| res + *f * z.powi(*p))
+        sum_terms(&self.factor, &self.power, z)
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Sums the terms `factor * x^power` of a polynomial
+fn sum_terms<S: Scalar>(factor: &[f64], power: &[i32], x: S) -> S {
+    factor.iter().zip(power).fold(S::zero(), |res, (f, p)| res + S::from_f64(*f) * x.powi(*p))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
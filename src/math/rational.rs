@@ -0,0 +1,406 @@
+//!
+//! # Rational numbers
+//!
+//! Exact fractions backed by `i128`, useful to carry intermediate computations without rounding errors
+//! before converting the final result to `f64`. The fraction is always kept in its reduced form, with a
+//! positive denominator. Operations panic on overflow rather than silently returning a wrong result.
+//!
+//! ```
+//! # use scilib::math::rational::Rational;
+//! let a = Rational::new(1, 3);
+//! let b = Rational::new(1, 6);
+//!
+//! assert_eq!(a + b, Rational::new(1, 2));
+//! assert_eq!(f64::from(a + b), 0.5);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{     // Implementing basic operations
+    Add,            // Addition
+    AddAssign,      // Assigning addition
+    Sub,            // Subtraction
+    SubAssign,      // Assigning addition
+    Mul,            // Multiplication
+    MulAssign,      // Assigning multiplication
+    Div,            // Division
+    DivAssign,      // Assigning division
+    Neg             // Negation
+};
+
+use std::cmp::Ordering;
+
+use crate::error::{ // Error handling
+    Error,          // The error type
+    Result          // The associated result
+};
+
+use std::fmt::{     // Formatter display
+    Display,        // The display itself
+    Result as DRes  // The associated result
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Rational structure
+///
+/// Stores the numerator and denominator in reduced form, the sign being held by the numerator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// The numerator
+    num: i128,
+    /// The denominator, always positive
+    den: i128
+}
+
+/// # Display
+///
+/// Shows the fraction as `num/den`, or only the numerator for integers.
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
+        if self.den == 1 {
+            write!(f, "{}", self.num)?;
+        } else {
+            write!(f, "{}/{}", self.num, self.den)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Default
+///
+/// The default value is zero.
+impl Default for Rational {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Implementing required methods
+impl Rational {
+    /// # New rational
+    ///
+    /// `num` is the numerator and `den` the denominator, which must not be zero.
+    ///
+    /// The fraction is reduced on creation.
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// let r = Rational::new(6, -4);
+    ///
+    /// assert_eq!(r.numer(), -3);
+    /// assert_eq!(r.denom(), 2);
+    /// ```
+    pub fn new(num: i128, den: i128) -> Self {
+
+        assert!(den != 0, "Denominator of a rational cannot be zero");
+
+        let g: i128 = gcd(num, den);
+        let sg: i128 = if den < 0 { -1 } else { 1 };
+
+        Self {
+            num: sg * num / g,
+            den: sg * den / g
+        }
+    }
+
+    /// # New rational, checked
+    ///
+    /// Same as `new`, but returns a `Domain` error for a zero denominator instead of panicking.
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// assert_eq!(Rational::try_new(2, 4), Ok(Rational::new(1, 2)));
+    /// assert!(Rational::try_new(1, 0).is_err());
+    /// ```
+    pub fn try_new(num: i128, den: i128) -> Result<Self> {
+        if den == 0 {
+            return Err(Error::Domain("denominator of a rational cannot be zero"));
+        }
+
+        Ok(Self::new(num, den))
+    }
+
+    /// # Zero
+    pub const fn zero() -> Self {
+        Self {
+            num: 0,
+            den: 1
+        }
+    }
+
+    /// # Unity
+    pub const fn one() -> Self {
+        Self {
+            num: 1,
+            den: 1
+        }
+    }
+
+    /// # Numerator of the reduced fraction
+    pub const fn numer(&self) -> i128 {
+        self.num
+    }
+
+    /// # Denominator of the reduced fraction
+    ///
+    /// Always strictly positive.
+    pub const fn denom(&self) -> i128 {
+        self.den
+    }
+
+    /// # Checks if the number is an integer
+    pub const fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// # Absolute value
+    pub fn abs(&self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den
+        }
+    }
+
+    /// # Reciprocal
+    ///
+    /// Returns `1 / self`, panics if self is zero.
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// assert_eq!(Rational::new(-2, 5).recip(), Rational::new(-5, 2));
+    /// ```
+    pub fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+
+    /// # Raising to an integer power
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// let r = Rational::new(2, 3);
+    ///
+    /// assert_eq!(r.powi(3), Rational::new(8, 27));
+    /// assert_eq!(r.powi(-2), Rational::new(9, 4));
+    /// assert_eq!(r.powi(0), Rational::one());
+    /// ```
+    pub fn powi(&self, e: i32) -> Self {
+
+        let base: Self = if e < 0 { self.recip() } else { *self };
+        let p: u32 = e.unsigned_abs();
+
+        let num: i128 = base.num.checked_pow(p).expect("Rational overflow");
+        let den: i128 = base.den.checked_pow(p).expect("Rational overflow");
+
+        // Powers of reduced fractions are still reduced
+        Self { num, den }
+    }
+
+    /// # Conversion to float
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// assert_eq!(Rational::new(3, 8).to_f64(), 0.375);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    /// # Floor
+    ///
+    /// Returns the largest integer lower or equal to self.
+    ///
+    /// ```
+    /// # use scilib::math::rational::Rational;
+    /// assert_eq!(Rational::new(7, 2).floor(), 3);
+    /// assert_eq!(Rational::new(-7, 2).floor(), -4);
+    /// ```
+    pub fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+}
+
+/// Greatest common divisor, always positive (and non-zero for non-zero inputs)
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut x, mut y): (u128, u128) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    if x == 0 { 1 } else { x as i128 }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Here comes a long list of implementations for the operations
+
+/// # Conversion from an integer
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// let r: Rational = 5.into();
+///
+/// assert!(r.is_integer() && r.numer() == 5);
+/// ```
+impl<T: Into<i128>> From<T> for Rational {
+    fn from(val: T) -> Self {
+        Self {
+            num: val.into(),
+            den: 1
+        }
+    }
+}
+
+/// # Conversion to f64
+///
+/// Allows the use of rationals in any function accepting `Into<f64>`.
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// # use scilib::math::basic::sinc;
+/// let x: f64 = Rational::new(1, 4).into();
+///
+/// assert_eq!(sinc(x), sinc(0.25));
+/// ```
+impl From<Rational> for f64 {
+    fn from(r: Rational) -> Self {
+        r.to_f64()
+    }
+}
+
+/// # Ordering
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// assert!(Rational::new(1, 3) < Rational::new(1, 2));
+/// assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+/// ```
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, cross multiplication preserves the order
+        let lhs: i128 = self.num.checked_mul(other.den).expect("Rational overflow");
+        let rhs: i128 = other.num.checked_mul(self.den).expect("Rational overflow");
+        lhs.cmp(&rhs)
+    }
+}
+
+/// # Addition
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// assert_eq!(Rational::new(1, 4) + Rational::new(1, 12), Rational::new(1, 3));
+/// assert_eq!(Rational::new(1, 2) + 1, Rational::new(3, 2));
+/// ```
+impl<T: Into<Self>> Add<T> for Rational {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+
+        // Working with the lcm of the denominators to limit the growth
+        let g: i128 = gcd(self.den, rhs.den);
+        let l: i128 = self.den / g;
+        let num: i128 = self.num.checked_mul(rhs.den / g)
+            .and_then(|a| rhs.num.checked_mul(l).and_then(|b| a.checked_add(b)))
+            .expect("Rational overflow");
+        let den: i128 = l.checked_mul(rhs.den).expect("Rational overflow");
+
+        Self::new(num, den)
+    }
+}
+
+/// # Assigning addition
+impl<T: Into<Self>> AddAssign<T> for Rational {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+/// # Subtraction
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// assert_eq!(Rational::new(1, 2) - Rational::new(1, 3), Rational::new(1, 6));
+/// ```
+impl<T: Into<Self>> Sub<T> for Rational {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        self + -rhs.into()
+    }
+}
+
+/// # Assigning subtraction
+impl<T: Into<Self>> SubAssign<T> for Rational {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+/// # Multiplication
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// assert_eq!(Rational::new(2, 3) * Rational::new(9, 4), Rational::new(3, 2));
+/// assert_eq!(Rational::new(2, 3) * 3, Rational::from(2));
+/// ```
+impl<T: Into<Self>> Mul<T> for Rational {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        let rhs: Self = rhs.into();
+
+        // Cross reducing before multiplying to limit the growth
+        let g1: i128 = gcd(self.num, rhs.den);
+        let g2: i128 = gcd(rhs.num, self.den);
+        let num: i128 = (self.num / g1).checked_mul(rhs.num / g2).expect("Rational overflow");
+        let den: i128 = (self.den / g2).checked_mul(rhs.den / g1).expect("Rational overflow");
+
+        Self::new(num, den)
+    }
+}
+
+/// # Assigning multiplication
+impl<T: Into<Self>> MulAssign<T> for Rational {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// # Division
+///
+/// ```
+/// # use scilib::math::rational::Rational;
+/// assert_eq!(Rational::new(2, 3) / Rational::new(4, 9), Rational::new(3, 2));
+/// ```
+impl<T: Into<Self>> Div<T> for Rational {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        let inv: Self = rhs.into().recip();
+        self.mul(inv)
+    }
+}
+
+/// # Assigning division
+impl<T: Into<Self>> DivAssign<T> for Rational {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// # Negation
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,270 @@
+//!
+//! # Zeros of analytic functions
+//!
+//! All the zeros of an analytic function `f(z)` inside a rectangle of the complex plane, as needed to
+//! find the modes of a waveguide or the roots of a dispersion relation.
+//!
+//! The number of zeros inside a rectangle is given by the argument principle: the winding number of `f`
+//! along the boundary, followed by sampling the boundary until the phase of `f` changes little between
+//! neighboring points. The rectangles holding several zeros are subdivided, and the isolated zeros are
+//! polished with Newton's method. A zero of multiplicity `m` is returned `m` times.
+//!
+//! ```
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::roots::complex_roots;
+//! // Cube roots of unity
+//! let roots = complex_roots(|z: Complex| z.powi(3) - 1.0, Complex::from(-2, -2), Complex::from(2, 2));
+//! assert_eq!(roots.len(), 3);
+//!
+//! for r in &roots {
+//!     assert!((r.powi(3) - 1.0).modulus() < 1.0e-14);
+//! }
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{             // Phase constants
+    PI,                             // Pi
+    FRAC_PI_4,                      // Largest phase step
+    TAU                             // Full turn
+};
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::diagnostics;             // Iteration reports
+
+use super::complex::Complex;        // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Relative precision of the zeros
+const ROOT_PRECISION: f64 = 1.0e-15;
+
+/// # Iteration cap of Newton's method
+const NEWTON_ITERATIONS: usize = 50;
+
+/// # Samples of each edge before refinement
+const EDGE_SAMPLES: usize = 16;
+
+/// # Halvings of a boundary segment before giving up
+const EDGE_DEPTH: usize = 40;
+
+/// # Subdivisions of the rectangles before giving up
+const SEARCH_DEPTH: usize = 60;
+
+/// # Positions of the cuts of a rectangle, away from the symmetry lines of the usual problems
+const SPLITS: [f64; 3] = [0.5127, 0.4619, 0.5583];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Number of zeros in a rectangle
+///
+/// Counts the zeros of the analytic function `f`, with their multiplicity, inside the rectangle of corners
+/// `lower` (smallest real and imaginary parts) and `upper`. Returns `None` when a zero lies on the boundary,
+/// or too close to it for the count to be reliable. For a meromorphic function, the poles would be counted
+/// negatively, and `None` is also returned for a negative count.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::roots::zero_count;
+/// let f = |z: Complex| z.sin();
+/// assert_eq!(zero_count(f, Complex::from(-10, -1), Complex::from(10, 1)), Some(7));
+///
+/// // A zero on the boundary
+/// assert_eq!(zero_count(f, Complex::from(0, -1), Complex::from(2, 1)), None);
+/// ```
+pub fn zero_count<F, T, U>(f: F, lower: T, upper: U) -> Option<usize>
+where F: Fn(Complex) -> Complex, T: Into<Complex>, U: Into<Complex> {
+    winding(&f, lower.into(), upper.into())
+}
+
+/// # Zeros in a rectangle
+///
+/// Finds all the zeros of the analytic function `f` inside the rectangle of corners `lower` (smallest real
+/// and imaginary parts) and `upper`, each one repeated according to its multiplicity. The zeros are
+/// polished to the precision set by the `Precision` policy. The zeros closer than about the square root of
+/// the precision are returned as a single multiple zero, and the boundary of the rectangle should stay clear
+/// of the zeros: no zero is found in a rectangle whose boundary holds one.
+///
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::roots::complex_roots;
+/// // First positive zeros of the sine, which are all real
+/// let mut roots = complex_roots(|z: Complex| z.sin(), Complex::from(1, -1), Complex::from(12, 1));
+/// roots.sort_by(|a, b| a.re.total_cmp(&b.re));
+///
+/// assert_eq!(roots.len(), 3);
+/// for (k, r) in roots.iter().enumerate() {
+///     assert!((r.re - (k + 1) as f64 * PI).abs() < 1.0e-8 && r.im.abs() < 1.0e-8);
+/// }
+///
+/// // A double zero, along with a simple one
+/// let f = |z: Complex| (z - Complex::from(1, 1)).powi(2) * (z + 2.0);
+/// let roots = complex_roots(f, Complex::from(-3, -3), Complex::from(3, 3));
+/// assert_eq!(roots.len(), 3);
+/// assert_eq!(roots.iter().filter(|r| (**r - Complex::from(1, 1)).modulus() < 1.0e-10).count(), 2);
+/// ```
+pub fn complex_roots<F, T, U>(f: F, lower: T, upper: U) -> Vec<Complex>
+where F: Fn(Complex) -> Complex, T: Into<Complex>, U: Into<Complex> {
+
+    let (lower, upper): (Complex, Complex) = (lower.into(), upper.into());
+    let mut res: Vec<Complex> = Vec::new();
+
+    if let Some(count) = winding(&f, lower, upper) {
+        search(&f, lower, upper, count, 0, &mut res);
+    }
+
+    res
+}
+
+/// Zeros of `f` in a rectangle known to hold `count` of them
+fn search<F>(f: &F, lower: Complex, upper: Complex, count: usize, depth: usize, res: &mut Vec<Complex>)
+where F: Fn(Complex) -> Complex {
+
+    if count == 0 {
+        return;
+    }
+
+    let center: Complex = (lower + upper) / 2.0;
+    let size: f64 = (upper - lower).modulus();
+    let tol: f64 = Precision::current().tolerance(ROOT_PRECISION);
+
+    // An isolated zero, or a multiple one in a rectangle too small to be split further, the multiple zeros
+    // being only defined to about the square root of the precision
+    let cluster: bool = size < tol.sqrt() * (1.0 + center.modulus()) || depth >= SEARCH_DEPTH;
+    if count == 1 || cluster {
+        if let Some(z) = newton(f, center, count, size).filter(|&z| inside(z, lower, upper)) {
+            res.extend(std::iter::repeat_n(z, count));
+            return;
+        } else if cluster {
+            return;
+        }
+    }
+
+    // Cutting into quadrants, moving the cuts until none of them holds a zero
+    for ratio in SPLITS {
+        let cut: Complex = lower + ratio * (upper - lower);
+        let quadrants: [(Complex, Complex); 4] = [
+            (lower, cut),
+            (Complex::from(cut.re, lower.im), Complex::from(upper.re, cut.im)),
+            (Complex::from(lower.re, cut.im), Complex::from(cut.re, upper.im)),
+            (cut, upper)
+        ];
+        let counts: Vec<Option<usize>> = quadrants.iter().map(|&(lo, hi)| winding(f, lo, hi)).collect();
+
+        if counts.iter().all(Option::is_some) && counts.iter().flatten().sum::<usize>() == count {
+            for (&(lo, hi), c) in quadrants.iter().zip(counts) {
+                search(f, lo, hi, c.unwrap(), depth + 1, res);
+            }
+            return;
+        }
+    }
+
+    // No clean cut, the zeros being too close to be told apart: they are polished together
+    if let Some(z) = newton(f, center, count, size).filter(|&z| inside(z, lower, upper)) {
+        res.extend(std::iter::repeat_n(z, count));
+    }
+}
+
+/// Winding number of `f` along the boundary of a rectangle
+fn winding<F>(f: &F, lower: Complex, upper: Complex) -> Option<usize>
+where F: Fn(Complex) -> Complex {
+
+    let corners: [Complex; 4] = [lower, Complex::from(upper.re, lower.im), upper, Complex::from(lower.re, upper.im)];
+    let mut total: f64 = 0.0;
+
+    for (c, &a) in corners.iter().enumerate() {
+        let b: Complex = corners[(c + 1) % 4];
+        let mut za: Complex = a;
+        let mut fa: Complex = f(a);
+        for s in 1..=EDGE_SAMPLES {
+            let zb: Complex = a + (s as f64 / EDGE_SAMPLES as f64) * (b - a);
+            let fb: Complex = f(zb);
+            total += phase_change(f, za, fa, zb, fb, 0)?;
+            (za, fa) = (zb, fb);
+        }
+    }
+
+    let turns: f64 = total / TAU;
+    if (turns - turns.round()).abs() > 0.25 || turns.round() < 0.0 {
+        return None;
+    }
+
+    Some(turns.round() as usize)
+}
+
+/// Change of the phase of `f` along a boundary segment, refined until the steps are small
+fn phase_change<F>(f: &F, za: Complex, fa: Complex, zb: Complex, fb: Complex, depth: usize) -> Option<f64>
+where F: Fn(Complex) -> Complex {
+
+    if fa.modulus() == 0.0 || fb.modulus() == 0.0 || !(fa.modulus() * fb.modulus()).is_finite() {
+        return None;
+    }
+
+    let step: f64 = (fb / fa).arg();
+    let zm: Complex = (za + zb) / 2.0;
+    let fm: Complex = f(zm);
+    if fm.modulus() == 0.0 {
+        return None;
+    }
+
+    // The step is accepted when f is nearly linear along the segment, its chord staying away from the origin
+    let smallest: f64 = fa.modulus().min(fb.modulus()).min(fm.modulus());
+    let linear: bool = (fm - (fa + fb) / 2.0).modulus() < 0.25 * smallest && (fb - fa).modulus() < smallest;
+    let halves: f64 = (fm / fa).arg() + (fb / fm).arg();
+    if linear && step.abs() < FRAC_PI_4 && (halves - step).abs() < 1.0e-3 * PI {
+        return Some(step);
+    } else if depth >= EDGE_DEPTH {
+        return None;
+    }
+
+    Some(phase_change(f, za, fa, zm, fm, depth + 1)? + phase_change(f, zm, fm, zb, fb, depth + 1)?)
+}
+
+/// Newton's method for a zero of multiplicity `m`, with a numerical derivative, returning the last iterate
+/// at the iteration cap, or `None` for a vanishing derivative
+fn newton<F>(f: &F, z0: Complex, m: usize, size: f64) -> Option<Complex>
+where F: Fn(Complex) -> Complex {
+
+    let prec: Precision = Precision::current();                 // Precision policy
+    let tol: f64 = prec.tolerance(ROOT_PRECISION);              // Convergence tolerance
+    let max_iter: usize = prec.iterations(NEWTON_ITERATIONS);   // Iteration cap
+
+    let mut z: Complex = z0;
+    for it in 0..max_iter {
+        let fz: Complex = f(z);
+        if fz.modulus() == 0.0 {
+            diagnostics::finish("roots::newton", it + 1, 0.0, tol);
+            return Some(z);
+        }
+
+        // Four point derivative, exact for the polynomials up to the fourth degree
+        let h: f64 = 1.0e-3 * size.min(1.0 + z.modulus()).max(f64::EPSILON);
+        let i: Complex = Complex::i();
+        let df: Complex = (f(z + h) - f(z - h) - i * (f(z + i * h) - f(z - i * h))) / (4.0 * h);
+        if df.modulus() == 0.0 || !df.modulus().is_finite() {
+            return None;
+        }
+
+        let dz: Complex = m as f64 * fz / df;
+        z -= dz;
+        let residual: f64 = dz.modulus() / (1.0 + z.modulus());
+        diagnostics::step("roots::newton", it, residual, fz.modulus());
+
+        if residual < tol || it + 1 == max_iter {
+            diagnostics::finish("roots::newton", it + 1, residual, tol);
+            break;
+        }
+    }
+
+    Some(z)
+}
+
+/// Whether `z` lies inside the rectangle
+fn inside(z: Complex, lower: Complex, upper: Complex) -> bool {
+    z.re >= lower.re && z.re <= upper.re && z.im >= lower.im && z.im <= upper.im
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,434 @@
+//!
+//! # Scalar traits
+//!
+//! Trait hierarchy describing the numbers the algorithms of the crate can work with, so that a single
+//! implementation serves `f64`, `f32`, `Complex`, `Complex32` and `Dual` numbers:
+//!
+//! - `Scalar`: ring operations, conversion from `f64` and a magnitude for convergence checks;
+//! - `ComplexField`: division and the elementary functions, along with the real and imaginary parts;
+//! - `RealField`: ordered fields, with comparisons and the functions only defined on the real line.
+//!
+//! Using `Dual` numbers through a generic algorithm gives its derivative (forward automatic
+//! differentiation), and `f32` or `Complex32` allow mixed precision workflows without duplicating code.
+//!
+//! - `SpecialField`: the scalars the special functions accept through `special`, with the result in the
+//!   precision of the argument.
+//!
+//! ```
+//! # use scilib::math::scalar::{ Scalar, RealField };
+//! # use scilib::math::dual::Dual;
+//! // A generic function, written once
+//! fn f<S: RealField>(x: S) -> S {
+//!     x.powi(3) - S::from_f64(2.0) * x.sin()
+//! }
+//!
+//! assert_eq!(f(1.5_f64), 1.5_f64.powi(3) - 2.0 * 1.5_f64.sin());
+//! assert!((f(1.5_f32) - 1.380010).abs() < 1.0e-5);
+//!
+//! // The derivative comes with the dual part
+//! let d = f(Dual::variable(1.5));
+//! assert!((d.du - (3.0 * 1.5_f64.powi(2) - 2.0 * 1.5_f64.cos())).abs() < 1.0e-14);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{     // Required operations
+    Add,            // Addition
+    AddAssign,      // Assigning addition
+    Sub,            // Subtraction
+    SubAssign,      // Assigning subtraction
+    Mul,            // Multiplication
+    MulAssign,      // Assigning multiplication
+    Div,            // Division
+    DivAssign,      // Assigning division
+    Neg             // Negation
+};
+
+use std::fmt::Debug;        // Debug display
+
+use super::complex::{   // Using Complex numbers
+    Complex,            // Double precision
+    Complex32           // Single precision
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Scalar
+///
+/// Numbers supporting the ring operations, the base of every generic algorithm of the crate.
+pub trait Scalar:
+    Copy + Debug + PartialEq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign {
+
+    /// # Additive identity
+    fn zero() -> Self;
+
+    /// # Multiplicative identity
+    fn one() -> Self;
+
+    /// # Conversion from a float
+    ///
+    /// The conversion may lose precision, as for `f32`.
+    fn from_f64(v: f64) -> Self;
+
+    /// # Magnitude
+    ///
+    /// Absolute value or modulus, as a float, used to check the convergence of iterative methods.
+    fn magnitude(self) -> f64;
+
+    /// # Integer power
+    fn powi(self, n: i32) -> Self;
+}
+
+/// # Complex field
+///
+/// Scalars with a division and the elementary functions. Every real field is also a complex field, with a
+/// null imaginary part.
+pub trait ComplexField: Scalar + Div<Output = Self> + DivAssign {
+
+    /// The type of the real and imaginary parts
+    type Real: RealField;
+
+    /// # Real part
+    fn re(self) -> Self::Real;
+
+    /// # Imaginary part
+    fn im(self) -> Self::Real;
+
+    /// # Complex conjugate
+    fn conj(self) -> Self;
+
+    /// # Embedding of a real number
+    fn from_real(v: Self::Real) -> Self;
+
+    /// # Square root, principal branch
+    fn sqrt(self) -> Self;
+
+    /// # Exponential
+    fn exp(self) -> Self;
+
+    /// # Natural logarithm, principal branch
+    fn ln(self) -> Self;
+
+    /// # Sine
+    fn sin(self) -> Self;
+
+    /// # Cosine
+    fn cos(self) -> Self;
+
+    /// # Real power
+    fn powf(self, e: f64) -> Self;
+
+    /// # Multiplicative inverse
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+}
+
+/// # Real field
+///
+/// Ordered complex fields, with their own type as real part.
+pub trait RealField: ComplexField<Real = Self> + PartialOrd {
+
+    /// # Conversion to a float
+    fn to_f64(self) -> f64;
+
+    /// # Absolute value
+    fn abs(self) -> Self;
+
+    /// # Four quadrant arctangent of `self / x`
+    fn atan2(self, x: Self) -> Self;
+
+    /// # Smallest of two numbers
+    fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    /// # Largest of two numbers
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+}
+
+/// # Special field
+///
+/// Scalars the special functions of `basic` and `bessel` can be evaluated on, through `special`. The
+/// argument converts exactly to `Complex`, and the result rounds back to the type of the argument; the real
+/// types keep the real part. The special functions compute in double precision whatever the type.
+pub trait SpecialField: ComplexField {
+
+    /// # Exact conversion to a double precision complex
+    fn widen(self) -> Complex;
+
+    /// # Rounding of a double precision complex
+    fn narrow(z: Complex) -> Self;
+}
+
+/// # Special function of a generic scalar
+///
+/// Evaluates `f` on `x` widened to `Complex`, and rounds the result to the type of `x`. A function written
+/// once over `SpecialField` then serves `f64`, `f32`, `Complex` and `Complex32`.
+///
+/// ```
+/// # use scilib::math::scalar::{ SpecialField, special };
+/// # use scilib::math::complex::{ Complex, Complex32 };
+/// # use scilib::math::basic::{ erf, gamma_complex };
+/// // A generic function, written once
+/// fn f<S: SpecialField>(x: S) -> S {
+///     special(x, erf) * special(x, gamma_complex)
+/// }
+///
+/// let exact = 0.5204998778130465 * 1.772453850905516;
+/// assert!((f(0.5_f64) - exact).abs() < 1.0e-12);
+/// assert!((f(0.5_f32) - exact as f32).abs() < 1.0e-6);
+///
+/// let z: Complex = f(Complex32::from(0.5, 0.0)).into();
+/// assert!((z - exact).modulus() < 1.0e-6);
+/// ```
+///
+/// The Bessel functions go the same way, with their order captured:
+///
+/// ```
+/// # #[cfg(feature = "bessel")] {
+/// # use scilib::math::scalar::special;
+/// # use scilib::math::bessel;
+/// let res: f32 = special(1.5_f32, |z| bessel::j(z, 0));
+/// assert!((res - 0.5118277).abs() < 1.0e-6);
+/// # }
+/// ```
+pub fn special<S, F>(x: S, f: F) -> S
+where S: SpecialField, F: FnOnce(Complex) -> Complex {
+    S::narrow(f(x.widen()))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implements the traits for the primitive floats
+macro_rules! impl_float {
+    ($t:ty) => {
+        impl Scalar for $t {
+            fn zero() -> Self { 0.0 }
+            fn one() -> Self { 1.0 }
+            fn from_f64(v: f64) -> Self { v as $t }
+            fn magnitude(self) -> f64 { self.abs() as f64 }
+            fn powi(self, n: i32) -> Self { <$t>::powi(self, n) }
+        }
+
+        impl ComplexField for $t {
+            type Real = $t;
+            fn re(self) -> Self { self }
+            fn im(self) -> Self { 0.0 }
+            fn conj(self) -> Self { self }
+            fn from_real(v: Self) -> Self { v }
+            fn sqrt(self) -> Self { <$t>::sqrt(self) }
+            fn exp(self) -> Self { <$t>::exp(self) }
+            fn ln(self) -> Self { <$t>::ln(self) }
+            fn sin(self) -> Self { <$t>::sin(self) }
+            fn cos(self) -> Self { <$t>::cos(self) }
+            fn powf(self, e: f64) -> Self { <$t>::powf(self, e as $t) }
+        }
+
+        impl RealField for $t {
+            fn to_f64(self) -> f64 { self as f64 }
+            fn abs(self) -> Self { <$t>::abs(self) }
+            fn atan2(self, x: Self) -> Self { <$t>::atan2(self, x) }
+        }
+
+        impl SpecialField for $t {
+            fn widen(self) -> Complex { Complex::from(self as f64, 0.0) }
+            fn narrow(z: Complex) -> Self { z.re as $t }
+        }
+    };
+}
+
+impl_float!(f64);
+impl_float!(f32);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Complex numbers as scalars
+///
+/// ```
+/// # use scilib::math::scalar::{ Scalar, ComplexField };
+/// # use scilib::math::complex::Complex;
+/// let z = Complex::from(-4.0, 0.0);
+///
+/// assert!((z.sqrt() - Complex::from(0.0, 2.0)).modulus() < 1.0e-15);
+/// assert_eq!(Complex::from(1.0, 2.0).conj(), Complex::from(1.0, -2.0));
+/// assert_eq!(z.magnitude(), 4.0);
+/// ```
+impl Scalar for Complex {
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    fn one() -> Self {
+        Self::unity()
+    }
+
+    fn from_f64(v: f64) -> Self {
+        Self::from(v, 0.0)
+    }
+
+    fn magnitude(self) -> f64 {
+        self.modulus()
+    }
+
+    fn powi(self, n: i32) -> Self {
+        Complex::powi(self, n)
+    }
+}
+
+/// Implementing the complex field
+impl ComplexField for Complex {
+    type Real = f64;
+
+    fn re(self) -> f64 {
+        self.re
+    }
+
+    fn im(self) -> f64 {
+        self.im
+    }
+
+    fn conj(self) -> Self {
+        self.conjugate()
+    }
+
+    fn from_real(v: f64) -> Self {
+        Self::from(v, 0.0)
+    }
+
+    fn sqrt(self) -> Self {
+        Self::from_polar(self.arg() / 2.0, self.modulus().sqrt())
+    }
+
+    fn exp(self) -> Self {
+        Complex::exp(&self)
+    }
+
+    fn ln(self) -> Self {
+        Complex::ln(&self)
+    }
+
+    fn sin(self) -> Self {
+        Complex::sin(&self)
+    }
+
+    fn cos(self) -> Self {
+        Complex::cos(&self)
+    }
+
+    fn powf(self, e: f64) -> Self {
+        Complex::powf(&self, e)
+    }
+}
+
+impl SpecialField for Complex {
+    fn widen(self) -> Complex {
+        self
+    }
+
+    fn narrow(z: Complex) -> Self {
+        z
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Single precision complex numbers as scalars
+///
+/// ```
+/// # use scilib::math::scalar::{ Scalar, ComplexField };
+/// # use scilib::math::complex::{ Complex, Complex32 };
+/// // A generic function, evaluated in single precision
+/// fn f<S: ComplexField>(z: S) -> S {
+///     z.exp() * z.conj() - S::one()
+/// }
+///
+/// let z = Complex32::from(0.5, 1.0);
+/// let exact = Complex::from(0.5, 1.0).exp() * Complex::from(0.5, -1.0) - 1.0;
+/// let res: Complex = f(z).into();
+/// assert!((res - exact).modulus() < 1.0e-6);
+/// assert_eq!(Complex32::from(-4.0, 0.0).sqrt().im(), 2.0_f32);
+/// ```
+impl Scalar for Complex32 {
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    fn one() -> Self {
+        Self::unity()
+    }
+
+    fn from_f64(v: f64) -> Self {
+        Self::from(v as f32, 0.0)
+    }
+
+    fn magnitude(self) -> f64 {
+        self.modulus() as f64
+    }
+
+    fn powi(self, n: i32) -> Self {
+        Complex32::powi(self, n)
+    }
+}
+
+/// Implementing the complex field
+impl ComplexField for Complex32 {
+    type Real = f32;
+
+    fn re(self) -> f32 {
+        self.re
+    }
+
+    fn im(self) -> f32 {
+        self.im
+    }
+
+    fn conj(self) -> Self {
+        self.conjugate()
+    }
+
+    fn from_real(v: f32) -> Self {
+        Self::from(v, 0.0)
+    }
+
+    fn sqrt(self) -> Self {
+        Complex32::sqrt(&self)
+    }
+
+    fn exp(self) -> Self {
+        Complex32::exp(&self)
+    }
+
+    fn ln(self) -> Self {
+        Complex32::ln(&self)
+    }
+
+    fn sin(self) -> Self {
+        Complex32::sin(&self)
+    }
+
+    fn cos(self) -> Self {
+        Complex32::cos(&self)
+    }
+
+    fn powf(self, e: f64) -> Self {
+        Complex32::powf(&self, e as f32)
+    }
+}
+
+impl SpecialField for Complex32 {
+    fn widen(self) -> Complex {
+        self.into()
+    }
+
+    fn narrow(z: Complex) -> Self {
+        z.into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
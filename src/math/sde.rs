@@ -0,0 +1,376 @@
+//!
+//! # Stochastic differential equations
+//!
+//! Integrators for the Itô equations `dX = a(t, X) dt + b(t, X) dW`, with `X` in `R^n` and a diagonal
+//! noise: each component `X_i` is driven by its own Wiener process `W_i`, with the weight `b_i(t, X)`.
+//!
+//! The integrators are driven by the increments of the Wiener processes over each step, drawn from a
+//! seedable `Noise` generator, so that a given path can be replayed with the same noise, or coarsened to
+//! compare step sizes. The Euler-Maruyama scheme has the strong order 1/2 and the weak order 1, and the
+//! Milstein scheme the strong order 1. `Ensemble` runs many trajectories, and estimates the strong and weak
+//! errors of a scheme, whose orders are then given by `convergence_order`.
+//!
+//! ```
+//! # use scilib::math::sde::{ euler_maruyama, Ensemble };
+//! // Ornstein-Uhlenbeck process, relaxing towards 0 with the rate 2
+//! let drift = |_t: f64, x: &[f64]| vec![-2.0 * x[0]];
+//! let diffusion = |_t: f64, _x: &[f64]| vec![0.5];
+//!
+//! let ensemble = Ensemble::new(4_000, 1, 1.0, 7);
+//! let paths = ensemble.trajectories(|dw| euler_maruyama(&drift, &diffusion, 0.0, &[1.0], 1.0, dw), 200);
+//!
+//! // Mean exp(-2 t), and variance (1 - exp(-4 t)) / 16
+//! let ends: Vec<f64> = paths.iter().map(|p| p.y[200][0]).collect();
+//! let mean: f64 = ends.iter().sum::<f64>() / 4_000.0;
+//! let var: f64 = ends.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 3_999.0;
+//! assert!((mean - (-2.0_f64).exp()).abs() < 0.01);
+//! assert!((var - (1.0 - (-4.0_f64).exp()) / 16.0).abs() < 0.005);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::TAU;          // Box-Muller angle
+
+use super::ode::OdeSolution;        // Sampled paths
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Increment of the SplitMix64 state
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// # Number of mantissa bits of the uniform draws
+const MANTISSA_BITS: i32 = 53;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Seedable noise generator
+///
+/// SplitMix64 generator of uniform and Gaussian draws, fully determined by its seed. It is fast and of a
+/// good statistical quality for simulations, but is not suited to cryptography.
+///
+/// ```
+/// # use scilib::math::sde::Noise;
+/// let mut noise = Noise::new(1);
+/// let draws: Vec<f64> = (0..100_000).map(|_| noise.normal()).collect();
+///
+/// let mean: f64 = draws.iter().sum::<f64>() / 1.0e5;
+/// let var: f64 = draws.iter().map(|x| x * x).sum::<f64>() / 1.0e5;
+/// assert!(mean.abs() < 0.01 && (var - 1.0).abs() < 0.015);
+///
+/// // The same seed replays the same draws
+/// assert_eq!(Noise::new(1).normal(), draws[0]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Noise {
+    /// State of the generator
+    state: u64,
+    /// Second Gaussian draw of the last Box-Muller pair
+    spare: Option<f64>
+}
+
+/// Implementing required methods
+impl Noise {
+
+    /// # Seeded generator
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed, spare: None }
+    }
+
+    /// # Uniform draw
+    ///
+    /// Value uniformly distributed in `[0, 1)`, with 53 random bits.
+    pub fn uniform(&mut self) -> f64 {
+        (self.next() >> (64 - MANTISSA_BITS)) as f64 / (1_u64 << MANTISSA_BITS) as f64
+    }
+
+    /// # Gaussian draw
+    ///
+    /// Value of the standard normal distribution, by the Box-Muller transform.
+    pub fn normal(&mut self) -> f64 {
+
+        if let Some(z) = self.spare.take() {
+            return z;
+        }
+
+        let r: f64 = (-2.0 * (1.0 - self.uniform()).ln()).sqrt();
+        let theta: f64 = TAU * self.uniform();
+        self.spare = Some(r * theta.sin());
+        r * theta.cos()
+    }
+
+    /// # Wiener increments
+    ///
+    /// Increments of `dim` independent Wiener processes over `steps` steps of duration `dt`: `res[k][i]`
+    /// is the increment of `W_i` over the step `k`, a Gaussian draw of variance `dt`.
+    ///
+    /// ```
+    /// # use scilib::math::sde::Noise;
+    /// let dw = Noise::new(3).brownian(2, 10_000, 1.0e-4);
+    ///
+    /// // W(1) of each process, and its quadratic variation
+    /// let w: f64 = dw.iter().map(|d| d[1]).sum();
+    /// let qv: f64 = dw.iter().map(|d| d[0] * d[0]).sum();
+    /// assert!(w.abs() < 4.0 && (qv - 1.0).abs() < 0.1);
+    /// ```
+    pub fn brownian(&mut self, dim: usize, steps: usize, dt: f64) -> Vec<Vec<f64>> {
+        let scale: f64 = dt.abs().sqrt();
+        (0..steps).map(|_| (0..dim).map(|_| scale * self.normal()).collect()).collect()
+    }
+
+    /// Next raw output of the generator
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Euler-Maruyama scheme
+///
+/// Integrates `dX = a(t, X) dt + b(t, X) dW` from `x0` at `t0` up to `t_end`, with one step per element of
+/// `increments`, the increments of the Wiener processes over the steps (see `Noise::brownian`). The scheme
+/// has the strong order 1/2 and the weak order 1.
+///
+/// ```
+/// # use scilib::math::sde::{ euler_maruyama, Noise };
+/// // Geometric Brownian motion, against its exact solution along the same path
+/// let (mu, sigma): (f64, f64) = (0.5, 0.3);
+/// let dw = Noise::new(11).brownian(1, 10_000, 1.0e-4);
+/// let path = euler_maruyama(|_t, x| vec![mu * x[0]], |_t, x| vec![sigma * x[0]], 0.0, &[1.0], 1.0, &dw);
+///
+/// let w: f64 = dw.iter().map(|d| d[0]).sum();
+/// let exact: f64 = (mu - 0.5 * sigma * sigma + sigma * w).exp();
+/// assert_eq!(path.t.len(), 10_001);
+/// assert!((path.y[10_000][0] - exact).abs() < 1.0e-2);
+/// ```
+pub fn euler_maruyama<A, B>(drift: A, diffusion: B, t0: f64, x0: &[f64], t_end: f64, increments: &[Vec<f64>])
+-> OdeSolution
+where A: Fn(f64, &[f64]) -> Vec<f64>, B: Fn(f64, &[f64]) -> Vec<f64> {
+
+    integrate(t0, x0, t_end, increments, |t, x, h, dw| {
+        let (a, b): (Vec<f64>, Vec<f64>) = (drift(t, x), diffusion(t, x));
+        (0..x.len()).map(|i| x[i] + a[i] * h + b[i] * dw[i]).collect()
+    })
+}
+
+/// # Milstein scheme
+///
+/// Integrates `dX = a(t, X) dt + b(t, X) dW` as `euler_maruyama`, with the strong order 1. The derivative
+/// of the diffusion, needed by the correction term `b_i b_i' (dW_i^2 - h) / 2`, is estimated without
+/// derivatives, from the diffusion at the support point `X + a h + b sqrt(h)` (Platen's form). The order
+/// holds for the diagonal noise, where `b_i` depends on `X_i` only among the components.
+///
+/// ```
+/// # use scilib::math::sde::{ milstein, euler_maruyama, Noise };
+/// // Geometric Brownian motion, with a coarse step
+/// let (mu, sigma): (f64, f64) = (0.5, 0.8);
+/// let dw = Noise::new(5).brownian(1, 64, 1.0 / 64.0);
+/// let a = |_t: f64, x: &[f64]| vec![mu * x[0]];
+/// let b = |_t: f64, x: &[f64]| vec![sigma * x[0]];
+///
+/// let w: f64 = dw.iter().map(|d| d[0]).sum();
+/// let exact: f64 = (mu - 0.5 * sigma * sigma + sigma * w).exp();
+/// let err_m: f64 = (milstein(&a, &b, 0.0, &[1.0], 1.0, &dw).y[64][0] - exact).abs();
+/// let err_em: f64 = (euler_maruyama(&a, &b, 0.0, &[1.0], 1.0, &dw).y[64][0] - exact).abs();
+/// assert!(err_m < 0.02 && err_m < err_em);
+/// ```
+pub fn milstein<A, B>(drift: A, diffusion: B, t0: f64, x0: &[f64], t_end: f64, increments: &[Vec<f64>])
+-> OdeSolution
+where A: Fn(f64, &[f64]) -> Vec<f64>, B: Fn(f64, &[f64]) -> Vec<f64> {
+
+    integrate(t0, x0, t_end, increments, |t, x, h, dw| {
+        let (a, b): (Vec<f64>, Vec<f64>) = (drift(t, x), diffusion(t, x));
+        let root: f64 = h.abs().sqrt();
+        let support: Vec<f64> = (0..x.len()).map(|i| x[i] + a[i] * h + b[i] * root).collect();
+        let bs: Vec<f64> = diffusion(t, &support);
+
+        (0..x.len()).map(|i| {
+            let correction: f64 = if root == 0.0 { 0.0 } else { (bs[i] - b[i]) * (dw[i] * dw[i] - h) / (2.0 * root) };
+            x[i] + a[i] * h + b[i] * dw[i] + correction
+        }).collect()
+    })
+}
+
+/// Steps of a one-step scheme, `step(t, x, h, dw)` returning the state at `t + h`
+fn integrate<S>(t0: f64, x0: &[f64], t_end: f64, increments: &[Vec<f64>], step: S) -> OdeSolution
+where S: Fn(f64, &[f64], f64, &[f64]) -> Vec<f64> {
+
+    let h: f64 = (t_end - t0) / increments.len() as f64;
+    let mut sol: OdeSolution = OdeSolution { t: vec![t0], y: vec![x0.to_vec()] };
+
+    for (k, dw) in increments.iter().enumerate() {
+        let t: f64 = t0 + k as f64 * h;
+        let next: Vec<f64> = step(t, &sol.y[k], h, dw);
+        sol.t.push(t + h);
+        sol.y.push(next);
+    }
+
+    sol
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Ensemble of trajectories
+///
+/// Monte Carlo settings: `count` trajectories, driven by `dim` Wiener processes over a time `span`. The
+/// trajectory `k` draws its noise from its own generator, seeded from `seed` and `k`, so that it follows
+/// the same Wiener path whatever the number of steps and the number of trajectories.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ensemble {
+    /// Number of trajectories
+    pub count: usize,
+    /// Number of Wiener processes
+    pub dim: usize,
+    /// Duration of the trajectories
+    pub span: f64,
+    /// Seed of the noise
+    pub seed: u64
+}
+
+/// Implementing required methods
+impl Ensemble {
+
+    /// # Ensemble settings
+    pub fn new(count: usize, dim: usize, span: f64, seed: u64) -> Self {
+        Self { count, dim, span, seed }
+    }
+
+    /// # Trajectories
+    ///
+    /// Runs `solver`, which integrates the equation over the span for the given Wiener increments (such as a
+    /// closure calling `milstein`), on each trajectory with `steps` steps.
+    pub fn trajectories<S>(&self, solver: S, steps: usize) -> Vec<OdeSolution>
+    where S: Fn(&[Vec<f64>]) -> OdeSolution {
+        (0..self.count).map(|k| solver(&self.path(k, steps))).collect()
+    }
+
+    /// # Strong errors
+    ///
+    /// Mean distance `E|X_N - X(T)|` between the end of the numerical trajectories and the exact solution,
+    /// for each number of steps in `levels`. `exact` returns the exact solution at the end of the span, from
+    /// the values `W(T)` of the Wiener processes. Each trajectory uses the same Wiener path at all the levels,
+    /// drawn with the largest number of steps, which the others must divide.
+    ///
+    /// ```
+    /// # use scilib::math::sde::{ euler_maruyama, milstein, convergence_order, Ensemble };
+    /// // Geometric Brownian motion
+    /// let (mu, sigma): (f64, f64) = (1.0, 0.5);
+    /// let a = |_t: f64, x: &[f64]| vec![mu * x[0]];
+    /// let b = |_t: f64, x: &[f64]| vec![sigma * x[0]];
+    /// let exact = |w: &[f64]| vec![(mu - 0.5 * sigma * sigma + sigma * w[0]).exp()];
+    ///
+    /// let ensemble = Ensemble::new(1_000, 1, 1.0, 2024);
+    /// let levels = [16, 32, 64, 128, 256];
+    /// let errs_em = ensemble.strong_errors(|dw| euler_maruyama(&a, &b, 0.0, &[1.0], 1.0, dw), exact, &levels);
+    /// let errs_m = ensemble.strong_errors(|dw| milstein(&a, &b, 0.0, &[1.0], 1.0, dw), exact, &levels);
+    ///
+    /// assert!((convergence_order(&levels, &errs_em) - 0.5).abs() < 0.1);
+    /// assert!((convergence_order(&levels, &errs_m) - 1.0).abs() < 0.1);
+    /// ```
+    pub fn strong_errors<S, E>(&self, solver: S, exact: E, levels: &[usize]) -> Vec<f64>
+    where S: Fn(&[Vec<f64>]) -> OdeSolution, E: Fn(&[f64]) -> Vec<f64> {
+
+        let finest: usize = levels.iter().copied().max().unwrap_or(0);
+        let mut res: Vec<f64> = vec![0.0; levels.len()];
+
+        for k in 0..self.count {
+            let dw: Vec<Vec<f64>> = self.path(k, finest);
+            let w: Vec<f64> = (0..self.dim).map(|i| dw.iter().map(|d| d[i]).sum()).collect();
+            let target: Vec<f64> = exact(&w);
+
+            for (err, &steps) in res.iter_mut().zip(levels) {
+                let sol: OdeSolution = solver(&coarsen(&dw, steps));
+                let end: &Vec<f64> = sol.y.last().unwrap();
+                *err += end.iter().zip(&target).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt();
+            }
+        }
+
+        res.iter().map(|err| err / self.count as f64).collect()
+    }
+
+    /// # Weak errors
+    ///
+    /// Distance `|E[g(X_N)] - expected|` between the Monte Carlo mean of an observable `g` at the end of the
+    /// numerical trajectories and its exact expectation, for each number of steps in `levels`. The paths are
+    /// shared between the levels as in `strong_errors`, which reduces the scatter of the errors, but the
+    /// statistical error of the mean, of order `1 / sqrt(count)`, must remain below the errors measured.
+    ///
+    /// ```
+    /// # use scilib::math::sde::{ euler_maruyama, convergence_order, Ensemble };
+    /// // Geometric Brownian motion, E[X(1)] = exp(mu)
+    /// let (mu, sigma): (f64, f64) = (1.0, 0.1);
+    /// let a = |_t: f64, x: &[f64]| vec![mu * x[0]];
+    /// let b = |_t: f64, x: &[f64]| vec![sigma * x[0]];
+    ///
+    /// let ensemble = Ensemble::new(20_000, 1, 1.0, 99);
+    /// let levels = [4, 8, 16, 32];
+    /// let solver = |dw: &[Vec<f64>]| euler_maruyama(&a, &b, 0.0, &[1.0], 1.0, dw);
+    /// let errs = ensemble.weak_errors(solver, |x| x[0], mu.exp(), &levels);
+    ///
+    /// assert!((convergence_order(&levels, &errs) - 1.0).abs() < 0.15);
+    /// ```
+    pub fn weak_errors<S, G>(&self, solver: S, g: G, expected: f64, levels: &[usize]) -> Vec<f64>
+    where S: Fn(&[Vec<f64>]) -> OdeSolution, G: Fn(&[f64]) -> f64 {
+
+        let finest: usize = levels.iter().copied().max().unwrap_or(0);
+        let mut means: Vec<f64> = vec![0.0; levels.len()];
+
+        for k in 0..self.count {
+            let dw: Vec<Vec<f64>> = self.path(k, finest);
+            for (mean, &steps) in means.iter_mut().zip(levels) {
+                *mean += g(solver(&coarsen(&dw, steps)).y.last().unwrap());
+            }
+        }
+
+        means.iter().map(|mean| (mean / self.count as f64 - expected).abs()).collect()
+    }
+
+    /// Wiener increments of the trajectory `k`
+    fn path(&self, k: usize, steps: usize) -> Vec<Vec<f64>> {
+        let seed: u64 = Noise::new(self.seed ^ (k as u64).wrapping_mul(GOLDEN_GAMMA)).next();
+        Noise::new(seed).brownian(self.dim, steps, self.span / steps as f64)
+    }
+}
+
+/// Sums of the increments over groups of consecutive steps, down to `steps` steps
+fn coarsen(increments: &[Vec<f64>], steps: usize) -> Vec<Vec<f64>> {
+
+    assert!(steps > 0 && increments.len().is_multiple_of(steps), "The levels must divide the finest one!");
+
+    let factor: usize = increments.len() / steps;
+    increments.chunks(factor).map(|group| {
+        (0..group[0].len()).map(|i| group.iter().map(|d| d[i]).sum()).collect()
+    }).collect()
+}
+
+/// # Order of convergence
+///
+/// Order `p` of the fit `err = C / steps^p` (or `C h^p`), by least squares on the logarithms, from the
+/// errors measured for each number of steps.
+///
+/// ```
+/// # use scilib::math::sde::convergence_order;
+/// let levels = [10, 20, 40, 80];
+/// let errs: Vec<f64> = levels.iter().map(|&n| 3.0 / (n as f64).powf(1.5)).collect();
+/// assert!((convergence_order(&levels, &errs) - 1.5).abs() < 1.0e-12);
+/// ```
+pub fn convergence_order(levels: &[usize], errors: &[f64]) -> f64 {
+
+    assert!(levels.len() == errors.len() && levels.len() > 1, "At least two levels are needed!");
+
+    let n: f64 = levels.len() as f64;
+    let xs: Vec<f64> = levels.iter().map(|&l| (l as f64).ln()).collect();
+    let ys: Vec<f64> = errors.iter().map(|e| e.ln()).collect();
+    let (mx, my): (f64, f64) = (xs.iter().sum::<f64>() / n, ys.iter().sum::<f64>() / n);
+
+    let sxy: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let sxx: f64 = xs.iter().map(|x| (x - mx) * (x - mx)).sum();
+    -sxy / sxx
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,424 @@
+//!
+//! # Cubic splines
+//!
+//! Piecewise cubic interpolation of tabulated values, twice continuously differentiable. The spline is
+//! stored through its second derivatives (the moments) at the knots, found by solving a tridiagonal system.
+//! Two boundary conditions are provided:
+//!
+//! - natural splines, with vanishing second derivatives at both ends;
+//! - periodic splines, where the table covers one period and the spline joins itself smoothly, as for
+//!   orbit interpolation tables or angular profiles.
+//!
+//! Closed planar curves, such as contours, are interpolated by a pair of periodic splines sharing the
+//! cumulative chord length as parameter.
+//!
+//! ```
+//! # use scilib::math::spline::CubicSpline;
+//! // One period of a sine, without repeating the last point
+//! let x: Vec<f64> = (0..24).map(|i| i as f64 * std::f64::consts::TAU / 24.0).collect();
+//! let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+//!
+//! let spline = CubicSpline::periodic(&x, &y, std::f64::consts::TAU);
+//! assert!((spline.eval(1.0) - 1.0_f64.sin()).abs() < 1.0e-5);
+//! assert!((spline.eval(1.0 + std::f64::consts::TAU) - spline.eval(1.0)).abs() < 1.0e-14);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::summation;       // Accurate sums
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Nodes of the 5 points Gauss-Legendre quadrature
+const GAUSS_NODES: [f64; 5] = [-0.906179845938664, -0.5384693101056831, 0.0, 0.5384693101056831, 0.906179845938664];
+
+/// # Weights of the 5 points Gauss-Legendre quadrature
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.23692688505618908, 0.47862867049936647, 0.5688888888888889, 0.47862867049936647, 0.23692688505618908
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Cubic spline
+///
+/// Knots, values and moments of the spline. For periodic splines, the first point is repeated one period
+/// later at the end of the table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CubicSpline {
+    /// Knots, strictly increasing
+    knots: Vec<f64>,
+    /// Values at the knots
+    values: Vec<f64>,
+    /// Second derivatives at the knots
+    moments: Vec<f64>,
+    /// Whether the spline repeats itself over the range of the knots
+    periodic: bool
+}
+
+/// Implementing required methods
+impl CubicSpline {
+    /// # Natural spline
+    ///
+    /// Interpolates the `values` at the (at least two) strictly increasing `knots`, with vanishing second
+    /// derivatives at both ends. Outside of the knots, the spline is extended by its first and last cubic pieces.
+    ///
+    /// ```
+    /// # use scilib::math::spline::CubicSpline;
+    /// let spline = CubicSpline::natural(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 0.0, 1.0]);
+    ///
+    /// assert!((spline.eval(2.0)).abs() < 1.0e-15);
+    /// assert!((spline.eval(0.5) - 0.75).abs() < 1.0e-15);
+    /// assert!((spline.derivative(1.5) - -4.0 / 3.0).abs() < 1.0e-15);
+    /// assert!(spline.second_derivative(3.0).abs() < 1.0e-15);
+    ///
+    /// // Linear data is reproduced exactly
+    /// let line = CubicSpline::natural(&[0.0, 0.5, 2.0], &[1.0, 2.0, 5.0]);
+    /// assert!((line.eval(1.2) - 3.4).abs() < 1.0e-15);
+    /// ```
+    pub fn natural<T, U>(knots: &[T], values: &[U]) -> Self
+    where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+        let (knots, values): (Vec<f64>, Vec<f64>) = Self::table(knots, values);
+        assert!(knots.len() >= 2, "A natural spline needs at least two knots");
+        let n: usize = knots.len() - 1;
+        let mut moments: Vec<f64> = vec![0.0; n + 1];
+
+        if n >= 2 {
+            // Interior equations, the end moments being zero
+            let (mut sub, mut diag): (Vec<f64>, Vec<f64>) = (Vec::with_capacity(n), Vec::with_capacity(n));
+            let (mut sup, mut rhs): (Vec<f64>, Vec<f64>) = (Vec::with_capacity(n), Vec::with_capacity(n));
+            for i in 1..n {
+                let (h_0, h_1): (f64, f64) = (knots[i] - knots[i - 1], knots[i + 1] - knots[i]);
+                sub.push(h_0);
+                diag.push(2.0 * (h_0 + h_1));
+                sup.push(h_1);
+                rhs.push(6.0 * ((values[i + 1] - values[i]) / h_1 - (values[i] - values[i - 1]) / h_0));
+            }
+            moments[1..n].copy_from_slice(&tridiagonal(&sub, &diag, &sup, &rhs));
+        }
+
+        Self { knots, values, moments, periodic: false }
+    }
+
+    /// # Periodic spline
+    ///
+    /// Interpolates the `values` at the strictly increasing `knots`, which cover less than one `period`:
+    /// the first point must not be repeated at the end. The spline and its first two derivatives are
+    /// continuous across the period, and it is evaluated anywhere by periodicity.
+    ///
+    /// ```
+    /// # use scilib::math::spline::CubicSpline;
+    /// // Right ascension of a body sampled irregularly over its period
+    /// let x = [0.0, 0.7, 1.9, 2.6, 4.1, 5.2];
+    /// let y: Vec<f64> = x.iter().map(|v: &f64| v.cos() + 0.3 * (2.0 * v).sin()).collect();
+    /// let spline = CubicSpline::periodic(&x, &y, std::f64::consts::TAU);
+    ///
+    /// assert!((spline.eval(1.9) - y[2]).abs() < 1.0e-15);
+    /// assert!((spline.eval(-1.9) - spline.eval(std::f64::consts::TAU - 1.9)).abs() < 1.0e-15);
+    ///
+    /// // Smooth across the period
+    /// let eps: f64 = 1.0e-9;
+    /// let left: f64 = spline.derivative(std::f64::consts::TAU - eps);
+    /// assert!((left - spline.derivative(0.0)).abs() < 1.0e-7);
+    ///
+    /// // Mean value over the period
+    /// let z: Vec<f64> = x.iter().map(|v: &f64| 2.0 + v.sin()).collect();
+    /// let spline = CubicSpline::periodic(&x, &z, std::f64::consts::TAU);
+    /// assert!((spline.integral() / std::f64::consts::TAU - 2.0).abs() < 1.0e-3);
+    /// ```
+    pub fn periodic<T, U>(knots: &[T], values: &[U], period: f64) -> Self
+    where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+        let (mut knots, mut values): (Vec<f64>, Vec<f64>) = Self::table(knots, values);
+        assert!(knots[knots.len() - 1] < knots[0] + period, "The knots must cover less than one period");
+
+        // Closing the table one period later
+        let n: usize = knots.len();
+        knots.push(knots[0] + period);
+        values.push(values[0]);
+
+        let h: Vec<f64> = knots.windows(2).map(|w| w[1] - w[0]).collect();
+        let slope: Vec<f64> = (0..n).map(|i| (values[i + 1] - values[i]) / h[i]).collect();
+
+        // Cyclic equations, the interval before the first knot being the last one
+        let mut moments: Vec<f64> = match n {
+            1 => vec![0.0],
+            2 => {
+                // Both neighbours of each knot are the other one
+                let s: f64 = h[0] + h[1];
+                let r: [f64; 2] = [6.0 * (slope[0] - slope[1]), 6.0 * (slope[1] - slope[0])];
+                vec![(2.0 * r[0] - r[1]) / (3.0 * s), (2.0 * r[1] - r[0]) / (3.0 * s)]
+            },
+            _ => {
+                let sub: Vec<f64> = (0..n).map(|i| h[(i + n - 1) % n]).collect();
+                let diag: Vec<f64> = (0..n).map(|i| 2.0 * (h[(i + n - 1) % n] + h[i])).collect();
+                let rhs: Vec<f64> = (0..n).map(|i| 6.0 * (slope[i] - slope[(i + n - 1) % n])).collect();
+                cyclic_tridiagonal(&sub, &diag, &h, &rhs)
+            }
+        };
+        moments.push(moments[0]);
+
+        Self { knots, values, moments, periodic: true }
+    }
+
+    /// # Value of the spline
+    pub fn eval(&self, t: f64) -> f64 {
+        let (i, a, b, h): (usize, f64, f64, f64) = self.locate(t);
+        a * self.values[i] + b * self.values[i + 1]
+            + ((a * a * a - a) * self.moments[i] + (b * b * b - b) * self.moments[i + 1]) * h * h / 6.0
+    }
+
+    /// # First derivative of the spline
+    pub fn derivative(&self, t: f64) -> f64 {
+        let (i, a, b, h): (usize, f64, f64, f64) = self.locate(t);
+        (self.values[i + 1] - self.values[i]) / h
+            + ((3.0 * b * b - 1.0) * self.moments[i + 1] - (3.0 * a * a - 1.0) * self.moments[i]) * h / 6.0
+    }
+
+    /// # Second derivative of the spline
+    pub fn second_derivative(&self, t: f64) -> f64 {
+        let (i, a, b, _): (usize, f64, f64, f64) = self.locate(t);
+        a * self.moments[i] + b * self.moments[i + 1]
+    }
+
+    /// # Integral of the spline
+    ///
+    /// Integral over the range of the knots, which is one period for periodic splines.
+    pub fn integral(&self) -> f64 {
+        summation::neumaier_sum((0..self.knots.len() - 1).map(|i| {
+            let h: f64 = self.knots[i + 1] - self.knots[i];
+            0.5 * h * (self.values[i] + self.values[i + 1]) - h * h * h * (self.moments[i] + self.moments[i + 1]) / 24.0
+        }))
+    }
+
+    /// # Period of the spline
+    ///
+    /// `None` for natural splines.
+    pub fn period(&self) -> Option<f64> {
+        if self.periodic {
+            Some(self.knots[self.knots.len() - 1] - self.knots[0])
+        } else {
+            None
+        }
+    }
+
+    /// Knots and values as `f64`, checking the table
+    fn table<T, U>(knots: &[T], values: &[U]) -> (Vec<f64>, Vec<f64>)
+    where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+        assert!(!knots.is_empty() && knots.len() == values.len(), "Knots and values must have the same length");
+        let knots: Vec<f64> = knots.iter().map(|&v| v.into()).collect();
+        assert!(knots.windows(2).all(|w| w[0] < w[1]), "The knots must be strictly increasing");
+
+        (knots, values.iter().map(|&v| v.into()).collect())
+    }
+
+    /// Interval containing `t`, with the weights `a` and `b` of its ends, and its width
+    fn locate(&self, t: f64) -> (usize, f64, f64, f64) {
+
+        let n: usize = self.knots.len() - 1;
+        let t: f64 = match self.period() {
+            Some(p) => self.knots[0] + (t - self.knots[0]).rem_euclid(p),
+            None => t
+        };
+
+        let i: usize = self.knots.partition_point(|&k| k <= t).clamp(1, n) - 1;
+        let h: f64 = self.knots[i + 1] - self.knots[i];
+        let b: f64 = (t - self.knots[i]) / h;
+
+        (i, 1.0 - b, b, h)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Closed curve
+///
+/// Periodic spline interpolation of a closed planar curve through the given points, taken in order. Each
+/// coordinate is a periodic spline of the cumulative chord length, which follows the arc length closely and
+/// keeps the interpolation smooth for unevenly spaced points.
+///
+/// ```
+/// # use scilib::math::spline::ClosedCurve;
+/// // Ellipse of semi-axes 2 and 1, sampled at 40 points
+/// let points: Vec<[f64; 2]> = (0..40).map(|i| {
+///     let t: f64 = i as f64 * std::f64::consts::TAU / 40.0;
+///     [2.0 * t.cos(), t.sin()]
+/// }).collect();
+///
+/// let curve = ClosedCurve::new(&points);
+/// assert!((curve.area() - 2.0 * std::f64::consts::PI).abs() < 1.0e-4);
+/// assert!((curve.length() - 9.688448220547675).abs() < 1.0e-4);
+///
+/// // The curve goes through the points, and closes on itself
+/// let p = curve.eval(curve.period());
+/// assert!((p[0] - 2.0).abs() < 1.0e-14 && p[1].abs() < 1.0e-14);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClosedCurve {
+    /// Abscissa along the curve
+    x: CubicSpline,
+    /// Ordinate along the curve
+    y: CubicSpline
+}
+
+/// Implementing required methods
+impl ClosedCurve {
+    /// # Curve through points
+    ///
+    /// At least three points are needed, consecutive points (including the last and first) being distinct.
+    pub fn new(points: &[[f64; 2]]) -> Self {
+
+        assert!(points.len() >= 3, "A closed curve needs at least three points");
+
+        let mut param: Vec<f64> = vec![0.0];
+        for (i, p) in points.iter().enumerate().skip(1) {
+            let chord: f64 = (p[0] - points[i - 1][0]).hypot(p[1] - points[i - 1][1]);
+            param.push(param[i - 1] + chord);
+        }
+        let (first, last): ([f64; 2], [f64; 2]) = (points[0], points[points.len() - 1]);
+        let period: f64 = param[points.len() - 1] + (first[0] - last[0]).hypot(first[1] - last[1]);
+
+        let xs: Vec<f64> = points.iter().map(|p| p[0]).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p[1]).collect();
+
+        Self {
+            x: CubicSpline::periodic(&param, &xs, period),
+            y: CubicSpline::periodic(&param, &ys, period)
+        }
+    }
+
+    /// # Range of the parameter
+    ///
+    /// The length of the closed polygon through the points.
+    pub fn period(&self) -> f64 {
+        self.x.period().unwrap_or(0.0)
+    }
+
+    /// # Point of the curve
+    pub fn eval(&self, s: f64) -> [f64; 2] {
+        [self.x.eval(s), self.y.eval(s)]
+    }
+
+    /// # Tangent vector
+    ///
+    /// Derivative of the curve with respect to the parameter, of norm close to one.
+    pub fn tangent(&self, s: f64) -> [f64; 2] {
+        [self.x.derivative(s), self.y.derivative(s)]
+    }
+
+    /// # Curvature
+    ///
+    /// Signed curvature, positive where the curve turns counterclockwise.
+    ///
+    /// ```
+    /// # use scilib::math::spline::ClosedCurve;
+    /// let points: Vec<[f64; 2]> = (0..64).map(|i| {
+    ///     let t: f64 = i as f64 * std::f64::consts::TAU / 64.0;
+    ///     [3.0 * t.cos(), 3.0 * t.sin()]
+    /// }).collect();
+    ///
+    /// let curve = ClosedCurve::new(&points);
+    /// assert!((curve.curvature(1.0) - 1.0 / 3.0).abs() < 1.0e-3);
+    /// ```
+    pub fn curvature(&self, s: f64) -> f64 {
+        let [dx, dy]: [f64; 2] = self.tangent(s);
+        let (ddx, ddy): (f64, f64) = (self.x.second_derivative(s), self.y.second_derivative(s));
+        (dx * ddy - dy * ddx) / dx.hypot(dy).powi(3)
+    }
+
+    /// # Enclosed area
+    ///
+    /// Signed area, positive for counterclockwise curves. The result is exact for the spline curve.
+    pub fn area(&self) -> f64 {
+        0.5 * self.quadrature(|s| {
+            let [x, y]: [f64; 2] = self.eval(s);
+            let [dx, dy]: [f64; 2] = self.tangent(s);
+            x * dy - y * dx
+        })
+    }
+
+    /// # Length of the curve
+    pub fn length(&self) -> f64 {
+        self.quadrature(|s| {
+            let [dx, dy]: [f64; 2] = self.tangent(s);
+            dx.hypot(dy)
+        })
+    }
+
+    /// # Resampling
+    ///
+    /// `n` points evenly spaced in the parameter, starting from the first point.
+    ///
+    /// ```
+    /// # use scilib::math::spline::ClosedCurve;
+    /// let curve = ClosedCurve::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    /// let points = curve.resample(8);
+    ///
+    /// assert_eq!(points.len(), 8);
+    /// assert!((points[2][0] - 1.0).abs() < 1.0e-15 && points[2][1].abs() < 1.0e-15);
+    /// ```
+    pub fn resample(&self, n: usize) -> Vec<[f64; 2]> {
+        let step: f64 = self.period() / n as f64;
+        (0..n).map(|i| self.eval(i as f64 * step)).collect()
+    }
+
+    /// Gauss-Legendre quadrature of `f` over the period, interval by interval
+    fn quadrature<F>(&self, f: F) -> f64
+    where F: Fn(f64) -> f64 {
+
+        summation::neumaier_sum(self.x.knots.windows(2).map(|w| {
+            let (mid, half): (f64, f64) = (0.5 * (w[0] + w[1]), 0.5 * (w[1] - w[0]));
+            half * GAUSS_NODES.iter().zip(&GAUSS_WEIGHTS).map(|(t, c)| c * f(mid + half * t)).sum::<f64>()
+        }))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Thomas algorithm for the tridiagonal system of sub-diagonal `sub` (the first being unused), diagonal
+/// `diag` and super-diagonal `sup` (the last being unused)
+fn tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Vec<f64> {
+
+    let n: usize = diag.len();
+    let mut c: Vec<f64> = vec![0.0; n];
+    let mut d: Vec<f64> = vec![0.0; n];
+
+    for i in 0..n {
+        let (c_prev, d_prev): (f64, f64) = if i == 0 { (0.0, 0.0) } else { (c[i - 1], d[i - 1]) };
+        let denom: f64 = diag[i] - sub[i] * c_prev;
+        c[i] = sup[i] / denom;
+        d[i] = (rhs[i] - sub[i] * d_prev) / denom;
+    }
+
+    for i in (0..n.saturating_sub(1)).rev() {
+        d[i] -= c[i] * d[i + 1];
+    }
+
+    d
+}
+
+/// Cyclic tridiagonal system, `sub[0]` and `sup[n-1]` being the corner elements, solved with the
+/// Sherman-Morrison formula (for at least three unknowns)
+fn cyclic_tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Vec<f64> {
+
+    let n: usize = diag.len();
+    let (alpha, beta): (f64, f64) = (sup[n - 1], sub[0]);
+    let gamma: f64 = -diag[0];
+
+    let mut modified: Vec<f64> = diag.to_vec();
+    modified[0] -= gamma;
+    modified[n - 1] -= alpha * beta / gamma;
+
+    let x: Vec<f64> = tridiagonal(sub, &modified, sup, rhs);
+    let mut u: Vec<f64> = vec![0.0; n];
+    u[0] = gamma;
+    u[n - 1] = alpha;
+    let z: Vec<f64> = tridiagonal(sub, &modified, sup, &u);
+
+    let factor: f64 = (x[0] + beta * x[n - 1] / gamma) / (1.0 + z[0] + beta * z[n - 1] / gamma);
+    x.iter().zip(&z).map(|(a, b)| a - factor * b).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
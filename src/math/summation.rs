@@ -0,0 +1,148 @@
+//!
+//! # Accurate summation
+//!
+//! The rounding errors of a plain sum of `n` values grow as `n` times the machine precision, and the sum of
+//! a few large values of opposite signs loses the small ones entirely. Compensated summation carries the
+//! rounding error of each addition along: Kahan's algorithm bounds the error independently of `n`, and
+//! Neumaier's variant also handles the terms larger than the running sum. The pairwise sum splits the
+//! values recursively, which brings the error down to `log n` times the machine precision, at the speed
+//! of the plain sum.
+//!
+//! ```
+//! # use scilib::math::summation::{ neumaier_sum, pairwise_sum };
+//! let values = [1.0, 1.0e100, 1.0, -1.0e100];
+//! assert_eq!(values.iter().sum::<f64>(), 0.0);
+//! assert_eq!(neumaier_sum(values), 2.0);
+//!
+//! let tenths: Vec<f64> = vec![0.1; 1_000_000];
+//! assert!((tenths.iter().sum::<f64>() - 1.0e5).abs() > 1.0e-6);
+//! assert!((pairwise_sum(&tenths) - 1.0e5).abs() < 1.0e-9);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Size of the blocks summed directly by the pairwise sum
+const PAIRWISE_BLOCK: usize = 128;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Kahan summation
+///
+/// Sum of the values with Kahan's compensation of the rounding errors, accurate to a few units of the
+/// machine precision of the sum of the absolute values, whatever their number.
+///
+/// ```
+/// # use scilib::math::summation::kahan_sum;
+/// let res = kahan_sum((0..1_000_000).map(|_| 0.1));
+/// assert_eq!(res, 1.0e5);
+/// ```
+pub fn kahan_sum<I, T>(values: I) -> f64
+where I: IntoIterator<Item = T>, T: Into<f64> {
+
+    let (mut sum, mut comp): (f64, f64) = (0.0, 0.0);
+    for v in values {
+        let y: f64 = v.into() - comp;
+        let t: f64 = sum + y;
+        comp = (t - sum) - y;
+        sum = t;
+    }
+
+    sum
+}
+
+/// # Neumaier summation
+///
+/// Sum of the values with Neumaier's compensation, which unlike Kahan's remains exact when a term is larger
+/// than the running sum, as in the cancellation of large values.
+///
+/// ```
+/// # use scilib::math::summation::{ kahan_sum, neumaier_sum };
+/// let values = [1.0, 1.0e100, 1.0, -1.0e100];
+/// assert_eq!(kahan_sum(values), 0.0);
+/// assert_eq!(neumaier_sum(values), 2.0);
+/// ```
+pub fn neumaier_sum<I, T>(values: I) -> f64
+where I: IntoIterator<Item = T>, T: Into<f64> {
+
+    let mut acc: CompensatedSum = CompensatedSum::new();
+    for v in values {
+        acc.add(v.into());
+    }
+
+    acc.value()
+}
+
+/// # Pairwise summation
+///
+/// Sum of the values by recursive halving, the blocks of 128 values being summed directly. The error grows
+/// as `log n`, at nearly the cost of the plain sum.
+///
+/// ```
+/// # use scilib::math::summation::pairwise_sum;
+/// let values: Vec<f64> = (1..=10_000).map(|k| 1.0 / k as f64).collect();
+/// assert!((pairwise_sum(&values) - 9.787606036044382).abs() < 1.0e-14);
+/// assert_eq!(pairwise_sum(&[1, 2, 3]), 6.0);
+/// ```
+pub fn pairwise_sum<T>(values: &[T]) -> f64
+where T: Into<f64> + Copy {
+
+    if values.len() <= PAIRWISE_BLOCK {
+        return values.iter().fold(0.0, |acc, &v| acc + v.into());
+    }
+
+    let (left, right): (&[T], &[T]) = values.split_at(values.len() / 2);
+    pairwise_sum(left) + pairwise_sum(right)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Compensated accumulator
+///
+/// Running sum with Neumaier's compensation, for the loops where the terms are produced one by one, as in
+/// the series.
+///
+/// ```
+/// # use scilib::math::summation::CompensatedSum;
+/// let mut acc = CompensatedSum::new();
+/// for k in 1..=100_000 {
+///     acc.add(1.0 / (k as f64 * k as f64));
+/// }
+///
+/// // Sum of 1/k^2, up to the tail 1/n
+/// let exact: f64 = std::f64::consts::PI.powi(2) / 6.0 - 1.0 / 100_000.0 + 0.5 / 1.0e10;
+/// assert!((acc.value() - exact).abs() < 1.0e-15);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompensatedSum {
+    /// Running sum
+    sum: f64,
+    /// Accumulated rounding errors
+    comp: f64
+}
+
+/// Implementing required methods
+impl CompensatedSum {
+    /// # Empty sum
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Adds a value to the sum
+    pub fn add(&mut self, v: f64) {
+        let t: f64 = self.sum + v;
+        if self.sum.abs() >= v.abs() {
+            self.comp += (self.sum - t) + v;
+        } else {
+            self.comp += (v - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    /// # Compensated value of the sum
+    pub fn value(&self) -> f64 {
+        self.sum + self.comp
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
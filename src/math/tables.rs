@@ -0,0 +1,147 @@
+//!
+//! # Precomputed tables
+//!
+//! Lookup tables for values that are repeatedly needed in series expansions: factorials, double factorials
+//! and the logarithm of the gamma function at half-integers. Using them in inner loops avoids recomputing
+//! products or gamma values for every term.
+//!
+//! The factorial tables are built at compile time and cover the whole range representable by `f64`. The
+//! log-gamma table is built on first use.
+//!
+//! ```
+//! # use scilib::math::tables;
+//! assert_eq!(tables::factorial(10), 3_628_800.0);
+//! assert_eq!(tables::double_factorial(7), 105.0);
+//! assert!(tables::factorial(171).is_infinite());
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::sync::OnceLock;        // Lazy table initialization
+
+use std::f64::consts::PI;       // Pi
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Largest n for which n! fits in a f64
+pub const FACTORIAL_MAX: usize = 170;
+
+/// # Largest n for which n!! fits in a f64
+pub const DOUBLE_FACTORIAL_MAX: usize = 300;
+
+/// # Factorials table
+///
+/// `FACTORIAL[n]` is `n!` as a float, for `n` up to `FACTORIAL_MAX`.
+pub const FACTORIAL: [f64; FACTORIAL_MAX + 1] = build_factorial();
+
+/// # Double factorials table
+///
+/// `DOUBLE_FACTORIAL[n]` is `n!! = n (n-2) (n-4) ...` as a float, for `n` up to `DOUBLE_FACTORIAL_MAX`.
+pub const DOUBLE_FACTORIAL: [f64; DOUBLE_FACTORIAL_MAX + 1] = build_double_factorial();
+
+/// Size of the log-gamma table
+const LN_GAMMA_HALF_SIZE: usize = 2048;
+
+/// Storage of the log-gamma table
+static LN_GAMMA_HALF: OnceLock<Vec<f64>> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the factorials at compile time
+const fn build_factorial() -> [f64; FACTORIAL_MAX + 1] {
+    let mut res: [f64; FACTORIAL_MAX + 1] = [1.0; FACTORIAL_MAX + 1];
+    let mut n: usize = 1;
+
+    while n <= FACTORIAL_MAX {
+        res[n] = res[n - 1] * n as f64;
+        n += 1;
+    }
+
+    res
+}
+
+/// Computes the double factorials at compile time
+const fn build_double_factorial() -> [f64; DOUBLE_FACTORIAL_MAX + 1] {
+    let mut res: [f64; DOUBLE_FACTORIAL_MAX + 1] = [1.0; DOUBLE_FACTORIAL_MAX + 1];
+    let mut n: usize = 2;
+
+    while n <= DOUBLE_FACTORIAL_MAX {
+        res[n] = res[n - 2] * n as f64;
+        n += 1;
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Factorial lookup
+///
+/// Returns `n!` as a float, or infinity when it exceeds the range of `f64`. Contrary to
+/// `basic::factorial`, this never overflows, and is suited for use in series coefficients.
+pub fn factorial(n: usize) -> f64 {
+    FACTORIAL.get(n).copied().unwrap_or(f64::INFINITY)
+}
+
+/// # Double factorial lookup
+///
+/// Returns `n!!` as a float, or infinity when it exceeds the range of `f64`. By convention `0!! = 1`.
+///
+/// ```
+/// # use scilib::math::tables::double_factorial;
+/// assert_eq!(double_factorial(0), 1.0);
+/// assert_eq!(double_factorial(8), 384.0);
+/// assert!(double_factorial(300).is_finite());
+/// ```
+pub fn double_factorial(n: usize) -> f64 {
+    DOUBLE_FACTORIAL.get(n).copied().unwrap_or(f64::INFINITY)
+}
+
+/// # Log-gamma at half-integers
+///
+/// Returns `ln(gamma(k / 2))` for `k >= 1`, which covers both the integers and the half-integers. The
+/// values are tabulated up to `k = 2047` on first use, larger arguments being extended with the
+/// recurrence `gamma(x + 1) = x gamma(x)`.
+///
+/// Such values appear in the normalization of spherical Bessel functions, Wigner symbols and
+/// chi-squared distributions.
+///
+/// ```
+/// # use scilib::math::tables::ln_gamma_half;
+/// // gamma(1/2) = sqrt(pi)
+/// assert!((ln_gamma_half(1) - std::f64::consts::PI.sqrt().ln()).abs() < 1.0e-15);
+/// // gamma(5) = 24
+/// assert!((ln_gamma_half(10) - 24.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_gamma_half(k: usize) -> f64 {
+
+    assert!(k > 0, "Gamma has a pole at 0");
+
+    let table: &Vec<f64> = LN_GAMMA_HALF.get_or_init(|| {
+        // Index k holds ln(gamma(k / 2)), index 0 is unused
+        let mut res: Vec<f64> = vec![0.0; LN_GAMMA_HALF_SIZE];
+        res[1] = PI.sqrt().ln();
+        res[2] = 0.0;
+        for i in 3..LN_GAMMA_HALF_SIZE {
+            res[i] = res[i - 2] + ((i - 2) as f64 / 2.0).ln();
+        }
+        res
+    });
+
+    if k < LN_GAMMA_HALF_SIZE {
+        return table[k];
+    }
+
+    // Extending from the last tabulated value of the same parity
+    let mut i: usize = LN_GAMMA_HALF_SIZE - 2 + (k - LN_GAMMA_HALF_SIZE) % 2;
+    let mut res: f64 = table[i];
+    while i < k {
+        res += (i as f64 / 2.0).ln();
+        i += 2;
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,209 @@
+//!
+//! # Jacobi theta functions
+//!
+//! The theta functions are quasi-periodic functions of `z`, defined from the nome `q = exp(i pi tau)` of a
+//! parameter `tau` of the upper half-plane (`Im(tau) > 0`):
+//!
+//! - `theta_1(z|tau) = 2 sum (-1)^n q^((n+1/2)^2) sin((2n+1) z)`
+//! - `theta_2(z|tau) = 2 sum q^((n+1/2)^2) cos((2n+1) z)`
+//! - `theta_3(z|tau) = 1 + 2 sum q^(n^2) cos(2n z)`
+//! - `theta_4(z|tau) = 1 + 2 sum (-1)^n q^(n^2) cos(2n z)`
+//!
+//! They are the building blocks of the elliptic functions, and give closed forms of lattice sums and of
+//! the heat kernel on a periodic domain.
+//!
+//! The series converge slowly when `q` approaches the unit circle. Before summing, `tau` is brought close
+//! to the fundamental domain `|tau| >= 1`, `|Re(tau)| <= 1/2` with the modular transformations
+//! `tau -> tau + 1` and `tau -> -1 / tau`, and `z` is reduced to the fundamental parallelogram with the
+//! quasi-periods `pi` and `pi tau`. The remaining series then have `|q| < 0.07` and converge in a few terms.
+//!
+//! ```
+//! # use scilib::math::complex::Complex;
+//! # use scilib::math::theta::{ theta_2, theta_3, theta_4 };
+//! let tau = Complex::from(0.1, 0.8);
+//!
+//! // Jacobi's identity
+//! let (t2, t3, t4) = (theta_2(0, tau), theta_3(0, tau), theta_4(0, tau));
+//! let diff = t3.powi(4) - t2.powi(4) - t4.powi(4);
+//! assert!(diff.modulus() < 1.0e-13);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{         // Using std lib constants
+    PI,                         // Pi
+    FRAC_PI_4                   // Pi / 4
+};
+
+use super::complex::Complex;    // Using Complex numbers
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relative size of the last terms of the series
+const PRECISION: f64 = 1.0e-17;
+
+/// Cap on the number of terms of the series
+const MAX_TERMS: usize = 1_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # First theta function
+///
+/// `z` is the value to evaluate and `tau` the parameter, with `Im(tau) > 0` (complex inputs accepted).
+/// The function is odd and `pi`-antiperiodic in `z`. Returns `NaN` if `Im(tau) <= 0`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::theta::theta_1;
+/// let res = theta_1(0.5, Complex::from(0.0, 1.0));
+/// assert!((res.re - 0.43547815754614333).abs() < 1.0e-15 && res.im.abs() < 1.0e-15);
+///
+/// // Close to the real axis, where the series alone converges slowly
+/// let res = theta_1(Complex::from(1.0, 0.5), Complex::from(0.3, 0.002));
+/// assert!((res.re / 1.7555339967389619e17 - 1.0).abs() < 1.0e-10 && (res.im / -2.1515175146963226e17 - 1.0).abs() < 1.0e-10);
+/// ```
+pub fn theta_1<T, U>(z: T, tau: U) -> Complex
+where T: Into<Complex>, U: Into<Complex> {
+    theta(1, z.into(), tau.into())
+}
+
+/// # Second theta function
+///
+/// `z` is the value to evaluate and `tau` the parameter, with `Im(tau) > 0` (complex inputs accepted).
+/// The function is even and `pi`-antiperiodic in `z`. Returns `NaN` if `Im(tau) <= 0`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::theta::theta_2;
+/// let res = theta_2(Complex::from(0.2, -0.4), Complex::from(-0.7, 0.3));
+/// assert!((res.re - 1.3997857557251245).abs() < 1.0e-14 && (res.im - -0.37244681899273494).abs() < 1.0e-14);
+/// ```
+pub fn theta_2<T, U>(z: T, tau: U) -> Complex
+where T: Into<Complex>, U: Into<Complex> {
+    theta(2, z.into(), tau.into())
+}
+
+/// # Third theta function
+///
+/// `z` is the value to evaluate and `tau` the parameter, with `Im(tau) > 0` (complex inputs accepted).
+/// The function is even and `pi`-periodic in `z`. Returns `NaN` if `Im(tau) <= 0`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::theta::theta_3;
+/// // theta_3(0 | i) = pi^(1/4) / Gamma(3/4)
+/// assert!((theta_3(0, Complex::from(0.0, 1.0)).re - 1.086434811213308).abs() < 1.0e-15);
+///
+/// let res = theta_3(Complex::from(3.0, 2.0), Complex::from(1.5, 1.2));
+/// assert!((res.re - 1.3522961871175432).abs() < 1.0e-14 && (res.im - -1.2085312679313951).abs() < 1.0e-14);
+/// ```
+pub fn theta_3<T, U>(z: T, tau: U) -> Complex
+where T: Into<Complex>, U: Into<Complex> {
+    theta(3, z.into(), tau.into())
+}
+
+/// # Fourth theta function
+///
+/// `z` is the value to evaluate and `tau` the parameter, with `Im(tau) > 0` (complex inputs accepted).
+/// The function is even and `pi`-periodic in `z`. Returns `NaN` if `Im(tau) <= 0`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::theta::theta_4;
+/// let res = theta_4(0.7, Complex::from(0.0, 0.05));
+/// assert!((res.re - 0.035809787976757029).abs() < 1.0e-15 && res.im.abs() < 1.0e-15);
+/// ```
+pub fn theta_4<T, U>(z: T, tau: U) -> Complex
+where T: Into<Complex>, U: Into<Complex> {
+    theta(4, z.into(), tau.into())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Theta function of index `k`, reducing the arguments before summing the series
+fn theta(k: u8, z: Complex, tau: Complex) -> Complex {
+
+    if tau.im <= 0.0 || tau.im.is_nan() || z.re.is_nan() || z.im.is_nan() {
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    // Shifting tau by an integer m: theta_1 and theta_2 gain a phase, theta_3 and theta_4 swap for odd m
+    let m: f64 = tau.re.round();
+    let tau: Complex = tau - m;
+    let (k, mut factor): (u8, Complex) = match k {
+        1 | 2 => (k, Complex::from_polar(FRAC_PI_4 * m.rem_euclid(8.0), 1.0)),
+        _ if m.rem_euclid(2.0) == 1.0 => (7 - k, Complex::from(1.0, 0.0)),
+        _ => (k, Complex::from(1.0, 0.0))
+    };
+
+    // Quasi-period pi tau: theta(z + n pi tau) = (+-) q^(-n^2) exp(-2inz) theta(z)
+    let n: f64 = (z.im / (PI * tau.im)).round();
+    let z: Complex = z - n * PI * tau;
+    factor *= (Complex::i() * (-PI * n * n) * tau - Complex::i() * (2.0 * n) * z).exp();
+    if (k == 1 || k == 4) && n.rem_euclid(2.0) == 1.0 {
+        factor = -factor;
+    }
+
+    // Period pi, up to the sign of theta_1 and theta_2
+    let l: f64 = (z.re / PI).round();
+    let z: Complex = z - l * PI;
+    if (k == 1 || k == 2) && l.rem_euclid(2.0) == 1.0 {
+        factor = -factor;
+    }
+
+    // Imaginary transformation tau -> -1 / tau, increasing Im(tau)
+    if tau.re * tau.re + tau.im * tau.im < 1.0 {
+        let tau_t: Complex = -1.0 / tau;
+        let (k_t, phase): (u8, Complex) = match k {
+            1 => (1, Complex::from(0.0, -1.0)),
+            2 => (4, Complex::from(1.0, 0.0)),
+            3 => (3, Complex::from(1.0, 0.0)),
+            _ => (2, Complex::from(1.0, 0.0))
+        };
+        let root: Complex = (Complex::from(0.0, -1.0) * tau).powf(0.5);
+        let gauss: Complex = (Complex::i() * tau_t * z * z * (1.0 / PI)).exp();
+        return factor * phase * gauss * theta(k_t, z * tau_t, tau_t) / root;
+    }
+
+    factor * series(k, z, tau)
+}
+
+/// Sum of the series, each term written as exponentials to avoid overflows of `sin` and `cos`
+fn series(k: u8, z: Complex, tau: Complex) -> Complex {
+
+    let i_pi_tau: Complex = Complex::i() * PI * tau;
+    let iz: Complex = Complex::i() * z;
+    let half: bool = k == 1 || k == 2;
+
+    let mut res: Complex = if half { Complex::new() } else { Complex::from(1.0, 0.0) };
+    let mut last: f64 = f64::INFINITY;
+
+    for n in 0..MAX_TERMS {
+        // Index of the term: n + 1/2 for theta_1 and theta_2, n for theta_3 and theta_4 (from 1)
+        let nu: f64 = if half { n as f64 + 0.5 } else { n as f64 + 1.0 };
+        let plus: Complex = (i_pi_tau * nu * nu + 2.0 * nu * iz).exp();
+        let minus: Complex = (i_pi_tau * nu * nu - 2.0 * nu * iz).exp();
+
+        let sign: f64 = match k {
+            1 | 4 if (nu.floor() as usize) % 2 == 1 => -1.0,
+            _ => 1.0
+        };
+        let term: Complex = match k {
+            1 => Complex::from(0.0, -sign) * (plus - minus),
+            _ => sign * (plus + minus)
+        };
+        res += term;
+
+        // The terms only decrease past the peak set by Im(z)
+        let size: f64 = term.modulus();
+        if size <= last && size <= PRECISION * res.modulus() {
+            break;
+        }
+        last = size;
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
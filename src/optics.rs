@@ -0,0 +1,567 @@
+//!
+//! # Optical propagation & diffraction
+//!
+//! Scalar propagation of monochromatic fields through free space. The fields are complex amplitudes sampled
+//! on a regular grid of step `dx` (the same along both axes), given as rows of equal lengths, with the
+//! optical axis at the index `[n / 2][m / 2]`. The time dependence is `exp(-iωt)`, so that a wave travelling
+//! along `z` gains the phase `exp(ikz)`.
+//!
+//! - The angular spectrum method decomposes the field into plane waves with the 2D FFT, and propagates each
+//!   one exactly, the evanescent ones decaying; it is valid at any distance, as long as the field stays
+//!   inside the window.
+//! - The Fresnel approximation replaces the propagator by its paraxial expansion.
+//! - The Fraunhofer far field is the Fourier transform of the field itself, on a grid whose step grows with
+//!   the distance.
+//!
+//! The diffraction pattern of a circular aperture, the Airy disk, follows in closed form from `J_1`.
+//!
+//! The geometrical optics of rotationally symmetric systems, made of spherical surfaces and thin lenses, is
+//! handled with the paraxial ray transfer (ABCD) matrices, giving the focal lengths, and with the exact
+//! tracing of meridional rays, giving the aberrations.
+//!
+//! ```
+//! # use scilib::math::complex::Complex;
+//! # use scilib::optics::{ angular_spectrum, fresnel };
+//! // Gaussian beam of waist 20 µm, at 633 nm, propagated over 2 mm
+//! let (n, dx, wavelength, z): (usize, f64, f64, f64) = (64, 4.0e-6, 633.0e-9, 2.0e-3);
+//! let w0: f64 = 20.0e-6;
+//! let beam: Vec<Vec<Complex>> = (0..n).map(|i| (0..n).map(|j| {
+//!     let r2: f64 = ((i as f64 - 32.0).powi(2) + (j as f64 - 32.0).powi(2)) * dx * dx;
+//!     Complex::from((-r2 / (w0 * w0)).exp(), 0)
+//! }).collect()).collect();
+//!
+//! let exact = angular_spectrum(&beam, dx, wavelength, z);
+//! let paraxial = fresnel(&beam, dx, wavelength, z);
+//! assert!((exact[32][32] - paraxial[32][32]).modulus() < 1.0e-3);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    TAU                     // Full turn
+};
+
+use crate::{                // Calling other modules
+    math::bessel,           // J_1 of the Airy disk
+    math::complex::Complex, // Using Complex numbers
+    signal                  // 2D FFT
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Angular spectrum propagation
+///
+/// Propagates the `field`, of sampling step `dx`, over the `distance` along the optical axis, for light of
+/// the given `wavelength` (in the same unit as `dx`). Each plane wave of spatial frequencies `(fx, fy)` is
+/// multiplied by `exp(2i pi z sqrt(1 / λ^2 - fx^2 - fy^2))`, the evanescent ones decaying exponentially.
+///
+/// The FFT makes the field periodic: the window must be large enough for the propagated field to stay
+/// inside it, and the method is best suited to short distances.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::optics::angular_spectrum;
+/// // A tilted plane wave only gains a phase
+/// let (n, dx, wavelength, z): (usize, f64, f64, f64) = (32, 1.0e-6, 0.5e-6, 1.0e-4);
+/// let fx: f64 = 4.0 / (n as f64 * dx);
+/// let wave: Vec<Vec<Complex>> = (0..n).map(|_| (0..n).map(|j| {
+///     Complex::from_polar(std::f64::consts::TAU * fx * j as f64 * dx, 1.0)
+/// }).collect()).collect();
+///
+/// let res = angular_spectrum(&wave, dx, wavelength, z);
+/// let kz: f64 = std::f64::consts::TAU * (1.0 / wavelength.powi(2) - fx * fx).sqrt();
+/// let phase = Complex::from_polar(kz * z, 1.0);
+/// for (row, ori) in res.iter().zip(&wave) {
+///     for (r, o) in row.iter().zip(ori) {
+///         assert!((*r - *o * phase).modulus() < 1.0e-10);
+///     }
+/// }
+/// ```
+pub fn angular_spectrum(field: &[Vec<Complex>], dx: f64, wavelength: f64, distance: f64) -> Vec<Vec<Complex>> {
+
+    let inv_l2: f64 = 1.0 / (wavelength * wavelength);
+    propagate(field, dx, |fx: f64, fy: f64| {
+        let f2: f64 = fx * fx + fy * fy;
+        if f2 <= inv_l2 {
+            Complex::from_polar(TAU * distance * (inv_l2 - f2).sqrt(), 1.0)
+        } else {
+            Complex::from((-TAU * distance.abs() * (f2 - inv_l2).sqrt()).exp(), 0.0)
+        }
+    })
+}
+
+/// # Fresnel propagation
+///
+/// Propagates the `field`, of sampling step `dx`, over the `distance` in the paraxial (Fresnel)
+/// approximation: each plane wave of spatial frequencies `(fx, fy)` is multiplied by
+/// `exp(ikz) exp(-i pi λ z (fx^2 + fy^2))`. The result is sampled on the same grid as the field.
+///
+/// The transfer function is sampled without aliasing for `z < n dx^2 / λ`; the far field of larger distances
+/// is given by `fraunhofer`.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::optics::fresnel;
+/// // Gaussian beam: its amplitude on the axis follows 1 / (1 + i z / z_R)
+/// let (n, dx, wavelength, z): (usize, f64, f64, f64) = (64, 3.0e-6, 1.0e-6, 3.0e-4);
+/// let w0: f64 = 15.0e-6;
+/// let beam: Vec<Vec<Complex>> = (0..n).map(|i| (0..n).map(|j| {
+///     let r2: f64 = ((i as f64 - 32.0).powi(2) + (j as f64 - 32.0).powi(2)) * dx * dx;
+///     Complex::from((-r2 / (w0 * w0)).exp(), 0)
+/// }).collect()).collect();
+///
+/// let res = fresnel(&beam, dx, wavelength, z);
+/// let k: f64 = std::f64::consts::TAU / wavelength;
+/// let z_r: f64 = k * w0 * w0 / 2.0;
+/// let exact = Complex::from_polar(k * z, 1.0) / Complex::from(1, z / z_r);
+/// assert!((res[32][32] - exact).modulus() < 1.0e-10);
+/// ```
+pub fn fresnel(field: &[Vec<Complex>], dx: f64, wavelength: f64, distance: f64) -> Vec<Vec<Complex>> {
+
+    let carrier: f64 = TAU * distance / wavelength;
+    propagate(field, dx, |fx: f64, fy: f64| {
+        Complex::from_polar(carrier - PI * wavelength * distance * (fx * fx + fy * fy), 1.0)
+    })
+}
+
+/// # Fraunhofer diffraction
+///
+/// Far field of the `field`, of sampling step `dx`, at the `distance` along the optical axis:
+/// `U(x, y) = exp(ikz) exp(ik (x^2 + y^2) / 2z) / (iλz) F(x / λz, y / λz)`, where `F` is the Fourier
+/// transform of the field. The result is sampled with the step `λ z / (n dx)` along an axis of `n` values,
+/// with the optical axis at the index `[n / 2][m / 2]` as for the field.
+///
+/// The approximation holds for distances well beyond `a^2 / λ`, `a` being the size of the source.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::optics::fraunhofer;
+/// // Square aperture of side 8 dx: the far field is a product of sinc functions
+/// let (n, dx, wavelength, z): (usize, f64, f64, f64) = (32, 1.0e-5, 0.5e-6, 2.0);
+/// let aperture: Vec<Vec<Complex>> = (0..n).map(|i| (0..n).map(|j| {
+///     let inside: bool = (12..20).contains(&i) && (12..20).contains(&j);
+///     Complex::from(if inside { 1.0 } else { 0.0 }, 0)
+/// }).collect()).collect();
+///
+/// let res = fraunhofer(&aperture, dx, wavelength, z);
+/// let area: f64 = 64.0 * dx * dx;
+/// assert!((res[16][16].modulus() - area / (wavelength * z)).abs() < 1.0e-10);
+///
+/// // Zeros of the sinc at the multiples of λz / (8 dx), four output steps
+/// assert!(res[16][20].modulus() < 1.0e-12 && res[12][16].modulus() < 1.0e-12);
+/// ```
+pub fn fraunhofer(field: &[Vec<Complex>], dx: f64, wavelength: f64, distance: f64) -> Vec<Vec<Complex>> {
+
+    let (n, m): (usize, usize) = dimensions(field);
+    if n * m == 0 {
+        return Vec::new();
+    }
+
+    // Origin moved to the index 0 for the FFT, and back to the center afterwards
+    let shifted: Vec<Vec<Complex>> = (0..n).map(|i| {
+        (0..m).map(|j| field[(i + n / 2) % n][(j + m / 2) % m]).collect()
+    }).collect();
+    let spectrum: Vec<Vec<Complex>> = signal::fft2(&shifted);
+
+    let scale: f64 = wavelength * distance / dx;
+    let (step_x, step_y): (f64, f64) = (scale / n as f64, scale / m as f64);
+    let factor: Complex = Complex::from_polar(TAU * distance / wavelength, dx * dx / (wavelength * distance))
+        / Complex::i();
+
+    (0..n).map(|i| {
+        let x: f64 = (i as f64 - (n / 2) as f64) * step_x;
+        (0..m).map(|j| {
+            let y: f64 = (j as f64 - (m / 2) as f64) * step_y;
+            let curvature: Complex = Complex::from_polar(PI * (x * x + y * y) / (wavelength * distance), 1.0);
+            let value: Complex = spectrum[(i + n - n / 2) % n][(j + m - m / 2) % m];
+            factor * curvature * value
+        }).collect()
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Airy pattern
+///
+/// Normalized intensity `(2 J_1(x) / x)^2` of the diffraction pattern of a circular aperture, equal to 1 at
+/// the center. For an aperture of diameter `D`, `x = pi D sin(θ) / λ` at the angle `θ` from the axis.
+///
+/// ```
+/// # use scilib::optics::airy_pattern;
+/// assert_eq!(airy_pattern(0.0), 1.0);
+/// assert!((airy_pattern(1.0) - 0.77457807205783633809).abs() < 1.0e-10);
+/// assert!((airy_pattern(5.0) - 0.017169294621626896536).abs() < 1.0e-10);
+/// assert!(airy_pattern(3.8317059702075123).abs() < 1.0e-20);
+/// ```
+pub fn airy_pattern<T: Into<f64>>(x: T) -> f64 {
+
+    let x: f64 = x.into();
+    if x == 0.0 {
+        return 1.0;
+    }
+
+    (2.0 * bessel::j(x, 1).re / x).powi(2)
+}
+
+/// # Radius of the Airy disk
+///
+/// Angle `θ` of the first dark ring of the diffraction pattern of a circular aperture of diameter `diameter`,
+/// `sin(θ) = 1.21967 λ / D`: the limit of resolution of an instrument, by Rayleigh's criterion.
+///
+/// ```
+/// # use scilib::optics::airy_radius;
+/// // 2.4 m telescope in visible light, in arc seconds
+/// let res: f64 = airy_radius(550.0e-9, 2.4).to_degrees() * 3600.0;
+/// assert!((res - 0.057652598164220106).abs() < 1.0e-12);
+/// ```
+pub fn airy_radius(wavelength: f64, diameter: f64) -> f64 {
+    (bessel::j_zero(1, 1) * wavelength / (PI * diameter)).asin()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Meridional ray
+///
+/// Ray in a plane containing the optical axis, given by its height above the axis and its angle to the axis,
+/// in radians, positive when the ray rises.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray {
+    /// Height above the optical axis
+    pub height: f64,
+    /// Angle to the optical axis
+    pub angle: f64
+}
+
+/// # Ray transfer matrix
+///
+/// Paraxial (ABCD) matrix mapping the height and angle of a ray, `y' = A y + B u` and `u' = C y + D u`. The
+/// determinant is the ratio of the refractive indices before and after, 1 in a single medium.
+///
+/// ```
+/// # use scilib::optics::{ Abcd, Ray };
+/// // Object 30 before a lens of focal length 20: the image is 60 after it, magnified -2 times
+/// let m = Abcd::propagation(30.0).then(Abcd::thin_lens(20.0)).then(Abcd::propagation(60.0));
+/// assert!(m.b.abs() < 1.0e-12 && (m.a + 2.0).abs() < 1.0e-12);
+///
+/// // Every ray from the object point meets at the image point
+/// for angle in [-0.05, 0.0, 0.02] {
+///     let out = m.apply(Ray { height: 1.5, angle });
+///     assert!((out.height + 3.0).abs() < 1.0e-12);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Abcd {
+    /// Height from height
+    pub a: f64,
+    /// Height from angle
+    pub b: f64,
+    /// Angle from height
+    pub c: f64,
+    /// Angle from angle
+    pub d: f64
+}
+
+/// # Identity matrix
+impl Default for Abcd {
+    fn default() -> Self {
+        Self { a: 1.0, b: 0.0, c: 0.0, d: 1.0 }
+    }
+}
+
+/// Implementing required methods
+impl Abcd {
+    /// # Free propagation
+    ///
+    /// Propagation over the `distance` along the axis, in a homogeneous medium.
+    pub fn propagation(distance: f64) -> Self {
+        Self { a: 1.0, b: distance, c: 0.0, d: 1.0 }
+    }
+
+    /// # Thin lens
+    ///
+    /// Ideal lens of the given `focal` length, positive for a converging lens.
+    pub fn thin_lens(focal: f64) -> Self {
+        Self { a: 1.0, b: 0.0, c: -1.0 / focal, d: 1.0 }
+    }
+
+    /// # Spherical refracting surface
+    ///
+    /// Refraction at a surface of the given `curvature` (inverse of the radius, positive when the center is
+    /// after the surface), from the refractive index `n1` to `n2`.
+    pub fn refraction(curvature: f64, n1: f64, n2: f64) -> Self {
+        Self { a: 1.0, b: 0.0, c: -curvature * (n2 - n1) / n2, d: n1 / n2 }
+    }
+
+    /// # Sequence of elements
+    ///
+    /// Matrix of `self` followed by `next`, the product `next * self`.
+    pub fn then(&self, next: Self) -> Self {
+        Self {
+            a: next.a * self.a + next.b * self.c,
+            b: next.a * self.b + next.b * self.d,
+            c: next.c * self.a + next.d * self.c,
+            d: next.c * self.b + next.d * self.d
+        }
+    }
+
+    /// # Paraxial ray transfer
+    pub fn apply(&self, ray: Ray) -> Ray {
+        Ray {
+            height: self.a * ray.height + self.b * ray.angle,
+            angle: self.c * ray.height + self.d * ray.angle
+        }
+    }
+}
+
+/// # Optical element
+///
+/// Element of a rotationally symmetric system, followed by the distance to the next element along the axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Element {
+    /// Spherical surface, of the given `curvature` (0 for a plane), with the medium of refractive `index`
+    /// after it
+    Surface {
+        /// Inverse of the radius, positive when the center of curvature is after the surface
+        curvature: f64,
+        /// Refractive index after the surface
+        index: f64,
+        /// Distance to the next element
+        thickness: f64
+    },
+    /// Ideal thin lens of the given `focal` length, free of aberrations
+    ThinLens {
+        /// Focal length, positive for a converging lens
+        focal: f64,
+        /// Distance to the next element
+        thickness: f64
+    }
+}
+
+/// # Spherical aberration
+///
+/// Departure of an exact ray, entering parallel to the axis at the given height, from the paraxial focus.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aberration {
+    /// Height of the ray entering the system
+    pub height: f64,
+    /// Position of the crossing of the axis by the ray, from the paraxial focus, negative before it
+    pub longitudinal: f64,
+    /// Height of the ray in the plane of the paraxial focus
+    pub transverse: f64
+}
+
+/// # Optical system
+///
+/// Sequence of elements along the optical axis, from the object space of refractive `index`. The last
+/// thickness is the distance from the last element to the output plane of the rays.
+///
+/// ```
+/// # use scilib::optics::{ Element, System };
+/// // Biconvex lens, of radii 50 and thickness 5, in glass of index 1.5
+/// let lens = System::new(&[
+///     Element::Surface { curvature: 1.0 / 50.0, index: 1.5, thickness: 5.0 },
+///     Element::Surface { curvature: -1.0 / 50.0, index: 1.0, thickness: 0.0 }
+/// ]);
+///
+/// assert!((lens.focal_length() - 50.847457627118644).abs() < 1.0e-12);
+/// assert!((lens.back_focal_distance() - 49.152542372881356).abs() < 1.0e-12);
+/// assert!((lens.front_focal_distance() - 49.152542372881356).abs() < 1.0e-12);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct System {
+    /// Elements in the order met by the light
+    pub elements: Vec<Element>,
+    /// Refractive index of the object space
+    pub index: f64
+}
+
+/// Implementing required methods
+impl System {
+    /// # System in air
+    ///
+    /// The given `elements`, with an object space of index 1.
+    pub fn new(elements: &[Element]) -> Self {
+        Self { elements: elements.to_vec(), index: 1.0 }
+    }
+
+    /// # Transfer matrix of the system
+    ///
+    /// Paraxial matrix from the first element to the output plane.
+    pub fn abcd(&self) -> Abcd {
+
+        let mut res: Abcd = Abcd::default();
+        let mut n: f64 = self.index;
+        for element in &self.elements {
+            let (m, t): (Abcd, f64) = match *element {
+                Element::Surface { curvature, index, thickness } => {
+                    let m: Abcd = Abcd::refraction(curvature, n, index);
+                    n = index;
+                    (m, thickness)
+                },
+                Element::ThinLens { focal, thickness } => (Abcd::thin_lens(focal), thickness)
+            };
+            res = res.then(m).then(Abcd::propagation(t));
+        }
+
+        res
+    }
+
+    /// # Effective focal length
+    ///
+    /// Paraxial focal length `-1 / C`, positive for a converging system, infinite for an afocal one.
+    pub fn focal_length(&self) -> f64 {
+        -1.0 / self.abcd().c
+    }
+
+    /// # Back focal distance
+    ///
+    /// Distance from the output plane to the paraxial image of an object at infinity, `-A / C`.
+    pub fn back_focal_distance(&self) -> f64 {
+        let m: Abcd = self.abcd();
+        -m.a / m.c
+    }
+
+    /// # Front focal distance
+    ///
+    /// Distance from the front focal point to the first element, `-D / C`: the rays leaving this point exit
+    /// parallel to the axis.
+    pub fn front_focal_distance(&self) -> f64 {
+        let m: Abcd = self.abcd();
+        -m.d / m.c
+    }
+
+    /// # Exact ray tracing
+    ///
+    /// Traces a meridional `ray`, given in the plane of the first element, through the spherical surfaces
+    /// with Snell's law, and returns it in the output plane. The thin lenses deviate the rays as ideal lenses,
+    /// `tan(u') = tan(u) - y / f`. Returns `None` if the ray misses a surface or is totally reflected.
+    ///
+    /// ```
+    /// # use scilib::optics::{ Element, Ray, System };
+    /// // Parallel ray at height 10 on a glass sphere of radius 50: it crosses the axis before the paraxial
+    /// // focus at 150
+    /// let surface = System::new(&[Element::Surface { curvature: 0.02, index: 1.5, thickness: 0.0 }]);
+    /// let out = surface.trace(Ray { height: 10.0, angle: 0.0 }).unwrap();
+    ///
+    /// assert!(out.angle < 0.0);
+    /// assert!((-out.height / out.angle.tan() - 148.65611087380487).abs() < 1.0e-10);
+    /// assert_eq!(surface.trace(Ray { height: 60.0, angle: 0.0 }), None);
+    /// ```
+    pub fn trace(&self, ray: Ray) -> Option<Ray> {
+
+        // Position along the axis from the current vertex, and direction cosines
+        let mut y: f64 = ray.height;
+        let (mut l, mut m): (f64, f64) = (ray.angle.cos(), ray.angle.sin());
+        let mut n: f64 = self.index;
+
+        for element in &self.elements {
+            let (z, t): (f64, f64) = match *element {
+                Element::Surface { curvature: c, index, thickness } => {
+                    // Intersection with the sphere c (z^2 + y^2) - 2 z = 0, nearest to the vertex
+                    let b: f64 = l - c * y * m;
+                    let disc: f64 = b * b - c * (c * y * y);
+                    if disc < 0.0 {
+                        return None;
+                    }
+                    let dist: f64 = c * y * y / (b + disc.sqrt());
+                    let z: f64 = dist * l;
+                    y += dist * m;
+
+                    // Refraction about the unit normal (1 - c z, -c y)
+                    let (nz, ny): (f64, f64) = (1.0 - c * z, -c * y);
+                    let cos_i: f64 = l * nz + m * ny;
+                    let mu: f64 = n / index;
+                    let cos_t2: f64 = 1.0 - mu * mu * (1.0 - cos_i * cos_i);
+                    if cos_t2 < 0.0 {
+                        return None;
+                    }
+                    let g: f64 = cos_t2.sqrt().copysign(cos_i) - mu * cos_i;
+                    (l, m) = (mu * l + g * nz, mu * m + g * ny);
+                    n = index;
+                    (z, thickness)
+                },
+                Element::ThinLens { focal, thickness } => {
+                    let u: f64 = m / l - y / focal;
+                    let norm: f64 = u.hypot(1.0);
+                    (l, m) = (1.0 / norm, u / norm);
+                    (0.0, thickness)
+                }
+            };
+
+            // Transfer to the plane of the next element
+            if l <= 0.0 {
+                return None;
+            }
+            y += (t - z) * m / l;
+        }
+
+        Some(Ray { height: y, angle: m.atan2(l) })
+    }
+
+    /// # Spherical aberration
+    ///
+    /// Traces the ray entering parallel to the axis at the given `height`, and compares its crossing of the
+    /// axis to the paraxial focus. Returns `None` if the ray does not go through the system.
+    ///
+    /// ```
+    /// # use scilib::optics::{ Element, System };
+    /// // Plano-convex lens, curved side first, of focal length 100
+    /// let lens = System::new(&[
+    ///     Element::Surface { curvature: 1.0 / 51.5, index: 1.515, thickness: 4.0 },
+    ///     Element::Surface { curvature: 0.0, index: 1.0, thickness: 0.0 }
+    /// ]);
+    /// let (edge, zone) = (lens.spherical_aberration(10.0).unwrap(), lens.spherical_aberration(5.0).unwrap());
+    ///
+    /// // Undercorrected: the marginal rays focus first, the aberration growing as the square of the height
+    /// assert!(edge.longitudinal < 0.0 && edge.transverse < 0.0);
+    /// assert!((edge.longitudinal / zone.longitudinal - 4.0).abs() < 0.1);
+    ///
+    /// // An ideal lens has none
+    /// let ideal = System::new(&[Element::ThinLens { focal: 100.0, thickness: 0.0 }]);
+    /// assert!(ideal.spherical_aberration(10.0).unwrap().longitudinal.abs() < 1.0e-12);
+    /// ```
+    pub fn spherical_aberration(&self, height: f64) -> Option<Aberration> {
+
+        let focus: f64 = self.back_focal_distance();
+        let out: Ray = self.trace(Ray { height, angle: 0.0 })?;
+        let slope: f64 = out.angle.tan();
+
+        Some(Aberration {
+            height,
+            longitudinal: -out.height / slope - focus,
+            transverse: out.height + focus * slope
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+
+/// Number of rows and columns of a rectangular field
+fn dimensions(field: &[Vec<Complex>]) -> (usize, usize) {
+    let m: usize = field.first().map_or(0, |r| r.len());
+    assert!(field.iter().all(|r| r.len() == m), "All the rows of the field must have the same length");
+    (field.len(), m)
+}
+
+/// Multiplies the spectrum of the field by the transfer function `h(fx, fy)` of the propagation
+fn propagate<F>(field: &[Vec<Complex>], dx: f64, h: F) -> Vec<Vec<Complex>>
+where F: Fn(f64, f64) -> Complex {
+
+    let (n, m): (usize, usize) = dimensions(field);
+    let frequency = |k: usize, len: usize| -> f64 {
+        let wrapped: f64 = if 2 * k < len { k as f64 } else { k as f64 - len as f64 };
+        wrapped / (len as f64 * dx)
+    };
+
+    let spectrum: Vec<Vec<Complex>> = signal::fft2(field);
+    let filtered: Vec<Vec<Complex>> = spectrum.iter().enumerate().map(|(i, row)| {
+        row.iter().enumerate().map(|(j, v)| *v * h(frequency(i, n), frequency(j, m))).collect()
+    }).collect();
+
+    signal::ifft2(&filtered)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
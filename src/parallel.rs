@@ -0,0 +1,198 @@
+//!
+//! # Parallel evaluation
+//!
+//! Batch versions of costly functions, spreading the work over several threads. This module is only
+//! available with the `parallel` feature, and relies on the scoped threads of the standard library, so that
+//! it does not bring any additional dependency.
+//!
+//! The number of worker threads defaults to the available parallelism of the machine, and can be
+//! configured once for the whole program with `set_threads`. The Bessel functions are evaluated on the
+//! workers with the `Precision` policy and the `BesselConfig` settings of the calling thread.
+//!
+//! ```
+//! # use scilib::parallel;
+//! # use scilib::math::bessel;
+//! let x: Vec<f64> = (0..1000).map(|i| i as f64 / 100.0).collect();
+//! let res = parallel::bessel_j(&x, 2);
+//!
+//! assert_eq!(res[350], bessel::j(3.5, 2));
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::sync::atomic::{            // Global thread configuration
+    AtomicUsize,                    // Shared counter
+    Ordering                        // Memory ordering
+};
+
+use std::thread;                    // Scoped threads
+
+use crate::math::{                  // Functions with batch versions
+    bessel,                         // Bessel functions
+    bessel::BesselConfig,           // Settings of the Bessel series
+    complex::Complex                // Complex numbers
+};
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::signal;                  // Fourier transforms
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Number of threads requested by the user, 0 meaning automatic
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// # Sets the number of worker threads
+///
+/// A value of 0 restores the default, which is the available parallelism of the machine.
+///
+/// ```
+/// # use scilib::parallel;
+/// parallel::set_threads(3);
+/// assert_eq!(parallel::threads(), 3);
+///
+/// parallel::set_threads(0);
+/// assert!(parallel::threads() >= 1);
+/// ```
+pub fn set_threads(n: usize) {
+    THREADS.store(n, Ordering::Relaxed);
+}
+
+/// # Number of worker threads used by the batch functions
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Parallel map
+///
+/// Applies `f` to each element of `data`, splitting the slice in contiguous chunks handled by separate
+/// threads. The order of the results matches the order of the input.
+///
+/// ```
+/// # use scilib::parallel;
+/// # use scilib::math::basic;
+/// let x: Vec<f64> = (1..=150).map(|i| i as f64 / 10.0).collect();
+/// let res = parallel::map(&x, |&v| basic::gamma(v));
+///
+/// assert_eq!(res[9], basic::gamma(1.0));
+/// ```
+pub fn map<T, U, F>(data: &[T], f: F) -> Vec<U>
+where T: Sync, U: Send, F: Fn(&T) -> U + Sync {
+
+    let n_threads: usize = threads().min(data.len());
+
+    if n_threads <= 1 {
+        return data.iter().map(f).collect();
+    }
+
+    let chunk: usize = data.len().div_ceil(n_threads);
+    let f: &F = &f;
+
+    thread::scope(|s| {
+        let handles: Vec<_> = data.chunks(chunk)
+            .map(|part| s.spawn(move || part.iter().map(f).collect::<Vec<U>>()))
+            .collect();
+
+        handles.into_iter()
+            .flat_map(|h| h.join().expect("A worker thread panicked"))
+            .collect()
+    })
+}
+
+/// # Bessel function of the first kind over a grid
+///
+/// Parallel version of `bessel::j_slice`, for a fixed integer order.
+///
+/// ```
+/// # use scilib::parallel;
+/// # use scilib::math::bessel::{ self, BesselConfig };
+/// let x: Vec<f64> = (0..2000).map(|i| i as f64 * 0.005).collect();
+///
+/// // The settings of the caller hold on the workers
+/// let quick = BesselConfig { tolerance: 1.0e-4, max_terms: 1_000 };
+/// let res = quick.scope(|| parallel::bessel_j(&x, 1));
+/// assert_eq!(res[1500], quick.scope(|| bessel::j(7.5, 1)));
+/// ```
+pub fn bessel_j<T>(x: &[T], n: i32) -> Vec<Complex>
+where T: Into<Complex> + Copy + Sync {
+    bessel_map(x, |v| bessel::j(v, n))
+}
+
+/// # Bessel function of the first kind over a grid, real order
+///
+/// Parallel version of `bessel::jf_slice`.
+pub fn bessel_jf<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy + Sync, U: Into<f64> + Copy + Sync {
+    bessel_map(x, |v| bessel::jf(v, order))
+}
+
+/// # Bessel function of the second kind over a grid
+///
+/// Parallel version of `bessel::y_slice`.
+pub fn bessel_y<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy + Sync, U: Into<f64> + Copy + Sync {
+    bessel_map(x, |v| bessel::y(v, order))
+}
+
+/// # Modified Bessel function of the first kind over a grid
+///
+/// Parallel version of `bessel::i_slice`.
+pub fn bessel_i<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy + Sync, U: Into<f64> + Copy + Sync {
+    bessel_map(x, |v| bessel::i(v, order))
+}
+
+/// # Modified Bessel function of the second kind over a grid
+///
+/// Parallel version of `bessel::k_slice`.
+///
+/// ```
+/// # use scilib::parallel;
+/// # use scilib::math::bessel;
+/// let x: Vec<f64> = (1..=400).map(|i| i as f64 * 0.05).collect();
+/// assert_eq!(parallel::bessel_k(&x, 0.5), bessel::k_slice(&x, 0.5));
+/// ```
+pub fn bessel_k<T, U>(x: &[T], order: U) -> Vec<Complex>
+where T: Into<Complex> + Copy + Sync, U: Into<f64> + Copy + Sync {
+    bessel_map(x, |v| bessel::k(v, order))
+}
+
+/// Parallel map carrying the precision settings of the calling thread over to the workers
+fn bessel_map<T, F>(x: &[T], f: F) -> Vec<Complex>
+where T: Copy + Sync, F: Fn(T) -> Complex + Sync {
+    let (prec, config): (Precision, BesselConfig) = (Precision::current(), BesselConfig::current());
+    map(x, |&v| prec.scope(|| config.scope(|| f(v))))
+}
+
+/// # Batch of Fourier transforms
+///
+/// Computes the `signal::fft` of each series, the series being distributed over the threads.
+///
+/// ```
+/// # use scilib::parallel;
+/// # use scilib::signal;
+/// let batch: Vec<Vec<f64>> = (0..8).map(|k| (0..32).map(|i| (0.1 * (k * i) as f64).sin()).collect()).collect();
+/// let res = parallel::fft_batch(&batch);
+///
+/// assert_eq!(res[5], signal::fft(&batch[5]));
+/// ```
+pub fn fft_batch<T>(data: &[Vec<T>]) -> Vec<Vec<Complex>>
+where T: Into<Complex> + Copy + Sync {
+    map(data, |v| signal::fft(v))
+}
+
+/// # Batch of inverse Fourier transforms
+///
+/// Computes the `signal::ifft` of each series, the series being distributed over the threads.
+pub fn ifft_batch<T>(data: &[Vec<T>]) -> Vec<Vec<Complex>>
+where T: Into<Complex> + Copy + Sync {
+    map(data, |v| signal::ifft(v))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
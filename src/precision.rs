@@ -0,0 +1,149 @@
+//!
+//! # Precision policy
+//!
+//! Controls the trade-off between speed and accuracy of the iterative methods of the crate. Each module
+//! keeps its own reference tolerance, tuned for its algorithms, and the `Precision` policy scales all of
+//! them consistently: series tolerances, iteration caps and method switching thresholds.
+//!
+//! The policy can be set for the whole program with `set_global`, or for a single computation with `scope`,
+//! which only affects the current thread.
+//!
+//! ```
+//! # use scilib::precision::Precision;
+//! # use scilib::math::basic;
+//! // Default behaviour
+//! let res = basic::erf(0.8);
+//!
+//! // Stricter tolerance for this call only
+//! let strict = Precision::Strict.scope(|| basic::erf(0.8));
+//!
+//! assert!((strict.re - 0.742100964707661).abs() < 1.0e-15);
+//! assert!((res.re - strict.re).abs() < 1.0e-12);
+//! assert_eq!(Precision::current(), Precision::Default);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::sync::atomic::{        // Global policy
+    AtomicU8,                   // Shared value
+    Ordering                    // Memory ordering
+};
+
+use std::cell::Cell;            // Thread local override
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Global policy, stored as its discriminant
+static GLOBAL: AtomicU8 = AtomicU8::new(Precision::Default as u8);
+
+thread_local! {
+    /// Override set by `Precision::scope` on the current thread
+    static LOCAL: Cell<Option<Precision>> = const { Cell::new(None) };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Precision policy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// Looser tolerances and fewer iterations, for quick previews
+    Fast,
+    /// The reference settings of each module
+    #[default]
+    Default,
+    /// Tolerances close to the machine precision, at a higher cost
+    Strict
+}
+
+/// Implementing required methods
+impl Precision {
+    /// # Sets the policy for the whole program
+    ///
+    /// Threads inside a `scope` keep their local policy until it ends.
+    pub fn set_global(self) {
+        GLOBAL.store(self as u8, Ordering::Relaxed);
+    }
+
+    /// # Policy currently in use
+    ///
+    /// Returns the policy of the innermost `scope` on this thread if any, the global one otherwise.
+    pub fn current() -> Self {
+        LOCAL.with(|l| l.get()).unwrap_or(match GLOBAL.load(Ordering::Relaxed) {
+            0 => Self::Fast,
+            2 => Self::Strict,
+            _ => Self::Default
+        })
+    }
+
+    /// # Runs a computation with this policy
+    ///
+    /// The policy applies to every function called by `f` on the current thread, and the previous policy
+    /// is restored afterwards, even if `f` panics.
+    pub fn scope<R, F>(self, f: F) -> R
+    where F: FnOnce() -> R {
+
+        /// Restores the previous policy when dropped
+        struct Guard(Option<Precision>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                LOCAL.with(|l| l.set(self.0));
+            }
+        }
+
+        let _guard: Guard = Guard(LOCAL.with(|l| l.replace(Some(self))));
+        f()
+    }
+
+    /// # Convergence tolerance
+    ///
+    /// Scales the reference tolerance `base` of a method: `Fast` loosens it by three orders of magnitude
+    /// (but no further than `1.0e-4`), `Strict` tightens it by four, down to the machine epsilon.
+    ///
+    /// ```
+    /// # use scilib::precision::Precision;
+    /// assert_eq!(Precision::Default.tolerance(1.0e-8), 1.0e-8);
+    /// assert!((Precision::Strict.tolerance(1.0e-8) - 1.0e-12).abs() < 1.0e-24);
+    /// assert_eq!(Precision::Strict.tolerance(1.0e-14), f64::EPSILON);
+    /// ```
+    pub fn tolerance(self, base: f64) -> f64 {
+        match self {
+            Self::Fast => (base * 1.0e3).min(1.0e-4).max(base),
+            Self::Default => base,
+            Self::Strict => (base * 1.0e-4).max(f64::EPSILON)
+        }
+    }
+
+    /// # Iteration cap
+    ///
+    /// Scales the reference iteration cap `base` of a series or iterative method: `Fast` divides it by
+    /// ten, `Strict` multiplies it by ten.
+    ///
+    /// ```
+    /// # use scilib::precision::Precision;
+    /// assert_eq!(Precision::Fast.iterations(10_000), 1_000);
+    /// assert_eq!(Precision::Strict.iterations(10_000), 100_000);
+    /// ```
+    pub const fn iterations(self, base: usize) -> usize {
+        match self {
+            Self::Fast => base / 10,
+            Self::Default => base,
+            Self::Strict => base.saturating_mul(10)
+        }
+    }
+
+    /// # Method switching threshold
+    ///
+    /// Scales a reference distance `base` used to switch between methods, or to approach a limit
+    /// numerically.
+    pub fn threshold(self, base: f64) -> f64 {
+        match self {
+            Self::Fast => base * 10.0,
+            Self::Default => base,
+            Self::Strict => base * 1.0e-2
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
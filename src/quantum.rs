@@ -10,9 +10,9 @@ use std::f64::consts::{     // Using std lib constants
 
 use crate::{                // Calling other modules
     math::{                 // Math crate
-        basic,              // Basic functions
         complex::Complex,   // Using Complex numbers
-        polynomial          // Special polynomials
+        polynomial,         // Special polynomials
+        tables              // Precomputed factorials
     },
     constant as cst         // Calling scilib constants
 };
@@ -67,7 +67,7 @@ pub fn radial_wavefunction(n: usize, l: usize, r: f64) -> f64 {
 
     // Computing the norm of the function
     let mut norm: f64 = (2.0 / (n as f64 * cst::A_0)).powi(3);
-    norm *= basic::factorial(n - l - 1) as f64 / (2 * n * basic::factorial(n + l).pow(3)) as f64;
+    norm *= tables::factorial(n - l - 1) / (2.0 * n as f64 * tables::factorial(n + l).powi(3));
 
     // Computing the term associated to the Laguerre polynomial
     let poly: f64 = polynomial::Laguerre::new(n - l - 1, 2 * l as i32).compute(2.0 * factor);
@@ -98,8 +98,8 @@ pub fn spherical_harmonics(l: usize, m: i32, theta: f64, phi: f64) -> Complex {
 
     // We follow QM norm
     let norm: f64 = (2 * l + 1) as f64 / (4.0 * PI);
-    let top: f64 = basic::factorial(l - mp as usize) as f64;
-    let bot: f64 = basic::factorial(l + mp as usize) as f64;
+    let top: f64 = tables::factorial(l - mp as usize);
+    let bot: f64 = tables::factorial(l + mp as usize);
 
     // Computation with Legendre polynomial
     let res: Complex = cpx * (norm * top / bot).sqrt() * poly.compute(theta.cos());
@@ -0,0 +1,146 @@
+//!
+//! # Reference data checks
+//!
+//! Helpers to compare computed values against high-precision reference tables, used by the accuracy
+//! regression tests of the crate and available to downstream users validating their own code.
+//!
+//! Errors are measured in units in the last place (ULP): the number of representable `f64` between the
+//! computed and the reference value. This gives a scale-independent budget, contrary to absolute tolerances.
+//!
+//! Reference tables are plain text files: one sample per line, whitespace separated columns, and lines
+//! starting with `#` for comments.
+//!
+//! ```
+//! # use scilib::reference::{ parse_table, check_table };
+//! let table = "# x sqrt(x)\n2 1.4142135623730951\n3 1.7320508075688772\n";
+//! let rows = parse_table(table);
+//! let report = check_table(&rows, |r| r[0].sqrt());
+//!
+//! assert_eq!(report.count, 2);
+//! assert!(report.max_ulp <= 1);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Distance in ULP between two floats
+///
+/// Returns the number of representable `f64` values between `a` and `b`. Both zeros are considered equal,
+/// and any comparison involving `NaN` returns `u64::MAX`.
+///
+/// ```
+/// # use scilib::reference::ulp_distance;
+/// assert_eq!(ulp_distance(1.0, 1.0), 0);
+/// assert_eq!(ulp_distance(1.0, 1.0 + f64::EPSILON), 1);
+/// assert_eq!(ulp_distance(-0.0, 0.0), 0);
+/// assert_eq!(ulp_distance(-f64::MIN_POSITIVE, f64::MIN_POSITIVE), 2 * (1 << 52));
+/// ```
+pub fn ulp_distance(a: f64, b: f64) -> u64 {
+
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+
+    // Mapping the floats to integers with the same ordering
+    let key = |v: f64| -> i128 {
+        let bits: i64 = v.to_bits() as i64;
+        if bits < 0 { -((bits & i64::MAX) as i128) } else { bits as i128 }
+    };
+
+    (key(a) - key(b)).unsigned_abs().min(u64::MAX as u128) as u64
+}
+
+/// # Checks that two floats are within a ULP budget
+pub fn close_ulp(a: f64, b: f64, ulps: u64) -> bool {
+    ulp_distance(a, b) <= ulps
+}
+
+/// # Asserts that two floats are within a ULP budget
+///
+/// Panics with both values and their distance otherwise.
+///
+/// ```
+/// # use scilib::assert_close_ulp;
+/// assert_close_ulp!(0.1 + 0.2, 0.3, 1);
+/// ```
+///
+/// ```should_panic
+/// # use scilib::assert_close_ulp;
+/// assert_close_ulp!(1.0, 1.0 + 1.0e-10, 1000);
+/// ```
+#[macro_export]
+macro_rules! assert_close_ulp {
+    ($left:expr, $right:expr, $ulps:expr) => {{
+        let (l, r): (f64, f64) = ($left, $right);
+        let d: u64 = $crate::reference::ulp_distance(l, r);
+        assert!(d <= $ulps, "values differ by {} ulp (budget {}): {} vs {}", d, $ulps, l, r);
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Parses a reference table
+///
+/// Returns the rows of numbers of the table, skipping empty lines and comments.
+///
+/// Panics on values that cannot be parsed, as reference tables are expected to be well formed.
+pub fn parse_table(text: &str) -> Vec<Vec<f64>> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| l.split_whitespace().map(|v| v.parse().expect("Invalid value in reference table")).collect())
+        .collect()
+}
+
+/// # Summary of a table check
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Number of rows checked
+    pub count: usize,
+    /// Largest error found, in ULP
+    pub max_ulp: u64,
+    /// Largest absolute error found
+    pub max_abs: f64,
+    /// Largest relative error found
+    pub max_rel: f64,
+    /// Row with the largest error
+    pub worst: Vec<f64>
+}
+
+/// # Checks a function against a reference table
+///
+/// `f` receives each row and computes the value to compare to the last column of the row.
+///
+/// ```
+/// # use scilib::reference::{ parse_table, check_table };
+/// let rows = parse_table("1 1\n2 4\n3 9.000001");
+/// let report = check_table(&rows, |r| r[0].powi(2));
+///
+/// assert_eq!(report.worst, vec![3.0, 9.000001]);
+/// assert!((report.max_rel - 1.0e-6 / 9.000001).abs() < 1.0e-15);
+/// ```
+pub fn check_table<F>(rows: &[Vec<f64>], f: F) -> Report
+where F: Fn(&[f64]) -> f64 {
+
+    let mut report: Report = Report::default();
+
+    for row in rows {
+        let expected: f64 = *row.last().expect("Empty row in reference table");
+        let value: f64 = f(row);
+        let ulp: u64 = ulp_distance(value, expected);
+
+        if ulp >= report.max_ulp {
+            report.max_ulp = ulp;
+            report.worst = row.clone();
+        }
+        report.max_abs = report.max_abs.max((value - expected).abs());
+        if expected != 0.0 {
+            report.max_rel = report.max_rel.max(((value - expected) / expected).abs());
+        }
+        report.count += 1;
+    }
+
+    report
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,13 +1,23 @@
 //!
 //! # Fourier transform algorithms
 //! 
+//! Convolutions, discrete Fourier transforms in one and two dimensions, the Goertzel evaluation of single
+//! frequencies, the discrete cosine and sine transforms built on them, the Abel transform of axially symmetric
+//! profiles, Welch-averaged cross spectra with the coherence and transfer function estimates, the detection of
+//! peaks, and phase unwrapping.
+//!
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use std::f64::consts::{     // Using std lib constants
-    PI                      // Pi
+    PI,                     // Pi
+    TAU                     // Full turn
 };
 
+use std::cmp::Ordering;             // Ordering of the pixels
+
+use std::collections::BinaryHeap;   // Pixels waiting to be unwrapped
+
 use crate::{                // Calling other modules
     math::complex::Complex  // Using Complex numbers
 };
@@ -225,4 +235,1006 @@ where T: Into<Complex> + Copy {
     res
 }
 
+/// # Goertzel algorithm
+///
+/// Evaluates the discrete Fourier transform of real `data`, sampled at `fs`, at a single `frequency`:
+/// `X(f) = sum x[n] exp(-2 i pi f n / fs)`. The samples go through a second order recursion with one real
+/// multiplication each, making it much cheaper than a full transform when only a few frequencies matter,
+/// as in tone detection or lock-in analysis. The frequency need not fall on a bin: for `f = k fs / N` the
+/// result is the bin `k` of `fft`.
+///
+/// ```
+/// # use scilib::signal::{ fft, goertzel };
+/// let data: Vec<f64> = (0..50).map(|i| (0.3 * i as f64).sin() + 0.2 * (i % 7) as f64).collect();
+/// let spectrum = fft(&data);
+///
+/// let res = goertzel(&data, 6.0, 50.0);
+/// assert!((res - spectrum[6]).modulus() < 1.0e-10);
+///
+/// // DTMF tone detection: 770 Hz and 1336 Hz (key 5), sampled at 8 kHz
+/// let tone: Vec<f64> = (0..205).map(|i| {
+///     let t: f64 = i as f64 / 8000.0;
+///     (std::f64::consts::TAU * 770.0 * t).sin() + (std::f64::consts::TAU * 1336.0 * t).sin()
+/// }).collect();
+/// let power: Vec<f64> = [697.0, 770.0, 852.0, 941.0].iter()
+///     .map(|&f| goertzel(&tone, f, 8000.0).modulus())
+///     .collect();
+/// assert!(power[1] > 90.0 && power.iter().enumerate().all(|(i, &p)| i == 1 || p < 20.0));
+/// ```
+pub fn goertzel<T>(data: &[T], frequency: f64, fs: f64) -> Complex
+where T: Into<f64> + Copy {
+
+    assert!(fs > 0.0, "The sampling frequency must be positive");
+    let omega: f64 = TAU * frequency / fs;
+    let coef: f64 = 2.0 * omega.cos();
+
+    let (mut s1, mut s2): (f64, f64) = (0.0, 0.0);
+    for &x in data {
+        let s: f64 = x.into() + coef * s1 - s2;
+        s2 = s1;
+        s1 = s;
+    }
+
+    // y = s[N-1] - exp(-i w) s[N-2] is the transform shifted by exp(i w (N - 1))
+    let y: Complex = Complex::from(s1 - omega.cos() * s2, omega.sin() * s2);
+    let n: f64 = data.len().saturating_sub(1) as f64;
+    y * Complex::from_polar(-omega * n, 1.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Two-dimensional fast Fourier transform
+///
+/// Computes the FFT of a two-dimensional array, given as rows of equal lengths, by transforming the rows and
+/// then the columns with `fft`. The element `[k][l]` of the result is the frequency `k` along the rows and
+/// `l` along the columns.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::signal::fft2;
+/// let image: Vec<Vec<f64>> = vec![vec![1.0, 2.0, 0.0], vec![0.5, -1.0, 3.0]];
+/// let res = fft2(&image);
+///
+/// assert!((res[0][0] - Complex::from(5.5, 0)).modulus() < 1.0e-14);
+/// assert!((res[1][0] - Complex::from(0.5, 0)).modulus() < 1.0e-14);
+/// assert!((res[0][1] - Complex::from(-0.5, 1.7320508075688772)).modulus() < 1.0e-14);
+/// assert!((res[1][2] - Complex::from(0.5, 5.196152422706632)).modulus() < 1.0e-14);
+/// ```
+pub fn fft2<T>(data: &[Vec<T>]) -> Vec<Vec<Complex>>
+where T: Into<Complex> + Copy {
+    transform2(data, false)
+}
+
+/// # Two-dimensional inverse fast Fourier transform
+///
+/// Computes the IFFT of a two-dimensional array, given as rows of equal lengths, so that
+/// `v = ifft2(fft2(v))` within numerical errors.
+///
+/// ```
+/// # use scilib::signal::{ fft2, ifft2 };
+/// let image: Vec<Vec<f64>> = (0..4).map(|i| (0..5).map(|j| (i * j) as f64 - 0.5 * j as f64).collect()).collect();
+/// let res = ifft2(&fft2(&image));
+///
+/// for (row, back) in image.iter().zip(&res) {
+///     for (ori, comp) in row.iter().zip(back) {
+///         assert!((ori - comp.re).abs() < 1.0e-13 && comp.im.abs() < 1.0e-13);
+///     }
+/// }
+/// ```
+pub fn ifft2<T>(data: &[Vec<T>]) -> Vec<Vec<Complex>>
+where T: Into<Complex> + Copy {
+    transform2(data, true)
+}
+
+/// # Two-dimensional convolution
+///
+/// Computes the full convolution of two real arrays, of `(r_a + r_b - 1)` rows of `(c_a + c_b - 1)` values,
+/// as the product of the FFTs of the zero-padded arrays. This is the blurring of an image by a point
+/// spread function, the center of a PSF of odd sizes being at the index `[r_b / 2][c_b / 2]` of the result.
+///
+/// ```
+/// # use scilib::signal::convolve2;
+/// let image: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+/// let psf: Vec<Vec<f64>> = vec![vec![0.0, 1.0], vec![0.5, 0.0]];
+/// let res = convolve2(&image, &psf);
+///
+/// let expected = [[0.0, 1.0, 2.0], [0.5, 4.0, 4.0], [1.5, 2.0, 0.0]];
+/// for (row, exp) in res.iter().zip(&expected) {
+///     for (r, e) in row.iter().zip(exp) {
+///         assert!((r - e).abs() < 1.0e-14);
+///     }
+/// }
+/// ```
+pub fn convolve2<T>(a: &[Vec<T>], b: &[Vec<T>]) -> Vec<Vec<f64>>
+where T: Into<f64> + Copy {
+    product2(a, b, false)
+}
+
+/// # Two-dimensional correlation
+///
+/// Computes the full cross-correlation of two real arrays, `c[k][l] = sum a[i][j] b[i + r_b - 1 - k][j + c_b - 1 - l]`,
+/// of the same size as the convolution: the element `[r_b - 1][c_b - 1]` is the correlation without shift.
+/// The correlation of an array with itself is its autocorrelation, as used in speckle analysis.
+///
+/// ```
+/// # use scilib::signal::correlate2;
+/// let a: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+/// let res = correlate2(&a, &a);
+///
+/// // Zero shift: sum of the squares
+/// assert!((res[1][1] - 30.0).abs() < 1.0e-13);
+/// assert!((res[0][0] - 4.0).abs() < 1.0e-13 && (res[2][2] - 4.0).abs() < 1.0e-13);
+/// assert!((res[1][0] - 14.0).abs() < 1.0e-13 && (res[0][1] - 11.0).abs() < 1.0e-13);
+/// ```
+pub fn correlate2<T>(a: &[Vec<T>], b: &[Vec<T>]) -> Vec<Vec<f64>>
+where T: Into<f64> + Copy {
+    product2(a, b, true)
+}
+
+/// FFT of the rows, then of the columns, of a rectangular array
+fn transform2<T>(data: &[Vec<T>], inverse: bool) -> Vec<Vec<Complex>>
+where T: Into<Complex> + Copy {
+
+    let cols: usize = data.first().map_or(0, |r| r.len());
+    assert!(data.iter().all(|r| r.len() == cols), "All the rows of the array must have the same length");
+    let transform = |v: &[Complex]| if inverse { ifft(v) } else { fft(v) };
+
+    let rows: Vec<Vec<Complex>> = data.iter().map(|r| {
+        transform(&r.iter().map(|&v| v.into()).collect::<Vec<Complex>>())
+    }).collect();
+
+    let columns: Vec<Vec<Complex>> = (0..cols).map(|j| {
+        transform(&rows.iter().map(|r| r[j]).collect::<Vec<Complex>>())
+    }).collect();
+
+    (0..rows.len()).map(|i| columns.iter().map(|c| c[i]).collect()).collect()
+}
+
+/// Full convolution of two rectangular arrays through their zero-padded FFTs, `b` being flipped in both
+/// directions for the correlation
+fn product2<T>(a: &[Vec<T>], b: &[Vec<T>], flip: bool) -> Vec<Vec<f64>>
+where T: Into<f64> + Copy {
+
+    let (rows_a, rows_b): (usize, usize) = (a.len(), b.len());
+    let (cols_a, cols_b): (usize, usize) = (a.first().map_or(0, |r| r.len()), b.first().map_or(0, |r| r.len()));
+    if rows_a * rows_b * cols_a * cols_b == 0 {
+        return Vec::new();
+    }
+
+    let (rows, cols): (usize, usize) = (rows_a + rows_b - 1, cols_a + cols_b - 1);
+    let padded = |m: &[Vec<T>], reverse: bool| -> Vec<Vec<f64>> {
+        let mut res: Vec<Vec<f64>> = vec![vec![0.0; cols]; rows];
+        let (r_m, c_m): (usize, usize) = (m.len(), m[0].len());
+        for (i, row) in m.iter().enumerate() {
+            assert!(row.len() == c_m, "All the rows of the arrays must have the same length");
+            for (j, &v) in row.iter().enumerate() {
+                let (p, q): (usize, usize) = if reverse { (r_m - 1 - i, c_m - 1 - j) } else { (i, j) };
+                res[p][q] = v.into();
+            }
+        }
+        res
+    };
+
+    let fa: Vec<Vec<Complex>> = fft2(&padded(a, false));
+    let fb: Vec<Vec<Complex>> = fft2(&padded(b, flip));
+    let prod: Vec<Vec<Complex>> = fa.iter().zip(&fb).map(|(ra, rb)| {
+        ra.iter().zip(rb).map(|(x, y)| *x * *y).collect()
+    }).collect();
+
+    ifft2(&prod).iter().map(|r| r.iter().map(|v| v.re).collect()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Discrete cosine transform
+///
+/// Computes the DCT of type `kind`, from 1 to 4, of a real array of length `N`, with the unnormalized
+/// conventions:
+/// - I: `y_k = x_0 + (-1)^k x_(N-1) + 2 sum_(n=1)^(N-2) x_n cos(pi k n / (N-1))`, for `N >= 2`
+/// - II: `y_k = 2 sum x_n cos(pi k (2n+1) / 2N)`
+/// - III: `y_k = x_0 + 2 sum_(n>=1) x_n cos(pi n (2k+1) / 2N)`
+/// - IV: `y_k = 2 sum x_n cos(pi (2k+1) (2n+1) / 4N)`
+///
+/// Each type is the FFT of the array extended with an even symmetry, of length `2(N-1)`, `4N` or `8N`. The
+/// cosine expansions match the Neumann boundary conditions of the spectral solvers, and the DCT-II is the
+/// transform of the usual compression schemes.
+///
+/// ```
+/// # use scilib::signal::dct;
+/// let x: Vec<f64> = vec![1.0, 2.0, 0.5, -1.5, 3.0];
+///
+/// let res = dct(&x, 2);
+/// let expected = [10.0, 0.3102707008666976, 5.163118960624632, -9.008536623235967, 2.663118960624632];
+/// for (r, e) in res.iter().zip(&expected) {
+///     assert!((r - e).abs() < 1.0e-12);
+/// }
+///
+/// assert!((dct(&x, 1)[1] - 2.9497474683058327).abs() < 1.0e-12);
+/// assert!((dct(&x, 3)[3] - -9.367429518679986).abs() < 1.0e-12);
+/// assert!((dct(&x, 4)[4] - 7.803163328444752).abs() < 1.0e-12);
+/// ```
+pub fn dct<T>(data: &[T], kind: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let n: usize = x.len();
+    assert!((1..=4).contains(&kind), "The type of the transform must be between 1 and 4");
+    if n == 0 {
+        return Vec::new();
+    }
+
+    match kind {
+        1 => {
+            assert!(n >= 2, "The DCT-I requires at least two values");
+            let v: Vec<Complex> = extended_fft(&x, 2 * (n - 1), |i| i, 1.0);
+            (0..n).map(|k| v[k].re).collect()
+        },
+        2 => {
+            let v: Vec<Complex> = extended_fft(&x, 4 * n, |i| 2 * i + 1, 1.0);
+            (0..n).map(|k| v[k].re).collect()
+        },
+        3 => {
+            let v: Vec<Complex> = extended_fft(&x, 4 * n, |i| i, 1.0);
+            (0..n).map(|k| v[2 * k + 1].re).collect()
+        },
+        _ => {
+            let v: Vec<Complex> = extended_fft(&x, 8 * n, |i| 2 * i + 1, 1.0);
+            (0..n).map(|k| v[2 * k + 1].re).collect()
+        }
+    }
+}
+
+/// # Discrete sine transform
+///
+/// Computes the DST of type `kind`, from 1 to 4, of a real array of length `N`, with the unnormalized
+/// conventions:
+/// - I: `y_k = 2 sum x_n sin(pi (k+1) (n+1) / (N+1))`
+/// - II: `y_k = 2 sum x_n sin(pi (k+1) (2n+1) / 2N)`
+/// - III: `y_k = (-1)^k x_(N-1) + 2 sum_(n=0)^(N-2) x_n sin(pi (2k+1) (n+1) / 2N)`
+/// - IV: `y_k = 2 sum x_n sin(pi (2k+1) (2n+1) / 4N)`
+///
+/// Each type is the FFT of the array extended with an odd symmetry. The sine expansions match the
+/// Dirichlet boundary conditions of the spectral solvers.
+///
+/// ```
+/// # use scilib::signal::dst;
+/// let x: Vec<f64> = vec![1.0, 2.0, 0.5, -1.5, 3.0];
+///
+/// let res = dst(&x, 1);
+/// let expected = [5.866025403784438, 2.598076211353316, 7.0, -9.526279441628825, 4.133974596215562];
+/// for (r, e) in res.iter().zip(&expected) {
+///     assert!((r - e).abs() < 1.0e-12);
+/// }
+///
+/// assert!((dst(&x, 2)[4] - 8.0).abs() < 1.0e-12);
+/// assert!((dst(&x, 3)[1] - 4.494632805182876).abs() < 1.0e-12);
+/// assert!((dst(&x, 4)[2] - 9.899494936611665).abs() < 1.0e-12);
+/// ```
+pub fn dst<T>(data: &[T], kind: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let n: usize = x.len();
+    assert!((1..=4).contains(&kind), "The type of the transform must be between 1 and 4");
+    if n == 0 {
+        return Vec::new();
+    }
+
+    match kind {
+        1 => {
+            let v: Vec<Complex> = extended_fft(&x, 2 * (n + 1), |i| i + 1, -1.0);
+            (0..n).map(|k| -v[k + 1].im).collect()
+        },
+        2 => {
+            let v: Vec<Complex> = extended_fft(&x, 4 * n, |i| 2 * i + 1, -1.0);
+            (0..n).map(|k| -v[k + 1].im).collect()
+        },
+        3 => {
+            // The last value sits on the symmetry axis, and is added separately
+            let v: Vec<Complex> = extended_fft(&x[..(n - 1)], 4 * n, |i| i + 1, -1.0);
+            (0..n).map(|k| -v[2 * k + 1].im + if k % 2 == 0 { x[n - 1] } else { -x[n - 1] }).collect()
+        },
+        _ => {
+            let v: Vec<Complex> = extended_fft(&x, 8 * n, |i| 2 * i + 1, -1.0);
+            (0..n).map(|k| -v[2 * k + 1].im).collect()
+        }
+    }
+}
+
+/// # Inverse discrete cosine transform
+///
+/// Inverse of the `dct` of the same type, so that `idct(dct(x, kind), kind) = x` within numerical errors:
+/// the DCT-I and DCT-IV are their own inverses up to a factor, and the DCT-II and DCT-III are the inverses
+/// of each other.
+///
+/// ```
+/// # use scilib::signal::{ dct, idct };
+/// let x: Vec<f64> = vec![0.3, -1.2, 2.5, 0.0, 4.1, -0.7];
+///
+/// for kind in 1..=4 {
+///     let back = idct(&dct(&x, kind), kind);
+///     for (ori, comp) in x.iter().zip(&back) {
+///         assert!((ori - comp).abs() < 1.0e-12);
+///     }
+/// }
+/// ```
+pub fn idct<T>(data: &[T], kind: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let n: f64 = data.len() as f64;
+    let (inverse, norm): (usize, f64) = match kind {
+        1 => (1, 2.0 * (n - 1.0)),
+        2 => (3, 2.0 * n),
+        3 => (2, 2.0 * n),
+        _ => (kind, 2.0 * n)
+    };
+
+    dct(data, inverse).iter().map(|v| v / norm).collect()
+}
+
+/// # Inverse discrete sine transform
+///
+/// Inverse of the `dst` of the same type, so that `idst(dst(x, kind), kind) = x` within numerical errors.
+///
+/// ```
+/// # use scilib::signal::{ dst, idst };
+/// let x: Vec<f64> = vec![0.3, -1.2, 2.5, 0.0, 4.1, -0.7];
+///
+/// for kind in 1..=4 {
+///     let back = idst(&dst(&x, kind), kind);
+///     for (ori, comp) in x.iter().zip(&back) {
+///         assert!((ori - comp).abs() < 1.0e-12);
+///     }
+/// }
+/// ```
+pub fn idst<T>(data: &[T], kind: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let n: f64 = data.len() as f64;
+    let (inverse, norm): (usize, f64) = match kind {
+        1 => (1, 2.0 * (n + 1.0)),
+        2 => (3, 2.0 * n),
+        3 => (2, 2.0 * n),
+        _ => (kind, 2.0 * n)
+    };
+
+    dst(data, inverse).iter().map(|v| v / norm).collect()
+}
+
+/// FFT of the sequence of length `len` holding `x_i` at the index `pos(i)`, and `sign * x_i` at its mirror
+/// `len - pos(i)`, the values lying on the symmetry axes being counted once
+fn extended_fft<F>(x: &[f64], len: usize, pos: F, sign: f64) -> Vec<Complex>
+where F: Fn(usize) -> usize {
+
+    let mut seq: Vec<Complex> = vec![Complex::new(); len];
+    for (i, &val) in x.iter().enumerate() {
+        let p: usize = pos(i);
+        seq[p] += val;
+        if p != 0 && 2 * p != len {
+            seq[len - p] += sign * val;
+        }
+    }
+
+    fft(&seq)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Abel transform
+///
+/// Projection `F(y) = 2 int_y^R f(r) r / sqrt(r^2 - y^2) dr` of an axially symmetric `profile`, sampled at
+/// the radii `r = i dr` from the axis. This is the line-of-sight integral of the profile through the
+/// distance `y` to the axis.
+///
+/// The profile is expanded on linear hat functions centred on the samples, vanishing one step past the
+/// last one, and the projection of each hat is integrated exactly. The error is then that of the linear
+/// interpolation of the profile.
+///
+/// ```
+/// # use scilib::signal::abel;
+/// // The projection of exp(-r^2) is sqrt(pi) exp(-y^2)
+/// let dr: f64 = 0.01;
+/// let profile: Vec<f64> = (0..500).map(|i| (-(i as f64 * dr).powi(2)).exp()).collect();
+/// let res = abel(&profile, dr);
+///
+/// for (i, val) in res.iter().enumerate().step_by(50) {
+///     let y: f64 = i as f64 * dr;
+///     assert!((val - std::f64::consts::PI.sqrt() * (-y * y).exp()).abs() < 1.0e-4);
+/// }
+/// ```
+pub fn abel<T>(profile: &[T], dr: f64) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let f: Vec<f64> = profile.iter().map(|&v| v.into()).collect();
+
+    (0..f.len()).map(|i| {
+        abel_weights(i, f.len(), dr).iter().zip(&f[i..]).map(|(w, v)| w * v).sum()
+    }).collect()
+}
+
+/// # Inverse Abel transform
+///
+/// Recovers the axially symmetric profile `f(r)` from its `projection` `F(y)`, sampled at `y = i dr` from
+/// the axis. The projection operator of [`abel`] is triangular, and is inverted exactly by back
+/// substitution from the outer edge, so that `abel_inverse(abel(f)) = f` up to rounding errors.
+///
+/// The inversion amounts to a half-order derivative, and amplifies the noise of the data, mostly close to
+/// the axis; noisy projections should be smoothed beforehand.
+///
+/// ```
+/// # use scilib::signal::{ abel, abel_inverse };
+/// let dr: f64 = 0.01;
+///
+/// // Recovering exp(-r^2) from its exact projection
+/// let projection: Vec<f64> = (0..500).map(|i| std::f64::consts::PI.sqrt() * (-(i as f64 * dr).powi(2)).exp()).collect();
+/// let res = abel_inverse(&projection, dr);
+/// for (i, val) in res.iter().enumerate().step_by(50) {
+///     assert!((val - (-(i as f64 * dr).powi(2)).exp()).abs() < 1.0e-3);
+/// }
+///
+/// // Round trip of a shell profile
+/// let shell: Vec<f64> = (0..200).map(|i| (-(i as f64 * dr - 1.0).powi(2) * 50.0).exp()).collect();
+/// let back = abel_inverse(&abel(&shell, dr), dr);
+/// for (ori, comp) in shell.iter().zip(&back) {
+///     assert!((ori - comp).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn abel_inverse<T>(projection: &[T], dr: f64) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let n: usize = projection.len();
+    let mut res: Vec<f64> = vec![0.0; n];
+
+    for i in (0..n).rev() {
+        let weights: Vec<f64> = abel_weights(i, n, dr);
+        let outer: f64 = weights[1..].iter().zip(&res[(i + 1)..]).map(|(w, v)| w * v).sum();
+        res[i] = (projection[i].into() - outer) / weights[0];
+    }
+
+    res
+}
+
+/// Weights of the samples `i..n` of a profile in its projection at `y = i dr`
+///
+/// Over the segment `[r_k, r_k+1]`, the profile is linear and the integrals of `r / s` and `r^2 / s`, with
+/// `s = sqrt(r^2 - y^2)`, have closed forms.
+fn abel_weights(i: usize, n: usize, dr: f64) -> Vec<f64> {
+
+    let y: f64 = i as f64 * dr;
+    let y2: f64 = y * y;
+
+    // Primitives of r / s and r^2 / s
+    let prim = |r: f64| -> (f64, f64) {
+        let s: f64 = (r * r - y2).max(0.0).sqrt();
+        let log: f64 = if y2 == 0.0 { 0.0 } else { y2 * (r + s).ln() };
+        (s, 0.5 * (r * s + log))
+    };
+
+    let mut weights: Vec<f64> = vec![0.0; n - i];
+    let mut low: (f64, f64) = prim(y);
+
+    for k in i..n {
+        let (r_k, r_next): (f64, f64) = (k as f64 * dr, (k + 1) as f64 * dr);
+        let high: (f64, f64) = prim(r_next);
+        let (i0, i1): (f64, f64) = (high.0 - low.0, high.1 - low.1);
+
+        // Falling part of the hat k, and rising part of the hat k + 1 (absent past the last sample)
+        weights[k - i] += 2.0 * (r_next * i0 - i1) / dr;
+        if k + 1 < n {
+            weights[k + 1 - i] += 2.0 * (i1 - r_k * i0) / dr;
+        }
+        low = high;
+    }
+
+    weights
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Cross spectral density
+///
+/// Welch estimate of the one-sided cross spectral density `Pxy = E[conj(X) Y]` of two signals sampled at
+/// the frequency `fs`, returned with the frequencies `k fs / segment` from 0 to `fs / 2`. The signals are
+/// cut into segments of `segment` samples overlapping by half, each segment having its mean removed and a
+/// Hann window applied. The result is a density: its sum times `fs / segment` gives the covariance.
+///
+/// ```
+/// # use scilib::signal::csd;
+/// // Sine of amplitude 2 at 12.5 Hz, sampled at 100 Hz
+/// let x: Vec<f64> = (0..1024).map(|i| 2.0 * (std::f64::consts::TAU * 12.5 * i as f64 / 100.0).sin()).collect();
+/// let (freq, pxx) = csd(&x, &x, 64, 100.0);
+///
+/// assert_eq!(freq.len(), 33);
+/// assert_eq!(freq[8], 12.5);
+/// let power: f64 = pxx.iter().map(|p| p.re).sum::<f64>() * 100.0 / 64.0;
+/// assert!((power - 2.0).abs() < 0.01);
+/// ```
+pub fn csd<T, U>(x: &[T], y: &[U], segment: usize, fs: f64) -> (Vec<f64>, Vec<Complex>)
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+    let (freq, _, _, pxy): (Vec<f64>, Vec<f64>, Vec<f64>, Vec<Complex>) = welch(x, y, segment, fs);
+    (freq, pxy)
+}
+
+/// # Magnitude-squared coherence
+///
+/// Coherence `|Pxy|^2 / (Pxx Pyy)` of two signals, from their Welch-averaged spectra (see `csd`), returned
+/// with the frequencies. It lies between 0 and 1, and measures the fraction of the power of `y` explained
+/// by a linear filtering of `x` at each frequency. It needs several segments to be meaningful: it equals 1
+/// for a single one.
+///
+/// ```
+/// # use scilib::math::sde::Noise;
+/// # use scilib::signal::coherence;
+/// // Seeded noise, and its sum with an independent noise of the same power
+/// let mut noise = Noise::new(7);
+/// let mut next = || noise.uniform() - 0.5;
+/// let x: Vec<f64> = (0..4096).map(|_| next()).collect();
+/// let y: Vec<f64> = x.iter().map(|v| 3.0 * v).collect();
+/// let z: Vec<f64> = x.iter().map(|v| v + next()).collect();
+///
+/// let (_, same) = coherence(&x, &y, 128, 1.0);
+/// let (_, half) = coherence(&x, &z, 128, 1.0);
+/// assert!(same.iter().all(|c| (c - 1.0).abs() < 1.0e-12));
+///
+/// let mean: f64 = half.iter().sum::<f64>() / half.len() as f64;
+/// assert!((mean - 0.5).abs() < 0.05);
+/// ```
+pub fn coherence<T, U>(x: &[T], y: &[U], segment: usize, fs: f64) -> (Vec<f64>, Vec<f64>)
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    let (freq, pxx, pyy, pxy): (Vec<f64>, Vec<f64>, Vec<f64>, Vec<Complex>) = welch(x, y, segment, fs);
+    let res: Vec<f64> = pxy.iter().zip(pxx.iter().zip(&pyy)).map(|(p, (a, b))| {
+        p.modulus().powi(2) / (a * b)
+    }).collect();
+
+    (freq, res)
+}
+
+/// # H1 transfer function estimate
+///
+/// Frequency response from the input `x` to the output `y`, estimated as `Pxy / Pxx` from the Welch-averaged
+/// spectra (see `csd`), returned with the frequencies. Noise on the output averages out, making it the usual
+/// estimate when the input is measured cleanly, such as a known excitation.
+///
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::sde::Noise;
+/// # use scilib::signal::transfer_h1;
+/// // Two-tap filter y[n] = 0.5 x[n] + 0.3 x[n-1], with a noisy output
+/// let mut noise = Noise::new(11);
+/// let mut next = || noise.uniform() - 0.5;
+/// let x: Vec<f64> = (0..8192).map(|_| next()).collect();
+/// let y: Vec<f64> = (0..x.len()).map(|i| {
+///     0.5 * x[i] + if i > 0 { 0.3 * x[i - 1] } else { 0.0 } + 0.1 * next()
+/// }).collect();
+///
+/// let (freq, h) = transfer_h1(&x, &y, 256, 1.0);
+/// for (f, v) in freq.iter().zip(&h) {
+///     let exact: Complex = Complex::from_polar(-std::f64::consts::TAU * f, 0.3) + 0.5;
+///     assert!((*v - exact).modulus() < 0.05);
+/// }
+/// ```
+pub fn transfer_h1<T, U>(x: &[T], y: &[U], segment: usize, fs: f64) -> (Vec<f64>, Vec<Complex>)
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    let (freq, pxx, _, pxy): (Vec<f64>, Vec<f64>, Vec<f64>, Vec<Complex>) = welch(x, y, segment, fs);
+    let res: Vec<Complex> = pxy.iter().zip(&pxx).map(|(&p, &a)| p / a).collect();
+
+    (freq, res)
+}
+
+/// # H2 transfer function estimate
+///
+/// Frequency response from the input `x` to the output `y`, estimated as `Pyy / Pyx` from the Welch-averaged
+/// spectra (see `csd`), returned with the frequencies. Noise on the input averages out instead of the output,
+/// and the ratio `H1 / H2` is the coherence: H2 is preferred near the resonances of a structure, where the
+/// output is strong and the input force is the noisier measurement.
+///
+/// ```
+/// # use scilib::signal::{ coherence, transfer_h1, transfer_h2 };
+/// let x: Vec<f64> = (0..2048).map(|i| (0.37 * i as f64).sin() + (1.91 * (i * i) as f64).cos()).collect();
+/// let y: Vec<f64> = (0..x.len()).map(|i| x[i] - if i > 1 { 0.4 * x[i - 2] } else { 0.0 }
+///     + 0.2 * (2.3 * i as f64).sin()).collect();
+///
+/// let (_, h1) = transfer_h1(&x, &y, 128, 1.0);
+/// let (_, h2) = transfer_h2(&x, &y, 128, 1.0);
+/// let (_, coh) = coherence(&x, &y, 128, 1.0);
+/// for ((a, b), c) in h1.iter().zip(&h2).zip(&coh) {
+///     assert!(((*a / *b).re - c).abs() < 1.0e-10 && (*a / *b).im.abs() < 1.0e-10);
+/// }
+/// ```
+pub fn transfer_h2<T, U>(x: &[T], y: &[U], segment: usize, fs: f64) -> (Vec<f64>, Vec<Complex>)
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    let (freq, _, pyy, pxy): (Vec<f64>, Vec<f64>, Vec<f64>, Vec<Complex>) = welch(x, y, segment, fs);
+    let res: Vec<Complex> = pxy.iter().zip(&pyy).map(|(&p, &b)| Complex::from(b, 0.0) / p.conjugate()).collect();
+
+    (freq, res)
+}
+
+/// Welch-averaged auto and cross spectral densities, with their frequencies
+fn welch<T, U>(x: &[T], y: &[U], segment: usize, fs: f64) -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<Complex>)
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    assert!(x.len() == y.len(), "The two signals must have the same length");
+    assert!(segment >= 2 && segment <= x.len(), "The segment length must be between 2 and the signal length");
+    assert!(fs > 0.0, "The sampling frequency must be positive");
+
+    // Periodic Hann window
+    let window: Vec<f64> = (0..segment).map(|i| 0.5 - 0.5 * (TAU * i as f64 / segment as f64).cos()).collect();
+    let step: usize = (segment / 2).max(1);
+    let bins: usize = segment / 2 + 1;
+
+    let mut pxx: Vec<f64> = vec![0.0; bins];
+    let mut pyy: Vec<f64> = vec![0.0; bins];
+    let mut pxy: Vec<Complex> = vec![Complex::default(); bins];
+    let mut count: usize = 0;
+
+    let mut start: usize = 0;
+    while start + segment <= x.len() {
+        let tapered = |s: &[f64]| -> Vec<Complex> {
+            let mean: f64 = s.iter().sum::<f64>() / segment as f64;
+            fft(&s.iter().zip(&window).map(|(v, w)| (v - mean) * w).collect::<Vec<f64>>())
+        };
+        let fx: Vec<Complex> = tapered(&x[start..start + segment].iter().map(|&v| v.into()).collect::<Vec<f64>>());
+        let fy: Vec<Complex> = tapered(&y[start..start + segment].iter().map(|&v| v.into()).collect::<Vec<f64>>());
+
+        for k in 0..bins {
+            pxx[k] += fx[k].modulus().powi(2);
+            pyy[k] += fy[k].modulus().powi(2);
+            pxy[k] += fx[k].conjugate() * fy[k];
+        }
+        count += 1;
+        start += step;
+    }
+
+    // Density scaling, the negative frequencies folded onto the positive ones
+    let scale: f64 = 1.0 / (fs * window.iter().map(|w| w * w).sum::<f64>() * count as f64);
+    for k in 0..bins {
+        let fold: f64 = if k == 0 || 2 * k == segment { 1.0 } else { 2.0 };
+        pxx[k] *= fold * scale;
+        pyy[k] *= fold * scale;
+        pxy[k] *= fold * scale;
+    }
+
+    let freq: Vec<f64> = (0..bins).map(|k| k as f64 * fs / segment as f64).collect();
+    (freq, pxx, pyy, pxy)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Peak selection criteria
+///
+/// Conditions on the peaks returned by `find_peaks`, the default selecting every local maximum.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PeakCriteria {
+    /// Lowest value of the peaks
+    pub height: Option<f64>,
+    /// Lowest prominence of the peaks
+    pub prominence: Option<f64>,
+    /// Lowest width of the peaks at half their prominence, in samples
+    pub width: Option<f64>,
+    /// Smallest distance between two peaks, in samples, the highest peak being kept
+    pub distance: usize
+}
+
+/// # Peak of a signal
+///
+/// Position and shape of a local maximum found by `find_peaks`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Peak {
+    /// Index of the highest sample (the middle one for a flat top)
+    pub index: usize,
+    /// Position of the vertex of the parabola through the sample and its neighbors
+    pub position: f64,
+    /// Value of the parabola at its vertex
+    pub height: f64,
+    /// Height of the peak above the highest of the two minima separating it from higher samples
+    pub prominence: f64,
+    /// Width at half the prominence, in samples, interpolated between the samples
+    pub width: f64,
+    /// Indices of the minima on each side, down to which the prominence is measured
+    pub bases: (usize, usize)
+}
+
+/// # Peak detection
+///
+/// Finds the local maxima of `data` satisfying the `criteria`, in increasing order of position. The
+/// criteria are applied in turn: height, distance (removing the lower of two close peaks), prominence,
+/// then width, as usually done after filtering or spectral analysis.
+///
+/// The prominence of a peak is its height above the higher of the minima reached on each side before a
+/// higher sample (or the edge of the data). Its width is measured at half the prominence, from the linear
+/// interpolation of the samples. The position and height are refined by the parabola through the peak and
+/// its two neighbors. The edges of the data and the `NaN` values are never peaks.
+///
+/// ```
+/// # use scilib::signal::{ PeakCriteria, find_peaks };
+/// // Two gaussian lines and a small ripple
+/// let data: Vec<f64> = (0..200).map(|i| {
+///     let x: f64 = i as f64;
+///     3.0 * (-(x - 50.3f64).powi(2) / 20.0).exp() + (-(x - 140.0f64).powi(2) / 50.0).exp() + 0.05 * (x / 2.0).sin()
+/// }).collect();
+///
+/// assert_eq!(find_peaks(&data, PeakCriteria::default()).len(), 13);
+/// assert_eq!(find_peaks(&data, PeakCriteria { distance: 20, ..Default::default() }).len(), 7);
+/// assert_eq!(find_peaks(&data, PeakCriteria { height: Some(2.0), ..Default::default() }).len(), 1);
+///
+/// let peaks = find_peaks(&data, PeakCriteria { prominence: Some(0.5), ..Default::default() });
+/// assert_eq!(peaks.len(), 2);
+/// assert_eq!((peaks[0].index, peaks[1].index), (50, 140));
+/// assert_eq!(peaks[1].bases, (110, 198));
+///
+/// // A single line, of standard deviation sqrt(10), between the samples
+/// let line: Vec<f64> = (0..100).map(|i| 3.0 * (-(i as f64 - 50.3f64).powi(2) / 20.0).exp()).collect();
+/// let peak = find_peaks(&line, PeakCriteria::default())[0];
+/// assert!((peak.position - 50.3).abs() < 0.01 && (peak.height - 3.0).abs() < 1.0e-3);
+/// assert!((peak.width - 2.0 * (20.0 * 2f64.ln()).sqrt()).abs() < 0.05);
+/// ```
+pub fn find_peaks<T>(data: &[T], criteria: PeakCriteria) -> Vec<Peak>
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let n: usize = x.len();
+
+    // Local maxima, the flat tops counting once at their middle
+    let mut candidates: Vec<usize> = Vec::new();
+    let mut i: usize = 1;
+    while i + 1 < n {
+        if x[i - 1] < x[i] {
+            let mut end: usize = i;
+            while end + 1 < n && x[end + 1] == x[i] {
+                end += 1;
+            }
+            if end + 1 < n && x[end + 1] < x[i] {
+                candidates.push((i + end) / 2);
+            }
+            i = end + 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    if let Some(h) = criteria.height {
+        candidates.retain(|&p| x[p] >= h);
+    }
+
+    // Distance: the peaks are visited from the highest, and remove their lower neighbors
+    if criteria.distance > 1 {
+        let mut order: Vec<usize> = (0..candidates.len()).collect();
+        order.sort_by(|&a, &b| x[candidates[b]].total_cmp(&x[candidates[a]]));
+        let mut keep: Vec<bool> = vec![true; candidates.len()];
+        for &k in &order {
+            if !keep[k] {
+                continue;
+            }
+            let p: usize = candidates[k];
+            for (other, &q) in candidates.iter().enumerate() {
+                if other != k && q.abs_diff(p) < criteria.distance {
+                    keep[other] = false;
+                }
+            }
+        }
+        candidates = candidates.iter().zip(&keep).filter(|(_, &k)| k).map(|(&p, _)| p).collect();
+    }
+
+    candidates.into_iter().map(|p| describe(&x, p)).filter(|peak| {
+        criteria.prominence.is_none_or(|v| peak.prominence >= v) && criteria.width.is_none_or(|v| peak.width >= v)
+    }).collect()
+}
+
+/// Prominence, width and refined vertex of the peak at `p`
+fn describe(x: &[f64], p: usize) -> Peak {
+
+    let top: f64 = x[p];
+
+    // Minima down to the first higher sample on each side
+    let mut left: usize = p;
+    let mut i: usize = p;
+    while i > 0 && x[i - 1] <= top {
+        i -= 1;
+        if x[i] < x[left] {
+            left = i;
+        }
+    }
+    let mut right: usize = p;
+    let mut i: usize = p;
+    while i + 1 < x.len() && x[i + 1] <= top {
+        i += 1;
+        if x[i] < x[right] {
+            right = i;
+        }
+    }
+    let prominence: f64 = top - x[left].max(x[right]);
+
+    // Crossings of half the prominence, interpolated between the samples
+    let level: f64 = top - prominence / 2.0;
+    let mut i: usize = p;
+    while i > left && x[i] > level {
+        i -= 1;
+    }
+    let left_cross: f64 = if x[i] < level { i as f64 + (level - x[i]) / (x[i + 1] - x[i]) } else { i as f64 };
+    let mut i: usize = p;
+    while i < right && x[i] > level {
+        i += 1;
+    }
+    let right_cross: f64 = if x[i] < level { i as f64 - (level - x[i]) / (x[i - 1] - x[i]) } else { i as f64 };
+
+    // Vertex of the parabola through the peak and its neighbors
+    let curvature: f64 = x[p - 1] - 2.0 * top + x[p + 1];
+    let offset: f64 = if curvature < 0.0 { 0.5 * (x[p - 1] - x[p + 1]) / curvature } else { 0.0 };
+
+    Peak {
+        index: p,
+        position: p as f64 + offset,
+        height: top - 0.25 * (x[p - 1] - x[p + 1]) * offset,
+        prominence,
+        width: right_cross - left_cross,
+        bases: (left, right)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Phase unwrapping
+///
+/// Removes the jumps of `2 pi` of a wrapped phase, such as the argument of a complex signal: each time the
+/// phase changes by more than `pi` between two samples, the following ones are shifted by a multiple of
+/// `2 pi`. The first value is left untouched, and the signal must be sampled finely enough for its true
+/// changes to stay below `pi`.
+///
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::signal::unwrap;
+/// // Chirp wrapped to (-pi, pi]
+/// let truth: Vec<f64> = (0..100).map(|i| 0.002 * (i * i) as f64 + 0.3).collect();
+/// let wrapped: Vec<f64> = truth.iter().map(|v| v.sin().atan2(v.cos())).collect();
+/// let res = unwrap(&wrapped);
+///
+/// for (r, t) in res.iter().zip(&truth) {
+///     assert!((r - t).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn unwrap<T>(phase: &[T]) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let mut res: Vec<f64> = Vec::with_capacity(phase.len());
+    let mut previous: Option<f64> = None;
+    let mut offset: f64 = 0.0;
+
+    for &p in phase {
+        let value: f64 = p.into();
+        if let Some(last) = previous {
+            offset -= TAU * ((value - last) / TAU).round();
+        }
+        previous = Some(value);
+        res.push(value + offset);
+    }
+
+    res
+}
+
+/// # Two-dimensional phase unwrapping
+///
+/// Unwraps a phase map, such as an interferogram, given as rows of equal lengths. The pixels are unwrapped
+/// in order of decreasing quality, starting from the best one, each new pixel being unwrapped against its
+/// already unwrapped neighbor (quality-guided path following). The quality of a pixel is the inverse of
+/// the wrapped second differences of the phase around it, so that the noisy areas and the discontinuities
+/// are reached last, without spreading their errors to the rest of the map.
+///
+/// The unwrapped phase is defined up to a multiple of `2 pi`: the best pixel keeps its wrapped value.
+///
+/// ```
+/// # use scilib::signal::unwrap2;
+/// // Tilted and curved wavefront
+/// let truth: Vec<Vec<f64>> = (0..40).map(|i| (0..50).map(|j| {
+///     0.4 * i as f64 - 0.25 * j as f64 + 0.01 * ((i - 20) * (i - 20) + (j - 25) * (j - 25)) as f64
+/// }).collect()).collect();
+/// let wrapped: Vec<Vec<f64>> = truth.iter().map(|r| r.iter().map(|v| v.sin().atan2(v.cos())).collect()).collect();
+///
+/// let res = unwrap2(&wrapped);
+/// let shift: f64 = res[0][0] - truth[0][0];
+/// assert!((shift / std::f64::consts::TAU - (shift / std::f64::consts::TAU).round()).abs() < 1.0e-12);
+/// for (row, exp) in res.iter().zip(&truth) {
+///     for (r, e) in row.iter().zip(exp) {
+///         assert!((r - e - shift).abs() < 1.0e-10);
+///     }
+/// }
+/// ```
+pub fn unwrap2<T>(phase: &[Vec<T>]) -> Vec<Vec<f64>>
+where T: Into<f64> + Copy {
+
+    let rows: usize = phase.len();
+    let cols: usize = phase.first().map_or(0, |r| r.len());
+    assert!(phase.iter().all(|r| r.len() == cols), "All the rows of the phase map must have the same length");
+    let wrapped: Vec<Vec<f64>> = phase.iter().map(|r| r.iter().map(|&v| v.into()).collect()).collect();
+    if rows * cols == 0 {
+        return wrapped;
+    }
+
+    // Quality of each pixel from the wrapped second differences along the rows, columns and diagonals
+    let quality: Vec<Vec<f64>> = (0..rows).map(|i| (0..cols).map(|j| {
+        let mut d2: f64 = 0.0;
+        for (di, dj) in [(1, 0), (0, 1), (1, 1), (1, -1)] {
+            let (a, b): (Option<f64>, Option<f64>) = (at(&wrapped, i, j, -di, -dj), at(&wrapped, i, j, di, dj));
+            if let (Some(a), Some(b)) = (a, b) {
+                d2 += (wrap(a - wrapped[i][j]) - wrap(wrapped[i][j] - b)).powi(2);
+            }
+        }
+        1.0 / (d2.sqrt() + f64::EPSILON)
+    }).collect()).collect();
+
+    let (mut start, mut best): ((usize, usize), f64) = ((0, 0), f64::NEG_INFINITY);
+    for (i, row) in quality.iter().enumerate() {
+        for (j, &q) in row.iter().enumerate() {
+            if q > best {
+                (start, best) = ((i, j), q);
+            }
+        }
+    }
+
+    // Flood fill from the best pixel, the best candidate on the border of the unwrapped area coming first
+    let mut res: Vec<Vec<f64>> = wrapped.clone();
+    let mut done: Vec<Vec<bool>> = vec![vec![false; cols]; rows];
+    let mut heap: BinaryHeap<Pixel> = BinaryHeap::new();
+    heap.push(Pixel { quality: best, pos: start, from: start });
+
+    while let Some(Pixel { pos: (i, j), from: (fi, fj), .. }) = heap.pop() {
+        if done[i][j] {
+            continue;
+        }
+        done[i][j] = true;
+        res[i][j] = res[fi][fj] + wrap(wrapped[i][j] - wrapped[fi][fj]);
+
+        for (di, dj) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (ni, nj): (isize, isize) = (i as isize + di, j as isize + dj);
+            if ni >= 0 && nj >= 0 && (ni as usize) < rows && (nj as usize) < cols && !done[ni as usize][nj as usize] {
+                let pos: (usize, usize) = (ni as usize, nj as usize);
+                heap.push(Pixel { quality: quality[pos.0][pos.1], pos, from: (i, j) });
+            }
+        }
+    }
+
+    res
+}
+
+/// Phase brought back to `[-pi, pi]`
+fn wrap(phase: f64) -> f64 {
+    phase - TAU * (phase / TAU).round()
+}
+
+/// Value of the map at the offset `(di, dj)` from `(i, j)`, if inside
+fn at(map: &[Vec<f64>], i: usize, j: usize, di: isize, dj: isize) -> Option<f64> {
+    let (ni, nj): (isize, isize) = (i as isize + di, j as isize + dj);
+    if ni < 0 || nj < 0 {
+        return None;
+    }
+    map.get(ni as usize).and_then(|r| r.get(nj as usize)).copied()
+}
+
+/// Pixel waiting to be unwrapped, from its unwrapped neighbor `from`, ordered by quality
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Pixel {
+    /// Quality of the pixel
+    quality: f64,
+    /// Position of the pixel
+    pos: (usize, usize),
+    /// Position of the unwrapped neighbor
+    from: (usize, usize)
+}
+
+impl Eq for Pixel {}
+
+impl PartialOrd for Pixel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pixel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.quality.total_cmp(&other.quality)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
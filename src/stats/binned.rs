@@ -0,0 +1,173 @@
+//!
+//! # Binned statistics
+//!
+//! Reduction of scattered data over bins of a coordinate: the mean, median or spread of the values falling
+//! in each bin gives profiles (a radial profile, a trend against time, ...), and the counts over a 2D grid
+//! give density maps.
+//!
+//! Bins are given by their edges, in increasing order and not necessarily evenly spaced: `n + 1` edges
+//! define `n` bins. Each bin includes its lower edge and excludes its upper edge, except for the last one
+//! which includes both; values outside of the edges are ignored.
+//!
+//! ```
+//! # use scilib::range;
+//! # use scilib::stats::binned::{ binned_statistic, Statistic };
+//! let x: Vec<f64> = (0..100).map(|i| 0.1 * i as f64 + 0.05).collect();
+//! let values: Vec<f64> = x.iter().map(|v| 2.0 * v).collect();
+//!
+//! let profile = binned_statistic(&x, &values, &range::linear(0.0, 10.0, 6), Statistic::Mean);
+//! assert_eq!(profile.len(), 5);
+//! assert!((profile[0] - 2.0).abs() < 1.0e-12 && (profile[4] - 18.0).abs() < 1.0e-12);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::robust;              // Median
+
+use crate::math::summation;     // Accurate sums
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Statistic computed in each bin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Statistic {
+    /// Number of values
+    Count,
+    /// Sum of the values
+    Sum,
+    /// Mean of the values
+    Mean,
+    /// Median of the values
+    Median,
+    /// Standard deviation of the values (population, normalized by `n`)
+    Std,
+    /// Smallest value
+    Min,
+    /// Largest value
+    Max
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Binned statistic
+///
+/// Computes `stat` over the `values` whose coordinate `x` falls in each bin defined by the `edges`.
+///
+/// Returns one result per bin. Empty bins give `NaN`, except for the count and sum which are null.
+///
+/// ```
+/// # use scilib::stats::binned::{ binned_statistic, Statistic };
+/// let x = [0.5, 1.5, 1.2, 3.9, 4.0, 2.2, -1.0];
+/// let values = [1.0, 4.0, 2.0, 7.0, 9.0, 3.0, 100.0];
+/// let edges = [0.0, 1.0, 2.0, 4.0];
+///
+/// assert_eq!(binned_statistic(&x, &values, &edges, Statistic::Count), vec![1.0, 2.0, 3.0]);
+/// assert_eq!(binned_statistic(&x, &values, &edges, Statistic::Median), vec![1.0, 3.0, 7.0]);
+/// assert_eq!(binned_statistic(&x, &values, &edges, Statistic::Std)[1], 1.0);
+///
+/// let res = binned_statistic(&x, &values, &[-5.0, -3.0, 0.0], Statistic::Max);
+/// assert!(res[0].is_nan() && res[1] == 100.0);
+/// ```
+pub fn binned_statistic<T, U>(x: &[T], values: &[U], edges: &[f64], stat: Statistic) -> Vec<f64>
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    let n_bins: usize = edges.len().saturating_sub(1);
+    let mut groups: Vec<Vec<f64>> = vec![Vec::new(); n_bins];
+
+    for (&xi, &vi) in x.iter().zip(values) {
+        if let Some(b) = bin_index(xi.into(), edges) {
+            groups[b].push(vi.into());
+        }
+    }
+
+    groups.iter().map(|g| reduce(g, stat)).collect()
+}
+
+/// # Histogram
+///
+/// Number of values falling in each bin defined by the `edges`.
+///
+/// ```
+/// # use scilib::stats::binned::histogram;
+/// assert_eq!(histogram(&[0.1, 0.5, 0.7, 1.0, 1.5, 3.0], &[0.0, 0.5, 1.0]), vec![1, 3]);
+/// ```
+pub fn histogram<T>(x: &[T], edges: &[f64]) -> Vec<usize>
+where T: Into<f64> + Copy {
+
+    let mut res: Vec<usize> = vec![0; edges.len().saturating_sub(1)];
+    for &v in x {
+        if let Some(b) = bin_index(v.into(), edges) {
+            res[b] += 1;
+        }
+    }
+
+    res
+}
+
+/// # 2D histogram
+///
+/// Number of points `(x, y)` falling in each cell of the grid defined by `x_edges` and `y_edges`.
+///
+/// Returns the counts indexed as `res[i][j]`, `i` being the bin in `x` and `j` the bin in `y`.
+///
+/// ```
+/// # use scilib::stats::binned::histogram_2d;
+/// let x = [0.2, 0.8, 0.4, 1.5, 1.9, 5.0];
+/// let y = [0.1, 0.2, 2.5, 2.9, 0.3, 0.0];
+///
+/// let res = histogram_2d(&x, &y, &[0.0, 1.0, 2.0], &[0.0, 1.0, 3.0]);
+/// assert_eq!(res, vec![vec![2, 1], vec![1, 1]]);
+/// ```
+pub fn histogram_2d<T, U>(x: &[T], y: &[U], x_edges: &[f64], y_edges: &[f64]) -> Vec<Vec<usize>>
+where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+    let mut res: Vec<Vec<usize>> = vec![vec![0; y_edges.len().saturating_sub(1)]; x_edges.len().saturating_sub(1)];
+
+    for (&xi, &yi) in x.iter().zip(y) {
+        if let (Some(i), Some(j)) = (bin_index(xi.into(), x_edges), bin_index(yi.into(), y_edges)) {
+            res[i][j] += 1;
+        }
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Index of the bin containing `v`, if any
+fn bin_index(v: f64, edges: &[f64]) -> Option<usize> {
+
+    let n: usize = edges.len();
+    if n < 2 || v.is_nan() || v < edges[0] || v > edges[n - 1] {
+        return None;
+    }
+
+    // The last edge belongs to the last bin
+    Some(edges.partition_point(|&e| e <= v).saturating_sub(1).min(n - 2))
+}
+
+/// Computes a statistic over the values of a bin
+fn reduce(values: &[f64], stat: Statistic) -> f64 {
+
+    let n: f64 = values.len() as f64;
+    match stat {
+        Statistic::Count => return n,
+        Statistic::Sum => return summation::pairwise_sum(values),
+        _ if values.is_empty() => return f64::NAN,
+        _ => ()
+    }
+
+    match stat {
+        Statistic::Mean => summation::pairwise_sum(values) / n,
+        Statistic::Median => robust::median(values),
+        Statistic::Std => {
+            let mean: f64 = summation::pairwise_sum(values) / n;
+            (summation::neumaier_sum(values.iter().map(|v| (v - mean).powi(2))) / n).sqrt()
+        },
+        Statistic::Min => values.iter().cloned().fold(f64::INFINITY, f64::min),
+        _ => values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,328 @@
+//!
+//! # Circular statistics
+//!
+//! Statistics of angles, directions and phases, whose values wrap around: the arithmetic mean of 350° and
+//! 10° is 180°, whereas they both point close to 0°. Angles are averaged as unit vectors instead, and the
+//! von Mises distribution plays the role of the normal distribution on the circle.
+//!
+//! All angles are in radians.
+//!
+//! ```
+//! # use scilib::stats::circular::{ circular_mean, resultant_length };
+//! let angles = [350.0_f64.to_radians(), 10.0_f64.to_radians()];
+//!
+//! assert!(circular_mean(&angles).abs() < 1.0e-15);
+//! assert!((resultant_length(&angles) - 10.0_f64.to_radians().cos()).abs() < 1.0e-15);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{         // Using std lib constants
+    PI,                         // Pi
+    TAU                         // 2 * Pi
+};
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::math::bessel;        // Modified Bessel function I
+
+use super::Distribution;        // Common interface
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Concentration above which the asymptotic expansion of I_0 is used
+const KAPPA_LARGE: f64 = 50.0;
+
+/// Concentration below which the distribution is taken as uniform
+const KAPPA_SMALL: f64 = 1.0e-8;
+
+/// Precision of the concentration estimate
+const PRECISION: f64 = 1.0e-14;
+
+/// Iteration cap of the concentration estimate
+const MAX_ITERATIONS: usize = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Von Mises distribution
+///
+/// Density `exp(kappa cos(x - mu)) / (2 pi I_0(kappa))` on `[mu - pi, mu + pi]`, where `mu` is the mean
+/// direction and `kappa >= 0` the concentration. The distribution is uniform for a null concentration,
+/// and tends to a normal distribution of variance `1 / kappa` for large ones.
+///
+/// ```
+/// # use scilib::stats::Distribution;
+/// # use scilib::stats::circular::VonMises;
+/// let dist = VonMises::new(0.5, 2.0);
+///
+/// assert!((dist.pdf(1.0) - 0.40385253335183775).abs() < 1.0e-11);
+/// assert!((dist.cdf(1.0) - 0.73819221441852618).abs() < 1.0e-14);
+/// assert!((dist.quantile(0.5) - 0.5).abs() < 1.0e-14);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VonMises {
+    /// Mean direction
+    pub mu: f64,
+    /// Concentration
+    pub kappa: f64
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementing required methods
+impl VonMises {
+    /// # Creates the distribution
+    ///
+    /// `mu` is the mean direction, `kappa >= 0` the concentration.
+    pub fn new<T, U>(mu: T, kappa: U) -> Self
+    where T: Into<f64>, U: Into<f64> {
+        Self { mu: mu.into(), kappa: kappa.into() }
+    }
+
+    /// # Fit by maximum likelihood
+    ///
+    /// The mean direction is the circular mean, and the concentration solves `I_1(kappa) / I_0(kappa) = R`,
+    /// where `R` is the mean resultant length. The estimate is biased upwards for small samples.
+    ///
+    /// ```
+    /// # use scilib::stats::circular::VonMises;
+    /// let fit = VonMises::fit(&[0.1, 0.3, -0.2, 0.25, 0.05, -0.1]);
+    ///
+    /// assert!((fit.mu - 0.06679832377618271).abs() < 1.0e-14);
+    /// assert!((fit.kappa - 32.254759156364657).abs() < 1.0e-10);
+    /// ```
+    pub fn fit<T>(angles: &[T]) -> Self
+    where T: Into<f64> + Copy {
+        Self { mu: circular_mean(angles), kappa: inverse_ratio(resultant_length(angles)) }
+    }
+
+    /// # Random sample
+    ///
+    /// Draws a value with the rejection method of Best and Fisher (1979), `uniform` returning independent
+    /// values uniformly distributed in `[0, 1)`. The result lies in `[mu - pi, mu + pi]`.
+    ///
+    /// ```
+    /// # use scilib::math::sde::Noise;
+    /// # use scilib::stats::circular::{ VonMises, circular_mean, resultant_length };
+    /// let mut noise = Noise::new(42);
+    /// let mut uniform = || noise.uniform();
+    ///
+    /// let dist = VonMises::new(1.0, 4.0);
+    /// let draws: Vec<f64> = (0..20_000).map(|_| dist.sample(&mut uniform)).collect();
+    ///
+    /// assert!((circular_mean(&draws) - 1.0).abs() < 0.02);
+    /// // Expected mean resultant length, I_1(4) / I_0(4)
+    /// assert!((resultant_length(&draws) - 0.8635226).abs() < 0.01);
+    /// ```
+    pub fn sample<R>(&self, uniform: &mut R) -> f64
+    where R: FnMut() -> f64 {
+
+        if self.kappa < KAPPA_SMALL {
+            return self.mu + PI * (2.0 * uniform() - 1.0);
+        }
+
+        let tau: f64 = 1.0 + (1.0 + 4.0 * self.kappa * self.kappa).sqrt();
+        let rho: f64 = (tau - (2.0 * tau).sqrt()) / (2.0 * self.kappa);
+        let r: f64 = (1.0 + rho * rho) / (2.0 * rho);
+
+        loop {
+            let z: f64 = (PI * uniform()).cos();
+            let f: f64 = (1.0 + r * z) / (r + z);
+            let c: f64 = self.kappa * (r - f);
+            let u: f64 = uniform();
+
+            if c * (2.0 - c) > u || (c / u).ln() + 1.0 - c >= 0.0 {
+                let theta: f64 = f.clamp(-1.0, 1.0).acos();
+                return if uniform() < 0.5 { self.mu - theta } else { self.mu + theta };
+            }
+        }
+    }
+}
+
+impl Distribution for VonMises {
+    fn pdf(&self, x: f64) -> f64 {
+        // Scaled by exp(-kappa) to avoid overflows
+        (self.kappa * ((x - self.mu).cos() - 1.0)).exp() / (TAU * scaled_i0(self.kappa))
+    }
+
+    /// Cumulative distribution from `mu - pi`, using the Fourier series of the density
+    fn cdf(&self, x: f64) -> f64 {
+
+        let theta: f64 = x - self.mu;
+        if theta <= -PI {
+            return 0.0;
+        }
+        if theta >= PI {
+            return 1.0;
+        }
+
+        let mut res: f64 = 0.5 + theta / TAU;
+        if self.kappa < KAPPA_SMALL {
+            return res;
+        }
+
+        // Ratios I_n / I_(n-1) from the backward recurrence, stable for any concentration
+        let count: usize = 30 + (10.0 * self.kappa.sqrt()) as usize;
+        let mut ratios: Vec<f64> = vec![0.0; count + 1];
+        for n in (1..count).rev() {
+            ratios[n] = 1.0 / (2.0 * n as f64 / self.kappa + ratios[n + 1]);
+        }
+
+        let mut coef: f64 = 1.0;
+        for (n, r) in ratios.iter().enumerate().take(count).skip(1) {
+            coef *= r;
+            res += coef * (n as f64 * theta).sin() / (PI * n as f64);
+            if coef < f64::EPSILON * 1.0e-3 {
+                break;
+            }
+        }
+
+        res.clamp(0.0, 1.0)
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (self.mu - PI, self.mu + PI)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Circular mean
+///
+/// Direction of the sum of the unit vectors of the angles, in `[-pi, pi]`. It is undefined (and returns 0)
+/// when the vectors cancel out.
+///
+/// ```
+/// # use scilib::stats::circular::circular_mean;
+/// let res = circular_mean(&[3.0, -3.0, 3.1]);
+/// assert!((res - 3.1276361964263836).abs() < 1.0e-14);
+/// ```
+pub fn circular_mean<T>(angles: &[T]) -> f64
+where T: Into<f64> + Copy {
+    let (c, s): (f64, f64) = vector_sum(angles);
+    s.atan2(c)
+}
+
+/// # Mean resultant length
+///
+/// Norm of the mean of the unit vectors of the angles, between 0 (spread out angles) and 1 (identical
+/// angles). The circular variance is `1 - R`.
+///
+/// ```
+/// # use scilib::stats::circular::resultant_length;
+/// assert!((resultant_length(&[0.0, 1.0, 2.0]) - 0.6935348705787598).abs() < 1.0e-15);
+/// assert_eq!(resultant_length(&[1.5, 1.5]), 1.0);
+/// ```
+pub fn resultant_length<T>(angles: &[T]) -> f64
+where T: Into<f64> + Copy {
+    let (c, s): (f64, f64) = vector_sum(angles);
+    c.hypot(s) / angles.len() as f64
+}
+
+/// # Rayleigh test
+///
+/// Tests the null hypothesis of uniformly distributed angles against a unimodal alternative.
+///
+/// Returns the statistic `Z = n R^2` and its p-value, with the approximation of Greenwood and Durand
+/// (1955), accurate even for small samples. Small p-values indicate a preferred direction.
+///
+/// ```
+/// # use scilib::stats::circular::rayleigh_test;
+/// let (z, p) = rayleigh_test(&[0.1, 0.3, -0.2, 0.25, 0.05, -0.1, 0.4, 0.15]);
+/// assert!((z - 7.719500281561702).abs() < 1.0e-12);
+/// assert!(p < 1.0e-4);
+///
+/// let (_, p) = rayleigh_test(&[0.0, 2.1, 4.2, 1.0, 3.1, 5.2]);
+/// assert!(p > 0.5);
+/// ```
+pub fn rayleigh_test<T>(angles: &[T]) -> (f64, f64)
+where T: Into<f64> + Copy {
+
+    let n: f64 = angles.len() as f64;
+    let (c, s): (f64, f64) = vector_sum(angles);
+    let r2: f64 = c * c + s * s;
+
+    let z: f64 = r2 / n;
+    let p: f64 = ((1.0 + 4.0 * n + 4.0 * (n * n - r2)).sqrt() - (1.0 + 2.0 * n)).exp();
+
+    (z, p.min(1.0))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Sum of the cosines and sines of the angles
+fn vector_sum<T>(angles: &[T]) -> (f64, f64)
+where T: Into<f64> + Copy {
+    angles.iter().fold((0.0, 0.0), |(c, s), &a| {
+        let a: f64 = a.into();
+        (c + a.cos(), s + a.sin())
+    })
+}
+
+/// Scaled modified Bessel function `I_0(x) exp(-x)`
+fn scaled_i0(x: f64) -> f64 {
+
+    if x < KAPPA_LARGE {
+        return bessel::i(x, 0).re * (-x).exp();
+    }
+
+    // Asymptotic expansion, whose terms decrease up to k = 2x
+    let mut term: f64 = 1.0;
+    let mut res: f64 = 1.0;
+    for k in 1..30 {
+        let odd: f64 = (2 * k - 1) as f64;
+        term *= odd * odd / (8.0 * k as f64 * x);
+        res += term;
+    }
+
+    res / (TAU * x).sqrt()
+}
+
+/// Ratio `I_1(x) / I_0(x)`, from the backward recurrence of the ratios
+fn bessel_ratio(x: f64) -> f64 {
+    let count: usize = 30 + (10.0 * x.sqrt()) as usize;
+    (1..=count).rev().fold(0.0, |r, n| 1.0 / (2.0 * n as f64 / x + r))
+}
+
+/// Solves `I_1(kappa) / I_0(kappa) = r` with Newton's method
+fn inverse_ratio(r: f64) -> f64 {
+
+    if r <= 0.0 {
+        return 0.0;
+    }
+    if r >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let prec: Precision = Precision::current();             // Precision policy
+    let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+    let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+    // Approximation of Best and Fisher (1981)
+    let mut kappa: f64 = if r < 0.53 {
+        2.0 * r + r.powi(3) + 5.0 * r.powi(5) / 6.0
+    } else if r < 0.85 {
+        -0.4 + 1.39 * r + 0.43 / (1.0 - r)
+    } else {
+        1.0 / (r.powi(3) - 4.0 * r * r + 3.0 * r)
+    };
+
+    for iter in 0..max_iter {
+        let a: f64 = bessel_ratio(kappa);
+        let step: f64 = (a - r) / (1.0 - a / kappa - a * a);
+        kappa = (kappa - step).max(0.5 * kappa);
+
+        diagnostics::step("circular::kappa", iter, (step / kappa).abs(), step);
+        if (step / kappa).abs() < tol {
+            diagnostics::finish("circular::kappa", iter + 1, (step / kappa).abs(), tol);
+            break;
+        }
+    }
+
+    kappa
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,209 @@
+//!
+//! # Contingency tables
+//!
+//! Tests of independence between two categorical variables, from the table of the counts of each pair of
+//! categories: `table[i][j]` is the number of observations in the row category `i` and the column category
+//! `j`. Under independence, the expected counts are the products of the row and column totals divided by
+//! the total count.
+//!
+//! Pearson's chi-square test compares the observed and expected counts, and relies on the asymptotic
+//! chi-square distribution of its statistic; it is reliable when the expected counts are large enough
+//! (all above 5, as a rule of thumb). Fisher's exact test sums the hypergeometric probabilities of the
+//! tables with the same totals, and holds for any count, at the cost of an enumeration of the tables.
+//!
+//! ```
+//! # use scilib::stats::contingency::{ chi_square_test, fisher_exact };
+//! let table = vec![vec![12, 5], vec![3, 10]];
+//!
+//! let (_, p_chi2) = chi_square_test(&table, true);
+//! let p_fisher = fisher_exact(&table);
+//!
+//! assert!(p_chi2 < 0.05 && p_fisher < 0.05);
+//! assert!((p_fisher - 0.025327687033676143).abs() < 1.0e-14);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::math::basic;         // Incomplete gamma and log gamma functions
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relative tolerance when comparing the probabilities of the tables to the observed one
+const TABLE_TOLERANCE: f64 = 1.0e-7;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Expected frequencies
+///
+/// Counts expected under the independence of the rows and columns, with the totals of the `table`.
+///
+/// ```
+/// # use scilib::stats::contingency::expected_frequencies;
+/// let table = vec![vec![10, 20], vec![30, 40]];
+/// assert_eq!(expected_frequencies(&table), vec![vec![12.0, 18.0], vec![28.0, 42.0]]);
+/// ```
+pub fn expected_frequencies<T>(table: &[Vec<T>]) -> Vec<Vec<f64>>
+where T: Into<f64> + Copy {
+
+    let (rows, cols, total): (Vec<f64>, Vec<f64>, f64) = margins(table);
+    rows.iter().map(|r| cols.iter().map(|c| r * c / total).collect()).collect()
+}
+
+/// # Pearson's chi-square test of independence
+///
+/// Computes the statistic `sum (O - E)^2 / E` over the cells of the `table`, with `(r - 1)(c - 1)` degrees
+/// of freedom. For 2×2 tables, `yates` applies the continuity correction, subtracting `1/2` to each
+/// `|O - E|`; it is ignored for larger tables.
+///
+/// Returns the statistic and its p-value. Both are `NaN` if a row or a column is empty.
+///
+/// ```
+/// # use scilib::stats::contingency::chi_square_test;
+/// let table = vec![vec![10, 20, 30], vec![6, 9, 17]];
+/// let (chi2, p) = chi_square_test(&table, false);
+///
+/// assert!((chi2 - 0.27157465150403504).abs() < 1.0e-14);
+/// assert!((p - 0.873028283380073).abs() < 1.0e-14);
+///
+/// let (chi2, _) = chi_square_test(&vec![vec![12, 5], vec![3, 10]], true);
+/// assert!((chi2 - 4.886877828054299).abs() < 1.0e-13);
+/// ```
+pub fn chi_square_test<T>(table: &[Vec<T>], yates: bool) -> (f64, f64)
+where T: Into<f64> + Copy {
+
+    let expected: Vec<Vec<f64>> = expected_frequencies(table);
+    let dof: usize = (table.len().saturating_sub(1)) * (expected.first().map_or(0, |r| r.len()).saturating_sub(1));
+    let correction: f64 = if yates && dof == 1 { 0.5 } else { 0.0 };
+
+    let mut chi2: f64 = 0.0;
+    for (row, exp_row) in table.iter().zip(&expected) {
+        for (&o, &e) in row.iter().zip(exp_row) {
+            let diff: f64 = ((o.into() - e).abs() - correction).max(0.0);
+            chi2 += diff * diff / e;
+        }
+    }
+
+    if dof == 0 || chi2.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    (chi2, basic::gammaincc(0.5 * dof as f64, 0.5 * chi2))
+}
+
+/// # Fisher's exact test
+///
+/// Two-sided exact test of independence of an R×C `table` of counts. Under independence and with fixed
+/// totals, the tables follow the multivariate hypergeometric distribution; the p-value is the sum of the
+/// probabilities of the tables not more likely than the observed one.
+///
+/// All the tables with the same totals are enumerated, which is immediate for 2×2 tables but grows quickly
+/// with the size and counts of larger tables. The counts are rounded to the nearest integers.
+///
+/// ```
+/// # use scilib::stats::contingency::fisher_exact;
+/// assert!((fisher_exact(&vec![vec![8, 2], vec![1, 5]]) - 0.03496503496503497).abs() < 1.0e-15);
+///
+/// let table = vec![vec![3, 1, 0], vec![1, 3, 2], vec![0, 1, 4]];
+/// assert!((fisher_exact(&table) - 0.08488337059765631).abs() < 1.0e-14);
+/// ```
+pub fn fisher_exact<T>(table: &[Vec<T>]) -> f64
+where T: Into<f64> + Copy {
+
+    let table: Vec<Vec<usize>> = table.iter().map(|r| r.iter().map(|&v| v.into().round() as usize).collect()).collect();
+    let rows: Vec<usize> = table.iter().map(|r| r.iter().sum()).collect();
+    let n_cols: usize = table.first().map_or(0, |r| r.len());
+    let cols: Vec<usize> = (0..n_cols).map(|j| table.iter().map(|r| r[j]).sum()).collect();
+    let total: usize = rows.iter().sum();
+
+    if total == 0 {
+        return 1.0;
+    }
+
+    // Log-probability of a table, without the cell factorials
+    let ln_fact = |k: usize| basic::ln_gamma(k as f64 + 1.0);
+    let base: f64 = rows.iter().chain(&cols).map(|&k| ln_fact(k)).sum::<f64>() - ln_fact(total);
+    let observed: f64 = base - table.iter().flatten().map(|&k| ln_fact(k)).sum::<f64>();
+
+    let mut p: f64 = 0.0;
+    let mut cells: Vec<usize> = Vec::with_capacity(rows.len() * n_cols);
+    let mut remaining: Vec<usize> = rows.clone();
+    enumerate(&cols, 0, 0, cols.first().copied().unwrap_or(0), &mut remaining, &mut cells, &mut |cells: &[usize]| {
+        let ln_p: f64 = base - cells.iter().map(|&k| ln_fact(k)).sum::<f64>();
+        if ln_p <= observed + TABLE_TOLERANCE {
+            p += ln_p.exp();
+        }
+    });
+
+    p.min(1.0)
+}
+
+/// # Odds ratio of a 2×2 table
+///
+/// Sample odds ratio `(a d) / (b c)` of the table `[[a, b], [c, d]]`, the ratio of the odds of the first
+/// column between the two rows; it equals one under independence.
+///
+/// ```
+/// # use scilib::stats::contingency::odds_ratio;
+/// assert_eq!(odds_ratio([[8, 2], [1, 5]]), 20.0);
+/// ```
+pub fn odds_ratio<T>(table: [[T; 2]; 2]) -> f64
+where T: Into<f64> + Copy {
+    (table[0][0].into() * table[1][1].into()) / (table[0][1].into() * table[1][0].into())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Row totals, column totals and total count of a table
+fn margins<T>(table: &[Vec<T>]) -> (Vec<f64>, Vec<f64>, f64)
+where T: Into<f64> + Copy {
+
+    let rows: Vec<f64> = table.iter().map(|r| r.iter().map(|&v| v.into()).sum()).collect();
+    let n_cols: usize = table.first().map_or(0, |r| r.len());
+    let cols: Vec<f64> = (0..n_cols).map(|j| table.iter().map(|r| r[j].into()).sum()).collect();
+    let total: f64 = rows.iter().sum();
+
+    (rows, cols, total)
+}
+
+/// Enumerates the tables with the given column totals and remaining row totals, column by column
+///
+/// `left` is the part of the total of column `col` still to distribute from the row `row` on. The cells
+/// are stored column after column, and the last column takes what remains of the rows.
+fn enumerate<F>(cols: &[usize], col: usize, row: usize, left: usize, remaining: &mut [usize], cells: &mut Vec<usize>, visit: &mut F)
+where F: FnMut(&[usize]) {
+
+    let n_rows: usize = remaining.len();
+
+    if col + 1 == cols.len() {
+        let len: usize = cells.len();
+        cells.extend_from_slice(remaining);
+        visit(cells);
+        cells.truncate(len);
+        return;
+    }
+
+    // The last row of a column takes what is left, if it fits
+    if row + 1 == n_rows {
+        if left <= remaining[row] {
+            remaining[row] -= left;
+            cells.push(left);
+            enumerate(cols, col + 1, 0, cols[col + 1], remaining, cells, visit);
+            cells.pop();
+            remaining[row] += left;
+        }
+        return;
+    }
+
+    // What the following rows can absorb bounds the cell from below
+    let capacity: usize = remaining[(row + 1)..].iter().sum();
+    for k in left.saturating_sub(capacity)..=left.min(remaining[row]) {
+        remaining[row] -= k;
+        cells.push(k);
+        enumerate(cols, col, row + 1, left - k, remaining, cells, visit);
+        cells.pop();
+        remaining[row] += k;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
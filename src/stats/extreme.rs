@@ -0,0 +1,631 @@
+//!
+//! # Extreme value distributions
+//!
+//! Distributions of the maxima of samples and of the excesses over a high threshold, used to estimate
+//! the return levels of rare events (floods, flares, wind gusts, ...) from the largest observations:
+//!
+//! - `Gumbel`, the limit of the maxima of light-tailed distributions
+//! - `Gev`, the generalized extreme value distribution of block maxima
+//! - `GeneralizedPareto`, the distribution of the excesses over a threshold
+//!
+//! The shape `xi` sets the tail: positive for heavy (Fréchet) tails, null for exponential tails and
+//! negative for a bounded support. Each distribution can be fitted by maximum likelihood, or with
+//! probability weighted moments (PWM) which are robust for short samples; the PWM estimates also start the
+//! likelihood maximization, done with the Nelder–Mead method.
+//!
+//! ```
+//! # use scilib::stats::Distribution;
+//! # use scilib::stats::extreme::Gev;
+//! let truth = Gev::new(10.0, 2.0, 0.1);
+//! let data: Vec<f64> = (0..200).map(|i| truth.quantile((i as f64 + 0.5) / 200.0)).collect();
+//!
+//! let fit = Gev::fit_mle(&data);
+//! assert!((fit.mu - 10.0).abs() < 0.05 && (fit.sigma - 2.0).abs() < 0.05 && (fit.xi - 0.1).abs() < 0.02);
+//!
+//! // Level exceeded once every 100 blocks on average
+//! let level = fit.quantile(1.0 - 1.0 / 100.0);
+//! assert!((level - truth.quantile(0.99)).abs() < 0.5);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::math::basic;         // Gamma function
+
+use super::Distribution;        // Common interface
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Euler–Mascheroni constant, mean of the standard Gumbel distribution
+const EULER: f64 = 0.577_215_664_901_532_9;
+
+/// Shapes below this magnitude use the exponential limit
+const XI_ZERO: f64 = 1.0e-12;
+
+/// Precision of the likelihood maximizations
+const PRECISION: f64 = 1.0e-12;
+
+/// Iteration cap of the likelihood maximizations
+const MAX_ITERATIONS: usize = 5_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Gumbel distribution
+///
+/// Cumulative distribution `exp(-exp(-(x - mu) / beta))`, the type I extreme value distribution.
+///
+/// ```
+/// # use scilib::stats::Distribution;
+/// # use scilib::stats::extreme::Gumbel;
+/// let dist = Gumbel::new(1.0, 2.0);
+///
+/// assert!((dist.cdf(3.0) - (-(-1.0_f64).exp()).exp()).abs() < 1.0e-15);
+/// assert!((dist.quantile(dist.cdf(4.5)) - 4.5).abs() < 1.0e-14);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Gumbel {
+    /// Location
+    pub mu: f64,
+    /// Scale
+    pub beta: f64
+}
+
+/// # Generalized extreme value distribution
+///
+/// Cumulative distribution `exp(-(1 + xi z)^(-1 / xi))` with `z = (x - mu) / sigma`, defined where
+/// `1 + xi z > 0`. A null `xi` gives the Gumbel distribution.
+///
+/// ```
+/// # use scilib::stats::Distribution;
+/// # use scilib::stats::extreme::Gev;
+/// let dist = Gev::new(0.0, 1.0, 0.5);
+///
+/// assert!((dist.cdf(2.0) - (-0.25_f64).exp()).abs() < 1.0e-15);
+/// assert!((dist.pdf(2.0) - 0.125 * (-0.25_f64).exp()).abs() < 1.0e-15);
+/// assert_eq!(dist.support(), (-2.0, f64::INFINITY));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Gev {
+    /// Location
+    pub mu: f64,
+    /// Scale
+    pub sigma: f64,
+    /// Shape
+    pub xi: f64
+}
+
+/// # Generalized Pareto distribution
+///
+/// Cumulative distribution `1 - (1 + xi z)^(-1 / xi)` with `z = (x - mu) / sigma`, for `x >= mu`. The
+/// location `mu` is the threshold over which the excesses are modelled; a null `xi` gives the exponential
+/// distribution.
+///
+/// ```
+/// # use scilib::stats::Distribution;
+/// # use scilib::stats::extreme::GeneralizedPareto;
+/// let dist = GeneralizedPareto::new(0.0, 2.0, -0.5);
+///
+/// assert!((dist.cdf(1.0) - 0.4375).abs() < 1.0e-15);
+/// assert_eq!(dist.support(), (0.0, 4.0));
+/// assert_eq!(dist.quantile(1.0), 4.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeneralizedPareto {
+    /// Location, the threshold
+    pub mu: f64,
+    /// Scale
+    pub sigma: f64,
+    /// Shape
+    pub xi: f64
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementing required methods
+impl Gumbel {
+    /// # Creates the distribution
+    ///
+    /// `mu` is the location, `beta > 0` the scale.
+    pub fn new<T, U>(mu: T, beta: U) -> Self
+    where T: Into<f64>, U: Into<f64> {
+        Self { mu: mu.into(), beta: beta.into() }
+    }
+
+    /// # Log-likelihood of a sample
+    pub fn log_likelihood<T>(&self, data: &[T]) -> f64
+    where T: Into<f64> + Copy {
+        Gev::new(self.mu, self.beta, 0.0).log_likelihood(data)
+    }
+
+    /// # Fit with probability weighted moments
+    ///
+    /// Requires at least two values, otherwise the parameters are `NaN`.
+    ///
+    /// ```
+    /// # use scilib::stats::extreme::Gumbel;
+    /// let data = [3.2, 4.1, 2.7, 5.9, 3.8, 4.4, 3.1, 6.3, 3.5, 4.0];
+    /// let fit = Gumbel::fit_pwm(&data);
+    ///
+    /// assert!((fit.mu - 3.5448358818154219).abs() < 1.0e-12);
+    /// assert!((fit.beta - 0.9617966939259756).abs() < 1.0e-12);
+    /// ```
+    pub fn fit_pwm<T>(data: &[T]) -> Self
+    where T: Into<f64> + Copy {
+
+        let (b0, b1, _): (f64, f64, f64) = pwm(data);
+        let beta: f64 = (2.0 * b1 - b0) / 2.0_f64.ln();
+
+        Self { mu: b0 - EULER * beta, beta }
+    }
+
+    /// # Fit by maximum likelihood
+    ///
+    /// The scale solves the likelihood equation with Newton's method, the location follows in closed form.
+    /// Requires at least two distinct values, otherwise the parameters are `NaN`.
+    ///
+    /// ```
+    /// # use scilib::stats::extreme::Gumbel;
+    /// let data = [3.2, 4.1, 2.7, 5.9, 3.8, 4.4, 3.1, 6.3, 3.5, 4.0];
+    /// let fit = Gumbel::fit_mle(&data);
+    ///
+    /// assert!((fit.mu - 3.5981460618690742).abs() < 1.0e-10);
+    /// assert!((fit.beta - 0.8175109727877674).abs() < 1.0e-10);
+    /// ```
+    pub fn fit_mle<T>(data: &[T]) -> Self
+    where T: Into<f64> + Copy {
+
+        let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+        let n: f64 = x.len() as f64;
+        let nan: Self = Self { mu: f64::NAN, beta: f64::NAN };
+        if x.len() < 2 {
+            return nan;
+        }
+
+        // Shifting by the minimum keeps the exponentials bounded
+        let low: f64 = x.iter().cloned().fold(f64::INFINITY, f64::min);
+        let mean: f64 = x.iter().sum::<f64>() / n;
+        let var: f64 = x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        if var == 0.0 {
+            return nan;
+        }
+
+        let prec: Precision = Precision::current();             // Precision policy
+        let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+        let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+        // Starting from the moments estimate
+        let mut beta: f64 = var.sqrt() * 6.0_f64.sqrt() / PI;
+
+        // Weighted moments of the sample, for the weights exp(-x / beta)
+        let moments = |beta: f64| {
+            let (mut s0, mut s1, mut s2): (f64, f64, f64) = (0.0, 0.0, 0.0);
+            for v in &x {
+                let w: f64 = (-(v - low) / beta).exp();
+                s0 += w;
+                s1 += w * v;
+                s2 += w * v * v;
+            }
+            (s0, s1 / s0, s2 / s0)
+        };
+
+        for iter in 0..max_iter {
+            let (_, m1, m2): (f64, f64, f64) = moments(beta);
+            let g: f64 = beta - mean + m1;
+            let dg: f64 = 1.0 + (m2 - m1 * m1) / (beta * beta);
+            let step: f64 = (g / dg).clamp(-0.5 * beta, beta);
+            beta -= step;
+
+            diagnostics::step("extreme::gumbel_mle", iter, (step / beta).abs(), step);
+            if (step / beta).abs() < tol {
+                diagnostics::finish("extreme::gumbel_mle", iter + 1, (step / beta).abs(), tol);
+                break;
+            }
+        }
+
+        let (s0, _, _): (f64, f64, f64) = moments(beta);
+        Self { mu: low - beta * (s0 / n).ln(), beta }
+    }
+}
+
+impl Distribution for Gumbel {
+    fn pdf(&self, x: f64) -> f64 {
+        Gev::new(self.mu, self.beta, 0.0).pdf(x)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Gev::new(self.mu, self.beta, 0.0).cdf(x)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        Gev::new(self.mu, self.beta, 0.0).quantile(p)
+    }
+}
+
+/// Implementing required methods
+impl Gev {
+    /// # Creates the distribution
+    ///
+    /// `mu` is the location, `sigma > 0` the scale and `xi` the shape.
+    pub fn new<T, U, V>(mu: T, sigma: U, xi: V) -> Self
+    where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+        Self { mu: mu.into(), sigma: sigma.into(), xi: xi.into() }
+    }
+
+    /// # Log-likelihood of a sample
+    ///
+    /// Returns `-inf` if a value lies outside of the support.
+    pub fn log_likelihood<T>(&self, data: &[T]) -> f64
+    where T: Into<f64> + Copy {
+
+        if self.sigma <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        data.iter().map(|&v| {
+            let z: f64 = (v.into() - self.mu) / self.sigma;
+            if self.xi.abs() < XI_ZERO {
+                return -self.sigma.ln() - z - (-z).exp();
+            }
+            let t: f64 = 1.0 + self.xi * z;
+            if t <= 0.0 {
+                return f64::NEG_INFINITY;
+            }
+            -self.sigma.ln() - (1.0 + 1.0 / self.xi) * t.ln() - t.powf(-1.0 / self.xi)
+        }).sum()
+    }
+
+    /// # Fit with probability weighted moments
+    ///
+    /// Uses the approximation of Hosking, Wallis and Wood (1985) for the shape, valid for `xi` between
+    /// about -0.5 and 0.5. Requires at least three values, otherwise the parameters are `NaN`.
+    ///
+    /// ```
+    /// # use scilib::stats::extreme::Gev;
+    /// let data = [3.2, 4.1, 2.7, 5.9, 3.8, 4.4, 3.1, 6.3, 3.5, 4.0];
+    /// let fit = Gev::fit_pwm(&data);
+    ///
+    /// assert!((fit.mu - 3.4898508694178628).abs() < 1.0e-12);
+    /// assert!((fit.sigma - 0.8340959626125369).abs() < 1.0e-12);
+    /// assert!((fit.xi - 0.13610638501507196).abs() < 1.0e-12);
+    /// ```
+    pub fn fit_pwm<T>(data: &[T]) -> Self
+    where T: Into<f64> + Copy {
+
+        let (b0, b1, b2): (f64, f64, f64) = pwm(data);
+
+        // Hosking's shape k is the opposite of xi
+        let c: f64 = (2.0 * b1 - b0) / (3.0 * b2 - b0) - 2.0_f64.ln() / 3.0_f64.ln();
+        let k: f64 = 7.8590 * c + 2.9554 * c * c;
+
+        if k.abs() < XI_ZERO {
+            let gumbel: Gumbel = Gumbel::fit_pwm(data);
+            return Self { mu: gumbel.mu, sigma: gumbel.beta, xi: 0.0 };
+        }
+
+        let g: f64 = basic::ln_gamma(1.0 + k).exp();
+        let sigma: f64 = (2.0 * b1 - b0) * k / (g * (1.0 - 2.0_f64.powf(-k)));
+
+        Self { mu: b0 + sigma * (g - 1.0) / k, sigma, xi: -k }
+    }
+
+    /// # Fit by maximum likelihood
+    ///
+    /// Maximizes the likelihood with the Nelder–Mead method, starting from the PWM estimates. Requires at
+    /// least three values, otherwise the parameters are `NaN`.
+    pub fn fit_mle<T>(data: &[T]) -> Self
+    where T: Into<f64> + Copy {
+
+        let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+        let start: Self = Self::fit_pwm(&x);
+        if !start.sigma.is_finite() || start.sigma <= 0.0 {
+            return Self { mu: f64::NAN, sigma: f64::NAN, xi: f64::NAN };
+        }
+
+        // The scale is optimized through its logarithm, to remain positive
+        let best: Vec<f64> = nelder_mead(|p| {
+            -Self::new(p[0], p[1].exp(), p[2]).log_likelihood(&x)
+        }, &[start.mu, start.sigma.ln(), start.xi.clamp(-0.45, 0.45)], &[0.1 * start.sigma, 0.1, 0.05]);
+
+        Self { mu: best[0], sigma: best[1].exp(), xi: best[2] }
+    }
+}
+
+impl Distribution for Gev {
+    fn pdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.mu) / self.sigma;
+        if self.xi.abs() < XI_ZERO {
+            return (-z - (-z).exp()).exp() / self.sigma;
+        }
+        let t: f64 = 1.0 + self.xi * z;
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let s: f64 = t.powf(-1.0 / self.xi);
+        s / t * (-s).exp() / self.sigma
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.mu) / self.sigma;
+        if self.xi.abs() < XI_ZERO {
+            return (-(-z).exp()).exp();
+        }
+        let t: f64 = 1.0 + self.xi * z;
+        if t <= 0.0 {
+            return if self.xi > 0.0 { 0.0 } else { 1.0 };
+        }
+        (-t.powf(-1.0 / self.xi)).exp()
+    }
+
+    fn support(&self) -> (f64, f64) {
+        let bound: f64 = self.mu - self.sigma / self.xi;
+        if self.xi.abs() < XI_ZERO {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        } else if self.xi > 0.0 {
+            (bound, f64::INFINITY)
+        } else {
+            (f64::NEG_INFINITY, bound)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        let y: f64 = -p.ln();
+        if self.xi.abs() < XI_ZERO {
+            return self.mu - self.sigma * y.ln();
+        }
+        self.mu + self.sigma * (y.powf(-self.xi) - 1.0) / self.xi
+    }
+}
+
+/// Implementing required methods
+impl GeneralizedPareto {
+    /// # Creates the distribution
+    ///
+    /// `mu` is the threshold, `sigma > 0` the scale and `xi` the shape.
+    pub fn new<T, U, V>(mu: T, sigma: U, xi: V) -> Self
+    where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+        Self { mu: mu.into(), sigma: sigma.into(), xi: xi.into() }
+    }
+
+    /// # Log-likelihood of a sample
+    ///
+    /// Returns `-inf` if a value lies outside of the support.
+    pub fn log_likelihood<T>(&self, data: &[T]) -> f64
+    where T: Into<f64> + Copy {
+
+        if self.sigma <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        data.iter().map(|&v| {
+            let z: f64 = (v.into() - self.mu) / self.sigma;
+            if z < 0.0 {
+                return f64::NEG_INFINITY;
+            }
+            if self.xi.abs() < XI_ZERO {
+                return -self.sigma.ln() - z;
+            }
+            let t: f64 = 1.0 + self.xi * z;
+            if t <= 0.0 {
+                return f64::NEG_INFINITY;
+            }
+            -self.sigma.ln() - (1.0 + 1.0 / self.xi) * t.ln()
+        }).sum()
+    }
+
+    /// # Fit with probability weighted moments
+    ///
+    /// `mu` is the threshold, known in advance; the data are the values over it. Uses the estimators of
+    /// Hosking and Wallis (1987), valid for `xi < 0.5`. Requires at least two values, otherwise the
+    /// parameters are `NaN`.
+    ///
+    /// ```
+    /// # use scilib::stats::extreme::GeneralizedPareto;
+    /// let excesses = [0.3, 1.2, 0.1, 2.5, 0.7, 0.4, 1.9, 0.2, 0.9, 3.8];
+    /// let fit = GeneralizedPareto::fit_pwm(&excesses, 0.0);
+    ///
+    /// assert!((fit.sigma - 0.96).abs() < 1.0e-12);
+    /// assert!((fit.xi - 0.2).abs() < 1.0e-12);
+    /// ```
+    pub fn fit_pwm<T>(data: &[T], mu: f64) -> Self
+    where T: Into<f64> + Copy {
+
+        let excess: Vec<f64> = data.iter().map(|&v| v.into() - mu).collect();
+        let (b0, b1, _): (f64, f64, f64) = pwm(&excess);
+
+        // Moments of the survival weights, a1 = E[x (1 - F)]
+        let a1: f64 = b0 - b1;
+        let k: f64 = b0 / (b0 - 2.0 * a1) - 2.0;
+
+        Self { mu, sigma: 2.0 * b0 * a1 / (b0 - 2.0 * a1), xi: -k }
+    }
+
+    /// # Fit by maximum likelihood
+    ///
+    /// `mu` is the threshold, known in advance; the data are the values over it. Maximizes the likelihood
+    /// with the Nelder–Mead method, starting from the PWM estimates.
+    ///
+    /// ```
+    /// # use scilib::stats::Distribution;
+    /// # use scilib::stats::extreme::GeneralizedPareto;
+    /// let truth = GeneralizedPareto::new(5.0, 1.5, 0.2);
+    /// let data: Vec<f64> = (0..400).map(|i| truth.quantile((i as f64 + 0.5) / 400.0)).collect();
+    /// let fit = GeneralizedPareto::fit_mle(&data, 5.0);
+    ///
+    /// assert!((fit.sigma - 1.5).abs() < 0.02 && (fit.xi - 0.2).abs() < 0.02);
+    /// ```
+    pub fn fit_mle<T>(data: &[T], mu: f64) -> Self
+    where T: Into<f64> + Copy {
+
+        let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+        let start: Self = Self::fit_pwm(&x, mu);
+        if !start.sigma.is_finite() || start.sigma <= 0.0 {
+            return Self { mu, sigma: f64::NAN, xi: f64::NAN };
+        }
+
+        // The PWM estimate may leave some values out of a bounded support
+        let top: f64 = x.iter().cloned().fold(mu, f64::max) - mu;
+        let xi: f64 = start.xi.max(-0.99 * start.sigma / top);
+
+        let best: Vec<f64> = nelder_mead(|p| {
+            -Self::new(mu, p[0].exp(), p[1]).log_likelihood(&x)
+        }, &[start.sigma.ln(), xi], &[0.1, 0.05]);
+
+        Self { mu, sigma: best[0].exp(), xi: best[1] }
+    }
+}
+
+impl Distribution for GeneralizedPareto {
+    fn pdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.mu) / self.sigma;
+        if z < 0.0 {
+            return 0.0;
+        }
+        if self.xi.abs() < XI_ZERO {
+            return (-z).exp() / self.sigma;
+        }
+        let t: f64 = 1.0 + self.xi * z;
+        if t <= 0.0 {
+            return 0.0;
+        }
+        t.powf(-1.0 / self.xi - 1.0) / self.sigma
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.mu) / self.sigma;
+        if z <= 0.0 {
+            return 0.0;
+        }
+        if self.xi.abs() < XI_ZERO {
+            return -(-z).exp_m1();
+        }
+        let t: f64 = 1.0 + self.xi * z;
+        if t <= 0.0 {
+            return 1.0;
+        }
+        -(-t.ln() / self.xi).exp_m1()
+    }
+
+    fn support(&self) -> (f64, f64) {
+        if self.xi < -XI_ZERO {
+            (self.mu, self.mu - self.sigma / self.xi)
+        } else {
+            (self.mu, f64::INFINITY)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        let y: f64 = -(-p).ln_1p();
+        if self.xi.abs() < XI_ZERO {
+            return self.mu + self.sigma * y;
+        }
+        self.mu + self.sigma * (y * self.xi).exp_m1() / self.xi
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Unbiased sample probability weighted moments b0, b1 and b2
+fn pwm<T>(data: &[T]) -> (f64, f64, f64)
+where T: Into<f64> + Copy {
+
+    let mut x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    x.sort_by(f64::total_cmp);
+    let n: f64 = x.len() as f64;
+
+    let (mut b0, mut b1, mut b2): (f64, f64, f64) = (0.0, 0.0, 0.0);
+    for (i, v) in x.iter().enumerate() {
+        let i: f64 = i as f64;
+        b0 += v;
+        b1 += v * i / (n - 1.0);
+        b2 += v * i * (i - 1.0) / ((n - 1.0) * (n - 2.0));
+    }
+
+    (b0 / n, b1 / n, b2 / n)
+}
+
+/// Minimizes a function with the Nelder–Mead simplex method, from a starting point and initial steps
+fn nelder_mead<F>(f: F, start: &[f64], steps: &[f64]) -> Vec<f64>
+where F: Fn(&[f64]) -> f64 {
+
+    let prec: Precision = Precision::current();             // Precision policy
+    let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+    let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+    let dim: usize = start.len();
+    let eval = |p: &[f64]| {
+        let v: f64 = f(p);
+        if v.is_nan() { f64::INFINITY } else { v }
+    };
+
+    // Initial simplex, along the axes
+    let mut simplex: Vec<(Vec<f64>, f64)> = (0..=dim).map(|i| {
+        let mut p: Vec<f64> = start.to_vec();
+        if i > 0 {
+            p[i - 1] += steps[i - 1];
+        }
+        let v: f64 = eval(&p);
+        (p, v)
+    }).collect();
+
+    let mut iter: usize = 0;
+    let mut spread: f64 = f64::INFINITY;
+    while iter < max_iter {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, worst): (f64, f64) = (simplex[0].1, simplex[dim].1);
+        spread = (worst - best).abs() / (best.abs() + worst.abs()).max(f64::MIN_POSITIVE);
+
+        diagnostics::step("extreme::nelder_mead", iter, spread, worst - best);
+        iter += 1;
+        if spread < tol {
+            break;
+        }
+
+        // Centroid of all but the worst point
+        let centroid: Vec<f64> = (0..dim).map(|d| simplex[..dim].iter().map(|s| s.0[d]).sum::<f64>() / dim as f64).collect();
+        let towards = |coef: f64| -> Vec<f64> {
+            centroid.iter().zip(&simplex[dim].0).map(|(c, w)| c + coef * (w - c)).collect()
+        };
+
+        let reflected: Vec<f64> = towards(-1.0);
+        let fr: f64 = eval(&reflected);
+
+        if fr < simplex[0].1 {
+            let expanded: Vec<f64> = towards(-2.0);
+            let fe: f64 = eval(&expanded);
+            simplex[dim] = if fe < fr { (expanded, fe) } else { (reflected, fr) };
+        } else if fr < simplex[dim - 1].1 {
+            simplex[dim] = (reflected, fr);
+        } else {
+            let contracted: Vec<f64> = if fr < simplex[dim].1 { towards(-0.5) } else { towards(0.5) };
+            let fc: f64 = eval(&contracted);
+            if fc < fr.min(simplex[dim].1) {
+                simplex[dim] = (contracted, fc);
+            } else {
+                // Shrinking towards the best point
+                let best: Vec<f64> = simplex[0].0.clone();
+                for s in simplex.iter_mut().skip(1) {
+                    s.0 = s.0.iter().zip(&best).map(|(p, b)| b + 0.5 * (p - b)).collect();
+                    s.1 = eval(&s.0);
+                }
+            }
+        }
+    }
+    diagnostics::finish("extreme::nelder_mead", iter, spread, tol);
+
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    simplex.swap_remove(0).0
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,345 @@
+//!
+//! # Generalized linear models
+//!
+//! Regression of a response whose mean depends on a linear combination of predictors through a link
+//! function, `g(mu) = X beta`, the response following a distribution of the exponential family. The
+//! binomial family with the logit link gives the logistic regression of proportions or binary outcomes,
+//! and the Poisson family with the log link the regression of counts.
+//!
+//! The model is given by its design matrix `X`, one row per observation and one column per coefficient;
+//! `with_intercept` prepends the column of ones of a constant term. The coefficients maximize the
+//! likelihood, found by iteratively reweighted least squares (Fisher scoring): each iteration solves a
+//! weighted least squares problem on a linearized response. The standard errors come from the inverse of
+//! the Fisher information at the solution, and the deviance measures the lack of fit against the
+//! saturated model.
+//!
+//! ```
+//! # use scilib::stats::glm::{ Glm, Family, with_intercept };
+//! let dose: Vec<Vec<f64>> = (0..8).map(|i| vec![i as f64]).collect();
+//! let dead = [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+//!
+//! let model = Glm::fit(Family::Binomial, &with_intercept(&dose), &dead).unwrap();
+//! assert!(model.coefficients[1] > 0.0);
+//! assert!((model.deviance - 9.469238715559895).abs() < 1.0e-9);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+use super::{                    // Using parts of the module
+    normal_cdf,                 // Normal distribution for the p-values
+    gp                          // Cholesky factorization
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relative precision on the deviance
+const PRECISION: f64 = 1.0e-12;
+
+/// Iteration cap of the reweighted least squares
+const MAX_ITERATIONS: usize = 100;
+
+/// Smallest distance of a binomial mean to 0 and 1
+const MU_EPSILON: f64 = 1.0e-15;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Distribution of the response
+///
+/// Each family uses its canonical link.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Family {
+    /// Proportions of successes in `[0, 1]`, with the logit link `ln(mu / (1 - mu))`
+    Binomial,
+    /// Non-negative counts, with the log link `ln(mu)`
+    Poisson
+}
+
+/// # Fitted generalized linear model
+#[derive(Clone, Debug, PartialEq)]
+pub struct Glm {
+    /// Distribution of the response
+    pub family: Family,
+    /// Coefficients of the columns of the design matrix
+    pub coefficients: Vec<f64>,
+    /// Standard errors of the coefficients
+    pub std_errors: Vec<f64>,
+    /// Residual deviance
+    pub deviance: f64,
+    /// Deviance of the model with a constant mean only
+    pub null_deviance: f64,
+    /// Degrees of freedom of the residual deviance
+    pub df_residual: usize,
+    /// Number of iterations of the fit
+    pub iterations: usize
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementing required methods
+impl Family {
+    /// Mean of the response from the linear predictor
+    fn inverse_link(&self, eta: f64) -> f64 {
+        match self {
+            Self::Binomial => (1.0 / (1.0 + (-eta).exp())).clamp(MU_EPSILON, 1.0 - MU_EPSILON),
+            Self::Poisson => eta.exp()
+        }
+    }
+
+    /// Variance of the response for a given mean, also the derivative of the mean for the canonical links
+    fn variance(&self, mu: f64) -> f64 {
+        match self {
+            Self::Binomial => mu * (1.0 - mu),
+            Self::Poisson => mu
+        }
+    }
+
+    /// Contribution of an observation to the deviance
+    fn unit_deviance(&self, y: f64, mu: f64) -> f64 {
+        let xlogy = |a: f64, b: f64| if a == 0.0 { 0.0 } else { a * (a / b).ln() };
+        match self {
+            Self::Binomial => 2.0 * (xlogy(y, mu) + xlogy(1.0 - y, 1.0 - mu)),
+            Self::Poisson => 2.0 * (xlogy(y, mu) - (y - mu))
+        }
+    }
+
+    /// Checks that a response is in the domain of the family
+    fn is_valid(&self, y: f64) -> bool {
+        match self {
+            Self::Binomial => (0.0..=1.0).contains(&y),
+            Self::Poisson => y >= 0.0 && y.is_finite()
+        }
+    }
+}
+
+/// Implementing required methods
+impl Glm {
+    /// # Fits a model
+    ///
+    /// `x` is the design matrix, one row per observation, and `y` the responses: proportions in `[0, 1]`
+    /// for the binomial family, counts for the Poisson family.
+    ///
+    /// Returns a `Domain` error if the sizes do not match, if a response is outside of the domain of the
+    /// family or if the columns of the design matrix are linearly dependent, and a `Convergence` error if
+    /// the iterations do not settle; this happens when the coefficients diverge, for instance with
+    /// perfectly separated binary data.
+    ///
+    /// ```
+    /// # use scilib::stats::glm::{ Glm, Family, with_intercept };
+    /// let x: Vec<Vec<f64>> = (0..6).map(|i| vec![i as f64]).collect();
+    /// let counts = [2.0, 3.0, 6.0, 7.0, 8.0, 9.0];
+    ///
+    /// let model = Glm::fit(Family::Poisson, &with_intercept(&x), &counts).unwrap();
+    /// assert!((model.coefficients[0] - 1.0168310902795112).abs() < 1.0e-9);
+    /// assert!((model.coefficients[1] - 0.2600473888145414).abs() < 1.0e-9);
+    /// assert!((model.std_errors[1] - 0.1051470824614665).abs() < 1.0e-9);
+    ///
+    /// // Separated data
+    /// assert!(Glm::fit(Family::Binomial, &with_intercept(&x), &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]).is_err());
+    ///
+    /// // Exact fit of large counts, whose deviance is rounding noise
+    /// let x: Vec<Vec<f64>> = (0..8).map(|i| vec![i as f64]).collect();
+    /// let counts: Vec<f64> = (0..8).map(|i| (3.0 * i as f64).exp().round()).collect();
+    /// let model = Glm::fit(Family::Poisson, &with_intercept(&x), &counts).unwrap();
+    /// assert!((model.coefficients[1] - 3.0000000415123355).abs() < 1.0e-12);
+    /// ```
+    pub fn fit(family: Family, x: &[Vec<f64>], y: &[f64]) -> Result<Self> {
+        Self::fit_weighted(family, x, y, &vec![1.0; y.len()])
+    }
+
+    /// # Fits a model with prior weights
+    ///
+    /// Same as `fit`, each observation being weighted by `weights`. For the binomial family, the response
+    /// being the proportion of successes, the weights are the numbers of trials.
+    ///
+    /// ```
+    /// # use scilib::stats::glm::{ Glm, Family, with_intercept };
+    /// let dose = vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0]];
+    /// let trials = [20.0, 20.0, 20.0, 20.0];
+    /// let dead = [2.0 / 20.0, 6.0 / 20.0, 11.0 / 20.0, 17.0 / 20.0];
+    ///
+    /// let model = Glm::fit_weighted(Family::Binomial, &with_intercept(&dose), &dead, &trials).unwrap();
+    /// assert!((model.coefficients[1] - 1.2682298655060804).abs() < 1.0e-9);
+    /// assert!((model.deviance - 0.16470307013290283).abs() < 1.0e-9);
+    /// assert_eq!(model.df_residual, 2);
+    /// ```
+    pub fn fit_weighted(family: Family, x: &[Vec<f64>], y: &[f64], weights: &[f64]) -> Result<Self> {
+
+        let n: usize = y.len();
+        let p: usize = x.first().map_or(0, |r| r.len());
+        if x.len() != n || weights.len() != n || x.iter().any(|r| r.len() != p) {
+            return Err(Error::Domain("the design matrix, responses and weights must have matching sizes"));
+        }
+        if n < p || p == 0 {
+            return Err(Error::Domain("more observations than coefficients are required"));
+        }
+        if y.iter().any(|&v| !family.is_valid(v)) || weights.iter().any(|&w| w < 0.0 || !w.is_finite()) {
+            return Err(Error::Domain("invalid response or weight"));
+        }
+
+        let deviance = |mu: &[f64]| -> f64 {
+            (0..n).map(|i| weights[i] * family.unit_deviance(y[i], mu[i])).sum()
+        };
+
+        // Starting from the data themselves, moved away from the boundaries
+        let mut mu: Vec<f64> = y.iter().zip(weights).map(|(&v, &w)| match family {
+            Family::Binomial => (w * v + 0.5) / (w + 1.0),
+            Family::Poisson => v + 0.1
+        }).collect();
+        let mut eta: Vec<f64> = mu.iter().map(|&m| match family {
+            Family::Binomial => (m / (1.0 - m)).ln(),
+            Family::Poisson => m.ln()
+        }).collect();
+        let mut dev: f64 = deviance(&mu);
+
+        let prec: Precision = Precision::current();             // Precision policy
+        let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+        let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+        let mut beta: Vec<f64> = vec![0.0; p];
+        let mut converged: Option<usize> = None;
+
+        for iter in 0..max_iter {
+            // Working weights and response of the linearized problem
+            let w: Vec<f64> = (0..n).map(|i| weights[i] * family.variance(mu[i])).collect();
+            let z: Vec<f64> = (0..n).map(|i| eta[i] + (y[i] - mu[i]) / family.variance(mu[i])).collect();
+
+            let chol: Vec<Vec<f64>> = information(x, &w).ok_or(Error::Domain("the design matrix is rank deficient"))?;
+            let rhs: Vec<f64> = (0..p).map(|c| (0..n).map(|i| x[i][c] * w[i] * z[i]).sum()).collect();
+            beta = gp::solve_upper(&chol, &gp::solve_lower(&chol, &rhs));
+
+            eta = x.iter().map(|r| dot(r, &beta)).collect();
+            mu = eta.iter().map(|&e| family.inverse_link(e)).collect();
+
+            let next: f64 = deviance(&mu);
+            let change: f64 = (next - dev).abs();
+            let residual: f64 = change / (next.abs() + 0.1);
+            diagnostics::step("glm::irls", iter, residual, change);
+            dev = next;
+
+            // Exact fits of large counts leave the deviance at its rounding error, below which the tolerance
+            // can't go
+            let rounding: f64 = f64::EPSILON * (0..n)
+                .map(|i| weights[i] * (y[i].abs() + mu[i]) * (1.0 + eta[i].abs()))
+                .sum::<f64>();
+            let tol: f64 = tol.max(2.0 * rounding / (next.abs() + 0.1));
+
+            if residual < tol {
+                diagnostics::finish("glm::irls", iter + 1, residual, tol);
+                converged = Some(iter + 1);
+                break;
+            }
+        }
+
+        let iterations: usize = converged.ok_or(Error::Convergence("the reweighted least squares did not converge"))?;
+
+        // Coefficients diverging to infinity also settle the deviance, close to zero
+        if beta.iter().any(|b| !b.is_finite()) || mu.iter().any(|&m| family == Family::Binomial && (m <= MU_EPSILON || m >= 1.0 - MU_EPSILON)) {
+            return Err(Error::Convergence("the coefficients diverge, the data are separated"));
+        }
+
+        // Inverse of the Fisher information, column by column
+        let w: Vec<f64> = (0..n).map(|i| weights[i] * family.variance(mu[i])).collect();
+        let chol: Vec<Vec<f64>> = information(x, &w).ok_or(Error::Domain("the design matrix is rank deficient"))?;
+        let std_errors: Vec<f64> = (0..p).map(|c| {
+            let mut unit: Vec<f64> = vec![0.0; p];
+            unit[c] = 1.0;
+            gp::solve_upper(&chol, &gp::solve_lower(&chol, &unit))[c].sqrt()
+        }).collect();
+
+        let total: f64 = weights.iter().sum();
+        let mean: f64 = y.iter().zip(weights).map(|(v, w)| v * w).sum::<f64>() / total;
+        let null_deviance: f64 = deviance(&vec![mean; n]);
+
+        Ok(Self { family, coefficients: beta, std_errors, deviance: dev, null_deviance, df_residual: n - p, iterations })
+    }
+
+    /// # Linear predictor
+    ///
+    /// Value of `X beta` for a row `x` of a design matrix.
+    pub fn linear_predictor(&self, x: &[f64]) -> f64 {
+        dot(x, &self.coefficients)
+    }
+
+    /// # Predicted mean
+    ///
+    /// Mean of the response for a row `x` of a design matrix: a probability for the binomial family, a
+    /// count for the Poisson family.
+    ///
+    /// ```
+    /// # use scilib::stats::glm::{ Glm, Family };
+    /// let x = vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 1.0]];
+    /// let model = Glm::fit(Family::Poisson, &x, &[3.0, 5.0, 10.0, 12.0]).unwrap();
+    ///
+    /// // With a single binary predictor, the fit reproduces the group means
+    /// assert!((model.predict(&[1.0, 0.0]) - 4.0).abs() < 1.0e-10);
+    /// assert!((model.predict(&[1.0, 1.0]) - 11.0).abs() < 1.0e-10);
+    /// ```
+    pub fn predict(&self, x: &[f64]) -> f64 {
+        self.family.inverse_link(self.linear_predictor(x))
+    }
+
+    /// # Wald statistics
+    ///
+    /// Ratios of the coefficients to their standard errors.
+    pub fn z_values(&self) -> Vec<f64> {
+        self.coefficients.iter().zip(&self.std_errors).map(|(b, s)| b / s).collect()
+    }
+
+    /// # p-values of the coefficients
+    ///
+    /// Two-sided p-values of the Wald tests of null coefficients, from the asymptotic normal distribution of
+    /// the statistics.
+    ///
+    /// ```
+    /// # use scilib::stats::glm::{ Glm, Family, with_intercept };
+    /// let x: Vec<Vec<f64>> = (0..6).map(|i| vec![i as f64]).collect();
+    /// let model = Glm::fit(Family::Poisson, &with_intercept(&x), &[2.0, 3.0, 6.0, 7.0, 8.0, 9.0]).unwrap();
+    ///
+    /// assert!((model.p_values()[1] - 0.013391764580327448).abs() < 1.0e-9);
+    /// ```
+    pub fn p_values(&self) -> Vec<f64> {
+        self.z_values().iter().map(|z| 2.0 * normal_cdf(-z.abs())).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Design matrix with an intercept
+///
+/// Prepends a column of ones to the rows of predictors `x`, for the constant term of a model.
+///
+/// ```
+/// # use scilib::stats::glm::with_intercept;
+/// assert_eq!(with_intercept(&[vec![2.0, 3.0], vec![4.0, 5.0]]), vec![vec![1.0, 2.0, 3.0], vec![1.0, 4.0, 5.0]]);
+/// ```
+pub fn with_intercept(x: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    x.iter().map(|r| std::iter::once(1.0).chain(r.iter().copied()).collect()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Cholesky factor of the weighted information matrix `X^T W X`
+fn information(x: &[Vec<f64>], w: &[f64]) -> Option<Vec<Vec<f64>>> {
+    let p: usize = x[0].len();
+    let mat: Vec<Vec<f64>> = (0..p).map(|r| (0..p).map(|c| {
+        x.iter().zip(w).map(|(row, wi)| row[r] * row[c] * wi).sum()
+    }).collect()).collect();
+    gp::cholesky(mat)
+}
+
+/// Dot product of two vectors
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(u, v)| u * v).sum()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
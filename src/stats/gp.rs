@@ -0,0 +1,238 @@
+//!
+//! # Gaussian process regression
+//!
+//! Non-parametric regression, where the unknown function is modelled as a Gaussian process of given
+//! covariance kernel, observed with Gaussian noise. Conditioning on the data gives the posterior mean,
+//! an interpolation of the observations, along with its variance, the uncertainty of the prediction.
+//!
+//! The posterior is computed from the Cholesky factorization of the covariance matrix of the data, which
+//! costs `O(n^3)` once; each prediction then costs `O(n^2)`. The log marginal likelihood measures how well
+//! the kernel explains the data, and is the objective to maximize when tuning the hyperparameters.
+//!
+//! ```
+//! # use scilib::stats::gp::{ GaussianProcess, Kernel };
+//! let x: Vec<Vec<f64>> = (0..12).map(|i| vec![i as f64 * 0.4]).collect();
+//! let y: Vec<f64> = x.iter().map(|p| p[0].sin()).collect();
+//!
+//! let gp = GaussianProcess::fit(Kernel::Rbf { variance: 1.0, length: 1.0 }, 1.0e-6, &x, &y).unwrap();
+//! let (mean, var) = gp.predict(&[2.0]);
+//!
+//! assert!((mean - 2.0_f64.sin()).abs() < 1.0e-3);
+//! assert!(var < 1.0e-5);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::TAU;      // 2 * Pi
+
+use crate::error::{             // Error handling
+    Error,                      // The error type
+    Result                      // The associated result
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Covariance kernel
+///
+/// Stationary kernels, functions of the Euclidean distance `r` between two points. `variance` is the prior
+/// variance of the process, and `length` the correlation length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kernel {
+    /// Squared exponential `v exp(-r^2 / 2l^2)`, for infinitely smooth functions
+    Rbf {
+        variance: f64,
+        length: f64
+    },
+    /// Matérn kernel of order 3/2, for once differentiable functions
+    Matern32 {
+        variance: f64,
+        length: f64
+    },
+    /// Matérn kernel of order 5/2, for twice differentiable functions
+    Matern52 {
+        variance: f64,
+        length: f64
+    }
+}
+
+/// # Gaussian process
+///
+/// Posterior of a process conditioned on observations, created with `fit`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaussianProcess {
+    /// Covariance kernel
+    pub kernel: Kernel,
+    /// Variance of the observation noise
+    pub noise: f64,
+    /// Observed points
+    points: Vec<Vec<f64>>,
+    /// Lower Cholesky factor of the covariance of the observations
+    chol: Vec<Vec<f64>>,
+    /// Weights of the kernel in the posterior mean
+    alpha: Vec<f64>,
+    /// Log marginal likelihood of the observations
+    lml: f64
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementing required methods
+impl Kernel {
+    /// # Evaluates the kernel
+    ///
+    /// Covariance between the points `a` and `b`, of identical dimension.
+    ///
+    /// ```
+    /// # use scilib::stats::gp::Kernel;
+    /// let k = Kernel::Matern32 { variance: 2.0, length: 0.5 };
+    /// let s = 3.0_f64.sqrt() * 2.0;
+    ///
+    /// assert!((k.eval(&[0.0, 0.0], &[0.6, 0.8]) - 2.0 * (1.0 + s) * (-s).exp()).abs() < 1.0e-15);
+    /// assert_eq!(k.eval(&[1.0], &[1.0]), 2.0);
+    /// ```
+    pub fn eval(&self, a: &[f64], b: &[f64]) -> f64 {
+
+        let r2: f64 = a.iter().zip(b).map(|(u, v)| (u - v).powi(2)).sum();
+
+        match *self {
+            Self::Rbf { variance, length } => variance * (-r2 / (2.0 * length * length)).exp(),
+            Self::Matern32 { variance, length } => {
+                let s: f64 = 3.0_f64.sqrt() * r2.sqrt() / length;
+                variance * (1.0 + s) * (-s).exp()
+            },
+            Self::Matern52 { variance, length } => {
+                let s: f64 = 5.0_f64.sqrt() * r2.sqrt() / length;
+                variance * (1.0 + s + s * s / 3.0) * (-s).exp()
+            }
+        }
+    }
+}
+
+/// Implementing required methods
+impl GaussianProcess {
+    /// # Conditions the process on observations
+    ///
+    /// `kernel` is the prior covariance, `noise` the variance of the observation noise (a small value
+    /// regularizes noiseless data), and the values `y` are observed at the `points`.
+    ///
+    /// Returns a `Domain` error if the sizes do not match, or if the covariance matrix is not positive
+    /// definite (duplicated points without noise, for instance).
+    ///
+    /// ```
+    /// # use scilib::stats::gp::{ GaussianProcess, Kernel };
+    /// let kernel = Kernel::Rbf { variance: 1.0, length: 1.0 };
+    /// let points = vec![vec![0.0], vec![0.0]];
+    ///
+    /// assert!(GaussianProcess::fit(kernel, 0.0, &points, &[1.0, 1.0]).is_err());
+    /// assert!(GaussianProcess::fit(kernel, 0.1, &points, &[1.0, 1.0]).is_ok());
+    /// ```
+    pub fn fit(kernel: Kernel, noise: f64, points: &[Vec<f64>], y: &[f64]) -> Result<Self> {
+
+        let n: usize = points.len();
+        if y.len() != n {
+            return Err(Error::Domain("a value is required for each point"));
+        }
+
+        let cov: Vec<Vec<f64>> = (0..n).map(|r| (0..n).map(|c| {
+            kernel.eval(&points[r], &points[c]) + if r == c { noise } else { 0.0 }
+        }).collect()).collect();
+
+        let chol: Vec<Vec<f64>> = cholesky(cov).ok_or(Error::Domain("the covariance matrix is not positive definite"))?;
+
+        // alpha = K^-1 y, from the two triangular systems
+        let alpha: Vec<f64> = solve_upper(&chol, &solve_lower(&chol, y));
+
+        let data_fit: f64 = y.iter().zip(&alpha).map(|(a, b)| a * b).sum::<f64>();
+        let log_det: f64 = 2.0 * (0..n).map(|i| chol[i][i].ln()).sum::<f64>();
+        let lml: f64 = -0.5 * (data_fit + log_det + n as f64 * TAU.ln());
+
+        Ok(Self { kernel, noise, points: points.to_vec(), chol, alpha, lml })
+    }
+
+    /// # Posterior prediction
+    ///
+    /// Returns the mean and variance of the process at the point `x`, without the observation noise.
+    pub fn predict(&self, x: &[f64]) -> (f64, f64) {
+
+        let ks: Vec<f64> = self.points.iter().map(|p| self.kernel.eval(p, x)).collect();
+        let mean: f64 = ks.iter().zip(&self.alpha).map(|(a, b)| a * b).sum();
+
+        let v: Vec<f64> = solve_lower(&self.chol, &ks);
+        let var: f64 = self.kernel.eval(x, x) - v.iter().map(|e| e * e).sum::<f64>();
+
+        (mean, var.max(0.0))
+    }
+
+    /// # Log marginal likelihood
+    ///
+    /// Logarithm of the probability density of the observations under the prior, integrated over the
+    /// function values. Its maximum gives the most plausible hyperparameters.
+    ///
+    /// ```
+    /// # use scilib::stats::gp::{ GaussianProcess, Kernel };
+    /// let x: Vec<Vec<f64>> = (0..10).map(|i| vec![i as f64 * 0.5]).collect();
+    /// let y: Vec<f64> = x.iter().map(|p| (2.0 * p[0]).cos()).collect();
+    ///
+    /// let lml = |length: f64| {
+    ///     let kernel = Kernel::Matern52 { variance: 1.0, length };
+    ///     GaussianProcess::fit(kernel, 1.0e-4, &x, &y).unwrap().log_marginal_likelihood()
+    /// };
+    ///
+    /// // A correlation length adapted to the data is preferred
+    /// assert!(lml(0.8) > lml(0.1) && lml(0.8) > lml(5.0));
+    /// assert!((lml(0.8) - -7.199323542293025).abs() < 1.0e-10);
+    /// ```
+    pub fn log_marginal_likelihood(&self) -> f64 {
+        self.lml
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Lower Cholesky factor of a symmetric matrix, `None` if it is not positive definite
+pub(crate) fn cholesky(mut mat: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+
+    let n: usize = mat.len();
+
+    for c in 0..n {
+        let diag: f64 = mat[c][c] - (0..c).map(|k| mat[c][k] * mat[c][k]).sum::<f64>();
+        if diag <= 0.0 || !diag.is_finite() {
+            return None;
+        }
+        mat[c][c] = diag.sqrt();
+
+        for r in (c + 1)..n {
+            let s: f64 = mat[r][c] - (0..c).map(|k| mat[r][k] * mat[c][k]).sum::<f64>();
+            mat[r][c] = s / mat[c][c];
+        }
+        for v in mat[c].iter_mut().skip(c + 1) {
+            *v = 0.0;
+        }
+    }
+
+    Some(mat)
+}
+
+/// Solves `L x = b` for a lower triangular `L`
+pub(crate) fn solve_lower(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let mut x: Vec<f64> = Vec::with_capacity(b.len());
+    for (r, row) in l.iter().enumerate() {
+        let s: f64 = (0..r).fold(b[r], |acc, c| acc - row[c] * x[c]);
+        x.push(s / row[r]);
+    }
+    x
+}
+
+/// Solves `L^T x = b` for a lower triangular `L`
+pub(crate) fn solve_upper(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = b.len();
+    let mut x: Vec<f64> = vec![0.0; n];
+    for r in (0..n).rev() {
+        let s: f64 = ((r + 1)..n).fold(b[r], |acc, c| acc - l[c][r] * x[c]);
+        x[r] = s / l[r][r];
+    }
+    x
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,219 @@
+//!
+//! # Statistics
+//!
+//! Probability distributions and statistical tools, built on the special functions of the math module.
+//!
+//! Continuous distributions implement the `Distribution` trait, which provides the density, the cumulative
+//! distribution and the quantile function. The quantile defaults to a numerical inversion of the cumulative
+//! distribution, and is overridden when a closed form or a better method exists.
+//!
+//! ```
+//! # use scilib::stats::Distribution;
+//! # use scilib::stats::noncentral::NoncentralChiSquared;
+//! let dist = NoncentralChiSquared::new(3.0, 2.0);
+//! let x = dist.quantile(0.9);
+//!
+//! assert!((dist.cdf(x) - 0.9).abs() < 1.0e-13);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::math::basic;         // Incomplete gamma function
+
+use crate::math::summation::CompensatedSum;     // Compensated sums of the series
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod binned;
+
+pub mod circular;
+
+pub mod contingency;
+
+pub mod extreme;
+
+pub mod glm;
+
+pub mod gp;
+
+pub mod noncentral;
+
+pub mod robust;
+
+pub mod select;
+
+pub mod stability;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relative precision of the numerical quantiles
+const PRECISION: f64 = 1.0e-15;
+
+/// Iteration cap of the numerical quantiles
+const MAX_ITERATIONS: usize = 2_000;
+
+/// Term cap of the Poisson mixtures, to which a multiple of the standard deviation is added
+const MIXTURE_TERMS: usize = 1_000;
+
+/// Width of the Poisson mixtures, in standard deviations
+const MIXTURE_WIDTH: f64 = 40.0;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Continuous distribution
+///
+/// Common interface of the continuous probability distributions.
+pub trait Distribution {
+    /// Probability density at `x`
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Cumulative distribution at `x`, the probability of a value lower or equal to `x`
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Interval outside of which the density is null, infinite by default
+    fn support(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// # Quantile function
+    ///
+    /// Value `x` such that `cdf(x) = p`, for `p` in `[0, 1]`; `NaN` outside of this range.
+    ///
+    /// The default implementation brackets the solution and refines it by bisection, which only relies
+    /// on the monotony of the cumulative distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), p, self.support())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Numerical inversion of a cumulative distribution on its support
+pub(crate) fn invert_cdf<F>(cdf: F, p: f64, support: (f64, f64)) -> f64
+where F: Fn(f64) -> f64 {
+
+    if !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return support.0;
+    }
+    if p == 1.0 {
+        return support.1;
+    }
+
+    // Finite starting point, inside the support
+    let start: f64 = match (support.0.is_finite(), support.1.is_finite()) {
+        (true, true) => 0.5 * (support.0 + support.1),
+        (true, false) => support.0 + 1.0,
+        (false, true) => support.1 - 1.0,
+        (false, false) => 0.0
+    };
+
+    // Bracketing, moving geometrically towards the infinite bounds
+    let (mut lo, mut hi): (f64, f64) = if cdf(start) < p { (start, support.1) } else { (support.0, start) };
+    let mut width: f64 = 1.0;
+    while !hi.is_finite() {
+        let trial: f64 = lo + width;
+        if cdf(trial) >= p { hi = trial } else { lo = trial }
+        width *= 2.0;
+    }
+    width = 1.0;
+    while !lo.is_finite() {
+        let trial: f64 = hi - width;
+        if cdf(trial) < p { lo = trial } else { hi = trial }
+        width *= 2.0;
+    }
+
+    let prec: Precision = Precision::current();             // Precision policy
+    let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+    let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+    let mut iter: usize = 0;
+    while iter < max_iter {
+        let mid: f64 = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if cdf(mid) < p { lo = mid } else { hi = mid }
+
+        let residual: f64 = (hi - lo) / (lo.abs() + hi.abs()).max(f64::MIN_POSITIVE);
+        diagnostics::step("stats::quantile", iter, residual, hi - lo);
+        iter += 1;
+        if residual < tol {
+            break;
+        }
+    }
+    diagnostics::finish("stats::quantile", iter, (hi - lo) / (lo.abs() + hi.abs()).max(f64::MIN_POSITIVE), tol);
+
+    0.5 * (lo + hi)
+}
+
+/// Cumulative distribution of the standard normal distribution, accurate in both tails
+pub(crate) fn normal_cdf(z: f64) -> f64 {
+    let half: f64 = 0.5 * basic::gammaincc(0.5, 0.5 * z * z);
+    if z < 0.0 { half } else if z == 0.0 { 0.5 } else { 1.0 - half }
+}
+
+/// Sums `weight(j) term(j)` over the Poisson weights of mean `mu`, from the mode outwards
+///
+/// Returns NaN for a non-finite mean or when a term is not finite.
+pub(crate) fn poisson_mixture<F>(mu: f64, term: F) -> f64
+where F: Fn(usize) -> f64 {
+
+    if !mu.is_finite() || mu < 0.0 {
+        return f64::NAN;
+    }
+    if mu == 0.0 {
+        return term(0);
+    }
+
+    let prec: Precision = Precision::current();
+    let tol: f64 = prec.tolerance(f64::EPSILON);
+    let max_terms: usize = prec.iterations(MIXTURE_TERMS) + (MIXTURE_WIDTH * mu.sqrt()) as usize;
+    let weight = |j: usize| (j as f64 * mu.ln() - mu - basic::ln_gamma(j as f64 + 1.0)).exp();
+
+    let mode: usize = mu.floor() as usize;
+    let mut res: CompensatedSum = CompensatedSum::new();
+    let mut terms: usize = 0;
+    let mut residual: f64 = f64::INFINITY;
+
+    // Upwards, the weights eventually decay faster than geometrically
+    for j in mode..mode.saturating_add(max_terms) {
+        let w: f64 = weight(j);
+        let v: f64 = w * term(j);
+        if !v.is_finite() {
+            return f64::NAN;
+        }
+        res.add(v);
+        terms += 1;
+        residual = w.max(v.abs()) / res.value().abs().max(f64::MIN_POSITIVE);
+        if j > mode && (w < tol * res.value().abs() || w == 0.0) && v.abs() <= tol * res.value().abs() {
+            residual = 0.0;
+            break;
+        }
+    }
+    diagnostics::finish("stats::poisson_mixture", terms, residual, tol);
+
+    // Downwards, to zero at most
+    for j in (0..mode).rev() {
+        let w: f64 = weight(j);
+        let v: f64 = w * term(j);
+        if !v.is_finite() {
+            return f64::NAN;
+        }
+        res.add(v);
+        if (w < tol * res.value().abs() || w == 0.0) && v.abs() <= tol * res.value().abs() {
+            break;
+        }
+    }
+
+    res.value()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,272 @@
+//!
+//! # Robust statistics
+//!
+//! Location and scale estimators resisting outliers. A single wrong value can move the mean and standard
+//! deviation arbitrarily far, while the median and the median absolute deviation tolerate up to half of
+//! the sample being contaminated. The biweight and Huber estimators sit in between: nearly as efficient
+//! as the mean for clean normal data, but with a bounded influence of the outliers.
+//!
+//! Sigma clipping removes the values too far from the center iteratively, and returns the mask of the
+//! rejected values so that they can be excluded from any subsequent computation.
+//!
+//! ```
+//! # use scilib::stats::robust::{ median, mad_std, sigma_clip };
+//! let data = [9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 55.0, 9.7, 10.3];
+//!
+//! let mean: f64 = data.iter().sum::<f64>() / data.len() as f64;
+//! assert!(mean > 14.0);
+//! assert_eq!(median(&data), 10.0);
+//! assert!((mad_std(&data) - 0.29652044370111935).abs() < 1.0e-14);
+//!
+//! let mask = sigma_clip(&data, 3.0, 5);
+//! assert_eq!(mask, vec![false, false, false, false, false, false, true, false, false]);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::diagnostics;         // Iteration reports
+
+use crate::precision::Precision;    // Precision policy
+
+use crate::math::summation;     // Accurate sums
+
+use super::select::{        // Order statistics
+    NanPolicy,              // Handling of the NaN values
+    kth_smallest            // Selection of a rank
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ratio between the standard deviation and the MAD of a normal distribution
+const MAD_TO_STD: f64 = 1.482_602_218_505_602;
+
+/// Precision of the Huber estimator
+const PRECISION: f64 = 1.0e-12;
+
+/// Iteration cap of the Huber estimator
+const MAX_ITERATIONS: usize = 1_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Median
+///
+/// Middle value of the sample, the mean of the two middle values for even sizes; `NaN` if empty.
+///
+/// ```
+/// # use scilib::stats::robust::median;
+/// assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+/// assert_eq!(median(&[4, 1, 3, 2]), 2.5);
+/// ```
+pub fn median<T>(data: &[T]) -> f64
+where T: Into<f64> + Copy {
+    let mut x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    select_median(&mut x)
+}
+
+/// # Median absolute deviation
+///
+/// Median of the absolute deviations from the median, `NaN` if empty. See `mad_std` for an estimate of
+/// the standard deviation.
+///
+/// ```
+/// # use scilib::stats::robust::mad;
+/// assert_eq!(mad(&[1.0, 2.0, 3.0, 4.0, 100.0]), 1.0);
+/// ```
+pub fn mad<T>(data: &[T]) -> f64
+where T: Into<f64> + Copy {
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    deviation(&x, median(&x))
+}
+
+/// # Standard deviation from the MAD
+///
+/// The median absolute deviation scaled by `1.4826`, a consistent estimator of the standard deviation of
+/// normal data.
+pub fn mad_std<T>(data: &[T]) -> f64
+where T: Into<f64> + Copy {
+    MAD_TO_STD * mad(data)
+}
+
+/// # Biweight location
+///
+/// Tukey's biweight estimate of the center, a weighted mean around the median where the weights
+/// `(1 - u^2)^2` vanish for the values further than `c` MADs (`u = (x - M) / (c MAD)`). The usual tuning
+/// constant is `c = 6`. Returns the median if the MAD is null.
+///
+/// ```
+/// # use scilib::stats::robust::biweight_location;
+/// let data = [9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 55.0, 9.7, 10.3];
+/// assert!((biweight_location(&data, 6.0) - 10.0).abs() < 1.0e-14);
+/// ```
+pub fn biweight_location<T>(data: &[T], c: f64) -> f64
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let med: f64 = median(&x);
+    let mad: f64 = deviation(&x, med);
+    if mad == 0.0 || mad.is_nan() {
+        return med;
+    }
+
+    let (mut num, mut den): (f64, f64) = (0.0, 0.0);
+    for v in &x {
+        let u: f64 = (v - med) / (c * mad);
+        if u.abs() < 1.0 {
+            let w: f64 = (1.0 - u * u).powi(2);
+            num += (v - med) * w;
+            den += w;
+        }
+    }
+
+    med + num / den
+}
+
+/// # Biweight scale
+///
+/// Square root of Tukey's biweight midvariance, a robust estimate of the standard deviation. The usual
+/// tuning constant is `c = 9`. Returns zero if the MAD is null.
+///
+/// ```
+/// # use scilib::stats::robust::biweight_scale;
+/// let data = [9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 55.0, 9.7, 10.3];
+/// assert!((biweight_scale(&data, 9.0) - 0.2029084247434554).abs() < 1.0e-14);
+/// ```
+pub fn biweight_scale<T>(data: &[T], c: f64) -> f64
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let med: f64 = median(&x);
+    let mad: f64 = deviation(&x, med);
+    if mad == 0.0 || mad.is_nan() {
+        return if x.is_empty() { f64::NAN } else { 0.0 };
+    }
+
+    let (mut num, mut den): (f64, f64) = (0.0, 0.0);
+    for v in &x {
+        let u: f64 = (v - med) / (c * mad);
+        if u.abs() < 1.0 {
+            let u2: f64 = u * u;
+            num += (v - med).powi(2) * (1.0 - u2).powi(4);
+            den += (1.0 - u2) * (1.0 - 5.0 * u2);
+        }
+    }
+
+    (x.len() as f64 * num).sqrt() / den.abs()
+}
+
+/// # Huber location
+///
+/// M-estimator of the center minimizing the Huber loss, quadratic within `k` standard deviations of the
+/// center and linear beyond; the scale is fixed to `mad_std`. The usual tuning constant `k = 1.345` gives
+/// 95% efficiency for normal data. Computed by iteratively reweighted averages.
+///
+/// ```
+/// # use scilib::stats::robust::huber_location;
+/// let data = [9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 55.0, 9.7, 10.3];
+/// assert!((huber_location(&data, 1.345) - 10.049852499597252).abs() < 1.0e-12);
+/// ```
+pub fn huber_location<T>(data: &[T], k: f64) -> f64
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let mut mu: f64 = median(&x);
+    let scale: f64 = MAD_TO_STD * deviation(&x, mu);
+    if scale == 0.0 || scale.is_nan() {
+        return mu;
+    }
+
+    let prec: Precision = Precision::current();             // Precision policy
+    let tol: f64 = prec.tolerance(PRECISION);               // Convergence tolerance
+    let max_iter: usize = prec.iterations(MAX_ITERATIONS);  // Iteration cap
+
+    for iter in 0..max_iter {
+        let (mut num, mut den): (f64, f64) = (0.0, 0.0);
+        for v in &x {
+            let r: f64 = ((v - mu) / scale).abs();
+            let w: f64 = if r <= k { 1.0 } else { k / r };
+            num += w * v;
+            den += w;
+        }
+
+        let next: f64 = num / den;
+        let residual: f64 = (next - mu).abs() / scale;
+        mu = next;
+
+        diagnostics::step("robust::huber", iter, residual, residual * scale);
+        if residual < tol {
+            diagnostics::finish("robust::huber", iter + 1, residual, tol);
+            break;
+        }
+    }
+
+    mu
+}
+
+/// # Sigma clipping
+///
+/// Iteratively rejects the values further than `sigma` standard deviations from the median of the kept
+/// values, until no value changes or `max_iters` iterations are done.
+///
+/// Returns the mask of the rejected values, `true` for the outliers.
+///
+/// ```
+/// # use scilib::stats::robust::sigma_clip;
+/// let data = [1.0, 1.2, 0.9, 1.1, 1.0, 0.8, 1.1, 1.0, 4.0, 1.3, 0.9, 1.0, 2.0];
+///
+/// // The second outlier only appears once the first is removed
+/// assert_eq!(sigma_clip(&data, 2.5, 1).iter().filter(|&&m| m).count(), 1);
+/// assert_eq!(sigma_clip(&data, 2.5, 10).iter().filter(|&&m| m).count(), 2);
+/// ```
+pub fn sigma_clip<T>(data: &[T], sigma: f64, max_iters: usize) -> Vec<bool>
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let mut mask: Vec<bool> = vec![false; x.len()];
+
+    for _ in 0..max_iters {
+        let kept: Vec<f64> = x.iter().zip(&mask).filter(|(_, &m)| !m).map(|(&v, _)| v).collect();
+        if kept.is_empty() {
+            break;
+        }
+
+        let center: f64 = median(&kept);
+        let mean: f64 = summation::pairwise_sum(&kept) / kept.len() as f64;
+        let std: f64 = (summation::neumaier_sum(kept.iter().map(|v| (v - mean).powi(2))) / kept.len() as f64).sqrt();
+
+        let mut changed: bool = false;
+        for (v, m) in x.iter().zip(mask.iter_mut()) {
+            if !*m && (v - center).abs() > sigma * std {
+                *m = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    mask
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Median of a slice by selection, reordering it in place, the `NaN` values ranking above every number
+fn select_median(x: &mut [f64]) -> f64 {
+
+    let n: usize = x.len();
+    if n == 0 {
+        return f64::NAN;
+    }
+
+    let upper: f64 = kth_smallest(x, n / 2, NanPolicy::Largest);
+    if n % 2 == 1 { upper } else { 0.5 * (kth_smallest(&mut x[..n / 2], n / 2 - 1, NanPolicy::Largest) + upper) }
+}
+
+/// Median absolute deviation around a given center
+fn deviation(x: &[f64], center: f64) -> f64 {
+    let mut dev: Vec<f64> = x.iter().map(|v| (v - center).abs()).collect();
+    select_median(&mut dev)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
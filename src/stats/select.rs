@@ -0,0 +1,183 @@
+//!
+//! # Selection
+//!
+//! Order statistics of large arrays without a full sort. The `k`-th smallest value is found by quickselect
+//! (falling back on median of medians, as in `select_nth_unstable_by`), in linear time, which makes the
+//! medians and quantiles of multi-million-point samples far cheaper than sorting them. The `k` largest
+//! values and the partial sorts only sort the selected part.
+//!
+//! The missing values (`NaN`) follow a `NanPolicy`: they can be ignored, ranked below or above every
+//! number, or make the whole selection fail.
+//!
+//! ```
+//! # use scilib::stats::select::{ NanPolicy, kth_smallest, top_k };
+//! let mut data = vec![4.0, f64::NAN, 1.0, 7.0, 3.0, 9.0];
+//!
+//! assert_eq!(kth_smallest(&mut data, 1, NanPolicy::Omit), 3.0);
+//! assert!(kth_smallest(&mut data, 1, NanPolicy::Propagate).is_nan());
+//! assert_eq!(top_k(&data, 2, NanPolicy::Omit), vec![9.0, 7.0]);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::cmp::Ordering;     // Comparison of the values
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Handling of the NaN values
+///
+/// How the selection functions treat the `NaN` values of the data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// The `NaN` values are ignored, and the ranks count the numbers only
+    #[default]
+    Omit,
+    /// The `NaN` values rank below every number
+    Smallest,
+    /// The `NaN` values rank above every number, as in a sort by `total_cmp`
+    Largest,
+    /// A single `NaN` makes the selection fail: `NaN` for a value, and no value for a list
+    Propagate
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # K-th smallest value
+///
+/// Value of rank `k` (starting at 0) of `data`, in linear time. The slice is reordered in place: afterwards,
+/// the values before the returned one are all smaller or equal, and those after it larger or equal. With
+/// `NanPolicy::Omit`, the `NaN` values are moved to the end of the slice and `k` counts the numbers only.
+///
+/// Returns `NaN` when `k` is out of range, or when `data` holds a `NaN` with `NanPolicy::Propagate`.
+///
+/// ```
+/// # use scilib::stats::select::{ NanPolicy, kth_smallest };
+/// let mut data: Vec<f64> = (0..1000).map(|i| ((i * 7919) % 1000) as f64).collect();
+/// assert_eq!(kth_smallest(&mut data, 500, NanPolicy::Omit), 500.0);
+/// assert!(data[..500].iter().all(|&v| v < 500.0));
+///
+/// let mut data = vec![2.0, f64::NAN, -1.0];
+/// assert!(kth_smallest(&mut data, 0, NanPolicy::Smallest).is_nan());
+/// assert_eq!(kth_smallest(&mut data, 0, NanPolicy::Largest), -1.0);
+/// assert!(kth_smallest(&mut data, 2, NanPolicy::Omit).is_nan());
+/// ```
+pub fn kth_smallest(data: &mut [f64], k: usize, policy: NanPolicy) -> f64 {
+
+    let valid: &mut [f64] = match numbers(data, policy) {
+        Some(v) => v,
+        None => return f64::NAN
+    };
+
+    if k >= valid.len() {
+        return f64::NAN;
+    }
+
+    *valid.select_nth_unstable_by(k, order(policy)).1
+}
+
+/// # Largest values
+///
+/// The `k` largest values of `data`, in decreasing order, or all of them if there are fewer. Only the
+/// selected values are sorted, in `O(n + k log k)`, and `data` is left untouched.
+///
+/// Returns an empty vector when `data` holds a `NaN` with `NanPolicy::Propagate`.
+///
+/// ```
+/// # use scilib::stats::select::{ NanPolicy, top_k };
+/// let data = [0.3, 2.5, -1.0, 8.0, 2.5, 4.1];
+/// assert_eq!(top_k(&data, 3, NanPolicy::Omit), vec![8.0, 4.1, 2.5]);
+/// assert_eq!(top_k(&data, 10, NanPolicy::Omit).len(), 6);
+///
+/// let data = [1.0, f64::NAN, 3.0];
+/// assert!(top_k(&data, 1, NanPolicy::Largest)[0].is_nan());
+/// assert!(top_k(&data, 1, NanPolicy::Propagate).is_empty());
+/// ```
+pub fn top_k(data: &[f64], k: usize, policy: NanPolicy) -> Vec<f64> {
+
+    let mut x: Vec<f64> = data.to_vec();
+    let valid: &mut [f64] = match numbers(&mut x, policy) {
+        Some(v) => v,
+        None => return Vec::new()
+    };
+
+    // The largest values are the smallest for the reversed order
+    let cmp = order(policy);
+    let reversed = |a: &f64, b: &f64| cmp(b, a);
+    let k: usize = k.min(valid.len());
+    if k > 0 && k < valid.len() {
+        valid.select_nth_unstable_by(k - 1, reversed);
+    }
+
+    let mut res: Vec<f64> = valid[..k].to_vec();
+    res.sort_unstable_by(reversed);
+    res
+}
+
+/// # Partial sort
+///
+/// Moves the `k` smallest values of `data` to its beginning, in increasing order, the rest of the slice
+/// being left in an unspecified order. With `NanPolicy::Omit`, the `NaN` values are moved to the end.
+///
+/// Returns the number of sorted values, lower than `k` when there are not enough numbers, and zero without
+/// touching `data` when it holds a `NaN` with `NanPolicy::Propagate`.
+///
+/// ```
+/// # use scilib::stats::select::{ NanPolicy, partial_sort };
+/// let mut data = vec![5.0, 1.0, f64::NAN, 4.0, 2.0, 3.0];
+/// assert_eq!(partial_sort(&mut data, 3, NanPolicy::Omit), 3);
+/// assert_eq!(data[..3], [1.0, 2.0, 3.0]);
+/// assert!(data[5].is_nan());
+///
+/// assert_eq!(partial_sort(&mut data, 2, NanPolicy::Smallest), 2);
+/// assert!(data[0].is_nan() && data[1] == 1.0);
+/// ```
+pub fn partial_sort(data: &mut [f64], k: usize, policy: NanPolicy) -> usize {
+
+    let valid: &mut [f64] = match numbers(data, policy) {
+        Some(v) => v,
+        None => return 0
+    };
+
+    let cmp = order(policy);
+    let k: usize = k.min(valid.len());
+    if k > 0 && k < valid.len() {
+        valid.select_nth_unstable_by(k - 1, cmp);
+    }
+
+    valid[..k].sort_unstable_by(cmp);
+    k
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Part of the data taking part in the selection: the numbers moved to the front with `Omit`, the whole
+/// slice with the ranking policies, and `None` for a `NaN` with `Propagate`
+fn numbers(data: &mut [f64], policy: NanPolicy) -> Option<&mut [f64]> {
+    match policy {
+        NanPolicy::Omit => {
+            let mut count: usize = 0;
+            for idx in 0..data.len() {
+                if !data[idx].is_nan() {
+                    data.swap(count, idx);
+                    count += 1;
+                }
+            }
+            Some(&mut data[..count])
+        },
+        NanPolicy::Propagate if data.iter().any(|v| v.is_nan()) => None,
+        _ => Some(data)
+    }
+}
+
+/// Total order of the values, placing the `NaN` values as required by the policy
+fn order(policy: NanPolicy) -> impl Fn(&f64, &f64) -> Ordering + Copy {
+    move |a: &f64, b: &f64| match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.total_cmp(b),
+        (true, true) => Ordering::Equal,
+        (true, false) => if policy == NanPolicy::Smallest { Ordering::Less } else { Ordering::Greater },
+        (false, true) => if policy == NanPolicy::Smallest { Ordering::Greater } else { Ordering::Less }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,236 @@
+//!
+//! # Frequency stability
+//!
+//! Statistics of the fluctuations of clocks and oscillators, as functions of the averaging time `tau`. The
+//! classical variance diverges for the noises of most oscillators (flicker and random walk of the
+//! frequency), and is replaced by variances of the differences of successive frequency averages:
+//!
+//! - The Allan variance, from the second differences of the phase, converges for all the usual noises and
+//!   reports a linear frequency drift as `D tau / sqrt(2)`.
+//! - The modified Allan variance averages the phase over `tau` as well, which separates the white and
+//!   flicker phase noises.
+//! - The Hadamard variance, from the third differences, is insensitive to a linear frequency drift, and
+//!   converges for steeper noises.
+//!
+//! The estimators are the overlapping ones, taking the phase data `x` (time error, in seconds) sampled every
+//! `tau0`; frequency data are integrated beforehand by `phase_from_frequency`. The averaging time is
+//! `tau = m tau0`. The confidence intervals follow the chi-squared distribution of the variances, with the
+//! number of independent terms of each estimator as degrees of freedom, which is conservative for every
+//! type of noise.
+//!
+//! ```
+//! # use scilib::stats::stability::{ allan, hadamard, phase_from_frequency };
+//! // Oscillator with a frequency offset and a linear drift of 1e-12 per second
+//! let frequency: Vec<f64> = (0..1000).map(|i| 2.0e-9 + 1.0e-12 * i as f64).collect();
+//! let phase = phase_from_frequency(&frequency, 1.0);
+//!
+//! let dev = allan(&phase, 1.0, 10);
+//! assert_eq!(dev.tau, 10.0);
+//! assert!((dev.value - 1.0e-12 * 10.0 / 2f64.sqrt()).abs() < 1.0e-20);
+//! assert!(hadamard(&phase, 1.0, 10).value < 1.0e-20);
+//! ```
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use crate::math::basic;             // Chi-squared quantiles
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Frequency stability estimate
+///
+/// Deviation at an averaging time, with the degrees of freedom of its chi-squared distribution.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Deviation {
+    /// Averaging time
+    pub tau: f64,
+    /// Estimated deviation, `NaN` when the data are too short
+    pub value: f64,
+    /// Degrees of freedom of the variance
+    pub dof: f64
+}
+
+/// Implementing required methods
+impl Deviation {
+    /// # Confidence interval
+    ///
+    /// Bounds of the deviation at the `confidence` level (e.g. `0.683` for one sigma, `0.95`), from the
+    /// quantiles of the chi-squared distribution: `value^2 dof / chi2` covers the true variance with the
+    /// given probability. Returns `NaN` bounds without degrees of freedom.
+    ///
+    /// ```
+    /// # use scilib::stats::stability::Deviation;
+    /// let dev = Deviation { tau: 10.0, value: 2.0e-12, dof: 99.0 };
+    /// let (lower, upper) = dev.interval(0.95);
+    ///
+    /// assert!((lower / 2.0e-12 - 0.87800684540387068).abs() < 1.0e-12);
+    /// assert!((upper / 2.0e-12 - 1.1616752552946210).abs() < 1.0e-12);
+    /// ```
+    pub fn interval(&self, confidence: f64) -> (f64, f64) {
+
+        assert!(confidence > 0.0 && confidence < 1.0, "The confidence level must be between 0 and 1");
+        if self.dof <= 0.0 || self.value.is_nan() {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let half: f64 = 0.5 * self.dof;
+        let high: f64 = 2.0 * basic::gammaincinv(half, 0.5 + 0.5 * confidence);
+        let low: f64 = 2.0 * basic::gammaincinv(half, 0.5 - 0.5 * confidence);
+
+        (self.value * (self.dof / high).sqrt(), self.value * (self.dof / low).sqrt())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Phase from frequency data
+///
+/// Integrates the fractional frequency `y`, averaged over successive intervals of `tau0`, into the phase
+/// (time error) `x`, starting from 0: `x[i + 1] = x[i] + y[i] tau0`. The phase has one more value than the
+/// frequency.
+///
+/// ```
+/// # use scilib::stats::stability::phase_from_frequency;
+/// let res = phase_from_frequency(&[1.0e-9, 3.0e-9, -2.0e-9], 10.0);
+/// assert_eq!(res.len(), 4);
+/// assert!((res[3] - 2.0e-8).abs() < 1.0e-22);
+/// ```
+pub fn phase_from_frequency<T>(frequency: &[T], tau0: f64) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let mut res: Vec<f64> = Vec::with_capacity(frequency.len() + 1);
+    let mut x: f64 = 0.0;
+    res.push(x);
+    for &y in frequency {
+        x += y.into() * tau0;
+        res.push(x);
+    }
+
+    res
+}
+
+/// # Allan deviation
+///
+/// Overlapping Allan deviation of the `phase` data, sampled every `tau0`, at the averaging time `m tau0`:
+/// `sigma^2 = sum (x[i+2m] - 2x[i+m] + x[i])^2 / (2 tau^2 (N - 2m))`.
+///
+/// ```
+/// # use scilib::math::sde::Noise;
+/// # use scilib::stats::stability::allan;
+/// // White frequency noise from a seeded generator: the deviation falls as 1 / sqrt(tau)
+/// let mut noise = Noise::new(12345);
+/// let mut phase: Vec<f64> = vec![0.0];
+/// for _ in 0..20_000 {
+///     phase.push(phase.last().unwrap() + noise.uniform() - 0.5);
+/// }
+///
+/// let (short, long) = (allan(&phase, 1.0, 1), allan(&phase, 1.0, 100));
+/// assert!((short.value / long.value / 10.0 - 1.0).abs() < 0.2);
+///
+/// let (lower, upper) = long.interval(0.95);
+/// assert!(lower < long.value && long.value < upper);
+/// ```
+pub fn allan<T>(phase: &[T], tau0: f64, m: usize) -> Deviation
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = values(phase);
+    let n: usize = x.len();
+    let tau: f64 = m as f64 * tau0;
+    if m == 0 || n < 2 * m + 1 {
+        return Deviation { tau, value: f64::NAN, dof: 0.0 };
+    }
+
+    let terms: usize = n - 2 * m;
+    let sum: f64 = (0..terms).map(|i| (x[i + 2 * m] - 2.0 * x[i + m] + x[i]).powi(2)).sum();
+    let variance: f64 = sum / (2.0 * tau * tau * terms as f64);
+
+    Deviation { tau, value: variance.sqrt(), dof: independent(n, m, 2) }
+}
+
+/// # Modified Allan deviation
+///
+/// Modified Allan deviation of the `phase` data, sampled every `tau0`, at the averaging time `m tau0`: the
+/// second differences are averaged over `m` successive starting points before being squared,
+/// `sigma^2 = sum_j (sum_(i=j)^(j+m-1) (x[i+2m] - 2x[i+m] + x[i]))^2 / (2 m^2 tau^2 (N - 3m + 1))`.
+///
+/// It equals the Allan deviation for `m = 1`, and falls faster with `tau` for the white phase noise.
+///
+/// ```
+/// # use scilib::stats::stability::{ allan, modified_allan };
+/// let phase: Vec<f64> = (0..500).map(|i| 0.5 * 1.0e-12 * (i * i) as f64).collect();
+/// assert!((modified_allan(&phase, 1.0, 20).value - 20.0e-12 / 2f64.sqrt()).abs() < 1.0e-22);
+/// assert_eq!(modified_allan(&phase, 1.0, 1).value, allan(&phase, 1.0, 1).value);
+/// ```
+pub fn modified_allan<T>(phase: &[T], tau0: f64, m: usize) -> Deviation
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = values(phase);
+    let n: usize = x.len();
+    let tau: f64 = m as f64 * tau0;
+    if m == 0 || n < 3 * m {
+        return Deviation { tau, value: f64::NAN, dof: 0.0 };
+    }
+
+    // Running sum of the second differences over m starting points
+    let second = |i: usize| x[i + 2 * m] - 2.0 * x[i + m] + x[i];
+    let mut inner: f64 = (0..m).map(second).sum();
+    let mut sum: f64 = inner * inner;
+    for j in 1..=(n - 3 * m) {
+        inner += second(j + m - 1) - second(j - 1);
+        sum += inner * inner;
+    }
+
+    let terms: usize = n - 3 * m + 1;
+    let variance: f64 = sum / (2.0 * (m * m) as f64 * tau * tau * terms as f64);
+
+    Deviation { tau, value: variance.sqrt(), dof: independent(n, m, 3) }
+}
+
+/// # Hadamard deviation
+///
+/// Overlapping Hadamard deviation of the `phase` data, sampled every `tau0`, at the averaging time `m tau0`:
+/// `sigma^2 = sum (x[i+3m] - 3x[i+2m] + 3x[i+m] - x[i])^2 / (6 tau^2 (N - 3m))`. The third differences
+/// remove a linear frequency drift, as found in rubidium clocks.
+///
+/// ```
+/// # use scilib::stats::stability::{ allan, hadamard };
+/// // Sinusoidal phase modulation on top of a frequency drift
+/// let phase: Vec<f64> = (0..400).map(|i| {
+///     let t: f64 = i as f64;
+///     0.5 * 1.0e-10 * t * t + 1.0e-9 * (std::f64::consts::TAU * t / 50.0).sin()
+/// }).collect();
+///
+/// let (had, adev) = (hadamard(&phase, 1.0, 5), allan(&phase, 1.0, 5));
+/// assert!(had.value < 2.0e-10 && adev.value > 3.0e-10);
+/// ```
+pub fn hadamard<T>(phase: &[T], tau0: f64, m: usize) -> Deviation
+where T: Into<f64> + Copy {
+
+    let x: Vec<f64> = values(phase);
+    let n: usize = x.len();
+    let tau: f64 = m as f64 * tau0;
+    if m == 0 || n < 3 * m + 1 {
+        return Deviation { tau, value: f64::NAN, dof: 0.0 };
+    }
+
+    let terms: usize = n - 3 * m;
+    let sum: f64 = (0..terms).map(|i| (x[i + 3 * m] - 3.0 * x[i + 2 * m] + 3.0 * x[i + m] - x[i]).powi(2)).sum();
+    let variance: f64 = sum / (6.0 * tau * tau * terms as f64);
+
+    Deviation { tau, value: variance.sqrt(), dof: independent(n, m, 3) }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Phase data as `f64`
+fn values<T>(phase: &[T]) -> Vec<f64>
+where T: Into<f64> + Copy {
+    phase.iter().map(|&v| v.into()).collect()
+}
+
+/// Number of non-overlapping differences of the given `order` of the `(n - 1) / m` frequency averages
+fn independent(n: usize, m: usize, order: usize) -> f64 {
+    ((n - 1) / m).saturating_sub(order - 1) as f64
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
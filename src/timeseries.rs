@@ -0,0 +1,491 @@
+//!
+//! # Time series
+//!
+//! Tools for the analysis of evenly sampled series: trend removal, differencing, autocovariance, and
+//! autoregressive (AR) modelling with the associated spectral estimate. Periodic signals, such as light
+//! curves or tides, can also be fitted with truncated Fourier series, from regular or irregular samples.
+//!
+//! AR models are written as `x[t] = a[1] x[t-1] + ... + a[p] x[t-p] + e[t]`, where `e` is a white noise
+//! of variance `sigma^2`. They give smooth spectra from short records, and handle red-noise dominated
+//! series better than the raw periodogram.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::TAU;          // 2 * Pi
+
+use crate::math::complex::Complex;  // Evaluating the AR transfer function
+
+use crate::math::eigen::solve;      // Normal equations of the least squares fits
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Polynomial detrending
+///
+/// Removes the least squares polynomial of the given `degree` from the data, the abscissa being the sample
+/// index. A `degree` of 0 removes the mean, and 1 a linear trend.
+///
+/// ```
+/// # use scilib::timeseries::detrend;
+/// let data: Vec<f64> = (0..50).map(|i| 3.0 + 0.5 * i as f64 + (i as f64).sin()).collect();
+/// let res = detrend(&data, 1);
+///
+/// // The mean and slope of the residuals are null
+/// let n = res.len() as f64;
+/// let mean: f64 = res.iter().sum::<f64>() / n;
+/// let slope: f64 = res.iter().enumerate().map(|(i, r)| (i as f64 - (n - 1.0) / 2.0) * r).sum();
+///
+/// assert!(mean.abs() < 1.0e-12);
+/// assert!(slope.abs() < 1.0e-10);
+/// ```
+pub fn detrend<T>(data: &[T], degree: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let n: usize = data.len();
+    let y: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+
+    if n == 0 {
+        return y;
+    }
+
+    // Mapping the index to [-1, 1] to keep the normal equations well conditioned
+    let half: f64 = ((n - 1) as f64 / 2.0).max(1.0);
+    let x: Vec<f64> = (0..n).map(|i| (i as f64 - (n - 1) as f64 / 2.0) / half).collect();
+    let m: usize = degree.min(n - 1) + 1;
+
+    // Building the normal equations of the least squares problem
+    let mut mat: Vec<Vec<f64>> = vec![vec![0.0; m + 1]; m];
+    for (xi, yi) in x.iter().zip(&y) {
+        let pw: Vec<f64> = (0..m).map(|k| xi.powi(k as i32)).collect();
+        for r in 0..m {
+            for c in 0..m {
+                mat[r][c] += pw[r] * pw[c];
+            }
+            mat[r][m] += pw[r] * yi;
+        }
+    }
+
+    // The distinct abscissas keep the system regular, up to the rounding of very large degrees
+    let coef: Vec<f64> = solve(mat).unwrap_or_else(|| vec![f64::NAN; m]);
+
+    x.iter().zip(&y).map(|(xi, yi)| {
+        yi - coef.iter().rev().fold(0.0, |res, c| res * xi + c)
+    }).collect()
+}
+
+/// # Differencing
+///
+/// Returns `y[t] - y[t - lag]`, which has `lag` fewer elements than the input. A `lag` of 1 gives the
+/// ordinary first difference, and a `lag` equal to the period removes a seasonal component. Higher orders
+/// are obtained by applying the function repeatedly.
+///
+/// ```
+/// # use scilib::timeseries::difference;
+/// // Linear trend plus a seasonal pattern of period 4
+/// let season = [1.0, -2.0, 0.5, 0.5];
+/// let data: Vec<f64> = (0..20).map(|i| 2.0 * i as f64 + season[i % 4]).collect();
+/// let res = difference(&data, 4);
+///
+/// assert_eq!(res.len(), 16);
+/// assert!(res.iter().all(|&v| v == 8.0));
+/// ```
+pub fn difference<T>(data: &[T], lag: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+    data.iter().skip(lag).zip(data).map(|(&a, &b)| a.into() - b.into()).collect()
+}
+
+/// # Autocovariance
+///
+/// Computes the biased estimate `c[k] = 1/n sum (x[t] - m)(x[t+k] - m)` for `k` from 0 to `max_lag`,
+/// where `m` is the mean of the series. The biased form guarantees a positive definite sequence, as
+/// required by the Yule–Walker equations.
+///
+/// ```
+/// # use scilib::timeseries::autocovariance;
+/// let res = autocovariance(&[1.0, 2.0, 3.0, 4.0], 2);
+///
+/// assert_eq!(res, vec![1.25, 0.3125, -0.375]);
+/// ```
+pub fn autocovariance<T>(data: &[T], max_lag: usize) -> Vec<f64>
+where T: Into<f64> + Copy {
+
+    let n: usize = data.len();
+    let y: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+    let mean: f64 = y.iter().sum::<f64>() / n as f64;
+
+    (0..=max_lag).map(|k| {
+        if k >= n {
+            return 0.0;
+        }
+        y.iter().zip(&y[k..]).map(|(a, b)| (a - mean) * (b - mean)).sum::<f64>() / n as f64
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Autoregressive model
+///
+/// Fitted with either `Ar::yule_walker` or `Ar::burg`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ar {
+    /// Coefficients `a[1]` to `a[p]`
+    pub coefficients: Vec<f64>,
+    /// Variance of the driving white noise
+    pub variance: f64,
+    /// Mean removed from the series before fitting
+    pub mean: f64
+}
+
+/// Implementing required methods
+impl Ar {
+    /// # Yule–Walker estimation
+    ///
+    /// Solves the Yule–Walker equations built from the biased autocovariance of the series, with the
+    /// Levinson–Durbin recursion. The resulting model is always stable, but the estimate is biased for short
+    /// records or nearly non-stationary processes; prefer `burg` in those cases.
+    ///
+    /// - `data`: the series
+    /// - `order`: the order `p` of the model
+    ///
+    /// ```
+    /// # use scilib::timeseries::Ar;
+    /// // Impulse response of an AR(1) process
+    /// let data: Vec<f64> = (0..100).map(|i| 0.6f64.powi(i)).collect();
+    /// let model = Ar::yule_walker(&data, 1);
+    ///
+    /// assert!((model.coefficients[0] - 0.6).abs() < 1.0e-3);
+    /// ```
+    pub fn yule_walker<T>(data: &[T], order: usize) -> Self
+    where T: Into<f64> + Copy {
+
+        let n: usize = data.len();
+        let mean: f64 = data.iter().map(|&v| v.into()).sum::<f64>() / n as f64;
+        let r: Vec<f64> = autocovariance(data, order);
+
+        let mut a: Vec<f64> = vec![];
+        let mut err: f64 = r[0];
+
+        // Levinson-Durbin recursion
+        for k in 1..=order {
+            let acc: f64 = a.iter().enumerate().fold(r[k], |res, (j, aj)| res - aj * r[k - 1 - j]);
+            let refl: f64 = if err > 0.0 { acc / err } else { 0.0 };
+
+            let prev: Vec<f64> = a.clone();
+            for j in 0..(k - 1) {
+                a[j] = prev[j] - refl * prev[k - 2 - j];
+            }
+            a.push(refl);
+            err *= 1.0 - refl.powi(2);
+        }
+
+        Self {
+            coefficients: a,
+            variance: err,
+            mean
+        }
+    }
+
+    /// # Burg estimation
+    ///
+    /// Estimates the reflection coefficients by minimizing the sum of the forward and backward prediction
+    /// errors at each stage. It does not assume that the data is zero outside the record, and gives better
+    /// resolved spectra than Yule–Walker for short series.
+    ///
+    /// - `data`: the series
+    /// - `order`: the order `p` of the model
+    ///
+    /// ```
+    /// # use scilib::timeseries::Ar;
+    /// // A pure sinusoid follows x[t] = 2 cos(w) x[t-1] - x[t-2]
+    /// let w: f64 = 0.3;
+    /// let data: Vec<f64> = (0..200).map(|t| (w * t as f64).sin()).collect();
+    /// let model = Ar::burg(&data, 2);
+    ///
+    /// assert!((model.coefficients[0] - 2.0 * w.cos()).abs() < 1.0e-3);
+    /// assert!((model.coefficients[1] + 1.0).abs() < 1.0e-3);
+    /// ```
+    pub fn burg<T>(data: &[T], order: usize) -> Self
+    where T: Into<f64> + Copy {
+
+        let n: usize = data.len();
+        let y: Vec<f64> = data.iter().map(|&v| v.into()).collect();
+        let mean: f64 = y.iter().sum::<f64>() / n as f64;
+
+        let mut f: Vec<f64> = y.iter().map(|v| v - mean).collect();
+        let mut b: Vec<f64> = f.clone();
+        let mut err: f64 = f.iter().map(|v| v.powi(2)).sum::<f64>() / n as f64;
+        let mut a: Vec<f64> = vec![];
+
+        for k in 1..=order.min(n.saturating_sub(1)) {
+            // Forward errors are f[k..n], backward errors are b[k-1..n-1]
+            let mut num: f64 = 0.0;
+            let mut den: f64 = 0.0;
+            for t in k..n {
+                num += f[t] * b[t - 1];
+                den += f[t].powi(2) + b[t - 1].powi(2);
+            }
+            let refl: f64 = if den > 0.0 { 2.0 * num / den } else { 0.0 };
+
+            // Updating the errors from the end, to keep the previous backward values
+            for t in (k..n).rev() {
+                let (ft, bt): (f64, f64) = (f[t], b[t - 1]);
+                f[t] = ft - refl * bt;
+                b[t] = bt - refl * ft;
+            }
+
+            let prev: Vec<f64> = a.clone();
+            for j in 0..(k - 1) {
+                a[j] = prev[j] - refl * prev[k - 2 - j];
+            }
+            a.push(refl);
+            err *= 1.0 - refl.powi(2);
+        }
+
+        Self {
+            coefficients: a,
+            variance: err,
+            mean
+        }
+    }
+
+    /// # Order of the model
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// # Power spectral density
+    ///
+    /// Computes `sigma^2 / |1 - sum a[k] exp(-2i pi f k)|^2`, the two-sided spectral density of the model
+    /// at the frequency `freq`, in cycles per sample (from -0.5 to 0.5). Multiply by the sampling interval
+    /// to get physical units.
+    ///
+    /// ```
+    /// # use scilib::timeseries::Ar;
+    /// let model = Ar { coefficients: vec![0.9], variance: 1.0, mean: 0.0 };
+    ///
+    /// // Red noise: the power is concentrated at low frequencies
+    /// assert!((model.psd(0.0) - 100.0).abs() < 1.0e-10);
+    /// assert!((model.psd(0.5) - 1.0 / 3.61).abs() < 1.0e-12);
+    /// ```
+    pub fn psd(&self, freq: f64) -> f64 {
+        let den: Complex = self.coefficients.iter().enumerate().fold(Complex::unity(), |res, (k, a)| {
+            res - Complex::from_polar(-TAU * freq * (k + 1) as f64, *a)
+        });
+
+        self.variance / den.modulus().powi(2)
+    }
+
+    /// # One step prediction
+    ///
+    /// Predicts the next value of the series from its last `p` values (`history` is in chronological order).
+    pub fn predict(&self, history: &[f64]) -> f64 {
+        self.coefficients.iter().zip(history.iter().rev())
+            .fold(self.mean, |res, (a, x)| res + a * (x - self.mean))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Truncated Fourier series
+///
+/// `f(t) = mean + sum_(k=1)^n (a_k cos(k w t) + b_k sin(k w t))`, with `w = 2 pi / period`. Fitted with either
+/// `FourierSeries::fit` for arbitrary sampling times, or `FourierSeries::from_samples` for evenly spaced
+/// samples covering one period.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FourierSeries {
+    /// Period of the series
+    pub period: f64,
+    /// Constant term
+    pub mean: f64,
+    /// Cosine coefficients `a_1` to `a_n`
+    pub cosines: Vec<f64>,
+    /// Sine coefficients `b_1` to `b_n`
+    pub sines: Vec<f64>
+}
+
+/// Implementing required methods
+impl FourierSeries {
+    /// # Least squares fit
+    ///
+    /// Fits the series with `harmonics` harmonics of the given `period` to samples taken at arbitrary
+    /// `times`, as is usual for light curves or tide gauge records with gaps. The number of harmonics is
+    /// capped so that the problem stays determined; the coefficients are `NaN` if the times still leave it
+    /// singular, as when they all fall on the same phase.
+    ///
+    /// ```
+    /// # use scilib::timeseries::FourierSeries;
+    /// // Irregular sampling of a signal with two harmonics
+    /// let times: Vec<f64> = (0..60).map(|i| i as f64 * 0.37 + 0.1 * (i as f64).sin()).collect();
+    /// let values: Vec<f64> = times.iter().map(|t| {
+    ///     1.5 + 2.0 * (t * 0.5).cos() - 0.7 * (t * 1.0).sin()
+    /// }).collect();
+    ///
+    /// let series = FourierSeries::fit(&times, &values, 4.0 * std::f64::consts::PI, 3);
+    /// assert!((series.mean - 1.5).abs() < 1.0e-12);
+    /// assert!((series.cosines[0] - 2.0).abs() < 1.0e-12);
+    /// assert!((series.sines[1] - -0.7).abs() < 1.0e-12);
+    /// assert!(series.cosines[2].abs() < 1.0e-12);
+    ///
+    /// // Samples on a single phase leave the harmonics undetermined
+    /// let flat = FourierSeries::fit(&[0.0, 1.0, 2.0, 3.0], &[1.0, 2.0, 1.0, 2.0], 1.0, 1);
+    /// assert!(flat.mean.is_nan());
+    /// ```
+    pub fn fit<T, U>(times: &[T], values: &[U], period: f64, harmonics: usize) -> Self
+    where T: Into<f64> + Copy, U: Into<f64> + Copy {
+
+        let n: usize = times.len().min(values.len());
+        let harmonics: usize = harmonics.min(n.saturating_sub(1) / 2);
+        let m: usize = 2 * harmonics + 1;
+        let w: f64 = TAU / period;
+
+        // Building the normal equations, with the basis 1, cos(wt), sin(wt), cos(2wt), ...
+        let mut mat: Vec<Vec<f64>> = vec![vec![0.0; m + 1]; m];
+        for (t, y) in times.iter().zip(values).take(n) {
+            let (t, y): (f64, f64) = ((*t).into(), (*y).into());
+            let mut basis: Vec<f64> = vec![1.0];
+            for k in 1..=harmonics {
+                let (s, c): (f64, f64) = (k as f64 * w * t).sin_cos();
+                basis.push(c);
+                basis.push(s);
+            }
+            for r in 0..m {
+                for c in 0..m {
+                    mat[r][c] += basis[r] * basis[c];
+                }
+                mat[r][m] += basis[r] * y;
+            }
+        }
+
+        let coef: Vec<f64> = if n == 0 { vec![0.0] } else { solve(mat).unwrap_or_else(|| vec![f64::NAN; m]) };
+
+        Self {
+            period,
+            mean: coef[0],
+            cosines: coef.iter().skip(1).step_by(2).copied().collect(),
+            sines: coef.iter().skip(2).step_by(2).copied().collect()
+        }
+    }
+
+    /// # Fit to evenly spaced samples
+    ///
+    /// The `values` are taken at `t = j period / N`, for `j` from 0 to `N - 1`, covering one period. The
+    /// coefficients are then the discrete Fourier transform of the samples, which coincides with the least
+    /// squares fit. The number of harmonics is capped at the Nyquist limit `N / 2`.
+    ///
+    /// ```
+    /// # use scilib::timeseries::FourierSeries;
+    /// let values: Vec<f64> = (0..16).map(|j| {
+    ///     let t: f64 = j as f64 / 16.0;
+    ///     0.25 + (std::f64::consts::TAU * 3.0 * t + 0.4).cos()
+    /// }).collect();
+    ///
+    /// let series = FourierSeries::from_samples(&values, 1.0, 8);
+    /// assert!((series.mean - 0.25).abs() < 1.0e-15);
+    /// assert!((series.amplitude(3) - 1.0).abs() < 1.0e-14);
+    /// assert!((series.phase(3) - -0.4).abs() < 1.0e-14);
+    /// assert!(series.amplitude(5) < 1.0e-15);
+    ///
+    /// // The series interpolates the samples
+    /// assert!((series.eval(5.0 / 16.0) - values[5]).abs() < 1.0e-14);
+    /// ```
+    pub fn from_samples<T>(values: &[T], period: f64, harmonics: usize) -> Self
+    where T: Into<f64> + Copy {
+
+        let n: usize = values.len();
+        let harmonics: usize = harmonics.min(n / 2);
+        let y: Vec<f64> = values.iter().map(|&v| v.into()).collect();
+
+        let mut cosines: Vec<f64> = Vec::with_capacity(harmonics);
+        let mut sines: Vec<f64> = Vec::with_capacity(harmonics);
+        for k in 1..=harmonics {
+            let (mut a, mut b): (f64, f64) = (0.0, 0.0);
+            for (j, v) in y.iter().enumerate() {
+                // Reducing k j modulo n keeps the angles small
+                let (s, c): (f64, f64) = (TAU * ((k * j) % n) as f64 / n as f64).sin_cos();
+                a += v * c;
+                b += v * s;
+            }
+
+            // The Nyquist harmonic is only seen through its cosine, with half the weight
+            let norm: f64 = if 2 * k == n { 1.0 / n as f64 } else { 2.0 / n as f64 };
+            cosines.push(a * norm);
+            sines.push(if 2 * k == n { 0.0 } else { b * norm });
+        }
+
+        Self {
+            period,
+            mean: if n == 0 { 0.0 } else { y.iter().sum::<f64>() / n as f64 },
+            cosines,
+            sines
+        }
+    }
+
+    /// # Number of harmonics
+    pub fn harmonics(&self) -> usize {
+        self.cosines.len()
+    }
+
+    /// # Evaluation
+    ///
+    /// Value of the series at the time `t`.
+    pub fn eval(&self, t: f64) -> f64 {
+        let w: f64 = TAU / self.period;
+        self.cosines.iter().zip(&self.sines).enumerate().fold(self.mean, |res, (k, (a, b))| {
+            let (s, c): (f64, f64) = ((k + 1) as f64 * w * t).sin_cos();
+            res + a * c + b * s
+        })
+    }
+
+    /// # Derivative
+    ///
+    /// Series of the time derivative, obtained term by term.
+    ///
+    /// ```
+    /// # use scilib::timeseries::FourierSeries;
+    /// let series = FourierSeries { period: 2.0, mean: 1.0, cosines: vec![0.5, 0.0], sines: vec![0.0, 2.0] };
+    /// let res = series.derivative();
+    ///
+    /// // d/dt (0.5 cos(pi t) + 2 sin(2 pi t))
+    /// let t: f64 = 0.3;
+    /// let pi = std::f64::consts::PI;
+    /// let expected: f64 = -0.5 * pi * (pi * t).sin() + 4.0 * pi * (2.0 * pi * t).cos();
+    /// assert!((res.eval(t) - expected).abs() < 1.0e-14);
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let w: f64 = TAU / self.period;
+        let scale = |k: usize| (k + 1) as f64 * w;
+
+        Self {
+            period: self.period,
+            mean: 0.0,
+            cosines: self.sines.iter().enumerate().map(|(k, b)| b * scale(k)).collect(),
+            sines: self.cosines.iter().enumerate().map(|(k, a)| -a * scale(k)).collect()
+        }
+    }
+
+    /// # Amplitude of a harmonic
+    ///
+    /// `A_k = sqrt(a_k^2 + b_k^2)` for the harmonic `k >= 1`, so that its term reads `A_k cos(k w t - phi_k)`.
+    /// Returns the absolute value of the mean for `k = 0`, and 0 past the last harmonic.
+    pub fn amplitude(&self, k: usize) -> f64 {
+        match k {
+            0 => self.mean.abs(),
+            _ if k > self.harmonics() => 0.0,
+            _ => self.cosines[k - 1].hypot(self.sines[k - 1])
+        }
+    }
+
+    /// # Phase of a harmonic
+    ///
+    /// `phi_k = atan2(b_k, a_k)`, in `[-pi, pi]`, so that the term of the harmonic `k >= 1` reads
+    /// `A_k cos(k w t - phi_k)`. Returns 0 for `k = 0` and past the last harmonic.
+    pub fn phase(&self, k: usize) -> f64 {
+        if k == 0 || k > self.harmonics() {
+            0.0
+        } else {
+            self.sines[k - 1].atan2(self.cosines[k - 1])
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
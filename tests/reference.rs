@@ -0,0 +1,96 @@
+//!
+//! # Accuracy regression tests
+//!
+//! Compares the special functions to the reference tables of `tests/data`, generated with mpmath at 50
+//! digits by `tests/data/generate.py`.
+//!
+//! Each function has a documented budget reflecting the accuracy of the current implementation, with some
+//! margin: a change degrading the results beyond it fails the test. Budgets are given in ULP where the
+//! implementation is close to machine precision, in relative or absolute error otherwise. Run with
+//! `--nocapture` to print the accuracy currently reached.
+//!
+
+use scilib::reference::{ parse_table, check_table, Report };
+
+use scilib::math::basic;
+
+#[cfg(feature = "bessel")]
+use scilib::math::bessel;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Gamma, relative error (Euler product, measured 1.4e-5)
+const GAMMA_REL: f64 = 2.0e-5;
+
+/// Error function, in ULP (series and continued fraction, measured 1198)
+const ERF_ULP: u64 = 2_000;
+
+/// Bessel J of integer order, relative error (series, measured 4.9e-10)
+#[cfg(feature = "bessel")]
+const BESSEL_J_REL: f64 = 1.0e-9;
+
+/// Bessel J of real order, relative error (integral form, measured 2.7e-6)
+#[cfg(feature = "bessel")]
+const BESSEL_JF_REL: f64 = 5.0e-6;
+
+/// Bessel Y, absolute error as the tables sample its zeros (measured 1.9e-4)
+#[cfg(feature = "bessel")]
+const BESSEL_Y_ABS: f64 = 5.0e-4;
+
+/// Bessel I, relative error (measured 2.5e-6)
+#[cfg(feature = "bessel")]
+const BESSEL_I_REL: f64 = 5.0e-6;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Checks a table and prints the accuracy reached
+fn run<F>(name: &str, table: &str, f: F) -> Report
+where F: Fn(&[f64]) -> f64 {
+    let report: Report = check_table(&parse_table(table), f);
+    println!("{}: {} rows, max {} ulp, max abs {:e}, max rel {:e}, worst {:?}",
+        name, report.count, report.max_ulp, report.max_abs, report.max_rel, report.worst);
+    assert!(report.count > 0, "Empty reference table for {}", name);
+    report
+}
+
+#[test]
+fn gamma() {
+    let report: Report = run("gamma", include_str!("data/gamma.txt"), |r| basic::gamma(r[0]));
+    assert!(report.max_rel <= GAMMA_REL, "worst case {:?}", report.worst);
+}
+
+#[test]
+fn erf() {
+    let report: Report = run("erf", include_str!("data/erf.txt"), |r| basic::erf(r[0]).re);
+    assert!(report.max_ulp <= ERF_ULP, "worst case {:?}", report.worst);
+}
+
+#[test]
+#[cfg(feature = "bessel")]
+fn bessel_j() {
+    let report: Report = run("bessel_j", include_str!("data/bessel_j.txt"), |r| bessel::j(r[1], r[0] as i32).re);
+    assert!(report.max_rel <= BESSEL_J_REL, "worst case {:?}", report.worst);
+}
+
+#[test]
+#[cfg(feature = "bessel")]
+fn bessel_jf() {
+    let report: Report = run("bessel_jf", include_str!("data/bessel_jf.txt"), |r| bessel::jf(r[1], r[0]).re);
+    assert!(report.max_rel <= BESSEL_JF_REL, "worst case {:?}", report.worst);
+}
+
+#[test]
+#[cfg(feature = "bessel")]
+fn bessel_y() {
+    let report: Report = run("bessel_y", include_str!("data/bessel_y.txt"), |r| bessel::y(r[1], r[0]).re);
+    assert!(report.max_abs <= BESSEL_Y_ABS, "worst case {:?}", report.worst);
+}
+
+#[test]
+#[cfg(feature = "bessel")]
+fn bessel_i() {
+    let report: Report = run("bessel_i", include_str!("data/bessel_i.txt"), |r| bessel::i(r[1], r[0]).re);
+    assert!(report.max_rel <= BESSEL_I_REL, "worst case {:?}", report.worst);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////